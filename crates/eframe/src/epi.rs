@@ -521,6 +521,16 @@ pub struct WebOptions {
     /// Maximum rate at which to repaint. This can be used to artificially reduce the repaint rate below
     /// vsync in order to save resources.
     pub max_fps: Option<u32>,
+
+    /// Prefix for the keys that eframe and your app use in local storage.
+    ///
+    /// When running several apps on the same page, give each one its own prefix
+    /// so that they don't overwrite each other's data.
+    ///
+    /// With `Some("left")`, the key `"egui_memory_ron"` becomes `"left/egui_memory_ron"`.
+    ///
+    /// Default: `None`.
+    pub storage_prefix: Option<String>,
 }
 
 #[cfg(target_arch = "wasm32")]
@@ -547,6 +557,8 @@ impl Default for WebOptions {
             should_prevent_default: Box::new(|_| true),
 
             max_fps: None,
+
+            storage_prefix: None,
         }
     }
 }
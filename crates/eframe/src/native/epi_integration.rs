@@ -17,12 +17,15 @@ pub fn viewport_builder(
     egui_zoom_factor: f32,
     event_loop: &ActiveEventLoop,
     native_options: &mut epi::NativeOptions,
-    window_settings: Option<WindowSettings>,
+    window_settings: Option<&mut WindowSettings>,
 ) -> ViewportBuilder {
     profiling::function_scope!();
 
     let mut viewport_builder = native_options.viewport.clone();
 
+    // Start hidden until we render the first frame to fix white flash on startup (https://github.com/emilk/egui/pull/3631)
+    viewport_builder.show_when_ready.get_or_insert(true);
+
     // On some Linux systems, a window size larger than the monitor causes crashes,
     // and on Windows the window does not appear at all.
     let clamp_size_to_monitor_size = viewport_builder.clamp_size_to_monitor_size.unwrap_or(true);
@@ -30,7 +33,7 @@ pub fn viewport_builder(
     // Always use the default window size / position on iOS. Trying to restore the previous position
     // causes the window to be shown too small.
     #[cfg(not(target_os = "ios"))]
-    let inner_size_points = if let Some(mut window_settings) = window_settings {
+    let inner_size_points = if let Some(window_settings) = window_settings {
         // Restore pos/size from previous session
 
         if clamp_size_to_monitor_size {
@@ -90,12 +93,13 @@ pub fn viewport_builder(
 }
 
 pub fn apply_window_settings(
+    egui_zoom_factor: f32,
     window: &winit::window::Window,
-    window_settings: Option<WindowSettings>,
+    window_settings: Option<&WindowSettings>,
 ) {
     profiling::function_scope!();
     if let Some(window_settings) = window_settings {
-        window_settings.initialize_window(window);
+        window_settings.initialize_window(egui_zoom_factor, window);
     }
 }
 
@@ -154,7 +158,6 @@ pub struct EpiIntegration {
     pub frame: epi::Frame,
     last_auto_save: Instant,
     pub beginning: Instant,
-    is_first_frame: bool,
     pub egui_ctx: egui::Context,
     pending_full_output: egui::FullOutput,
 
@@ -224,7 +227,6 @@ impl EpiIntegration {
             beginning: Instant::now()
                 .checked_sub(web_time::Duration::from_secs_f64(egui_ctx.time()))
                 .unwrap_or_else(Instant::now),
-            is_first_frame: true,
             egui_ctx,
         }
     }
@@ -319,14 +321,6 @@ impl EpiIntegration {
         self.frame.info.cpu_usage = Some(seconds);
     }
 
-    pub fn post_rendering(&mut self, window: &winit::window::Window) {
-        profiling::function_scope!();
-        if std::mem::take(&mut self.is_first_frame) {
-            // We keep hidden until we've painted something. See https://github.com/emilk/egui/pull/2279
-            window.set_visible(true);
-        }
-    }
-
     // ------------------------------------------------------------------------
     // Persistence stuff:
 
@@ -104,6 +104,11 @@ struct GlutinWindowContext {
     window_from_viewport: OrderedViewportIdMap<WindowId>,
 
     focused_viewport: Option<ViewportId>,
+    window_levels: egui_winit::WindowLevels,
+
+    /// Set once the app is running, to give the windows of child viewports their own AccessKit adapter.
+    #[cfg(feature = "accesskit")]
+    accesskit_proxy: Option<EventLoopProxy<UserEvent>>,
 }
 
 struct Viewport {
@@ -154,15 +159,14 @@ impl<'app> GlowWinitApp<'app> {
         native_options: &mut NativeOptions,
     ) -> Result<(GlutinWindowContext, egui_glow::Painter)> {
         profiling::function_scope!();
-        let window_settings = epi_integration::load_window_settings(storage);
+        let mut window_settings = epi_integration::load_window_settings(storage);
 
         let winit_window_builder = epi_integration::viewport_builder(
             egui_ctx.zoom_factor(),
             event_loop,
             native_options,
-            window_settings,
-        )
-        .with_visible(false); // Start hidden until we render the first frame to fix white flash on startup (https://github.com/emilk/egui/pull/3631)
+            window_settings.as_mut(),
+        );
 
         let mut glutin_window_context = unsafe {
             GlutinWindowContext::new(egui_ctx, winit_window_builder, native_options, event_loop)?
@@ -174,7 +178,11 @@ impl<'app> GlowWinitApp<'app> {
         {
             let viewport = &glutin_window_context.viewports[&ViewportId::ROOT];
             let window = viewport.window.as_ref().unwrap(); // Can't fail - we just called `initialize_all_viewports`
-            epi_integration::apply_window_settings(window, window_settings);
+            epi_integration::apply_window_settings(
+                egui_ctx.zoom_factor(),
+                window,
+                window_settings.as_ref(),
+            );
         }
 
         let gl = unsafe {
@@ -275,6 +283,7 @@ impl<'app> GlowWinitApp<'app> {
         #[cfg(feature = "accesskit")]
         {
             let event_loop_proxy = self.repaint_proxy.lock().clone();
+            glutin.accesskit_proxy = Some(event_loop_proxy.clone());
             let viewport = glutin.viewports.get_mut(&ViewportId::ROOT).unwrap(); // we always have a root
             if let Viewport {
                 window: Some(window),
@@ -453,7 +462,7 @@ impl WinitApp for GlowWinitApp<'_> {
         _: winit::event::DeviceId,
         event: winit::event::DeviceEvent,
     ) -> crate::Result<EventResult> {
-        if let winit::event::DeviceEvent::MouseMotion { delta } = event
+        if let winit::event::DeviceEvent::MouseMotion { .. } = event
             && let Some(running) = &mut self.running
         {
             let mut glutin = running.glutin.borrow_mut();
@@ -473,7 +482,7 @@ impl WinitApp for GlowWinitApp<'_> {
                 }
 
                 if let Some(egui_winit) = viewport.egui_winit.as_mut()
-                    && egui_winit.on_mouse_motion(delta)
+                    && egui_winit.on_device_event(&event).repaint
                 {
                     return Ok(EventResult::RepaintNext(window.id()));
                 }
@@ -743,10 +752,11 @@ impl GlowWinitRunning<'_> {
                                 }
                             }
                         }
+                        ActionRequested::ShowWhenReady => {
+                            window.set_visible(true);
+                        }
                     }
                 }
-
-                integration.post_rendering(&window);
             }
 
             {
@@ -1143,6 +1153,9 @@ impl GlutinWindowContext {
             max_texture_side: None,
             window_from_viewport,
             focused_viewport: Some(ViewportId::ROOT),
+            window_levels: Default::default(),
+            #[cfg(feature = "accesskit")]
+            accesskit_proxy: None,
         };
 
         slf.initialize_window(ViewportId::ROOT, event_loop)?;
@@ -1179,14 +1192,29 @@ impl GlutinWindowContext {
             .get_mut(&viewport_id)
             .expect("viewport doesn't exist");
 
+        #[cfg(feature = "accesskit")]
+        let (mut is_new_window, mut show_after_accesskit) = (false, false);
+
         let window = if let Some(window) = &mut viewport.window {
             window
         } else {
             log::debug!("Creating a window for viewport {viewport_id:?}");
-            let window_attributes = egui_winit::create_winit_window_attributes(
-                &self.egui_ctx,
-                viewport.builder.clone(),
-            );
+
+            #[cfg(feature = "accesskit")]
+            let builder = if self.accesskit_proxy.is_some() {
+                let (builder, show) =
+                    super::winit_integration::hidden_until_accesskit_ready(&viewport.builder);
+                is_new_window = true;
+                show_after_accesskit = show;
+                builder
+            } else {
+                viewport.builder.clone()
+            };
+            #[cfg(not(feature = "accesskit"))]
+            let builder = viewport.builder.clone();
+
+            let window_attributes =
+                egui_winit::create_winit_window_attributes(&self.egui_ctx, builder);
             if window_attributes.transparent()
                 && self.gl_config.supports_transparency() == Some(false)
             {
@@ -1201,20 +1229,38 @@ impl GlutinWindowContext {
             );
 
             egui_winit::update_viewport_info(&mut viewport.info, &self.egui_ctx, &window, true);
+            viewport.info.window_level = viewport.builder.window_level;
+            if egui_winit::is_shown_when_ready(&viewport.builder) {
+                viewport
+                    .actions_requested
+                    .push(ActionRequested::ShowWhenReady);
+            }
             viewport.window.insert(Arc::new(window))
         };
 
-        viewport.egui_winit.get_or_insert_with(|| {
+        if viewport.egui_winit.is_none() {
             log::debug!("Initializing egui_winit for viewport {viewport_id:?}");
-            egui_winit::State::new(
+            #[allow(clippy::allow_attributes, unused_mut)] // used for accesskit
+            let mut egui_winit = egui_winit::State::new(
                 self.egui_ctx.clone(),
                 viewport_id,
                 event_loop,
                 Some(window.scale_factor() as f32),
                 event_loop.system_theme(),
                 self.max_texture_side,
-            )
-        });
+            );
+
+            // The adapter can only be created for windows that haven't been shown yet:
+            #[cfg(feature = "accesskit")]
+            if is_new_window && let Some(event_loop_proxy) = &self.accesskit_proxy {
+                egui_winit.init_accesskit(event_loop, window, event_loop_proxy.clone());
+                if show_after_accesskit {
+                    window.set_visible(true);
+                }
+            }
+
+            viewport.egui_winit = Some(egui_winit);
+        }
 
         if viewport.gl_surface.is_none() {
             log::debug!("Creating a gl_surface for viewport {viewport_id:?}");
@@ -1404,6 +1450,13 @@ impl GlutinWindowContext {
         self.initialize_all_windows(event_loop);
 
         self.remove_viewports_not_in(viewport_output);
+
+        self.window_levels.update(
+            self.focused_viewport.is_some(),
+            self.viewports
+                .values()
+                .filter_map(|viewport| Some((viewport.window.as_deref()?, &viewport.info))),
+        );
     }
 }
 
@@ -79,6 +79,11 @@ pub struct SharedState {
     viewport_from_window: HashMap<WindowId, ViewportId>,
     focused_viewport: Option<ViewportId>,
     resized_viewport: Option<ViewportId>,
+    window_levels: egui_winit::WindowLevels,
+
+    /// Used to give the windows of child viewports their own AccessKit adapter.
+    #[cfg(feature = "accesskit")]
+    accesskit_proxy: EventLoopProxy<UserEvent>,
 }
 
 pub type Viewports = egui::OrderedViewportIdMap<Viewport>;
@@ -140,6 +145,8 @@ impl<'app> WgpuWinitApp<'app> {
             viewports,
             painter,
             viewport_from_window,
+            #[cfg(feature = "accesskit")]
+            accesskit_proxy,
             ..
         } = &mut *shared;
 
@@ -149,6 +156,8 @@ impl<'app> WgpuWinitApp<'app> {
                 &running.integration.egui_ctx,
                 viewport_from_window,
                 painter,
+                #[cfg(feature = "accesskit")]
+                accesskit_proxy,
             );
         }
     }
@@ -160,6 +169,8 @@ impl<'app> WgpuWinitApp<'app> {
             viewports,
             viewport_from_window,
             painter,
+            #[cfg(feature = "accesskit")]
+            accesskit_proxy,
             ..
         } = &mut *running.shared.borrow_mut();
 
@@ -171,7 +182,14 @@ impl<'app> WgpuWinitApp<'app> {
             None,
             painter,
         )
-        .initialize_window(event_loop, egui_ctx, viewport_from_window, painter);
+        .initialize_window(
+            event_loop,
+            egui_ctx,
+            viewport_from_window,
+            painter,
+            #[cfg(feature = "accesskit")]
+            accesskit_proxy,
+        );
     }
 
     #[cfg(target_os = "android")]
@@ -218,6 +236,7 @@ impl<'app> WgpuWinitApp<'app> {
 
         let mut viewport_info = ViewportInfo::default();
         egui_winit::update_viewport_info(&mut viewport_info, &egui_ctx, &window, true);
+        viewport_info.window_level = builder.window_level;
 
         {
             // Tell egui right away about native_pixels_per_point etc,
@@ -315,6 +334,11 @@ impl<'app> WgpuWinitApp<'app> {
         let mut viewport_from_window = HashMap::default();
         viewport_from_window.insert(window.id(), ViewportId::ROOT);
 
+        let mut actions_requested = Vec::new();
+        if egui_winit::is_shown_when_ready(&builder) {
+            actions_requested.push(ActionRequested::ShowWhenReady);
+        }
+
         let mut viewports = Viewports::default();
         viewports.insert(
             ViewportId::ROOT,
@@ -324,7 +348,7 @@ impl<'app> WgpuWinitApp<'app> {
                 builder,
                 deferred_commands: vec![],
                 info: viewport_info,
-                actions_requested: Default::default(),
+                actions_requested,
                 viewport_ui_cb: None,
                 window: Some(window),
                 egui_winit: Some(egui_winit),
@@ -338,6 +362,9 @@ impl<'app> WgpuWinitApp<'app> {
             painter,
             focused_viewport: Some(ViewportId::ROOT),
             resized_viewport: None,
+            window_levels: Default::default(),
+            #[cfg(feature = "accesskit")]
+            accesskit_proxy: self.repaint_proxy.lock().clone(),
         }));
 
         {
@@ -471,7 +498,7 @@ impl WinitApp for WgpuWinitApp<'_> {
         _: winit::event::DeviceId,
         event: winit::event::DeviceEvent,
     ) -> crate::Result<EventResult> {
-        if let winit::event::DeviceEvent::MouseMotion { delta } = event
+        if let winit::event::DeviceEvent::MouseMotion { .. } = event
             && let Some(running) = &mut self.running
         {
             let mut shared = running.shared.borrow_mut();
@@ -491,7 +518,7 @@ impl WinitApp for WgpuWinitApp<'_> {
                 }
 
                 if let Some(egui_winit) = viewport.egui_winit.as_mut()
-                    && egui_winit.on_mouse_motion(delta)
+                    && egui_winit.on_device_event(&event).repaint
                 {
                     return Ok(EventResult::RepaintNext(window.id()));
                 }
@@ -688,6 +715,8 @@ impl WgpuWinitRunning<'_> {
             viewports,
             painter,
             viewport_from_window,
+            focused_viewport,
+            window_levels,
             ..
         } = &mut *shared_mut;
 
@@ -762,11 +791,12 @@ impl WgpuWinitRunning<'_> {
                             }
                         }
                     }
+                    ActionRequested::ShowWhenReady => {
+                        window.set_visible(true);
+                    }
                 }
             }
 
-            integration.post_rendering(window);
-
             vsync_secs
         } else {
             0.0
@@ -787,6 +817,13 @@ impl WgpuWinitRunning<'_> {
         viewport_from_window.retain(|_, id| active_viewports_ids.contains(id));
         painter.gc_viewports(&active_viewports_ids);
 
+        window_levels.update(
+            focused_viewport.is_some(),
+            viewports
+                .values()
+                .filter_map(|viewport| Some((viewport.window.as_deref()?, &viewport.info))),
+        );
+
         let window = viewport_from_window
             .get(&window_id)
             .and_then(|id| viewports.get(id))
@@ -967,6 +1004,7 @@ impl Viewport {
         egui_ctx: &egui::Context,
         windows_id: &mut HashMap<WindowId, ViewportId>,
         painter: &mut egui_wgpu::winit::Painter,
+        #[cfg(feature = "accesskit")] accesskit_proxy: &EventLoopProxy<UserEvent>,
     ) {
         if self.window.is_some() {
             return; // we already have one
@@ -976,7 +1014,13 @@ impl Viewport {
 
         let viewport_id = self.ids.this;
 
-        match egui_winit::create_window(egui_ctx, event_loop, &self.builder) {
+        #[cfg(feature = "accesskit")]
+        let (builder, show_after_accesskit) =
+            winit_integration::hidden_until_accesskit_ready(&self.builder);
+        #[cfg(not(feature = "accesskit"))]
+        let builder = self.builder.clone();
+
+        match egui_winit::create_window(egui_ctx, event_loop, &builder) {
             Ok(window) => {
                 windows_id.insert(window.id(), viewport_id);
 
@@ -988,16 +1032,29 @@ impl Viewport {
                     log::error!("on set_window: viewport_id {viewport_id:?} {err}");
                 }
 
-                self.egui_winit = Some(egui_winit::State::new(
+                #[allow(clippy::allow_attributes, unused_mut)] // used for accesskit
+                let mut egui_winit = egui_winit::State::new(
                     egui_ctx.clone(),
                     viewport_id,
                     event_loop,
                     Some(window.scale_factor() as f32),
                     event_loop.system_theme(),
                     painter.max_texture_side(),
-                ));
+                );
+                #[cfg(feature = "accesskit")]
+                {
+                    egui_winit.init_accesskit(event_loop, &window, accesskit_proxy.clone());
+                    if show_after_accesskit {
+                        window.set_visible(true);
+                    }
+                }
+                self.egui_winit = Some(egui_winit);
 
                 egui_winit::update_viewport_info(&mut self.info, egui_ctx, &window, true);
+                self.info.window_level = self.builder.window_level;
+                if egui_winit::is_shown_when_ready(&self.builder) {
+                    self.actions_requested.push(ActionRequested::ShowWhenReady);
+                }
                 self.window = Some(window);
             }
             Err(err) => {
@@ -1015,17 +1072,20 @@ fn create_window(
 ) -> Result<(Window, ViewportBuilder), winit::error::OsError> {
     profiling::function_scope!();
 
-    let window_settings = epi_integration::load_window_settings(storage);
+    let mut window_settings = epi_integration::load_window_settings(storage);
     let viewport_builder = epi_integration::viewport_builder(
         egui_ctx.zoom_factor(),
         event_loop,
         native_options,
-        window_settings,
-    )
-    .with_visible(false); // Start hidden until we render the first frame to fix white flash on startup (https://github.com/emilk/egui/pull/3631)
+        window_settings.as_mut(),
+    );
 
     let window = egui_winit::create_window(egui_ctx, event_loop, &viewport_builder)?;
-    epi_integration::apply_window_settings(&window, window_settings);
+    epi_integration::apply_window_settings(
+        egui_ctx.zoom_factor(),
+        &window,
+        window_settings.as_ref(),
+    );
     Ok((window, viewport_builder))
 }
 
@@ -1067,6 +1127,8 @@ fn render_immediate_viewport(
             viewports,
             painter,
             viewport_from_window,
+            #[cfg(feature = "accesskit")]
+            accesskit_proxy,
             ..
         } = &mut *shared.borrow_mut();
 
@@ -1080,7 +1142,14 @@ fn render_immediate_viewport(
         );
         if viewport.window.is_none() {
             event_loop_context::with_current_event_loop(|event_loop| {
-                viewport.initialize_window(event_loop, egui_ctx, viewport_from_window, painter);
+                viewport.initialize_window(
+                    event_loop,
+                    egui_ctx,
+                    viewport_from_window,
+                    painter,
+                    #[cfg(feature = "accesskit")]
+                    accesskit_proxy,
+                );
             });
         }
 
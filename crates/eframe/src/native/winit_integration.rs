@@ -157,6 +157,24 @@ pub enum EventResult {
     Exit,
 }
 
+/// AccessKit adapters must be created before their window is shown for the first time,
+/// so a window that would be visible right away is created hidden instead.
+///
+/// Returns the builder to create the window with,
+/// and whether to show the window once the adapter has been created.
+#[cfg(feature = "accesskit")]
+pub(crate) fn hidden_until_accesskit_ready(
+    builder: &egui::ViewportBuilder,
+) -> (egui::ViewportBuilder, bool) {
+    let visible_on_creation =
+        builder.visible.unwrap_or(true) && builder.show_when_ready != Some(true);
+    if visible_on_creation {
+        (builder.clone().with_visible(false), true)
+    } else {
+        (builder.clone(), false)
+    }
+}
+
 #[cfg(feature = "accesskit")]
 pub(crate) fn on_accesskit_window_event(
     egui_winit: &mut egui_winit::State,
@@ -165,7 +183,7 @@ pub(crate) fn on_accesskit_window_event(
 ) -> EventResult {
     match event {
         accesskit_winit::WindowEvent::InitialTreeRequested => {
-            egui_winit.egui_ctx().enable_accesskit();
+            egui_winit.on_accesskit_activated();
             // Because we can't provide the initial tree synchronously
             // (because that would require the activation handler to access
             // the same mutable state as the winit event handler), some
@@ -182,7 +200,7 @@ pub(crate) fn on_accesskit_window_event(
             EventResult::RepaintNext(window_id)
         }
         accesskit_winit::WindowEvent::AccessibilityDeactivated => {
-            egui_winit.egui_ctx().disable_accesskit();
+            egui_winit.on_accesskit_deactivated();
             // Disabling AccessKit support should have no visible effect,
             // so there's no need to repaint.
             EventResult::Wait
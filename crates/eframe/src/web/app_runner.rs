@@ -90,12 +90,14 @@ impl AppRunner {
             },
             cpu_usage: None,
         };
-        let storage = LocalStorage::default();
+        let storage = LocalStorage {
+            prefix: web_options.storage_prefix.clone(),
+        };
 
         egui_ctx.set_os(egui::os::OperatingSystem::from_user_agent(
             &super::user_agent().unwrap_or_default(),
         ));
-        super::storage::load_memory(&egui_ctx);
+        super::storage::load_memory(&egui_ctx, web_options.storage_prefix.as_deref());
 
         egui_ctx.options_mut(|o| {
             // On web by default egui follows the zoom factor of the browser,
@@ -200,7 +202,7 @@ impl AppRunner {
 
     pub fn save(&mut self) {
         if self.app.persist_egui_memory() {
-            super::storage::save_memory(&self.egui_ctx);
+            super::storage::save_memory(&self.egui_ctx, self.web_options.storage_prefix.as_deref());
         }
         if let Some(storage) = self.frame.storage_mut() {
             self.app.save(storage);
@@ -362,7 +364,11 @@ impl AppRunner {
     fn handle_platform_output(&self, platform_output: egui::PlatformOutput) {
         #[cfg(feature = "web_screen_reader")]
         if self.egui_ctx.options(|o| o.screen_reader) {
-            super::screen_reader::speak(&platform_output.events_description());
+            let description = platform_output.focus_summary.as_ref().map_or_else(
+                || platform_output.events_description(),
+                egui::FocusSummary::text,
+            );
+            super::screen_reader::speak(&description);
         }
 
         let egui::PlatformOutput {
@@ -370,10 +376,14 @@ impl AppRunner {
             cursor_icon,
             cursor_image: _, // TODO(alextournai): support custom bitmap cursors on the web (via CSS `url(...)`)
             events: _,       // already handled
+            widget_events: _, // for the app
+            focus_summary: _, // already handled
+            focused_rect: _, // the browser follows focus through the text agent and accessibility tree
             mutable_text_under_cursor: _, // TODO(#4569): https://github.com/emilk/egui/issues/4569
             ime,
-            accesskit_update: _,        // not currently implemented
-            num_completed_passes: _,    // handled by `Context::run`
+            pointer_confinement: _, // the browser can only lock the pointer, not confine it
+            accesskit_update: _,    // not currently implemented
+            num_completed_passes: _, // handled by `Context::run`
             request_discard_reasons: _, // handled by `Context::run`
         } = platform_output;
 
@@ -385,6 +395,9 @@ impl AppRunner {
                 egui::OutputCommand::CopyImage(image) => {
                     super::set_clipboard_image(&image);
                 }
+                egui::OutputCommand::CopyFiles(_) => {
+                    log::warn!("Copying files is not supported on web.");
+                }
                 egui::OutputCommand::OpenUrl(open_url) => {
                     super::open_url(&open_url.url, open_url.new_tab);
                 }
@@ -419,20 +432,28 @@ impl AppRunner {
 
 // ----------------------------------------------------------------------------
 
-#[derive(Default)]
-struct LocalStorage {}
+struct LocalStorage {
+    /// See [`crate::WebOptions::storage_prefix`].
+    prefix: Option<String>,
+}
+
+impl LocalStorage {
+    fn key(&self, key: &str) -> String {
+        super::storage::prefixed_key(self.prefix.as_deref(), key)
+    }
+}
 
 impl epi::Storage for LocalStorage {
     fn get_string(&self, key: &str) -> Option<String> {
-        super::storage::local_storage_get(key)
+        super::storage::local_storage_get(&self.key(key))
     }
 
     fn set_string(&mut self, key: &str, value: String) {
-        super::storage::local_storage_set(key, &value);
+        super::storage::local_storage_set(&self.key(key), &value);
     }
 
     fn remove_string(&mut self, key: &str) {
-        super::storage::local_storage_remove(key);
+        super::storage::local_storage_remove(&self.key(key));
     }
 
     fn flush(&mut self) {}
@@ -205,6 +205,7 @@ pub(crate) fn on_keydown(event: web_sys::KeyboardEvent, runner: &mut AppRunner)
         let egui_event = egui::Event::Key {
             key: egui_key,
             physical_key: None, // TODO(fornwall)
+            location: key_location_from_kb_event(&event),
             pressed: true,
             repeat: false, // egui will fill this in for us!
             modifiers,
@@ -234,6 +235,14 @@ pub(crate) fn on_keydown(event: web_sys::KeyboardEvent, runner: &mut AppRunner)
     }
 }
 
+fn key_location_from_kb_event(event: &web_sys::KeyboardEvent) -> egui::KeyLocation {
+    if event.location() == web_sys::KeyboardEvent::DOM_KEY_LOCATION_NUMPAD {
+        egui::KeyLocation::Numpad
+    } else {
+        egui::KeyLocation::Standard
+    }
+}
+
 /// If the canvas (or text agent) has focus:
 /// should we prevent the default browser event action when the user presses this key?
 fn should_prevent_default_for_key(
@@ -295,6 +304,7 @@ pub(crate) fn on_keyup(event: web_sys::KeyboardEvent, runner: &mut AppRunner) {
         let egui_event = egui::Event::Key {
             key,
             physical_key: None, // TODO(fornwall)
+            location: key_location_from_kb_event(&event),
             pressed: false,
             repeat: false,
             modifiers,
@@ -316,6 +326,7 @@ pub(crate) fn on_keyup(event: web_sys::KeyboardEvent, runner: &mut AppRunner) {
             let egui_event = egui::Event::Key {
                 key,
                 physical_key: None,
+                location: egui::KeyLocation::Standard,
                 pressed: false,
                 repeat: false,
                 modifiers,
@@ -847,6 +858,7 @@ fn install_wheel(runner_ref: &WebRunner, target: &EventTarget) -> Result<(), JsV
                 delta,
                 modifiers,
                 phase: egui::TouchPhase::Move,
+                momentum: false,
             }
         };
         let should_stop_propagation = (runner.web_options.should_stop_propagation)(&egui_event);
@@ -940,11 +952,14 @@ fn install_drag_and_drop(runner_ref: &WebRunner, target: &EventTarget) -> Result
 
             // NOTE: data_transfer.files() is always empty in dragover
 
+            let pos = pos_from_mouse_event(runner.canvas(), &event, runner.egui_ctx());
+
             let items = data_transfer.items();
             for i in 0..items.length() {
                 if let Some(item) = items.get(i) {
                     runner.input.raw.hovered_files.push(egui::HoveredFile {
                         mime: item.type_(),
+                        pos: Some(pos),
                         ..Default::default()
                     });
                 }
@@ -952,11 +967,10 @@ fn install_drag_and_drop(runner_ref: &WebRunner, target: &EventTarget) -> Result
 
             if runner.input.raw.hovered_files.is_empty() {
                 // Fallback: just preview anything. Needed on Desktop Safari.
-                runner
-                    .input
-                    .raw
-                    .hovered_files
-                    .push(egui::HoveredFile::default());
+                runner.input.raw.hovered_files.push(egui::HoveredFile {
+                    pos: Some(pos),
+                    ..Default::default()
+                });
             }
 
             runner.needs_repaint.repaint_asap();
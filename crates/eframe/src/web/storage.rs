@@ -41,9 +41,17 @@ pub fn local_storage_remove(key: &str) {
     }
 }
 
+/// The local storage key for `key`, with the prefix from [`crate::WebOptions::storage_prefix`].
+pub(crate) fn prefixed_key(prefix: Option<&str>, key: &str) -> String {
+    match prefix {
+        Some(prefix) => format!("{prefix}/{key}"),
+        None => key.to_owned(),
+    }
+}
+
 #[cfg(feature = "persistence")]
-pub(crate) fn load_memory(ctx: &egui::Context) {
-    if let Some(memory_string) = local_storage_get("egui_memory_ron") {
+pub(crate) fn load_memory(ctx: &egui::Context, prefix: Option<&str>) {
+    if let Some(memory_string) = local_storage_get(&prefixed_key(prefix, "egui_memory_ron")) {
         match ron::from_str(&memory_string) {
             Ok(memory) => {
                 ctx.memory_mut(|m| *m = memory);
@@ -56,13 +64,13 @@ pub(crate) fn load_memory(ctx: &egui::Context) {
 }
 
 #[cfg(not(feature = "persistence"))]
-pub(crate) fn load_memory(_: &egui::Context) {}
+pub(crate) fn load_memory(_: &egui::Context, _: Option<&str>) {}
 
 #[cfg(feature = "persistence")]
-pub(crate) fn save_memory(ctx: &egui::Context) {
+pub(crate) fn save_memory(ctx: &egui::Context, prefix: Option<&str>) {
     match ctx.memory(ron::to_string) {
         Ok(ron) => {
-            local_storage_set("egui_memory_ron", &ron);
+            local_storage_set(&prefixed_key(prefix, "egui_memory_ron"), &ron);
         }
         Err(err) => {
             log::warn!("Failed to serialize memory as RON: {err}");
@@ -71,4 +79,4 @@ pub(crate) fn save_memory(ctx: &egui::Context) {
 }
 
 #[cfg(not(feature = "persistence"))]
-pub(crate) fn save_memory(_: &egui::Context) {}
+pub(crate) fn save_memory(_: &egui::Context, _: Option<&str>) {}
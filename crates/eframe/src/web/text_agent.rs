@@ -46,6 +46,11 @@ impl TextAgent {
         style.set_property("font-size", "16px")?;
 
         let root = canvas.get_root_node();
+
+        // Several apps can share a page, and the page may have focused something else already.
+        let is_something_else_focused = super::focused_element(&root)
+            .is_some_and(|element| !element.tag_name().eq_ignore_ascii_case("body"));
+
         if root.has_type::<Document>() {
             // root object is a document, append to its body
             root.dyn_into::<Document>()?
@@ -62,7 +67,9 @@ impl TextAgent {
         // since the browser scrolls the focused element into view when
         // honoring `autofocus`, and there is no way to prevent that.
         // See https://github.com/emilk/egui/issues/8295
-        super::focus_without_scroll(&input).ok();
+        if !is_something_else_focused {
+            super::focus_without_scroll(&input).ok();
+        }
 
         // attach event listeners
 
@@ -181,6 +188,8 @@ impl TextAgent {
 
         let Some(ime) = ime else { return Ok(()) };
 
+        self.set_purpose(ime.purpose)?;
+
         if ime.should_interrupt_composition {
             // no-op for now: currently, the text agent is sizeless, so any
             // click shifts focus to the canvas, which naturally interrupts the
@@ -214,6 +223,23 @@ impl TextAgent {
         Ok(())
     }
 
+    /// Pick the on-screen keyboard layout that fits the text being edited.
+    fn set_purpose(&self, purpose: egui::InputPurpose) -> Result<(), JsValue> {
+        // We use `inputmode` rather than e.g. `type="email"`,
+        // since the latter doesn't support the selection API we rely on.
+        let (input_type, input_mode) = match purpose {
+            egui::InputPurpose::Normal => ("text", "text"),
+            egui::InputPurpose::Password => ("password", "text"),
+            egui::InputPurpose::Email => ("text", "email"),
+            egui::InputPurpose::Number => ("text", "decimal"),
+            egui::InputPurpose::Url => ("text", "url"),
+        };
+        if self.input.type_() != input_type {
+            self.input.set_type(input_type);
+        }
+        self.input.set_attribute("inputmode", input_mode)
+    }
+
     pub fn set_focus(&self, on: bool) {
         if on {
             self.focus();
@@ -14,6 +14,10 @@ use super::{
 ///
 /// This is cheap to clone.
 ///
+/// You can run several independent apps on the same page by creating one [`WebRunner`] per canvas.
+/// Each one gets its own input and keyboard focus.
+/// Give them different [`crate::WebOptions::storage_prefix`] so they don't overwrite each other's saved state.
+///
 /// See [the crate level docs](crate) for an example.
 #[derive(Clone)]
 pub struct WebRunner {
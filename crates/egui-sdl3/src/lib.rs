@@ -0,0 +1,822 @@
+//! [`egui`] bindings for [`SDL3`](https://github.com/vhspace/sdl3-rs).
+//!
+//! The library translates SDL events to egui, handles copy/paste,
+//! updates the cursor, positions the IME, open links clicked in egui, etc.
+//!
+//! This is for applications and engines that are built on SDL.
+//! If you are free to choose, use `egui-winit` or `eframe` instead.
+//!
+//! ## Feature flags
+#![cfg_attr(feature = "document-features", doc = document_features::document_features!())]
+//!
+
+mod translate;
+
+pub use egui;
+use egui::{Pos2, Rect, ViewportId, ViewportInfo};
+pub use sdl3;
+
+use sdl3::{
+    clipboard::ClipboardUtil,
+    event::{Event, WindowEvent},
+    keyboard::{Keycode, Mod, Scancode, TextInputUtil},
+    mouse::{Cursor, MouseButton, MouseUtil, MouseWheelDirection, SystemCursor},
+    video::Window,
+};
+
+/// The size of the drawable area of the window, in physical pixels.
+pub fn screen_size_in_pixels(window: &Window) -> egui::Vec2 {
+    let (width, height) = window.size_in_pixels();
+    egui::vec2(width as f32, height as f32)
+}
+
+/// Calculate the `pixels_per_point` for a given window, given the current egui zoom factor
+pub fn pixels_per_point(egui_ctx: &egui::Context, window: &Window) -> f32 {
+    let native_pixels_per_point = window.display_scale();
+    let egui_zoom_factor = egui_ctx.zoom_factor();
+    egui_zoom_factor * native_pixels_per_point
+}
+
+/// How many egui points there are per SDL window coordinate.
+///
+/// SDL reports mouse and window positions in window coordinates,
+/// which are physical pixels on some platforms and logical ones on others.
+fn points_per_window_coordinate(egui_ctx: &egui::Context, window: &Window) -> f32 {
+    window.pixel_density() / pixels_per_point(egui_ctx, window)
+}
+
+// ----------------------------------------------------------------------------
+
+#[must_use]
+#[derive(Clone, Copy, Debug, Default)]
+pub struct EventResponse {
+    /// If true, egui consumed this event, i.e. wants exclusive use of this event
+    /// (e.g. a mouse click on an egui window, or entering text into a text field).
+    ///
+    /// For instance, if you use egui for a game, you should only
+    /// pass on the events to your game when [`Self::consumed`] is `false`.
+    ///
+    /// Note that egui uses `tab` to move focus between elements, so this will always be `true` for tabs.
+    pub consumed: bool,
+
+    /// Do we need an egui refresh because of this event?
+    pub repaint: bool,
+}
+
+// ----------------------------------------------------------------------------
+
+/// Handles the integration between egui and an SDL window.
+///
+/// Instantiate one of these per viewport/window.
+pub struct State {
+    /// Shared clone.
+    egui_ctx: egui::Context,
+
+    viewport_id: ViewportId,
+    window_id: u32,
+    start_time: web_time::Instant,
+    egui_input: egui::RawInput,
+    pointer_pos_in_points: Option<egui::Pos2>,
+    any_pointer_button_down: bool,
+
+    mouse: MouseUtil,
+    clipboard: ClipboardUtil,
+    text_input: TextInputUtil,
+
+    current_cursor_icon: Option<egui::CursorIcon>,
+
+    /// SDL only shows a cursor for as long as it is alive, so we keep the current one here.
+    current_cursor: Option<Cursor>,
+
+    allow_ime: bool,
+    ime_rect_px: Option<egui::Rect>,
+    pointer_confinement: Option<egui::Rect>,
+
+    /// Is there an ongoing IME composition?
+    ///
+    /// SDL sends both committed compositions and plain typing as [`Event::TextInput`].
+    is_composing: bool,
+}
+
+impl State {
+    /// Construct a new instance for the given window.
+    pub fn new(
+        egui_ctx: egui::Context,
+        viewport_id: ViewportId,
+        sdl: &sdl3::Sdl,
+        window: &Window,
+        max_texture_side: Option<usize>,
+    ) -> Self {
+        profiling::function_scope!();
+
+        let egui_input = egui::RawInput {
+            focused: false, // SDL will tell us when we have focus
+            ..Default::default()
+        };
+
+        let video = window.subsystem();
+
+        let mut slf = Self {
+            viewport_id,
+            window_id: window.id(),
+            start_time: web_time::Instant::now()
+                .checked_sub(web_time::Duration::from_secs_f64(egui_ctx.time()))
+                .unwrap_or_else(web_time::Instant::now),
+            egui_ctx,
+            egui_input,
+            pointer_pos_in_points: None,
+            any_pointer_button_down: false,
+
+            mouse: sdl.mouse(),
+            clipboard: video.clipboard(),
+            text_input: video.text_input(),
+
+            current_cursor_icon: None,
+            current_cursor: None,
+
+            allow_ime: false,
+            ime_rect_px: None,
+            pointer_confinement: None,
+            is_composing: false,
+        };
+
+        // SDL starts out with text input enabled on some platforms.
+        // egui enables it when a text field gets focus.
+        slf.text_input.stop(window);
+
+        slf.egui_input
+            .viewports
+            .entry(viewport_id)
+            .or_default()
+            .native_pixels_per_point = Some(window.display_scale());
+
+        if let Some(max_texture_side) = max_texture_side {
+            slf.set_max_texture_side(max_texture_side);
+        }
+        slf
+    }
+
+    /// Call this once a graphics context has been created to update the maximum texture dimensions
+    /// that egui will use.
+    pub fn set_max_texture_side(&mut self, max_texture_side: usize) {
+        self.egui_input.max_texture_side = Some(max_texture_side);
+    }
+
+    /// Fetches text from the clipboard and returns it.
+    pub fn clipboard_text(&self) -> Option<String> {
+        if !self.clipboard.has_clipboard_text() {
+            return None;
+        }
+        match self.clipboard.clipboard_text() {
+            Ok(text) => Some(text),
+            Err(err) => {
+                log::error!("Failed to read clipboard: {err}");
+                None
+            }
+        }
+    }
+
+    /// Places the text onto the clipboard.
+    pub fn set_clipboard_text(&self, text: &str) {
+        if let Err(err) = self.clipboard.set_clipboard_text(text) {
+            log::error!("Failed to set clipboard: {err}");
+        }
+    }
+
+    /// Returns [`false`] or whether text input was last started for the window, used for debouncing.
+    pub fn allow_ime(&self) -> bool {
+        self.allow_ime
+    }
+
+    #[inline]
+    pub fn egui_ctx(&self) -> &egui::Context {
+        &self.egui_ctx
+    }
+
+    /// The current input state.
+    /// This is changed by [`Self::on_event`] and cleared by [`Self::take_egui_input`].
+    #[inline]
+    pub fn egui_input(&self) -> &egui::RawInput {
+        &self.egui_input
+    }
+
+    /// The current input state.
+    /// This is changed by [`Self::on_event`] and cleared by [`Self::take_egui_input`].
+    #[inline]
+    pub fn egui_input_mut(&mut self) -> &mut egui::RawInput {
+        &mut self.egui_input
+    }
+
+    /// Prepare for a new frame by extracting the accumulated input,
+    ///
+    /// as well as setting [the time](egui::RawInput::time) and [screen rectangle](egui::RawInput::screen_rect).
+    ///
+    /// You need to set [`egui::RawInput::viewports`] yourself though.
+    /// Use [`update_viewport_info`] to update the info for each
+    /// viewport.
+    pub fn take_egui_input(&mut self, window: &Window) -> egui::RawInput {
+        profiling::function_scope!();
+
+        self.egui_input.time = Some(self.start_time.elapsed().as_secs_f64());
+
+        // A minimized window may have 0 width and height.
+        let screen_size_in_pixels = screen_size_in_pixels(window);
+        let screen_size_in_points =
+            screen_size_in_pixels / pixels_per_point(&self.egui_ctx, window);
+
+        self.egui_input.screen_rect = (screen_size_in_points.x > 0.0
+            && screen_size_in_points.y > 0.0)
+            .then(|| Rect::from_min_size(Pos2::ZERO, screen_size_in_points));
+
+        // Tell egui which viewport is now active:
+        self.egui_input.viewport_id = self.viewport_id;
+
+        self.egui_input
+            .viewports
+            .entry(self.viewport_id)
+            .or_default()
+            .native_pixels_per_point = Some(window.display_scale());
+
+        self.egui_input.take()
+    }
+
+    /// Call this for every SDL event.
+    ///
+    /// Events for other windows are ignored, so with several windows
+    /// you can pass each event to the [`State`] of every window.
+    ///
+    /// The result can be found in [`Self::egui_input`] and be extracted with [`Self::take_egui_input`].
+    pub fn on_event(&mut self, window: &Window, event: &Event) -> EventResponse {
+        profiling::function_scope!();
+
+        if event
+            .get_window_id()
+            .is_some_and(|window_id| window_id != self.window_id)
+        {
+            return EventResponse::default();
+        }
+
+        match event {
+            Event::Window { win_event, .. } => self.on_window_event(win_event),
+
+            Event::MouseMotion { which, x, y, .. } => {
+                if translate::is_touch_mouse(*which) {
+                    // We get proper touch events for these.
+                    return EventResponse::default();
+                }
+                self.on_cursor_moved(window, *x, *y);
+                EventResponse {
+                    repaint: true,
+                    consumed: self.egui_ctx.egui_is_using_pointer(),
+                }
+            }
+            Event::MouseButtonDown {
+                which,
+                mouse_btn,
+                x,
+                y,
+                ..
+            }
+            | Event::MouseButtonUp {
+                which,
+                mouse_btn,
+                x,
+                y,
+                ..
+            } => {
+                if translate::is_touch_mouse(*which) {
+                    return EventResponse::default();
+                }
+                let pressed = matches!(event, Event::MouseButtonDown { .. });
+                self.on_cursor_moved(window, *x, *y);
+                self.on_mouse_button_input(*mouse_btn, pressed);
+                EventResponse {
+                    repaint: true,
+                    consumed: self.egui_ctx.egui_wants_pointer_input(),
+                }
+            }
+            Event::MouseWheel {
+                which,
+                x,
+                y,
+                direction,
+                ..
+            } => {
+                if translate::is_touch_mouse(*which) {
+                    return EventResponse::default();
+                }
+                self.egui_input.events.push(translate::mouse_wheel_event(
+                    *x,
+                    *y,
+                    *direction == MouseWheelDirection::Flipped,
+                    self.egui_input.modifiers,
+                ));
+                EventResponse {
+                    repaint: true,
+                    consumed: self.egui_ctx.egui_wants_pointer_input(),
+                }
+            }
+
+            Event::FingerDown {
+                touch_id,
+                finger_id,
+                x,
+                y,
+                pressure,
+                ..
+            }
+            | Event::FingerMotion {
+                touch_id,
+                finger_id,
+                x,
+                y,
+                pressure,
+                ..
+            }
+            | Event::FingerUp {
+                touch_id,
+                finger_id,
+                x,
+                y,
+                pressure,
+                ..
+            }
+            | Event::FingerCanceled {
+                touch_id,
+                finger_id,
+                x,
+                y,
+                pressure,
+                ..
+            } => {
+                let phase = match event {
+                    Event::FingerDown { .. } => egui::TouchPhase::Start,
+                    Event::FingerMotion { .. } => egui::TouchPhase::Move,
+                    Event::FingerUp { .. } => egui::TouchPhase::End,
+                    _ => egui::TouchPhase::Cancel,
+                };
+
+                // SDL gives us the position normalized to the window size:
+                let (width, height) = window.size();
+                let scale = points_per_window_coordinate(&self.egui_ctx, window);
+                let pos = egui::pos2(*x * width as f32, *y * height as f32) * scale;
+
+                self.egui_input.events.push(egui::Event::Touch {
+                    device_id: egui::TouchDeviceId(*touch_id),
+                    id: egui::TouchId(*finger_id),
+                    phase,
+                    pos,
+                    force: Some(*pressure),
+                });
+
+                // SDL emulates the mouse from the first finger itself,
+                // and those events get here as mouse events.
+
+                let consumed = if phase == egui::TouchPhase::Move {
+                    self.egui_ctx.egui_is_using_pointer()
+                } else {
+                    self.egui_ctx.egui_wants_pointer_input()
+                };
+                EventResponse {
+                    repaint: true,
+                    consumed,
+                }
+            }
+
+            Event::KeyDown {
+                keycode,
+                scancode,
+                keymod,
+                ..
+            }
+            | Event::KeyUp {
+                keycode,
+                scancode,
+                keymod,
+                ..
+            } => {
+                let pressed = matches!(event, Event::KeyDown { .. });
+                self.on_keyboard_input(*keycode, *scancode, *keymod, pressed);
+
+                // When pressing the Tab key, egui focuses the first focusable element, hence Tab always consumes.
+                let consumed =
+                    self.egui_ctx.egui_wants_keyboard_input() || *keycode == Some(Keycode::Tab);
+                EventResponse {
+                    repaint: true,
+                    consumed,
+                }
+            }
+
+            Event::TextEditing {
+                text,
+                start,
+                length,
+                ..
+            } => {
+                self.is_composing = !text.is_empty();
+                self.egui_input
+                    .events
+                    .push(translate::preedit_event(text, *start, *length));
+                EventResponse {
+                    repaint: true,
+                    consumed: self.egui_ctx.egui_wants_keyboard_input(),
+                }
+            }
+            Event::TextInput { text, .. } => {
+                if self.is_composing {
+                    self.is_composing = false;
+                    self.egui_input
+                        .events
+                        .push(egui::Event::Ime(egui::ImeEvent::Commit(text.clone())));
+                } else if let Some(event) = translate::text_event(text) {
+                    self.egui_input.events.push(event);
+                }
+                EventResponse {
+                    repaint: true,
+                    consumed: self.egui_ctx.egui_wants_keyboard_input(),
+                }
+            }
+
+            Event::DropFile { filename, .. } => {
+                self.egui_input.hovered_files.clear();
+                self.egui_input.dropped_files.push(egui::DroppedFile {
+                    path: Some(filename.into()),
+                    ..Default::default()
+                });
+                EventResponse {
+                    repaint: true,
+                    consumed: false,
+                }
+            }
+            Event::DropBegin { .. } => {
+                // SDL doesn't tell us what is being dragged until it is dropped.
+                self.egui_input
+                    .hovered_files
+                    .push(egui::HoveredFile::default());
+                EventResponse {
+                    repaint: true,
+                    consumed: false,
+                }
+            }
+            Event::DropComplete { .. } => {
+                self.egui_input.hovered_files.clear();
+                EventResponse {
+                    repaint: true,
+                    consumed: false,
+                }
+            }
+
+            // Things that may require repaint:
+            Event::AppDidEnterForeground { .. } | Event::ClipboardUpdate { .. } => EventResponse {
+                repaint: true,
+                consumed: false,
+            },
+
+            _ => EventResponse::default(),
+        }
+    }
+
+    fn on_window_event(&mut self, event: &WindowEvent) -> EventResponse {
+        match event {
+            WindowEvent::FocusGained | WindowEvent::FocusLost => {
+                let focused = *event == WindowEvent::FocusGained;
+                self.egui_input.focused = focused;
+                self.egui_input
+                    .events
+                    .push(egui::Event::WindowFocused(focused));
+                EventResponse {
+                    repaint: true,
+                    consumed: false,
+                }
+            }
+            WindowEvent::MouseLeave => {
+                self.pointer_pos_in_points = None;
+                self.egui_input.events.push(egui::Event::PointerGone);
+                EventResponse {
+                    repaint: true,
+                    consumed: false,
+                }
+            }
+
+            // Things that may require repaint:
+            WindowEvent::Shown
+            | WindowEvent::Exposed
+            | WindowEvent::Moved(..)
+            | WindowEvent::Resized(..)
+            | WindowEvent::PixelSizeChanged(..)
+            | WindowEvent::Maximized
+            | WindowEvent::Restored
+            | WindowEvent::MouseEnter
+            | WindowEvent::CloseRequested
+            | WindowEvent::DisplayChanged(..) => EventResponse {
+                repaint: true,
+                consumed: false,
+            },
+
+            // Things we completely ignore:
+            WindowEvent::None
+            | WindowEvent::Hidden
+            | WindowEvent::Minimized
+            | WindowEvent::Occluded
+            | WindowEvent::HitTest(..)
+            | WindowEvent::ICCProfChanged => EventResponse::default(),
+        }
+    }
+
+    /// Returns `true` when the pointer is currently inside the window.
+    pub fn is_pointer_in_window(&self) -> bool {
+        self.pointer_pos_in_points.is_some()
+    }
+
+    /// Returns `true` if any pointer button is currently held down.
+    pub fn is_any_pointer_button_down(&self) -> bool {
+        self.any_pointer_button_down
+    }
+
+    fn on_cursor_moved(&mut self, window: &Window, x: f32, y: f32) {
+        let scale = points_per_window_coordinate(&self.egui_ctx, window);
+        let pos_in_points = egui::pos2(x, y) * scale;
+        if self.pointer_pos_in_points == Some(pos_in_points) {
+            return;
+        }
+        self.pointer_pos_in_points = Some(pos_in_points);
+        self.egui_input
+            .events
+            .push(egui::Event::PointerMoved(pos_in_points));
+    }
+
+    fn on_mouse_button_input(&mut self, button: MouseButton, pressed: bool) {
+        if let Some(pos) = self.pointer_pos_in_points
+            && let Some(button) = translate::pointer_button(button as u8)
+        {
+            self.any_pointer_button_down = pressed;
+            self.egui_input.events.push(egui::Event::PointerButton {
+                pos,
+                button,
+                pressed,
+                modifiers: self.egui_input.modifiers,
+            });
+        }
+    }
+
+    fn on_keyboard_input(
+        &mut self,
+        keycode: Option<Keycode>,
+        scancode: Option<Scancode>,
+        keymod: Mod,
+        pressed: bool,
+    ) {
+        self.egui_input.modifiers = translate::modifiers(keymod.bits());
+
+        // The keycode is what the key means in the current keyboard layout,
+        // and the scancode is where it is on the keyboard.
+        let keycode_raw = keycode.map(|keycode| keycode.to_ll().0);
+        let scancode_raw = scancode.and_then(|scancode| {
+            u32::try_from(sdl3::sys::scancode::SDL_Scancode::from(scancode).0).ok()
+        });
+        let logical_key = keycode_raw.and_then(translate::key_from_keycode);
+        let physical_key = scancode_raw.and_then(translate::key_from_scancode);
+
+        // Helpful logging to enable when adding new key support
+        log::trace!(
+            "logical {keycode:?} -> {logical_key:?},  physical {scancode:?} -> {physical_key:?}"
+        );
+
+        // "Logical OR physical key" is a fallback mechanism for keyboard layouts without Latin characters: it lets them
+        // emit events as if the corresponding keys from the Latin layout were pressed. In this case, clipboard shortcuts
+        // are mapped to the physical keys that normally contain C, X, V, etc.
+        // See also: https://github.com/emilk/egui/issues/3653
+        let Some(active_key) = logical_key.or(physical_key) else {
+            return;
+        };
+
+        if pressed {
+            if translate::is_cut_command(self.egui_input.modifiers, active_key) {
+                self.egui_input.events.push(egui::Event::Cut);
+                return;
+            } else if translate::is_copy_command(self.egui_input.modifiers, active_key) {
+                self.egui_input.events.push(egui::Event::Copy);
+                return;
+            } else if translate::is_paste_command(self.egui_input.modifiers, active_key) {
+                if let Some(event) = self
+                    .clipboard_text()
+                    .and_then(|contents| translate::paste_event(&contents))
+                {
+                    self.egui_input.events.push(event);
+                }
+                return;
+            }
+        }
+
+        self.egui_input.events.push(egui::Event::Key {
+            key: active_key,
+            physical_key,
+            location: scancode_raw.map_or(
+                egui::KeyLocation::Standard,
+                translate::key_location_from_scancode,
+            ),
+            pressed,
+            repeat: false, // egui will fill this in for us!
+            modifiers: self.egui_input.modifiers,
+        });
+    }
+
+    /// Call with the output given by `egui`.
+    ///
+    /// This will, if needed:
+    /// * update the cursor
+    /// * copy text to the clipboard
+    /// * open any clicked urls
+    /// * start, stop and position the IME
+    pub fn handle_platform_output(
+        &mut self,
+        window: &Window,
+        platform_output: egui::PlatformOutput,
+    ) {
+        profiling::function_scope!();
+
+        let egui::PlatformOutput {
+            commands,
+            cursor_icon,
+            cursor_image: _,              // TODO(emilk): support custom cursors
+            events: _,                    // handled elsewhere
+            widget_events: _,             // for the app
+            focus_summary: _,             // for the app
+            focused_rect: _,              // for the app
+            mutable_text_under_cursor: _, // only used in eframe web
+            ime,
+            pointer_confinement,
+            accesskit_update: _,        // not supported
+            num_completed_passes: _,    // `egui::Context::run` handles this
+            request_discard_reasons: _, // `egui::Context::run` handles this
+        } = platform_output;
+
+        for command in commands {
+            match command {
+                egui::OutputCommand::CopyText(text) => {
+                    self.set_clipboard_text(&text);
+                }
+                egui::OutputCommand::CopyImage(_) => {
+                    log::warn!("Copying images is not supported by egui-sdl3.");
+                }
+                egui::OutputCommand::CopyFiles(_) => {
+                    log::warn!("Copying files is not supported by egui-sdl3.");
+                }
+                egui::OutputCommand::OpenUrl(open_url) => {
+                    if let Err(err) = sdl3::url::open_url(&open_url.url) {
+                        log::warn!("Failed to open url: {err}");
+                    }
+                }
+            }
+        }
+
+        self.set_cursor_icon(cursor_icon);
+
+        if self.pointer_confinement != pointer_confinement {
+            self.pointer_confinement = pointer_confinement;
+
+            // SDL wants the area in window coordinates:
+            let scale = 1.0 / points_per_window_coordinate(&self.egui_ctx, window);
+            let mouse_rect = pointer_confinement.map(|rect| {
+                let rect = scale * rect;
+                sdl3::rect::Rect::new(
+                    rect.min.x.round() as i32,
+                    rect.min.y.round() as i32,
+                    rect.width().round() as u32,
+                    rect.height().round() as u32,
+                )
+            });
+            if let Err(err) = window.set_mouse_rect(mouse_rect) {
+                log::warn!("Failed to confine the mouse: {err}");
+            }
+        }
+
+        let allow_ime = ime.is_some();
+        let is_toggling_ime = self.allow_ime != allow_ime;
+        if is_toggling_ime {
+            self.allow_ime = allow_ime;
+            self.is_composing = false;
+
+            profiling::scope!("set_text_input");
+            if allow_ime {
+                self.text_input.start(window);
+            } else {
+                self.text_input.stop(window);
+            }
+        }
+
+        if let Some(ime) = ime {
+            if !is_toggling_ime && ime.should_interrupt_composition && self.is_composing {
+                self.is_composing = false;
+                self.text_input.stop(window);
+                self.text_input.start(window);
+            }
+
+            let pixels_per_point = pixels_per_point(&self.egui_ctx, window);
+            let ime_rect_px = pixels_per_point * ime.cursor_row_rect;
+            if self.ime_rect_px != Some(ime_rect_px)
+                || self.egui_ctx.input(|i| !i.events.is_empty())
+            {
+                self.ime_rect_px = Some(ime_rect_px);
+
+                // SDL wants the area in window coordinates:
+                let scale = 1.0 / points_per_window_coordinate(&self.egui_ctx, window);
+                let rect = scale * ime.cursor_row_rect;
+                let cursor = scale * (ime.cursor_rect.min.x - ime.cursor_row_rect.min.x);
+
+                profiling::scope!("set_text_input_area");
+                self.text_input.set_rect(
+                    window,
+                    sdl3::rect::Rect::new(
+                        rect.min.x.round() as i32,
+                        rect.min.y.round() as i32,
+                        rect.width().round() as u32,
+                        rect.height().round() as u32,
+                    ),
+                    cursor.round() as i32,
+                );
+            }
+        } else {
+            self.ime_rect_px = None;
+        }
+    }
+
+    fn set_cursor_icon(&mut self, cursor_icon: egui::CursorIcon) {
+        if self.current_cursor_icon == Some(cursor_icon) {
+            // Early-out to save CPU.
+            return;
+        }
+
+        let is_pointer_in_window = self.pointer_pos_in_points.is_some();
+        if !is_pointer_in_window {
+            // Make sure the cursor is set again when the pointer comes back.
+            self.current_cursor_icon = None;
+            return;
+        }
+
+        self.current_cursor_icon = Some(cursor_icon);
+
+        if let Some(system_cursor) = translate::cursor(cursor_icon).map(system_cursor) {
+            match Cursor::from_system(system_cursor) {
+                Ok(cursor) => {
+                    cursor.set();
+                    self.current_cursor = Some(cursor);
+                }
+                Err(err) => {
+                    log::warn!("Failed to create cursor: {err}");
+                }
+            }
+            self.mouse.show_cursor(true);
+        } else {
+            self.mouse.show_cursor(false);
+        }
+    }
+}
+
+/// The inner rectangle of the window, in egui points.
+pub fn inner_rect_in_points(window: &Window, pixels_per_point: f32) -> Rect {
+    let (x, y) = window.position();
+    let (width, height) = window.size();
+    let scale = window.pixel_density() / pixels_per_point;
+    let rect = Rect::from_min_size(
+        egui::pos2(x as f32, y as f32),
+        egui::vec2(width as f32, height as f32),
+    );
+    scale * rect
+}
+
+/// Update the given viewport info with the current state of the window.
+///
+/// Call before [`State::take_egui_input`].
+pub fn update_viewport_info(
+    viewport_info: &mut ViewportInfo,
+    egui_ctx: &egui::Context,
+    window: &Window,
+) {
+    profiling::function_scope!();
+
+    let pixels_per_point = pixels_per_point(egui_ctx, window);
+
+    viewport_info.native_pixels_per_point = Some(window.display_scale());
+    viewport_info.inner_rect = Some(inner_rect_in_points(window, pixels_per_point));
+    viewport_info.minimized = Some(window.is_minimized());
+    viewport_info.maximized = Some(window.is_maximized());
+    viewport_info.focused = Some(window.has_input_focus());
+}
+
+fn system_cursor(cursor: translate::Cursor) -> SystemCursor {
+    match cursor {
+        translate::Cursor::Default => SystemCursor::Arrow,
+        translate::Cursor::Text => SystemCursor::IBeam,
+        translate::Cursor::Wait => SystemCursor::Wait,
+        translate::Cursor::Crosshair => SystemCursor::Crosshair,
+        translate::Cursor::Progress => SystemCursor::WaitArrow,
+        translate::Cursor::NwseResize => SystemCursor::SizeNWSE,
+        translate::Cursor::NeswResize => SystemCursor::SizeNESW,
+        translate::Cursor::EwResize => SystemCursor::SizeWE,
+        translate::Cursor::NsResize => SystemCursor::SizeNS,
+        translate::Cursor::Move => SystemCursor::SizeAll,
+        translate::Cursor::NotAllowed => SystemCursor::No,
+        translate::Cursor::Pointer => SystemCursor::Hand,
+    }
+}
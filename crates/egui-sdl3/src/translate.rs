@@ -0,0 +1,528 @@
+//! Translating SDL input to egui.
+//!
+//! This works on the raw values SDL uses for keys, modifiers and mouse buttons,
+//! and doesn't use the `sdl3` crate, so that it can be tested without linking to SDL
+//! (see `tests/translate.rs`).
+
+use egui::Key;
+
+/// Keycodes of keys without a character are their scancode with this bit set.
+const SCANCODE_MASK: u32 = 1 << 30;
+
+/// The mouse events SDL emulates from touches and pens have these as their mouse id.
+const TOUCH_MOUSE_ID: u32 = u32::MAX;
+const PEN_MOUSE_ID: u32 = u32::MAX - 1;
+
+const DIGITS: [Key; 10] = [
+    Key::Num0,
+    Key::Num1,
+    Key::Num2,
+    Key::Num3,
+    Key::Num4,
+    Key::Num5,
+    Key::Num6,
+    Key::Num7,
+    Key::Num8,
+    Key::Num9,
+];
+
+const LETTERS: [Key; 26] = [
+    Key::A,
+    Key::B,
+    Key::C,
+    Key::D,
+    Key::E,
+    Key::F,
+    Key::G,
+    Key::H,
+    Key::I,
+    Key::J,
+    Key::K,
+    Key::L,
+    Key::M,
+    Key::N,
+    Key::O,
+    Key::P,
+    Key::Q,
+    Key::R,
+    Key::S,
+    Key::T,
+    Key::U,
+    Key::V,
+    Key::W,
+    Key::X,
+    Key::Y,
+    Key::Z,
+];
+
+const FUNCTION_KEYS: [Key; 24] = [
+    Key::F1,
+    Key::F2,
+    Key::F3,
+    Key::F4,
+    Key::F5,
+    Key::F6,
+    Key::F7,
+    Key::F8,
+    Key::F9,
+    Key::F10,
+    Key::F11,
+    Key::F12,
+    Key::F13,
+    Key::F14,
+    Key::F15,
+    Key::F16,
+    Key::F17,
+    Key::F18,
+    Key::F19,
+    Key::F20,
+    Key::F21,
+    Key::F22,
+    Key::F23,
+    Key::F24,
+];
+
+/// The SDL system cursors we use, see [`cursor`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) enum Cursor {
+    Default,
+    Text,
+    Wait,
+    Crosshair,
+    Progress,
+    NwseResize,
+    NeswResize,
+    EwResize,
+    NsResize,
+    Move,
+    NotAllowed,
+    Pointer,
+}
+
+/// SDL emulates the mouse with touches and pens, which we get proper events for.
+pub(crate) fn is_touch_mouse(which: u32) -> bool {
+    which == TOUCH_MOUSE_ID || which == PEN_MOUSE_ID
+}
+
+/// SDL sends us control characters as text for some keys.
+/// Ignore those.
+pub(crate) fn is_printable_char(chr: char) -> bool {
+    let is_in_private_use_area = ('\u{e000}'..='\u{f8ff}').contains(&chr)
+        || ('\u{f0000}'..='\u{ffffd}').contains(&chr)
+        || ('\u{100000}'..='\u{10fffd}').contains(&chr);
+
+    !is_in_private_use_area && !chr.is_ascii_control()
+}
+
+pub(crate) fn is_cut_command(modifiers: egui::Modifiers, keycode: Key) -> bool {
+    keycode == Key::Cut
+        || (modifiers.command && keycode == Key::X)
+        || (cfg!(target_os = "windows") && modifiers.shift && keycode == Key::Delete)
+}
+
+pub(crate) fn is_copy_command(modifiers: egui::Modifiers, keycode: Key) -> bool {
+    keycode == Key::Copy
+        || (modifiers.command && keycode == Key::C)
+        || (cfg!(target_os = "windows") && modifiers.ctrl && keycode == Key::Insert)
+}
+
+pub(crate) fn is_paste_command(modifiers: egui::Modifiers, keycode: Key) -> bool {
+    keycode == Key::Paste
+        || (modifiers.command && keycode == Key::V)
+        || (cfg!(target_os = "windows") && modifiers.shift && keycode == Key::Insert)
+}
+
+/// Translate `SDL_Keymod` bits.
+pub(crate) fn modifiers(keymod: u16) -> egui::Modifiers {
+    let alt = keymod & 0x0300 != 0; // left | right
+    let ctrl = keymod & 0x00c0 != 0;
+    let shift = keymod & 0x0003 != 0;
+    let super_ = keymod & 0x0c00 != 0;
+
+    egui::Modifiers {
+        alt,
+        ctrl,
+        shift,
+        mac_cmd: cfg!(target_os = "macos") && super_,
+        command: if cfg!(target_os = "macos") {
+            super_
+        } else {
+            ctrl
+        },
+    }
+}
+
+/// Translate an SDL mouse button index.
+pub(crate) fn pointer_button(button: u8) -> Option<egui::PointerButton> {
+    match button {
+        1 => Some(egui::PointerButton::Primary),
+        2 => Some(egui::PointerButton::Middle),
+        3 => Some(egui::PointerButton::Secondary),
+        4 => Some(egui::PointerButton::Extra1),
+        5 => Some(egui::PointerButton::Extra2),
+        _ => None,
+    }
+}
+
+/// The key of an `SDL_Keycode`, i.e. as interpreted by the current keyboard layout.
+pub(crate) fn key_from_keycode(keycode: u32) -> Option<Key> {
+    if keycode & SCANCODE_MASK != 0 {
+        return match keycode & !SCANCODE_MASK {
+            282 => Some(Key::BrowserBack),
+            scancode => key_from_scancode(scancode),
+        };
+    }
+
+    // Otherwise the keycode is the character the key types, without shift:
+    Some(match char::from_u32(keycode)? {
+        '\r' => Key::Enter,
+        '\u{1b}' => Key::Escape,
+        '\u{8}' => Key::Backspace,
+        '\t' => Key::Tab,
+        '\u{7f}' => Key::Delete,
+
+        // Punctuation
+        ' ' => Key::Space,
+        ':' => Key::Colon,
+        ',' => Key::Comma,
+        '\\' => Key::Backslash,
+        '/' => Key::Slash,
+        '|' => Key::Pipe,
+        '?' => Key::Questionmark,
+        '!' => Key::Exclamationmark,
+        '[' => Key::OpenBracket,
+        ']' => Key::CloseBracket,
+        '{' => Key::OpenCurlyBracket,
+        '}' => Key::CloseCurlyBracket,
+        '`' => Key::Backtick,
+        '-' => Key::Minus,
+        '.' => Key::Period,
+        '+' => Key::Plus,
+        '=' => Key::Equals,
+        ';' => Key::Semicolon,
+        '\'' => Key::Quote,
+
+        chr @ '0'..='9' => DIGITS[chr as usize - '0' as usize],
+        chr @ 'a'..='z' => LETTERS[chr as usize - 'a' as usize],
+
+        _ => return None,
+    })
+}
+
+/// The key at the position of an `SDL_Scancode` on a US keyboard, regardless of layout.
+///
+/// Scancodes are USB HID usage ids.
+pub(crate) fn key_from_scancode(scancode: u32) -> Option<Key> {
+    Some(match scancode {
+        4..=29 => LETTERS[scancode as usize - 4],
+        30..=38 => DIGITS[scancode as usize - 29], // 1-9
+        39 | 98 => Key::Num0,
+        89..=97 => DIGITS[scancode as usize - 88], // keypad 1-9
+
+        40 | 88 => Key::Enter, // return, keypad enter
+        41 => Key::Escape,
+        42 => Key::Backspace,
+        43 => Key::Tab,
+
+        // Punctuation
+        44 => Key::Space,
+        45 | 86 => Key::Minus, // and keypad minus
+        46 => Key::Equals,
+        47 => Key::OpenBracket,
+        48 => Key::CloseBracket,
+        49 => Key::Backslash,
+        51 => Key::Semicolon,
+        52 => Key::Quote,
+        53 => Key::Backtick,
+        54 => Key::Comma,
+        55 => Key::Period,
+        56 | 84 => Key::Slash, // and keypad divide
+        87 => Key::Plus,       // keypad plus
+
+        58..=69 => FUNCTION_KEYS[scancode as usize - 58], // F1-F12
+        104..=115 => FUNCTION_KEYS[scancode as usize - 92], // F13-F24
+
+        73 => Key::Insert,
+        74 => Key::Home,
+        75 => Key::PageUp,
+        76 => Key::Delete,
+        77 => Key::End,
+        78 => Key::PageDown,
+        79 => Key::ArrowRight,
+        80 => Key::ArrowLeft,
+        81 => Key::ArrowDown,
+        82 => Key::ArrowUp,
+
+        // ISO 102nd key — `<>|` on French AZERTY, `\|` on UK QWERTY.
+        100 => Key::IntlBackslash,
+
+        123 => Key::Cut,
+        124 => Key::Copy,
+        125 => Key::Paste,
+
+        224 => Key::ControlLeft,
+        225 => Key::ShiftLeft,
+        226 => Key::AltLeft,
+        227 => Key::SuperLeft,
+        228 => Key::ControlRight,
+        229 => Key::ShiftRight,
+        230 => Key::AltRight,
+        231 => Key::SuperRight,
+
+        _ => return None,
+    })
+}
+
+/// Where on the keyboard the key of an `SDL_Scancode` is.
+pub(crate) fn key_location_from_scancode(scancode: u32) -> egui::KeyLocation {
+    match scancode {
+        84 | 86 | 87 | 88 | 89..=98 => egui::KeyLocation::Numpad,
+        _ => egui::KeyLocation::Standard,
+    }
+}
+
+/// The closest SDL system cursor, or `None` to hide the cursor.
+pub(crate) fn cursor(cursor_icon: egui::CursorIcon) -> Option<Cursor> {
+    // SDL has fewer cursors than egui, so some of them share one.
+    match cursor_icon {
+        egui::CursorIcon::None => None,
+
+        egui::CursorIcon::Alias
+        | egui::CursorIcon::ContextMenu
+        | egui::CursorIcon::Copy
+        | egui::CursorIcon::Default
+        | egui::CursorIcon::Help
+        | egui::CursorIcon::ZoomIn
+        | egui::CursorIcon::ZoomOut => Some(Cursor::Default),
+
+        egui::CursorIcon::Cell | egui::CursorIcon::Crosshair => Some(Cursor::Crosshair),
+        egui::CursorIcon::AllScroll
+        | egui::CursorIcon::Grab
+        | egui::CursorIcon::Grabbing
+        | egui::CursorIcon::Move => Some(Cursor::Move),
+        egui::CursorIcon::NoDrop | egui::CursorIcon::NotAllowed => Some(Cursor::NotAllowed),
+        egui::CursorIcon::PointingHand => Some(Cursor::Pointer),
+        egui::CursorIcon::Progress => Some(Cursor::Progress),
+        egui::CursorIcon::Wait => Some(Cursor::Wait),
+        egui::CursorIcon::Text | egui::CursorIcon::VerticalText => Some(Cursor::Text),
+
+        egui::CursorIcon::ResizeHorizontal
+        | egui::CursorIcon::ResizeEast
+        | egui::CursorIcon::ResizeWest
+        | egui::CursorIcon::ResizeColumn => Some(Cursor::EwResize),
+        egui::CursorIcon::ResizeVertical
+        | egui::CursorIcon::ResizeNorth
+        | egui::CursorIcon::ResizeSouth
+        | egui::CursorIcon::ResizeRow => Some(Cursor::NsResize),
+        egui::CursorIcon::ResizeNeSw
+        | egui::CursorIcon::ResizeNorthEast
+        | egui::CursorIcon::ResizeSouthWest => Some(Cursor::NeswResize),
+        egui::CursorIcon::ResizeNwSe
+        | egui::CursorIcon::ResizeNorthWest
+        | egui::CursorIcon::ResizeSouthEast => Some(Cursor::NwseResize),
+    }
+}
+
+/// Translate `SDL_EVENT_MOUSE_WHEEL`.
+pub(crate) fn mouse_wheel_event(
+    x: f32,
+    y: f32,
+    flipped: bool,
+    modifiers: egui::Modifiers,
+) -> egui::Event {
+    let mut delta = egui::vec2(x, y);
+    if flipped {
+        delta = -delta;
+    }
+    egui::Event::MouseWheel {
+        unit: egui::MouseWheelUnit::Line,
+        delta,
+        phase: egui::TouchPhase::Move,
+        momentum: false,
+        modifiers,
+    }
+}
+
+/// Translate `SDL_EVENT_TEXT_EDITING`.
+///
+/// `start` and `length` count characters, and are -1 when not known.
+pub(crate) fn preedit_event(text: &str, start: i32, length: i32) -> egui::Event {
+    let active_range_chars = usize::try_from(start)
+        .ok()
+        .zip(usize::try_from(length).ok())
+        .map(|(start, length)| start..start + length);
+    egui::Event::Ime(egui::ImeEvent::Preedit {
+        text: text.to_owned(),
+        active_range_chars,
+    })
+}
+
+/// Translate `SDL_EVENT_TEXT_INPUT` while not composing.
+pub(crate) fn text_event(text: &str) -> Option<egui::Event> {
+    (!text.is_empty() && text.chars().all(is_printable_char))
+        .then(|| egui::Event::Text(text.to_owned()))
+}
+
+/// Text pasted from the clipboard.
+pub(crate) fn paste_event(contents: &str) -> Option<egui::Event> {
+    let contents = contents.replace("\r\n", "\n");
+    (!contents.is_empty()).then_some(egui::Event::Paste(contents))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_keycodes() {
+        assert_eq!(key_from_keycode('a' as u32), Some(Key::A));
+        assert_eq!(key_from_keycode('z' as u32), Some(Key::Z));
+        assert_eq!(key_from_keycode('0' as u32), Some(Key::Num0));
+        assert_eq!(key_from_keycode('7' as u32), Some(Key::Num7));
+        assert_eq!(key_from_keycode('\r' as u32), Some(Key::Enter));
+        assert_eq!(key_from_keycode(0x1b), Some(Key::Escape));
+        assert_eq!(key_from_keycode(0x7f), Some(Key::Delete));
+        assert_eq!(key_from_keycode('{' as u32), Some(Key::OpenCurlyBracket));
+        assert_eq!(key_from_keycode('\'' as u32), Some(Key::Quote));
+
+        // Keys without a character:
+        assert_eq!(key_from_keycode(SCANCODE_MASK | 82), Some(Key::ArrowUp));
+        assert_eq!(key_from_keycode(SCANCODE_MASK | 58), Some(Key::F1));
+        assert_eq!(key_from_keycode(SCANCODE_MASK | 115), Some(Key::F24));
+        assert_eq!(key_from_keycode(SCANCODE_MASK | 88), Some(Key::Enter));
+        assert_eq!(key_from_keycode(SCANCODE_MASK | 98), Some(Key::Num0));
+        assert_eq!(key_from_keycode(SCANCODE_MASK | 123), Some(Key::Cut));
+        assert_eq!(key_from_keycode(SCANCODE_MASK | 229), Some(Key::ShiftRight));
+        assert_eq!(
+            key_from_keycode(SCANCODE_MASK | 282),
+            Some(Key::BrowserBack)
+        );
+
+        // Not something egui knows about:
+        assert_eq!(key_from_keycode('é' as u32), None);
+        assert_eq!(key_from_keycode(SCANCODE_MASK | 57), None); // caps lock
+    }
+
+    #[test]
+    fn test_scancodes() {
+        assert_eq!(key_from_scancode(4), Some(Key::A));
+        assert_eq!(key_from_scancode(29), Some(Key::Z));
+        assert_eq!(key_from_scancode(30), Some(Key::Num1));
+        assert_eq!(key_from_scancode(38), Some(Key::Num9));
+        assert_eq!(key_from_scancode(39), Some(Key::Num0));
+        assert_eq!(key_from_scancode(89), Some(Key::Num1));
+        assert_eq!(key_from_scancode(97), Some(Key::Num9));
+        assert_eq!(key_from_scancode(69), Some(Key::F12));
+        assert_eq!(key_from_scancode(104), Some(Key::F13));
+        assert_eq!(key_from_scancode(100), Some(Key::IntlBackslash));
+        assert_eq!(key_from_scancode(0), None);
+
+        assert_eq!(key_location_from_scancode(98), egui::KeyLocation::Numpad);
+        assert_eq!(key_location_from_scancode(88), egui::KeyLocation::Numpad);
+        assert_eq!(key_location_from_scancode(39), egui::KeyLocation::Standard);
+        assert_eq!(key_location_from_scancode(40), egui::KeyLocation::Standard);
+    }
+
+    #[test]
+    fn test_modifiers() {
+        assert_eq!(modifiers(0), egui::Modifiers::NONE);
+
+        let left_shift = modifiers(0x0001);
+        assert!(left_shift.shift && !left_shift.ctrl && !left_shift.alt);
+        assert!(modifiers(0x0002).shift);
+        assert!(modifiers(0x0100).alt);
+        assert!(modifiers(0x0200).alt);
+
+        let right_ctrl = modifiers(0x0080);
+        assert!(right_ctrl.ctrl);
+        assert_eq!(right_ctrl.command, !cfg!(target_os = "macos"));
+
+        let left_gui = modifiers(0x0400);
+        assert!(!left_gui.ctrl);
+        assert_eq!(left_gui.command, cfg!(target_os = "macos"));
+        assert_eq!(left_gui.mac_cmd, cfg!(target_os = "macos"));
+
+        // Num lock and caps lock aren't modifiers for egui:
+        assert_eq!(modifiers(0x1000 | 0x2000), egui::Modifiers::NONE);
+    }
+
+    #[test]
+    fn test_commands() {
+        assert!(is_copy_command(egui::Modifiers::COMMAND, Key::C));
+        assert!(is_cut_command(egui::Modifiers::COMMAND, Key::X));
+        assert!(is_paste_command(egui::Modifiers::COMMAND, Key::V));
+        assert!(is_paste_command(egui::Modifiers::NONE, Key::Paste));
+        assert!(!is_copy_command(egui::Modifiers::NONE, Key::C));
+        assert!(!is_paste_command(egui::Modifiers::SHIFT, Key::V));
+    }
+
+    #[test]
+    fn test_mouse() {
+        assert_eq!(pointer_button(1), Some(egui::PointerButton::Primary));
+        assert_eq!(pointer_button(2), Some(egui::PointerButton::Middle));
+        assert_eq!(pointer_button(3), Some(egui::PointerButton::Secondary));
+        assert_eq!(pointer_button(5), Some(egui::PointerButton::Extra2));
+        assert_eq!(pointer_button(0), None);
+
+        assert!(is_touch_mouse(u32::MAX));
+        assert!(is_touch_mouse(u32::MAX - 1));
+        assert!(!is_touch_mouse(0));
+
+        let modifiers = egui::Modifiers::SHIFT;
+        assert_eq!(
+            mouse_wheel_event(1.0, -2.0, true, modifiers),
+            egui::Event::MouseWheel {
+                unit: egui::MouseWheelUnit::Line,
+                delta: egui::vec2(-1.0, 2.0),
+                phase: egui::TouchPhase::Move,
+                momentum: false,
+                modifiers,
+            }
+        );
+    }
+
+    #[test]
+    fn test_cursor() {
+        assert_eq!(cursor(egui::CursorIcon::None), None);
+        assert_eq!(cursor(egui::CursorIcon::Default), Some(Cursor::Default));
+        assert_eq!(cursor(egui::CursorIcon::Text), Some(Cursor::Text));
+        assert_eq!(cursor(egui::CursorIcon::Grab), Some(Cursor::Move));
+        assert_eq!(
+            cursor(egui::CursorIcon::PointingHand),
+            Some(Cursor::Pointer)
+        );
+        assert_eq!(
+            cursor(egui::CursorIcon::ResizeNorthWest),
+            Some(Cursor::NwseResize)
+        );
+    }
+
+    #[test]
+    fn test_text() {
+        assert_eq!(text_event("å"), Some(egui::Event::Text("å".to_owned())));
+        assert_eq!(text_event("\u{8}"), None);
+        assert_eq!(text_event("\u{f700}"), None); // private use, e.g. arrow keys on macOS
+        assert_eq!(text_event(""), None);
+
+        assert_eq!(
+            preedit_event("にほん", 1, 2),
+            egui::Event::Ime(egui::ImeEvent::Preedit {
+                text: "にほん".to_owned(),
+                active_range_chars: Some(1..3),
+            })
+        );
+        assert_eq!(
+            preedit_event("に", -1, -1),
+            egui::Event::Ime(egui::ImeEvent::Preedit {
+                text: "に".to_owned(),
+                active_range_chars: None,
+            })
+        );
+
+        assert_eq!(
+            paste_event("a\r\nb"),
+            Some(egui::Event::Paste("a\nb".to_owned()))
+        );
+        assert_eq!(paste_event(""), None);
+    }
+}
@@ -0,0 +1,6 @@
+//! Runs the tests of `src/translate.rs`.
+//!
+//! The library itself links to SDL, which isn't needed for these, so they are built on their own.
+
+#[path = "../src/translate.rs"]
+mod translate;
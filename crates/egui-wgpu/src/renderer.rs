@@ -2,7 +2,7 @@ use std::{borrow::Cow, num::NonZeroU64, ops::Range};
 
 use ahash::HashMap;
 use bytemuck::Zeroable as _;
-use epaint::{PaintCallbackInfo, Primitive, Vertex, emath::NumExt as _};
+use epaint::{Color32, PaintCallbackInfo, Primitive, Rgba, Vertex, emath::NumExt as _};
 
 use wgpu::util::DeviceExt as _;
 
@@ -632,26 +632,38 @@ impl Renderer {
                 Cow::Borrowed(&image.pixels)
             }
         };
-        let data_bytes: &[u8] = bytemuck::cast_slice(data_color32.as_slice());
-
-        let queue_write_data_to_texture = |texture, origin| {
-            profiling::scope!("write_texture");
-            queue.write_texture(
-                wgpu::TexelCopyTextureInfo {
-                    texture,
-                    mip_level: 0,
-                    origin,
-                    aspect: wgpu::TextureAspect::All,
-                },
-                data_bytes,
-                wgpu::TexelCopyBufferLayout {
-                    offset: 0,
-                    bytes_per_row: Some(4 * width),
-                    rows_per_image: Some(height),
-                },
-                size,
-            );
-        };
+
+        let queue_write_data_to_texture =
+            |texture: &wgpu::Texture,
+             mip_level: u32,
+             origin: wgpu::Origin3d,
+             pixels: &[Color32],
+             [width, height]: [u32; 2]| {
+                profiling::scope!("write_texture");
+                // Partial updates may not line up with the smaller mip levels:
+                let mip_size = texture
+                    .size()
+                    .mip_level_size(mip_level, wgpu::TextureDimension::D2);
+                queue.write_texture(
+                    wgpu::TexelCopyTextureInfo {
+                        texture,
+                        mip_level,
+                        origin,
+                        aspect: wgpu::TextureAspect::All,
+                    },
+                    bytemuck::cast_slice(pixels),
+                    wgpu::TexelCopyBufferLayout {
+                        offset: 0,
+                        bytes_per_row: Some(4 * width),
+                        rows_per_image: Some(height),
+                    },
+                    wgpu::Extent3d {
+                        width: width.min(mip_size.width.saturating_sub(origin.x)),
+                        height: height.min(mip_size.height.saturating_sub(origin.y)),
+                        depth_or_array_layers: 1,
+                    },
+                );
+            };
 
         // Use same label for all resources associated with this texture id (no point in retyping the type)
         let label_str = format!("egui_texid_{id:?}");
@@ -693,7 +705,11 @@ impl Renderer {
                 device.create_texture(&wgpu::TextureDescriptor {
                     label,
                     size,
-                    mip_level_count: 1,
+                    mip_level_count: if image_delta.options.mipmap_mode.is_some() {
+                        size.max_mips(wgpu::TextureDimension::D2)
+                    } else {
+                        1
+                    },
                     sample_count: 1,
                     dimension: wgpu::TextureDimension::D2,
                     format: wgpu::TextureFormat::Rgba8Unorm,
@@ -728,7 +744,22 @@ impl Renderer {
             })
         });
 
-        queue_write_data_to_texture(&texture, origin);
+        queue_write_data_to_texture(&texture, 0, origin, &data_color32, [width, height]);
+
+        if 1 < texture.mip_level_count() {
+            profiling::scope!("generate mipmaps");
+            let mut pixels = data_color32.into_owned();
+            let mut size = [width, height];
+            for mip_level in 1..texture.mip_level_count() {
+                (pixels, size) = downsample(&pixels, size);
+                let origin = wgpu::Origin3d {
+                    x: origin.x >> mip_level,
+                    y: origin.y >> mip_level,
+                    z: 0,
+                };
+                queue_write_data_to_texture(&texture, mip_level, origin, &pixels, size);
+            }
+        }
 
         // A full update must (re)create the texture at exactly the delta's size,
         // or glyph UVs (normalized by the CPU atlas size) will sample the wrong rows.
@@ -1088,6 +1119,19 @@ fn create_sampler(
         epaint::textures::TextureFilter::Nearest => wgpu::FilterMode::Nearest,
         epaint::textures::TextureFilter::Linear => wgpu::FilterMode::Linear,
     };
+    let mipmap_filter = match options.mipmap_mode {
+        None | Some(epaint::textures::TextureFilter::Nearest) => wgpu::MipmapFilterMode::Nearest,
+        Some(epaint::textures::TextureFilter::Linear) => wgpu::MipmapFilterMode::Linear,
+    };
+    // wgpu only allows anisotropic filtering when all filters are linear:
+    let all_linear = mag_filter == wgpu::FilterMode::Linear
+        && min_filter == wgpu::FilterMode::Linear
+        && options.mipmap_mode == Some(epaint::textures::TextureFilter::Linear);
+    let anisotropy_clamp = if all_linear {
+        u16::from(options.max_anisotropy).clamp(1, 16)
+    } else {
+        1
+    };
     let address_mode = match options.wrap_mode {
         epaint::textures::TextureWrapMode::ClampToEdge => wgpu::AddressMode::ClampToEdge,
         epaint::textures::TextureWrapMode::Repeat => wgpu::AddressMode::Repeat,
@@ -1099,12 +1143,41 @@ fn create_sampler(
         )),
         mag_filter,
         min_filter,
+        mipmap_filter,
+        anisotropy_clamp,
         address_mode_u: address_mode,
         address_mode_v: address_mode,
         ..Default::default()
     })
 }
 
+/// Halve the size of an image (rounding up), averaging each 2x2 block of pixels.
+///
+/// Used to generate mipmaps.
+fn downsample(pixels: &[Color32], [width, height]: [u32; 2]) -> (Vec<Color32>, [u32; 2]) {
+    let (width, height) = (width as usize, height as usize);
+    let new_width = width.div_ceil(2).max(1);
+    let new_height = height.div_ceil(2).max(1);
+
+    let mut new_pixels = Vec::with_capacity(new_width * new_height);
+    for y in 0..new_height {
+        for x in 0..new_width {
+            let mut sum = Rgba::TRANSPARENT;
+            let mut count = 0.0;
+            for sy in (2 * y)..(2 * y + 2).min(height) {
+                for sx in (2 * x)..(2 * x + 2).min(width) {
+                    // Average in linear space, so the mipmaps don't darken:
+                    sum = sum + Rgba::from(pixels[sy * width + sx]);
+                    count += 1.0;
+                }
+            }
+            new_pixels.push(Color32::from(sum * (1.0 / count)));
+        }
+    }
+
+    (new_pixels, [new_width as u32, new_height as u32])
+}
+
 fn create_vertex_buffer(device: &wgpu::Device, size: u64) -> wgpu::Buffer {
     profiling::function_scope!();
     device.create_buffer(&wgpu::BufferDescriptor {
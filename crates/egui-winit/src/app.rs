@@ -0,0 +1,513 @@
+//! A ready-made [`ApplicationHandler`] for apps that use egui-winit without eframe.
+
+use std::{
+    collections::HashMap,
+    sync::{Arc, Weak},
+};
+
+use egui::{ViewportBuilder, ViewportId, ViewportInfo};
+use web_time::Instant;
+use winit::{
+    application::ApplicationHandler,
+    event::WindowEvent,
+    event_loop::{ActiveEventLoop, ControlFlow, EventLoopProxy},
+    window::{Window, WindowId},
+};
+
+use crate::{ActionRequested, EventResponse, State, WindowLevels};
+
+/// A window managed by [`MultiWindowApp`], with its own [`egui::Context`] and [`State`].
+pub struct EguiWindow {
+    pub window: Arc<Window>,
+    pub egui_ctx: egui::Context,
+    pub state: State,
+    viewport_info: ViewportInfo,
+    next_repaint: Option<Instant>,
+
+    /// Show the window once the next frame has been painted.
+    show_when_ready: bool,
+}
+
+impl EguiWindow {
+    /// The id of the winit window.
+    pub fn id(&self) -> WindowId {
+        self.window.id()
+    }
+}
+
+/// A repaint egui asked for, e.g. from another thread, see [`Windows::set_repaint_proxy`].
+#[derive(Clone, Copy, Debug)]
+pub struct RepaintRequest {
+    pub window_id: WindowId,
+
+    /// Repaint at this time, or as soon as possible if it has passed.
+    pub when: Instant,
+}
+
+/// Sends a [`RepaintRequest`] to the event loop.
+type RepaintWaker = Arc<dyn Fn(RepaintRequest) + Send + Sync>;
+
+/// The windows of a [`MultiWindowApp`].
+#[derive(Default)]
+pub struct Windows {
+    windows: HashMap<WindowId, EguiWindow>,
+    window_levels: WindowLevels,
+
+    /// Set by [`Self::set_repaint_proxy`].
+    repaint_waker: Option<RepaintWaker>,
+}
+
+impl Windows {
+    /// Open a new window, with a fresh [`egui::Context`].
+    ///
+    /// # Errors
+    /// See [`crate::create_window`].
+    pub fn create(
+        &mut self,
+        event_loop: &ActiveEventLoop,
+        viewport_builder: &ViewportBuilder,
+    ) -> Result<WindowId, winit::error::OsError> {
+        let egui_ctx = egui::Context::default();
+        let window = crate::create_window(&egui_ctx, event_loop, viewport_builder)?;
+        let window_id = self.insert(event_loop, egui_ctx, window);
+        if let Some(win) = self.windows.get_mut(&window_id) {
+            win.show_when_ready = crate::is_shown_when_ready(viewport_builder);
+            win.viewport_info.window_level = viewport_builder.window_level;
+        }
+        Ok(window_id)
+    }
+
+    /// Manage a window you created yourself.
+    pub fn insert(
+        &mut self,
+        event_loop: &ActiveEventLoop,
+        egui_ctx: egui::Context,
+        window: Window,
+    ) -> WindowId {
+        let window = Arc::new(window);
+        self.install_repaint_callback(&egui_ctx, &window);
+
+        let state = State::new(
+            egui_ctx.clone(),
+            ViewportId::ROOT,
+            event_loop,
+            Some(window.scale_factor() as f32),
+            window.theme(),
+            None,
+        );
+
+        let mut viewport_info = ViewportInfo::default();
+        crate::update_viewport_info(&mut viewport_info, &egui_ctx, &window, true);
+
+        let window_id = window.id();
+        window.request_redraw();
+        self.windows.insert(
+            window_id,
+            EguiWindow {
+                window,
+                egui_ctx,
+                state,
+                viewport_info,
+                next_repaint: None,
+                show_when_ready: false,
+            },
+        );
+        window_id
+    }
+
+    /// Wake the event loop through `proxy` whenever egui asks for a repaint.
+    ///
+    /// Without this, repaints requested from other threads only wake the event loop if they
+    /// are immediate, and ones with a delay wait for the next event.
+    /// Hand the [`RepaintRequest`]s to [`Self::on_repaint_request`] in [`MultiWindowHooks::user_event`].
+    pub fn set_repaint_proxy<T: From<RepaintRequest> + Send + 'static>(
+        &mut self,
+        proxy: EventLoopProxy<T>,
+    ) {
+        self.repaint_waker = Some(Arc::new(move |request| {
+            proxy.send_event(request.into()).ok();
+        }));
+
+        #[expect(clippy::iter_over_hash_type)] // Order doesn't matter
+        for win in self.windows.values() {
+            self.install_repaint_callback(&win.egui_ctx, &win.window);
+        }
+    }
+
+    /// Schedule the repaint of the window it is for.
+    ///
+    /// Call this from [`MultiWindowHooks::user_event`], see [`Self::set_repaint_proxy`].
+    pub fn on_repaint_request(&mut self, request: RepaintRequest) {
+        let Some(win) = self.windows.get_mut(&request.window_id) else {
+            return;
+        };
+        if request.when <= Instant::now() {
+            win.window.request_redraw();
+        } else {
+            win.next_repaint = Some(
+                win.next_repaint
+                    .map_or(request.when, |next| next.min(request.when)),
+            );
+        }
+    }
+
+    /// Route the repaints `egui_ctx` asks for, e.g. from another thread, to `window`.
+    fn install_repaint_callback(&self, egui_ctx: &egui::Context, window: &Arc<Window>) {
+        let window_id = window.id();
+        let window: Weak<Window> = Arc::downgrade(window);
+        let waker = self.repaint_waker.clone();
+        egui_ctx.set_request_repaint_callback(move |info| {
+            if info.viewport_id != ViewportId::ROOT {
+                return; // child viewports aren't supported
+            }
+            if let Some(waker) = &waker {
+                if let Some(when) = Instant::now().checked_add(info.delay) {
+                    waker(RepaintRequest { window_id, when });
+                }
+            } else if info.delay.is_zero()
+                && let Some(window) = window.upgrade()
+            {
+                // This is thread-safe, and wakes up the event loop:
+                window.request_redraw();
+            }
+        });
+    }
+
+    pub fn get(&self, window_id: WindowId) -> Option<&EguiWindow> {
+        self.windows.get(&window_id)
+    }
+
+    pub fn get_mut(&mut self, window_id: WindowId) -> Option<&mut EguiWindow> {
+        self.windows.get_mut(&window_id)
+    }
+
+    /// Close a window.
+    pub fn remove(&mut self, window_id: WindowId) -> Option<EguiWindow> {
+        self.windows.remove(&window_id)
+    }
+
+    /// Route an AccessKit event to the window it is for.
+    ///
+    /// Call this from [`MultiWindowHooks::user_event`], after calling [`State::init_accesskit`]
+    /// for each window that should be accessible.
+    #[cfg(feature = "accesskit")]
+    pub fn on_accesskit_event(&mut self, event: &accesskit_winit::Event) {
+        let Some(win) = self.windows.get_mut(&event.window_id) else {
+            return;
+        };
+        match &event.window_event {
+            accesskit_winit::WindowEvent::InitialTreeRequested => {
+                win.state.on_accesskit_activated();
+                win.window.request_redraw();
+            }
+            accesskit_winit::WindowEvent::ActionRequested(request) => {
+                win.state.on_accesskit_action_request(request.clone());
+                win.window.request_redraw();
+            }
+            accesskit_winit::WindowEvent::AccessibilityDeactivated => {
+                win.state.on_accesskit_deactivated();
+            }
+        }
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &EguiWindow> {
+        self.windows.values()
+    }
+
+    pub fn len(&self) -> usize {
+        self.windows.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.windows.is_empty()
+    }
+
+    fn update_window_levels(&mut self) {
+        let app_focused = self.windows.values().any(|win| win.window.has_focus());
+        self.window_levels.update(
+            app_focused,
+            self.windows
+                .values()
+                .map(|win| (&*win.window, &win.viewport_info)),
+        );
+    }
+}
+
+/// What happens in a [`MultiWindowApp`].
+///
+/// Only [`Self::resumed`], [`Self::ui`] and [`Self::paint`] are required.
+pub trait MultiWindowHooks<T: 'static = ()> {
+    /// The app was started or resumed. Create your windows here with [`Windows::create`].
+    fn resumed(&mut self, event_loop: &ActiveEventLoop, windows: &mut Windows);
+
+    /// Show the ui of a window.
+    fn ui(&mut self, window_id: WindowId, ui: &mut egui::Ui);
+
+    /// Paint the output of [`Self::ui`] to the window, using your renderer of choice.
+    fn paint(
+        &mut self,
+        window: &EguiWindow,
+        textures_delta: egui::TexturesDelta,
+        clipped_primitives: Vec<egui::ClippedPrimitive>,
+        pixels_per_point: f32,
+    );
+
+    /// Called for every window event, after egui has seen it.
+    ///
+    /// Use this to e.g. resize your surface on [`WindowEvent::Resized`].
+    fn window_event(&mut self, window: &EguiWindow, event: &WindowEvent, response: &EventResponse) {
+        _ = (window, event, response);
+    }
+
+    /// The user wants to close a window. Return `false` to keep it open.
+    fn close_requested(&mut self, window_id: WindowId) -> bool {
+        _ = window_id;
+        true
+    }
+
+    /// A window was closed. Free any resources you have for it here.
+    fn window_closed(&mut self, window_id: WindowId) {
+        _ = window_id;
+    }
+
+    /// The last window was closed. Exits the event loop by default.
+    fn all_windows_closed(&mut self, event_loop: &ActiveEventLoop) {
+        event_loop.exit();
+    }
+
+    /// A user event sent through an [`winit::event_loop::EventLoopProxy`].
+    fn user_event(&mut self, event_loop: &ActiveEventLoop, windows: &mut Windows, event: T) {
+        _ = (event_loop, windows, event);
+    }
+}
+
+/// An [`ApplicationHandler`] that manages any number of egui windows.
+///
+/// It routes window events to the [`State`] of the right window,
+/// runs egui when a window needs to be redrawn,
+/// handles [`egui::PlatformOutput`] and [`egui::ViewportCommand`]s,
+/// and schedules repaints, including ones requested from other threads
+/// (see [`Windows::set_repaint_proxy`] for ones with a delay).
+/// What to show and how to paint it is up to your [`MultiWindowHooks`].
+///
+/// ```no_run
+/// # use egui_winit::{EguiWindow, MultiWindowApp, MultiWindowHooks, Windows};
+/// # use egui_winit::winit::{event_loop::{ActiveEventLoop, EventLoop}, window::WindowId};
+/// struct MyApp;
+///
+/// impl MultiWindowHooks for MyApp {
+///     fn resumed(&mut self, event_loop: &ActiveEventLoop, windows: &mut Windows) {
+///         if windows.is_empty() {
+///             windows.create(event_loop, &egui::ViewportBuilder::default()).unwrap();
+///             windows.create(event_loop, &egui::ViewportBuilder::default()).unwrap();
+///         }
+///     }
+///
+///     fn ui(&mut self, window_id: WindowId, ui: &mut egui::Ui) {
+///         ui.label(format!("Hello from {window_id:?}"));
+///     }
+///
+///     fn paint(
+///         &mut self,
+///         window: &EguiWindow,
+///         textures_delta: egui::TexturesDelta,
+///         clipped_primitives: Vec<egui::ClippedPrimitive>,
+///         pixels_per_point: f32,
+///     ) {
+///         // Paint with egui-wgpu, egui_glow, or your own renderer.
+///     }
+/// }
+///
+/// let event_loop = EventLoop::new().unwrap();
+/// event_loop.run_app(&mut MultiWindowApp::new(MyApp)).unwrap();
+/// ```
+pub struct MultiWindowApp<H> {
+    pub hooks: H,
+    pub windows: Windows,
+}
+
+impl<H> MultiWindowApp<H> {
+    pub fn new(hooks: H) -> Self {
+        Self {
+            hooks,
+            windows: Windows::default(),
+        }
+    }
+
+    fn close_window<T: 'static>(&mut self, event_loop: &ActiveEventLoop, window_id: WindowId)
+    where
+        H: MultiWindowHooks<T>,
+    {
+        if self.hooks.close_requested(window_id) && self.windows.remove(window_id).is_some() {
+            self.hooks.window_closed(window_id);
+            if self.windows.is_empty() {
+                self.hooks.all_windows_closed(event_loop);
+            }
+        }
+    }
+
+    fn redraw<T: 'static>(&mut self, event_loop: &ActiveEventLoop, window_id: WindowId)
+    where
+        H: MultiWindowHooks<T>,
+    {
+        profiling::function_scope!();
+
+        let Some(win) = self.windows.get_mut(window_id) else {
+            return;
+        };
+        let EguiWindow {
+            window,
+            egui_ctx,
+            state,
+            viewport_info,
+            next_repaint,
+            show_when_ready,
+        } = win;
+        *next_repaint = None;
+
+        crate::update_viewport_info(viewport_info, egui_ctx, window, false);
+        let mut raw_input = state.take_egui_input(&**window);
+        raw_input
+            .viewports
+            .insert(ViewportId::ROOT, viewport_info.take());
+
+        let hooks = &mut self.hooks;
+        let full_output = egui_ctx.run_ui(raw_input, |ui| hooks.ui(window_id, ui));
+
+        let egui::FullOutput {
+            platform_output,
+            textures_delta,
+            shapes,
+            pixels_per_point,
+            viewport_output,
+        } = full_output;
+
+        state.handle_platform_output(&**window, platform_output);
+
+        let mut actions_requested = Vec::new();
+        let mut repaint_delay = None;
+        for (viewport_id, output) in viewport_output {
+            if viewport_id != ViewportId::ROOT {
+                log::warn!("MultiWindowApp doesn't support child viewports");
+                continue;
+            }
+            crate::process_viewport_commands(
+                egui_ctx,
+                viewport_info,
+                output.commands,
+                window,
+                &mut actions_requested,
+            );
+            repaint_delay = Some(output.repaint_delay);
+        }
+
+        for action in actions_requested {
+            let event = match action {
+                ActionRequested::Cut => egui::Event::Cut,
+                ActionRequested::Copy => egui::Event::Copy,
+                ActionRequested::Paste => {
+                    let Some(contents) = state.clipboard_text() else {
+                        continue;
+                    };
+                    egui::Event::Paste(contents.replace("\r\n", "\n"))
+                }
+                ActionRequested::Screenshot(user_data) => {
+                    // We don't own the renderer, so we can't read back the frame ourselves:
+                    if !state.request_portal_screenshot(user_data) {
+                        log::warn!(
+                            "MultiWindowApp only supports screenshots with the `screenshot_portal` feature"
+                        );
+                    }
+                    continue;
+                }
+                ActionRequested::ShowWhenReady => {
+                    *show_when_ready = true;
+                    continue;
+                }
+            };
+            state.egui_input_mut().events.push(event);
+        }
+
+        match repaint_delay {
+            Some(delay) if delay.is_zero() => window.request_redraw(),
+            Some(delay) => *next_repaint = Instant::now().checked_add(delay),
+            None => {}
+        }
+
+        let close_requested = viewport_info.close_requested();
+
+        let clipped_primitives = egui_ctx.tessellate(shapes, pixels_per_point);
+        if let Some(win) = self.windows.get(window_id) {
+            self.hooks
+                .paint(win, textures_delta, clipped_primitives, pixels_per_point);
+        }
+
+        if let Some(win) = self.windows.get_mut(window_id)
+            && std::mem::take(&mut win.show_when_ready)
+        {
+            win.window.set_visible(true);
+        }
+
+        self.windows.update_window_levels();
+
+        if close_requested {
+            self.close_window(event_loop, window_id);
+        }
+    }
+}
+
+impl<T: 'static, H: MultiWindowHooks<T>> ApplicationHandler<T> for MultiWindowApp<H> {
+    fn resumed(&mut self, event_loop: &ActiveEventLoop) {
+        self.hooks.resumed(event_loop, &mut self.windows);
+        for win in self.windows.iter() {
+            win.window.request_redraw();
+        }
+    }
+
+    fn user_event(&mut self, event_loop: &ActiveEventLoop, event: T) {
+        self.hooks.user_event(event_loop, &mut self.windows, event);
+    }
+
+    fn window_event(
+        &mut self,
+        event_loop: &ActiveEventLoop,
+        window_id: WindowId,
+        event: WindowEvent,
+    ) {
+        let Some(win) = self.windows.get_mut(window_id) else {
+            return;
+        };
+
+        let response = win.state.on_window_event(&*win.window, &event);
+        self.hooks.window_event(win, &event, &response);
+
+        match event {
+            WindowEvent::CloseRequested => self.close_window(event_loop, window_id),
+            WindowEvent::RedrawRequested => self.redraw(event_loop, window_id),
+            _ => {
+                if response.repaint {
+                    win.window.request_redraw();
+                }
+            }
+        }
+    }
+
+    fn about_to_wait(&mut self, event_loop: &ActiveEventLoop) {
+        let now = Instant::now();
+        let mut next_repaint: Option<Instant> = None;
+
+        #[expect(clippy::iter_over_hash_type)] // Order doesn't matter
+        for win in self.windows.windows.values_mut() {
+            if let Some(when) = win.next_repaint {
+                if when <= now {
+                    win.next_repaint = None;
+                    win.window.request_redraw();
+                } else {
+                    next_repaint = Some(next_repaint.map_or(when, |next| next.min(when)));
+                }
+            }
+        }
+
+        event_loop.set_control_flow(next_repaint.map_or(ControlFlow::Wait, ControlFlow::WaitUntil));
+    }
+}
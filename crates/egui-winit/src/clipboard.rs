@@ -1,10 +1,88 @@
+use std::{
+    path::PathBuf,
+    sync::{
+        Arc,
+        atomic::{AtomicBool, Ordering},
+        mpsc,
+    },
+    time::Duration,
+};
+
+use egui::ViewportId;
+
 use raw_window_handle::RawDisplayHandle;
 
+/// Which clipboard to use, see [`crate::State::new_with_clipboard`].
+#[derive(Default)]
+pub enum ClipboardConfig {
+    /// The OS clipboard: smithay-clipboard on Wayland, and arboard elsewhere.
+    #[default]
+    Auto,
+
+    /// The OS clipboard through arboard, even on Wayland.
+    Arboard,
+
+    /// The Wayland clipboard through smithay-clipboard, even if arboard is available.
+    ///
+    /// Falls back to [`Self::InApp`] when not running on Wayland.
+    Smithay,
+
+    /// A clipboard that only works within the app, e.g. for kiosks.
+    InApp,
+
+    /// A clipboard implemented by the application.
+    Custom(Box<dyn ClipboardProvider>),
+}
+
+/// A clipboard implemented by the application, see [`ClipboardConfig::Custom`].
+///
+/// Only text is required, the other formats are optional.
+pub trait ClipboardProvider {
+    /// Fetches text from the clipboard.
+    fn get_text(&mut self) -> Option<String>;
+
+    /// Places the text onto the clipboard.
+    fn set_text(&mut self, text: String);
+
+    /// Fetches HTML from the clipboard, if there is any.
+    fn get_html(&mut self) -> Option<String> {
+        None
+    }
+
+    /// Places the HTML onto the clipboard, with `alt_text` for applications that only accept plain text.
+    fn set_html(&mut self, html: String, alt_text: String) {
+        _ = html;
+        self.set_text(alt_text);
+    }
+
+    /// Fetches the files on the clipboard, if there are any.
+    fn get_file_list(&mut self) -> Option<Vec<PathBuf>> {
+        None
+    }
+
+    /// Places the files onto the clipboard.
+    fn set_file_list(&mut self, paths: &[PathBuf]) {
+        _ = paths;
+        log::warn!("Copying files is not supported by this clipboard");
+    }
+
+    /// Places the image onto the clipboard.
+    fn set_image(&mut self, image: &egui::ColorImage) {
+        _ = image;
+        log::warn!("Copying images is not supported by this clipboard");
+    }
+}
+
 /// Handles interfacing with the OS clipboard.
 ///
 /// If the "clipboard" feature is off, or we cannot connect to the OS clipboard,
 /// then a fallback clipboard that just works within the same app is used instead.
+///
+/// Which clipboard is used can be chosen with [`ClipboardConfig`].
 pub struct Clipboard {
+    /// Set by [`ClipboardConfig::Custom`].
+    custom: Option<Box<dyn ClipboardProvider>>,
+
     #[cfg(all(
         not(any(target_os = "android", target_os = "ios")),
         feature = "arboard",
@@ -21,21 +99,61 @@ pub struct Clipboard {
         ),
         feature = "smithay-clipboard"
     ))]
-    smithay: Option<smithay_clipboard::Clipboard>,
+    smithay: Option<Arc<egui::mutex::Mutex<smithay_clipboard::Clipboard>>>,
+
+    /// For the separate smithay clipboard of the [`ClipboardWatcher`].
+    #[cfg(all(
+        any(
+            target_os = "linux",
+            target_os = "dragonfly",
+            target_os = "freebsd",
+            target_os = "netbsd",
+            target_os = "openbsd"
+        ),
+        feature = "smithay-clipboard"
+    ))]
+    wayland_display: Option<RawDisplayHandle>,
 
     /// Fallback manual clipboard.
     clipboard: String,
+
+    /// Fallback manual clipboard for [`Self::set_html`].
+    html: Option<String>,
+
+    /// Fallback manual clipboard for [`Self::set_file_list`].
+    files: Vec<PathBuf>,
 }
 
 impl Clipboard {
     /// Construct a new instance
-    pub fn new(_raw_display_handle: Option<RawDisplayHandle>) -> Self {
+    pub fn new(raw_display_handle: Option<RawDisplayHandle>) -> Self {
+        Self::with_config(raw_display_handle, ClipboardConfig::Auto)
+    }
+
+    /// Construct a new instance, using the given kind of clipboard.
+    pub fn with_config(
+        _raw_display_handle: Option<RawDisplayHandle>,
+        config: ClipboardConfig,
+    ) -> Self {
+        let (use_arboard, use_smithay) = match &config {
+            ClipboardConfig::Auto => (true, true),
+            ClipboardConfig::Arboard => (true, false),
+            ClipboardConfig::Smithay => (false, true),
+            ClipboardConfig::InApp | ClipboardConfig::Custom(_) => (false, false),
+        };
+        _ = (use_arboard, use_smithay); // Unused on some platforms
+
         Self {
+            custom: match config {
+                ClipboardConfig::Custom(provider) => Some(provider),
+                _ => None,
+            },
+
             #[cfg(all(
                 not(any(target_os = "android", target_os = "ios")),
                 feature = "arboard",
             ))]
-            arboard: init_arboard(),
+            arboard: use_arboard.then(init_arboard).flatten(),
 
             #[cfg(all(
                 any(
@@ -47,13 +165,34 @@ impl Clipboard {
                 ),
                 feature = "smithay-clipboard"
             ))]
-            smithay: init_smithay_clipboard(_raw_display_handle),
+            smithay: use_smithay
+                .then(|| init_smithay_clipboard(_raw_display_handle))
+                .flatten()
+                .map(|clipboard| Arc::new(egui::mutex::Mutex::new(clipboard))),
+
+            #[cfg(all(
+                any(
+                    target_os = "linux",
+                    target_os = "dragonfly",
+                    target_os = "freebsd",
+                    target_os = "netbsd",
+                    target_os = "openbsd"
+                ),
+                feature = "smithay-clipboard"
+            ))]
+            wayland_display: _raw_display_handle,
 
             clipboard: Default::default(),
+            html: None,
+            files: Vec::new(),
         }
     }
 
     pub fn get(&mut self) -> Option<String> {
+        if let Some(custom) = &mut self.custom {
+            return custom.get_text();
+        }
+
         #[cfg(all(
             any(
                 target_os = "linux",
@@ -64,8 +203,8 @@ impl Clipboard {
             ),
             feature = "smithay-clipboard"
         ))]
-        if let Some(clipboard) = &mut self.smithay {
-            match clipboard.load() {
+        if let Some(clipboard) = &self.smithay {
+            match clipboard.lock().load() {
                 Ok(text) => return Some(text),
                 Err(err) => {
                     log::error!("smithay paste error: {err}");
@@ -90,7 +229,160 @@ impl Clipboard {
         Some(self.clipboard.clone())
     }
 
+    /// Like [`Self::get_file_list`] and [`Self::get`], but returns a function that can be called
+    /// on another thread, since reading the clipboard can block for a while.
+    pub(crate) fn paste_reader(&mut self) -> impl FnOnce() -> Pasted + Send + 'static {
+        // A custom clipboard can't be used from another thread, so read it right away:
+        let custom = self.custom.as_mut().map(|custom| Pasted {
+            files: custom.get_file_list(),
+            text: custom.get_text(),
+        });
+
+        #[cfg(all(
+            any(
+                target_os = "linux",
+                target_os = "dragonfly",
+                target_os = "freebsd",
+                target_os = "netbsd",
+                target_os = "openbsd"
+            ),
+            feature = "smithay-clipboard"
+        ))]
+        let smithay = self.smithay.clone();
+
+        #[cfg(all(
+            not(any(target_os = "android", target_os = "ios")),
+            feature = "arboard",
+        ))]
+        let has_arboard = self.arboard.is_some();
+
+        let fallback = Pasted {
+            files: (!self.files.is_empty()).then(|| self.files.clone()),
+            text: Some(self.clipboard.clone()),
+        };
+
+        move || {
+            if let Some(pasted) = custom {
+                return pasted;
+            }
+
+            #[cfg(all(
+                any(
+                    target_os = "linux",
+                    target_os = "dragonfly",
+                    target_os = "freebsd",
+                    target_os = "netbsd",
+                    target_os = "openbsd"
+                ),
+                feature = "smithay-clipboard"
+            ))]
+            if let Some(clipboard) = &smithay {
+                // smithay-clipboard only supports plain text
+                match clipboard.lock().load() {
+                    Ok(text) => {
+                        return Pasted {
+                            files: None,
+                            text: Some(text),
+                        };
+                    }
+                    Err(err) => {
+                        log::error!("smithay paste error: {err}");
+                    }
+                }
+            }
+
+            #[cfg(all(
+                not(any(target_os = "android", target_os = "ios")),
+                feature = "arboard",
+            ))]
+            if has_arboard {
+                // `arboard::Clipboard` can't be sent to other threads on all platforms,
+                // but creating a new one is cheap:
+                let mut clipboard = match arboard::Clipboard::new() {
+                    Ok(clipboard) => clipboard,
+                    Err(err) => {
+                        log::error!("arboard paste error: {err}");
+                        return Pasted::default();
+                    }
+                };
+                let files = match clipboard.get().file_list() {
+                    Ok(paths) => (!paths.is_empty()).then_some(paths),
+                    Err(arboard::Error::ContentNotAvailable) => None,
+                    Err(err) => {
+                        log::error!("arboard paste error: {err}");
+                        None
+                    }
+                };
+                let text = match clipboard.get_text() {
+                    Ok(text) => Some(text),
+                    Err(err) => {
+                        log::error!("arboard paste error: {err}");
+                        None
+                    }
+                };
+                return Pasted { files, text };
+            }
+
+            fallback
+        }
+    }
+
+    /// A function that can be called on another thread, for [`ClipboardWatcher`],
+    /// which returns something different whenever the clipboard has changed.
+    ///
+    /// Windows and macOS count the changes to the clipboard, so there we don't need to read it.
+    /// Elsewhere we fall back to reading and hashing the text, without logging errors,
+    /// since this is called over and over.
+    /// `None` if we're using the fallback clipboard, which only we can change.
+    fn change_counter(&self) -> Option<Box<dyn FnMut() -> u64 + Send>> {
+        #[cfg(all(
+            any(
+                target_os = "linux",
+                target_os = "dragonfly",
+                target_os = "freebsd",
+                target_os = "netbsd",
+                target_os = "openbsd"
+            ),
+            feature = "smithay-clipboard"
+        ))]
+        if self.smithay.is_some() {
+            // A clipboard of our own, so that we never hold up copying and pasting:
+            let clipboard = init_smithay_clipboard(self.wayland_display)?;
+            return Some(Box::new(move || egui::util::hash(clipboard.load().ok())));
+        }
+
+        #[cfg(all(
+            not(any(target_os = "android", target_os = "ios")),
+            feature = "arboard",
+        ))]
+        if self.arboard.is_some() {
+            #[cfg(target_os = "windows")]
+            return Some(Box::new(windows_clipboard_sequence_number));
+
+            #[cfg(target_os = "macos")]
+            return Some(Box::new(macos_pasteboard_change_count));
+
+            // `arboard::Clipboard` can't be sent to other threads on all platforms,
+            // but creating a new one is cheap:
+            #[cfg(not(any(target_os = "windows", target_os = "macos")))]
+            return Some(Box::new(|| {
+                egui::util::hash(
+                    arboard::Clipboard::new()
+                        .and_then(|mut clipboard| clipboard.get_text())
+                        .ok(),
+                )
+            }));
+        }
+
+        None
+    }
+
     pub fn set_text(&mut self, text: String) {
+        if let Some(custom) = &mut self.custom {
+            custom.set_text(text);
+            return;
+        }
+
         #[cfg(all(
             any(
                 target_os = "linux",
@@ -101,8 +393,8 @@ impl Clipboard {
             ),
             feature = "smithay-clipboard"
         ))]
-        if let Some(clipboard) = &mut self.smithay {
-            clipboard.store(text);
+        if let Some(clipboard) = &self.smithay {
+            clipboard.lock().store(text);
             return;
         }
 
@@ -118,9 +410,185 @@ impl Clipboard {
         }
 
         self.clipboard = text;
+        self.html = None;
+        self.files.clear();
+    }
+
+    /// Fetches HTML from the clipboard, e.g. rich text copied from a browser or an office app.
+    ///
+    /// Returns `None` if there is no HTML on the clipboard, or the OS clipboard doesn't support it.
+    pub fn get_html(&mut self) -> Option<String> {
+        if let Some(custom) = &mut self.custom {
+            return custom.get_html();
+        }
+
+        #[cfg(all(
+            any(
+                target_os = "linux",
+                target_os = "dragonfly",
+                target_os = "freebsd",
+                target_os = "netbsd",
+                target_os = "openbsd"
+            ),
+            feature = "smithay-clipboard"
+        ))]
+        if self.smithay.is_some() {
+            // smithay-clipboard only supports plain text
+            return None;
+        }
+
+        #[cfg(all(
+            not(any(target_os = "android", target_os = "ios")),
+            feature = "arboard",
+        ))]
+        if let Some(clipboard) = &mut self.arboard {
+            return match clipboard.get().html() {
+                Ok(html) => Some(html),
+                Err(arboard::Error::ContentNotAvailable) => None,
+                Err(err) => {
+                    log::error!("arboard paste error: {err}");
+                    None
+                }
+            };
+        }
+
+        self.html.clone()
+    }
+
+    /// Places the HTML onto the clipboard, so that formatting is kept when pasting into
+    /// e.g. a browser or an office app.
+    ///
+    /// `alt_text` is what gets pasted into applications that only accept plain text.
+    pub fn set_html(&mut self, html: String, alt_text: String) {
+        if let Some(custom) = &mut self.custom {
+            custom.set_html(html, alt_text);
+            return;
+        }
+
+        #[cfg(all(
+            any(
+                target_os = "linux",
+                target_os = "dragonfly",
+                target_os = "freebsd",
+                target_os = "netbsd",
+                target_os = "openbsd"
+            ),
+            feature = "smithay-clipboard"
+        ))]
+        if let Some(clipboard) = &self.smithay {
+            // smithay-clipboard only supports plain text
+            clipboard.lock().store(alt_text);
+            return;
+        }
+
+        #[cfg(all(
+            not(any(target_os = "android", target_os = "ios")),
+            feature = "arboard",
+        ))]
+        if let Some(clipboard) = &mut self.arboard {
+            if let Err(err) = clipboard.set_html(html, Some(alt_text)) {
+                log::error!("arboard copy/cut error: {err}");
+            }
+            return;
+        }
+
+        self.clipboard = alt_text;
+        self.html = Some(html);
+        self.files.clear();
+    }
+
+    /// Fetches the files on the clipboard, e.g. copied in a file manager
+    /// (`CF_HDROP` on Windows, `text/uri-list` on Linux).
+    ///
+    /// Returns `None` if there are no files on the clipboard, or the OS clipboard doesn't support it.
+    pub fn get_file_list(&mut self) -> Option<Vec<PathBuf>> {
+        if let Some(custom) = &mut self.custom {
+            return custom.get_file_list();
+        }
+
+        #[cfg(all(
+            any(
+                target_os = "linux",
+                target_os = "dragonfly",
+                target_os = "freebsd",
+                target_os = "netbsd",
+                target_os = "openbsd"
+            ),
+            feature = "smithay-clipboard"
+        ))]
+        if self.smithay.is_some() {
+            // smithay-clipboard only supports plain text
+            return None;
+        }
+
+        #[cfg(all(
+            not(any(target_os = "android", target_os = "ios")),
+            feature = "arboard",
+        ))]
+        if let Some(clipboard) = &mut self.arboard {
+            return match clipboard.get().file_list() {
+                Ok(paths) => (!paths.is_empty()).then_some(paths),
+                Err(arboard::Error::ContentNotAvailable) => None,
+                Err(err) => {
+                    log::error!("arboard paste error: {err}");
+                    None
+                }
+            };
+        }
+
+        (!self.files.is_empty()).then(|| self.files.clone())
+    }
+
+    /// Places the files onto the clipboard, to be pasted into e.g. a file manager.
+    pub fn set_file_list(&mut self, paths: &[PathBuf]) {
+        if let Some(custom) = &mut self.custom {
+            custom.set_file_list(paths);
+            return;
+        }
+
+        #[cfg(all(
+            any(
+                target_os = "linux",
+                target_os = "dragonfly",
+                target_os = "freebsd",
+                target_os = "netbsd",
+                target_os = "openbsd"
+            ),
+            feature = "smithay-clipboard"
+        ))]
+        if let Some(clipboard) = &self.smithay {
+            // smithay-clipboard only supports plain text, so copy the paths as text:
+            let text = paths
+                .iter()
+                .map(|path| path.display().to_string())
+                .collect::<Vec<_>>()
+                .join("\n");
+            clipboard.lock().store(text);
+            return;
+        }
+
+        #[cfg(all(
+            not(any(target_os = "android", target_os = "ios")),
+            feature = "arboard",
+        ))]
+        if let Some(clipboard) = &mut self.arboard {
+            if let Err(err) = clipboard.set().file_list(paths) {
+                log::error!("arboard copy/cut error: {err}");
+            }
+            return;
+        }
+
+        self.clipboard.clear();
+        self.html = None;
+        self.files = paths.to_vec();
     }
 
     pub fn set_image(&mut self, image: &egui::ColorImage) {
+        if let Some(custom) = &mut self.custom {
+            custom.set_image(image);
+            return;
+        }
+
         #[cfg(all(
             not(any(target_os = "android", target_os = "ios")),
             feature = "arboard",
@@ -144,6 +612,169 @@ impl Clipboard {
     }
 }
 
+/// Watches the clipboard on a background thread, see [`crate::State::set_watch_clipboard`].
+///
+/// On Windows and macOS this only looks at the change count the OS keeps for the clipboard.
+/// Elsewhere there is no such count we can get at, so we fall back to reading the clipboard text
+/// and comparing it to last time.
+///
+/// Stops when dropped.
+pub(crate) struct ClipboardWatcher {
+    changed: Arc<AtomicBool>,
+    stop: Arc<AtomicBool>,
+    thread: std::thread::Thread,
+}
+
+impl ClipboardWatcher {
+    /// How often we look at the clipboard.
+    const INTERVAL: Duration = Duration::from_millis(250);
+
+    /// Start watching the clipboard, requesting a repaint of the viewport when it changes.
+    ///
+    /// `None` if the clipboard can't be watched.
+    pub fn start(
+        clipboard: &Clipboard,
+        egui_ctx: &egui::Context,
+        viewport_id: ViewportId,
+    ) -> Option<Self> {
+        let mut change_counter = clipboard.change_counter()?;
+        let changed = Arc::new(AtomicBool::new(false));
+        let stop = Arc::new(AtomicBool::new(false));
+
+        let result = std::thread::Builder::new()
+            .name("clipboard_watcher".to_owned())
+            .spawn({
+                let changed = Arc::clone(&changed);
+                let stop = Arc::clone(&stop);
+                let egui_ctx = egui_ctx.clone();
+                move || {
+                    let mut last_count = change_counter();
+                    loop {
+                        // Unparked when dropped, so we stop right away:
+                        std::thread::park_timeout(Self::INTERVAL);
+                        if stop.load(Ordering::Relaxed) {
+                            break;
+                        }
+                        let count = change_counter();
+                        if count != last_count {
+                            last_count = count;
+                            changed.store(true, Ordering::Relaxed);
+                            egui_ctx.request_repaint_of(viewport_id);
+                        }
+                    }
+                }
+            });
+        let thread = match result {
+            Ok(handle) => handle.thread().clone(),
+            Err(err) => {
+                log::warn!("Failed to spawn the clipboard watcher thread: {err}");
+                return None;
+            }
+        };
+
+        Some(Self {
+            changed,
+            stop,
+            thread,
+        })
+    }
+
+    /// Has the clipboard changed since last time?
+    pub fn take_changed(&self) -> bool {
+        self.changed.swap(false, Ordering::Relaxed)
+    }
+}
+
+impl Drop for ClipboardWatcher {
+    fn drop(&mut self) {
+        self.stop.store(true, Ordering::Relaxed);
+        self.thread.unpark();
+    }
+}
+
+/// Increases every time the contents of the clipboard change.
+#[cfg(all(target_os = "windows", feature = "arboard"))]
+fn windows_clipboard_sequence_number() -> u64 {
+    // SAFETY: it takes no arguments, and can be called from any thread.
+    #[expect(unsafe_code)]
+    let number = unsafe { windows_sys::Win32::System::DataExchange::GetClipboardSequenceNumber() };
+    u64::from(number)
+}
+
+/// Increases every time the owner of the general pasteboard changes.
+#[cfg(all(target_os = "macos", feature = "arboard"))]
+#[expect(unsafe_code)]
+fn macos_pasteboard_change_count() -> u64 {
+    use objc2::{class, msg_send, rc::Retained, runtime::AnyObject};
+
+    objc2::rc::autoreleasepool(|_| {
+        // SAFETY: `generalPasteboard` takes no arguments and returns an `NSPasteboard`,
+        // which can be used from any thread.
+        let pasteboard: Option<Retained<AnyObject>> =
+            unsafe { msg_send![class!(NSPasteboard), generalPasteboard] };
+        pasteboard.map_or(0, |pasteboard| {
+            // SAFETY: `changeCount` takes no arguments and returns an `NSInteger`.
+            let count: isize = unsafe { msg_send![&*pasteboard, changeCount] };
+            egui::util::hash(count)
+        })
+    })
+}
+
+/// What was read from the clipboard by [`Clipboard::paste_reader`].
+#[derive(Default)]
+pub(crate) struct Pasted {
+    /// The files on the clipboard, if any.
+    pub files: Option<Vec<PathBuf>>,
+
+    /// The text on the clipboard, if any.
+    pub text: Option<String>,
+}
+
+/// Clipboard reads on background threads, see [`crate::State::set_async_clipboard`].
+pub(crate) struct AsyncPaste {
+    sender: mpsc::Sender<Pasted>,
+    receiver: mpsc::Receiver<Pasted>,
+}
+
+impl Default for AsyncPaste {
+    fn default() -> Self {
+        let (sender, receiver) = mpsc::channel();
+        Self { sender, receiver }
+    }
+}
+
+impl AsyncPaste {
+    /// Call `read` (from [`Clipboard::paste_reader`]) on a background thread.
+    ///
+    /// Once the clipboard has been read, a repaint of the viewport is requested.
+    pub fn request(
+        &self,
+        read: impl FnOnce() -> Pasted + Send + 'static,
+        egui_ctx: &egui::Context,
+        viewport_id: ViewportId,
+    ) {
+        let sender = self.sender.clone();
+        let egui_ctx = egui_ctx.clone();
+        let result = std::thread::Builder::new()
+            .name("clipboard_paste".to_owned())
+            .spawn(move || {
+                let pasted = read();
+                if (pasted.files.is_some() || pasted.text.is_some()) && sender.send(pasted).is_ok()
+                {
+                    egui_ctx.request_repaint_of(viewport_id);
+                }
+            });
+        if let Err(err) = result {
+            log::warn!("Failed to spawn the clipboard thread: {err}");
+        }
+    }
+
+    /// What has been read from the clipboard since last time.
+    pub fn arrived(&self) -> impl Iterator<Item = Pasted> + '_ {
+        self.receiver.try_iter()
+    }
+}
+
 #[cfg(all(
     not(any(target_os = "android", target_os = "ios")),
     feature = "arboard",
@@ -0,0 +1,262 @@
+//! A software compose key, see [`crate::State::set_compose_key`].
+//!
+//! After the compose key, two characters are composed into one, in either order:
+//! a letter and an accent (e.g. `e` + `'` = `é`), or one of the [`SYMBOLS`] (e.g. `o` + `e` = `œ`).
+//! `u`, up to six hex digits, and space (or enter) types any Unicode character (e.g. `u20ac` = `€`),
+//! except control characters.
+
+use winit::keyboard::{Key, NamedKey};
+
+/// Pairs of characters that compose into a symbol.
+const SYMBOLS: &[(&str, char)] = &[
+    ("ss", 'ß'),
+    ("ae", 'æ'),
+    ("AE", 'Æ'),
+    ("oe", 'œ'),
+    ("OE", 'Œ'),
+    ("o/", 'ø'),
+    ("O/", 'Ø'),
+    ("!!", '¡'),
+    ("??", '¿'),
+    ("<<", '«'),
+    (">>", '»'),
+    ("=e", '€'),
+    ("-L", '£'),
+    ("=Y", '¥'),
+    ("|c", '¢'),
+    ("co", '©'),
+    ("ro", '®'),
+    ("tm", '™'),
+    ("+-", '±'),
+    ("xx", '×'),
+    (":-", '÷'),
+    ("12", '½'),
+    ("14", '¼'),
+    ("34", '¾'),
+    ("^1", '¹'),
+    ("^2", '²'),
+    ("^3", '³'),
+    ("oo", '°'),
+    ("..", '…'),
+    ("-.", '–'),
+    ("--", '—'),
+];
+
+/// What [`Compose::on_key_press`] did with a key press.
+pub(crate) enum Composed {
+    /// We are not composing, so the key press should be handled as usual.
+    No,
+
+    /// The key press was part of a compose sequence.
+    Consumed,
+
+    /// The compose sequence was finished with this character.
+    Char(char),
+}
+
+/// A compose sequence being typed.
+#[derive(Default)]
+pub(crate) struct Compose {
+    /// What has been typed since the compose key was pressed, or `None` when not composing.
+    typed: Option<String>,
+}
+
+impl Compose {
+    /// Stop composing, dropping what has been typed so far.
+    pub fn cancel(&mut self) {
+        self.typed = None;
+    }
+
+    /// Call on every key press that isn't a modifier.
+    pub fn on_key_press(
+        &mut self,
+        is_compose_key: bool,
+        logical_key: &Key,
+        text: Option<&str>,
+    ) -> Composed {
+        if is_compose_key {
+            // Pressing it again starts over:
+            self.typed = Some(String::new());
+            return Composed::Consumed;
+        }
+
+        let Some(typed) = &mut self.typed else {
+            return Composed::No;
+        };
+
+        match logical_key {
+            Key::Named(NamedKey::Escape) => {
+                self.typed = None;
+                return Composed::Consumed;
+            }
+            Key::Named(NamedKey::Backspace) => {
+                typed.pop();
+                return Composed::Consumed;
+            }
+            _ => {}
+        }
+
+        let mut chars = text.unwrap_or_default().chars();
+        let (Some(c), None) = (chars.next(), chars.next()) else {
+            // E.g. an arrow key, which ends the sequence like it would any other text input:
+            self.typed = None;
+            return Composed::No;
+        };
+
+        if let Some(hex) = typed.strip_prefix('u') {
+            if c == ' ' || c == '\r' {
+                let composed = u32::from_str_radix(hex, 16)
+                    .ok()
+                    .and_then(char::from_u32)
+                    .filter(|c| !c.is_control());
+                self.typed = None;
+                return composed.map_or(Composed::Consumed, Composed::Char);
+            } else if c.is_ascii_hexdigit() && hex.len() < 6 {
+                typed.push(c);
+                return Composed::Consumed;
+            } else if !hex.is_empty() {
+                self.typed = None;
+                return Composed::Consumed;
+            }
+            // Otherwise this could still be e.g. `u` + `"` = `ü`.
+        }
+
+        let Some(first) = typed.chars().next() else {
+            typed.push(c);
+            return Composed::Consumed;
+        };
+
+        self.typed = None;
+        compose_pair(first, c).map_or(Composed::Consumed, Composed::Char)
+    }
+}
+
+fn compose_pair(a: char, b: char) -> Option<char> {
+    symbol(a, b)
+        .or_else(|| symbol(b, a))
+        .or_else(|| accented(a, b))
+        .or_else(|| accented(b, a))
+}
+
+fn symbol(a: char, b: char) -> Option<char> {
+    SYMBOLS.iter().find_map(|(pair, symbol)| {
+        let mut chars = pair.chars();
+        (chars.next() == Some(a) && chars.next() == Some(b)).then_some(*symbol)
+    })
+}
+
+fn accented(accent: char, letter: char) -> Option<char> {
+    // The ASCII characters that stand in for the accents:
+    let dead_key = match accent {
+        '`' | '\'' | '^' | '~' | '"' => accent,
+        ',' => '¸',
+        'o' => '˚',
+        '<' => 'ˇ',
+        _ => return None,
+    };
+    if letter == ' ' {
+        return None;
+    }
+    crate::dead_key::compose(dead_key, &letter.to_string())?
+        .chars()
+        .next()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Type the compose key followed by `typed`, returning what the last key press resulted in.
+    fn compose(typed: &[Key]) -> Option<char> {
+        let mut compose = Compose::default();
+        assert!(matches!(
+            compose.on_key_press(true, &Key::Named(NamedKey::Alt), None),
+            Composed::Consumed
+        ));
+        let mut result = None;
+        for key in typed {
+            let text = match key {
+                Key::Character(c) => Some(c.as_str()),
+                Key::Named(NamedKey::Space) => Some(" "),
+                Key::Named(NamedKey::Enter) => Some("\r"),
+                _ => None,
+            };
+            result = match compose.on_key_press(false, key, text) {
+                Composed::No => panic!("{key:?} was not part of the compose sequence"),
+                Composed::Consumed => None,
+                Composed::Char(c) => Some(c),
+            };
+        }
+        result
+    }
+
+    fn chars(s: &str) -> Vec<Key> {
+        s.chars()
+            .map(|c| Key::Character(c.to_string().into()))
+            .collect()
+    }
+
+    #[test]
+    fn test_pairs_in_either_order() {
+        for (typed, expected) in [
+            ("e'", 'é'),
+            ("'e", 'é'),
+            ("\"u", 'ü'),
+            ("u\"", 'ü'),
+            (",c", 'ç'),
+            ("<s", 'š'),
+            ("oa", 'å'),
+            ("oe", 'œ'),
+            ("eo", 'œ'),
+            ("ss", 'ß'),
+            ("=e", '€'),
+            ("e=", '€'),
+        ] {
+            assert_eq!(compose(&chars(typed)), Some(expected), "{typed:?}");
+        }
+        assert_eq!(compose(&chars("qq")), None);
+    }
+
+    #[test]
+    fn test_backspace_and_escape() {
+        let mut typed = chars("x");
+        typed.push(Key::Named(NamedKey::Backspace));
+        typed.extend(chars("e'"));
+        assert_eq!(compose(&typed), Some('é'));
+
+        let mut compose = Compose::default();
+        compose.on_key_press(true, &Key::Named(NamedKey::Alt), None);
+        compose.on_key_press(false, &Key::Character("e".into()), Some("e"));
+        assert!(matches!(
+            compose.on_key_press(false, &Key::Named(NamedKey::Escape), None),
+            Composed::Consumed
+        ));
+        assert!(matches!(
+            compose.on_key_press(false, &Key::Character("'".into()), Some("'")),
+            Composed::No
+        ));
+    }
+
+    #[test]
+    fn test_hex() {
+        let mut typed = chars("u20ac");
+        typed.push(Key::Named(NamedKey::Space));
+        assert_eq!(compose(&typed), Some('€'));
+
+        let mut typed = chars("u1f600");
+        typed.push(Key::Named(NamedKey::Enter));
+        assert_eq!(compose(&typed), Some('😀'));
+
+        // No control characters:
+        for hex in ["u0", "ua", "u7f", "u9b"] {
+            let mut typed = chars(hex);
+            typed.push(Key::Named(NamedKey::Space));
+            assert_eq!(compose(&typed), None, "{hex:?}");
+        }
+
+        // Not a valid `char`:
+        let mut typed = chars("ud800");
+        typed.push(Key::Named(NamedKey::Space));
+        assert_eq!(compose(&typed), None);
+    }
+}
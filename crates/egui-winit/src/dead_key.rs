@@ -0,0 +1,105 @@
+//! Composing the character typed after a dead key, e.g. `´` + `e` = `é`.
+//!
+//! Most platforms do this for us, and give us the composed text directly.
+//! This is for those that don't.
+
+/// The accents dead keys can put on a letter.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Accent {
+    Grave,
+    Acute,
+    Circumflex,
+    Tilde,
+    Diaeresis,
+    Cedilla,
+    Ring,
+    Caron,
+}
+
+impl Accent {
+    /// Platforms report dead keys either with the spacing or the combining form of the accent.
+    fn from_dead_key(dead_key: char) -> Option<Self> {
+        Some(match dead_key {
+            '`' | '\u{300}' => Self::Grave,
+            '´' | '\'' | '\u{301}' => Self::Acute,
+            '^' | 'ˆ' | '\u{302}' => Self::Circumflex,
+            '~' | '˜' | '\u{303}' => Self::Tilde,
+            '¨' | '"' | '\u{308}' => Self::Diaeresis,
+            '¸' | '\u{327}' => Self::Cedilla,
+            '˚' | '°' | '\u{30a}' => Self::Ring,
+            'ˇ' | '\u{30c}' => Self::Caron,
+            _ => return None,
+        })
+    }
+
+    /// The accent on its own, as typed with a dead key followed by space.
+    fn spacing_char(self) -> char {
+        match self {
+            Self::Grave => '`',
+            Self::Acute => '´',
+            Self::Circumflex => '^',
+            Self::Tilde => '~',
+            Self::Diaeresis => '¨',
+            Self::Cedilla => '¸',
+            Self::Ring => '˚',
+            Self::Caron => 'ˇ',
+        }
+    }
+
+    /// The plain letters this accent applies to, and the accented versions of them.
+    fn table(self) -> (&'static str, &'static str) {
+        match self {
+            Self::Grave => ("aeiouAEIOU", "àèìòùÀÈÌÒÙ"),
+            Self::Acute => ("aeiouycnszAEIOUYCNSZ", "áéíóúýćńśźÁÉÍÓÚÝĆŃŚŹ"),
+            Self::Circumflex => ("aeiouAEIOU", "âêîôûÂÊÎÔÛ"),
+            Self::Tilde => ("anoANO", "ãñõÃÑÕ"),
+            Self::Diaeresis => ("aeiouyAEIOUY", "äëïöüÿÄËÏÖÜŸ"),
+            Self::Cedilla => ("cC", "çÇ"),
+            Self::Ring => ("auAU", "åůÅŮ"),
+            Self::Caron => ("cdenrstzCDENRSTZ", "čďěňřšťžČĎĚŇŘŠŤŽ"),
+        }
+    }
+}
+
+/// Compose the text typed right after `dead_key` was pressed.
+///
+/// Returns `None` if they don't compose, e.g. because the platform already did it for us,
+/// in which case the text should be used as is.
+pub(crate) fn compose(dead_key: char, text: &str) -> Option<String> {
+    let accent = Accent::from_dead_key(dead_key)?;
+
+    let mut chars = text.chars();
+    let (Some(base), None) = (chars.next(), chars.next()) else {
+        return None;
+    };
+
+    if base == ' ' {
+        return Some(accent.spacing_char().to_string());
+    }
+
+    let (plain, accented) = accent.table();
+    let index = plain.chars().position(|c| c == base)?;
+    accented.chars().nth(index).map(String::from)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_compose() {
+        assert_eq!(compose('´', "e").as_deref(), Some("é"));
+        assert_eq!(compose('\u{301}', "E").as_deref(), Some("É"));
+        assert_eq!(compose('^', "o").as_deref(), Some("ô"));
+        assert_eq!(compose('ˇ', "z").as_deref(), Some("ž"));
+        assert_eq!(compose('¨', " ").as_deref(), Some("¨"));
+
+        // Doesn't compose:
+        assert_eq!(compose('´', "q"), None);
+        assert_eq!(compose('x', "e"), None);
+
+        // Already composed by the platform:
+        assert_eq!(compose('´', "é"), None);
+        assert_eq!(compose('´', "ab"), None);
+    }
+}
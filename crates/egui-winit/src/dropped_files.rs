@@ -0,0 +1,133 @@
+//! Reading the contents of dropped files, see [`crate::State::set_read_dropped_files`],
+//! and the sizes of hovered files.
+
+use std::{path::PathBuf, sync::mpsc};
+
+use egui::ViewportId;
+
+/// Dropped files being read on background threads.
+pub(crate) struct DroppedFileReader {
+    sender: mpsc::Sender<egui::DroppedFile>,
+    receiver: mpsc::Receiver<egui::DroppedFile>,
+}
+
+impl Default for DroppedFileReader {
+    fn default() -> Self {
+        let (sender, receiver) = mpsc::channel();
+        Self { sender, receiver }
+    }
+}
+
+impl DroppedFileReader {
+    /// Read the dropped file on a background thread, if it is at most `max_size` bytes.
+    ///
+    /// Once it has been read, a repaint of the viewport is requested.
+    pub fn read(
+        &self,
+        egui_ctx: &egui::Context,
+        viewport_id: ViewportId,
+        path: PathBuf,
+        max_size: u64,
+    ) {
+        let sender = self.sender.clone();
+        let egui_ctx = egui_ctx.clone();
+        let thread_path = path.clone();
+        let result = std::thread::Builder::new()
+            .name("read_dropped_file".to_owned())
+            .spawn(move || {
+                if sender.send(read_file(thread_path, max_size)).is_ok() {
+                    egui_ctx.request_repaint_of(viewport_id);
+                }
+            });
+        if let Err(err) = result {
+            log::warn!("Failed to spawn a thread to read the dropped file: {err}");
+            // Still let the app know about the file:
+            self.sender
+                .send(egui::DroppedFile {
+                    path: Some(path),
+                    ..Default::default()
+                })
+                .ok();
+        }
+    }
+
+    /// The files that have been read since last time.
+    pub fn arrived(&self) -> impl Iterator<Item = egui::DroppedFile> + '_ {
+        self.receiver.try_iter()
+    }
+}
+
+/// Looks up the sizes of hovered files on background threads,
+/// since that can block for a while, e.g. on a network drive.
+pub(crate) struct HoveredFileSizes {
+    sender: mpsc::Sender<(PathBuf, u64)>,
+    receiver: mpsc::Receiver<(PathBuf, u64)>,
+}
+
+impl Default for HoveredFileSizes {
+    fn default() -> Self {
+        let (sender, receiver) = mpsc::channel();
+        Self { sender, receiver }
+    }
+}
+
+impl HoveredFileSizes {
+    /// Look up the size of the file on a background thread.
+    ///
+    /// Once it is known, a repaint of the viewport is requested.
+    pub fn request(&self, egui_ctx: &egui::Context, viewport_id: ViewportId, path: PathBuf) {
+        let sender = self.sender.clone();
+        let egui_ctx = egui_ctx.clone();
+        let result = std::thread::Builder::new()
+            .name("hovered_file_size".to_owned())
+            .spawn(move || {
+                if let Ok(metadata) = std::fs::metadata(&path)
+                    && sender.send((path, metadata.len())).is_ok()
+                {
+                    egui_ctx.request_repaint_of(viewport_id);
+                }
+            });
+        if let Err(err) = result {
+            log::warn!("Failed to spawn a thread to look up the hovered file size: {err}");
+        }
+    }
+
+    /// Fill in the sizes that have been looked up since last time.
+    pub fn fill_in(&self, hovered_files: &mut [egui::HoveredFile]) {
+        for (path, size) in self.receiver.try_iter() {
+            for file in hovered_files
+                .iter_mut()
+                .filter(|file| file.path.as_ref() == Some(&path))
+            {
+                file.size = Some(size);
+            }
+        }
+    }
+}
+
+/// Fill in what the web backend does for dropped files.
+fn read_file(path: PathBuf, max_size: u64) -> egui::DroppedFile {
+    let metadata = std::fs::metadata(&path).ok();
+
+    let bytes = metadata
+        .as_ref()
+        .filter(|metadata| metadata.is_file() && metadata.len() <= max_size)
+        .and_then(|_| match std::fs::read(&path) {
+            Ok(bytes) => Some(bytes.into()),
+            Err(err) => {
+                log::warn!("Failed to read dropped file {}: {err}", path.display());
+                None
+            }
+        });
+
+    egui::DroppedFile {
+        name: path
+            .file_name()
+            .map(|name| name.to_string_lossy().into_owned())
+            .unwrap_or_default(),
+        mime: crate::guess_mime(&path),
+        last_modified: metadata.and_then(|metadata| metadata.modified().ok()),
+        bytes,
+        path: Some(path),
+    }
+}
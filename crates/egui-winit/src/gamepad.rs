@@ -0,0 +1,227 @@
+use egui::{Key, Modifiers, Vec2};
+
+/// How far a stick must be pushed before it counts as a direction press.
+const STICK_PRESS_THRESHOLD: f32 = 0.5;
+
+/// Below this, a stick is considered released again.
+const STICK_RELEASE_THRESHOLD: f32 = 0.3;
+
+/// Sticks report some noise even when untouched.
+const STICK_DEAD_ZONE: f32 = 0.15;
+
+/// How many points per second the right stick scrolls at full deflection.
+const SCROLL_POINTS_PER_SECOND: f32 = 1500.0;
+
+/// A button on a gamepad, using the layout of a standard (Xbox-like) controller.
+///
+/// The face buttons are named by position, since their labels differ between brands.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum GamepadButton {
+    /// A on Xbox, ✕ on `PlayStation`, B on Nintendo. Activates the focused widget.
+    South,
+
+    /// B on Xbox, ○ on `PlayStation`, A on Nintendo. Acts like `Escape`.
+    East,
+
+    /// X on Xbox, □ on `PlayStation`, Y on Nintendo.
+    West,
+
+    /// Y on Xbox, △ on `PlayStation`, X on Nintendo.
+    North,
+
+    /// Moves focus to the previous widget.
+    LeftShoulder,
+
+    /// Moves focus to the next widget.
+    RightShoulder,
+
+    DPadUp,
+    DPadDown,
+    DPadLeft,
+    DPadRight,
+
+    Start,
+    Select,
+}
+
+/// An analog stick axis on a gamepad.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum GamepadAxis {
+    /// Moves focus left and right, like the d-pad.
+    LeftStickX,
+
+    /// Moves focus up and down, like the d-pad.
+    LeftStickY,
+
+    /// Scrolls horizontally.
+    RightStickX,
+
+    /// Scrolls vertically.
+    RightStickY,
+}
+
+/// A gamepad event, to be passed to [`crate::State::on_gamepad_event`].
+///
+/// egui-winit does not read gamepads itself, since winit doesn't support them.
+/// Poll them with e.g. [`gilrs`](https://docs.rs/gilrs) and translate its events into these.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum GamepadEvent {
+    ButtonPressed(GamepadButton),
+    ButtonReleased(GamepadButton),
+
+    /// The new value of an axis, in `[-1, 1]`.
+    ///
+    /// Positive Y is up, as reported by most gamepad libraries.
+    AxisChanged(GamepadAxis, f32),
+}
+
+/// Turns gamepad events into the key and scroll events egui already understands,
+/// so that focus moves and widgets activate just as they do from the keyboard.
+#[derive(Default)]
+pub(crate) struct GamepadState {
+    left_stick: Vec2,
+    right_stick: Vec2,
+
+    /// The arrow key the left stick is currently holding down.
+    left_stick_key: Option<Key>,
+
+    /// When we last emitted a scroll event for the right stick.
+    last_scroll_time: Option<f64>,
+}
+
+impl GamepadState {
+    /// Returns `true` if any egui events were produced.
+    pub fn on_event(&mut self, event: &GamepadEvent, events: &mut Vec<egui::Event>) -> bool {
+        let num_events = events.len();
+
+        match *event {
+            GamepadEvent::ButtonPressed(button) => {
+                if let Some((key, modifiers)) = button_key(button) {
+                    events.push(key_event(key, modifiers, true));
+                }
+            }
+            GamepadEvent::ButtonReleased(button) => {
+                if let Some((key, modifiers)) = button_key(button) {
+                    events.push(key_event(key, modifiers, false));
+                }
+            }
+            GamepadEvent::AxisChanged(axis, value) => {
+                let value = value.clamp(-1.0, 1.0);
+                match axis {
+                    GamepadAxis::LeftStickX => self.left_stick.x = value,
+                    GamepadAxis::LeftStickY => self.left_stick.y = value,
+                    GamepadAxis::RightStickX => self.right_stick.x = value,
+                    GamepadAxis::RightStickY => self.right_stick.y = value,
+                }
+                if matches!(axis, GamepadAxis::LeftStickX | GamepadAxis::LeftStickY) {
+                    self.update_left_stick_key(events);
+                }
+            }
+        }
+
+        events.len() != num_events
+    }
+
+    /// Emit scroll events for the right stick, if it is deflected.
+    ///
+    /// Returns `true` if we are scrolling, and so need to be called again next frame.
+    pub fn scroll(&mut self, time: f64, events: &mut Vec<egui::Event>) -> bool {
+        let stick = dead_zone(self.right_stick);
+        if stick == Vec2::ZERO {
+            self.last_scroll_time = None;
+            return false;
+        }
+
+        if let Some(last_scroll_time) = self.last_scroll_time {
+            let dt = (time - last_scroll_time) as f32;
+            // Moving the stick up should reveal what is above, i.e. move the content down:
+            let delta = Vec2::new(-stick.x, stick.y) * SCROLL_POINTS_PER_SECOND * dt;
+            events.push(egui::Event::MouseWheel {
+                unit: egui::MouseWheelUnit::Point,
+                delta,
+                phase: egui::TouchPhase::Move,
+                momentum: false,
+                modifiers: Modifiers::NONE,
+            });
+        }
+        self.last_scroll_time = Some(time);
+        true
+    }
+
+    fn update_left_stick_key(&mut self, events: &mut Vec<egui::Event>) {
+        let stick = self.left_stick;
+        let still_held = self.left_stick_key.is_some_and(|key| {
+            let amount = match key {
+                Key::ArrowLeft => -stick.x,
+                Key::ArrowRight => stick.x,
+                Key::ArrowUp => stick.y,
+                _ => -stick.y,
+            };
+            STICK_RELEASE_THRESHOLD <= amount
+        });
+        if still_held {
+            return;
+        }
+
+        if let Some(key) = self.left_stick_key.take() {
+            events.push(key_event(key, Modifiers::NONE, false));
+        }
+
+        let key = if stick.x.abs().max(stick.y.abs()) < STICK_PRESS_THRESHOLD {
+            None
+        } else if stick.x.abs() > stick.y.abs() {
+            Some(if stick.x < 0.0 {
+                Key::ArrowLeft
+            } else {
+                Key::ArrowRight
+            })
+        } else {
+            Some(if stick.y < 0.0 {
+                Key::ArrowDown
+            } else {
+                Key::ArrowUp
+            })
+        };
+        if let Some(key) = key {
+            events.push(key_event(key, Modifiers::NONE, true));
+            self.left_stick_key = Some(key);
+        }
+    }
+}
+
+/// The key a gamepad button acts as.
+fn button_key(button: GamepadButton) -> Option<(Key, Modifiers)> {
+    match button {
+        GamepadButton::South => Some((Key::Enter, Modifiers::NONE)),
+        GamepadButton::East => Some((Key::Escape, Modifiers::NONE)),
+        GamepadButton::LeftShoulder => Some((Key::Tab, Modifiers::SHIFT)),
+        GamepadButton::RightShoulder => Some((Key::Tab, Modifiers::NONE)),
+        GamepadButton::DPadUp => Some((Key::ArrowUp, Modifiers::NONE)),
+        GamepadButton::DPadDown => Some((Key::ArrowDown, Modifiers::NONE)),
+        GamepadButton::DPadLeft => Some((Key::ArrowLeft, Modifiers::NONE)),
+        GamepadButton::DPadRight => Some((Key::ArrowRight, Modifiers::NONE)),
+        GamepadButton::West
+        | GamepadButton::North
+        | GamepadButton::Start
+        | GamepadButton::Select => None,
+    }
+}
+
+fn key_event(key: Key, modifiers: Modifiers, pressed: bool) -> egui::Event {
+    egui::Event::Key {
+        key,
+        physical_key: None,
+        location: egui::KeyLocation::Standard,
+        pressed,
+        repeat: false,
+        modifiers,
+    }
+}
+
+fn dead_zone(stick: Vec2) -> Vec2 {
+    if stick.length() < STICK_DEAD_ZONE {
+        Vec2::ZERO
+    } else {
+        stick
+    }
+}
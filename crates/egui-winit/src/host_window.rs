@@ -0,0 +1,151 @@
+use winit::{
+    dpi::{PhysicalPosition, PhysicalSize},
+    window::{Cursor, ImePurpose, Window},
+};
+
+use crate::ScreenArea;
+
+/// The parts of a window that [`crate::State`] reads from and writes to.
+///
+/// This is implemented for [`winit::window::Window`].
+///
+/// Hosts that don't have a winit window, only a
+/// [`RawWindowHandle`](raw_window_handle::RawWindowHandle) and their own event source
+/// (custom engines, Qt or GTK embeddings, …) can implement this for their window
+/// to reuse the event translation and platform-output handling of [`crate::State`].
+/// Translate your events to [`winit::event::WindowEvent`] and pass them to
+/// [`crate::State::on_window_event`].
+///
+/// The methods that have no sensible meaning for your host can be left as no-ops.
+pub trait HostWindow {
+    /// The ratio of physical pixels to logical pixels, like [`Window::scale_factor`].
+    fn scale_factor(&self) -> f64;
+
+    /// The size of the client area of the window, in physical pixels, like [`Window::inner_size`].
+    fn inner_size(&self) -> PhysicalSize<u32>;
+
+    /// The area egui paints to, and how much of it is covered by system UI.
+    ///
+    /// Defaults to the [`Self::inner_size`], with no insets.
+    fn screen_area(&self) -> ScreenArea {
+        let size = self.inner_size();
+        ScreenArea {
+            size_in_pixels: egui::vec2(size.width as f32, size.height as f32),
+            insets_in_pixels: None,
+        }
+    }
+
+    /// Does the window have keyboard focus, like [`Window::has_focus`]?
+    fn has_focus(&self) -> bool;
+
+    /// Enable or disable IME input, like [`Window::set_ime_allowed`].
+    fn set_ime_allowed(&self, allowed: bool);
+
+    /// Where the IME candidate box should be shown, in physical pixels,
+    /// like [`Window::set_ime_cursor_area`].
+    fn set_ime_cursor_area(&self, position: PhysicalPosition<f32>, size: PhysicalSize<f32>);
+
+    /// What kind of text the IME is used for, like [`Window::set_ime_purpose`].
+    ///
+    /// Defaults to doing nothing.
+    fn set_ime_purpose(&self, purpose: ImePurpose) {
+        _ = purpose;
+    }
+
+    /// Show or hide the cursor, like [`Window::set_cursor_visible`].
+    fn set_cursor_visible(&self, visible: bool);
+
+    /// Set the cursor icon, like [`Window::set_cursor`].
+    fn set_cursor(&self, cursor: Cursor);
+
+    /// The winit window, if there is one.
+    ///
+    /// Needed for AccessKit support.
+    fn winit_window(&self) -> Option<&Window> {
+        None
+    }
+}
+
+impl HostWindow for Window {
+    fn scale_factor(&self) -> f64 {
+        self.scale_factor()
+    }
+
+    fn inner_size(&self) -> PhysicalSize<u32> {
+        self.inner_size()
+    }
+
+    fn screen_area(&self) -> ScreenArea {
+        ScreenArea::of_window(self)
+    }
+
+    fn has_focus(&self) -> bool {
+        self.has_focus()
+    }
+
+    fn set_ime_allowed(&self, allowed: bool) {
+        self.set_ime_allowed(allowed);
+    }
+
+    fn set_ime_cursor_area(&self, position: PhysicalPosition<f32>, size: PhysicalSize<f32>) {
+        self.set_ime_cursor_area(position, size);
+    }
+
+    fn set_ime_purpose(&self, purpose: ImePurpose) {
+        self.set_ime_purpose(purpose);
+    }
+
+    fn set_cursor_visible(&self, visible: bool) {
+        self.set_cursor_visible(visible);
+    }
+
+    fn set_cursor(&self, cursor: Cursor) {
+        self.set_cursor(cursor);
+    }
+
+    fn winit_window(&self) -> Option<&Window> {
+        Some(self)
+    }
+}
+
+impl<W: HostWindow + ?Sized> HostWindow for std::sync::Arc<W> {
+    fn scale_factor(&self) -> f64 {
+        (**self).scale_factor()
+    }
+
+    fn inner_size(&self) -> PhysicalSize<u32> {
+        (**self).inner_size()
+    }
+
+    fn screen_area(&self) -> ScreenArea {
+        (**self).screen_area()
+    }
+
+    fn has_focus(&self) -> bool {
+        (**self).has_focus()
+    }
+
+    fn set_ime_allowed(&self, allowed: bool) {
+        (**self).set_ime_allowed(allowed);
+    }
+
+    fn set_ime_cursor_area(&self, position: PhysicalPosition<f32>, size: PhysicalSize<f32>) {
+        (**self).set_ime_cursor_area(position, size);
+    }
+
+    fn set_ime_purpose(&self, purpose: ImePurpose) {
+        (**self).set_ime_purpose(purpose);
+    }
+
+    fn set_cursor_visible(&self, visible: bool) {
+        (**self).set_cursor_visible(visible);
+    }
+
+    fn set_cursor(&self, cursor: Cursor) {
+        (**self).set_cursor(cursor);
+    }
+
+    fn winit_window(&self) -> Option<&Window> {
+        (**self).winit_window()
+    }
+}
@@ -0,0 +1,91 @@
+use std::collections::HashMap;
+
+use winit::keyboard::{Key, KeyCode, PhysicalKey};
+
+/// winit doesn't tell us when the keyboard layout changes,
+/// so we notice it when a physical key starts producing a different logical key.
+#[derive(Default)]
+pub(crate) struct KeyboardLayout {
+    /// The logical key (without modifiers) of each physical key pressed since the last layout change.
+    keys: HashMap<KeyCode, Key>,
+}
+
+impl KeyboardLayout {
+    /// Returns `true` if this key press shows that the layout has changed.
+    pub fn on_key_press(
+        &mut self,
+        event: &winit::event::KeyEvent,
+        modifiers: egui::Modifiers,
+    ) -> bool {
+        let PhysicalKey::Code(key_code) = event.physical_key else {
+            return false;
+        };
+        let Some(logical_key) = unmodified_logical_key(event, modifiers) else {
+            return false;
+        };
+        if !matches!(logical_key, Key::Character(_) | Key::Dead(_)) {
+            // Named keys like `Enter` are the same in all layouts.
+            return false;
+        }
+
+        let previous = self.keys.insert(key_code, logical_key.clone());
+        let changed = previous.is_some_and(|previous| previous != logical_key);
+        if changed {
+            // Forget the old layout:
+            self.keys.clear();
+            self.keys.insert(key_code, logical_key);
+        }
+        changed
+    }
+
+    /// The logical key (without modifiers) of each physical key pressed in the current layout.
+    pub fn keys(&self) -> impl Iterator<Item = (KeyCode, &Key)> {
+        self.keys.iter().map(|(key_code, key)| (*key_code, key))
+    }
+}
+
+#[cfg(any(
+    target_os = "windows",
+    target_os = "macos",
+    target_os = "linux",
+    target_os = "dragonfly",
+    target_os = "freebsd",
+    target_os = "netbsd",
+    target_os = "openbsd"
+))]
+#[expect(clippy::unnecessary_wraps)] // Not so on other platforms
+fn unmodified_logical_key(
+    event: &winit::event::KeyEvent,
+    _modifiers: egui::Modifiers,
+) -> Option<Key> {
+    use winit::platform::modifier_supplement::KeyEventExtModifierSupplement as _;
+
+    Some(lowercase(event.key_without_modifiers()))
+}
+
+/// Elsewhere we can only trust key presses without any modifiers.
+#[cfg(not(any(
+    target_os = "windows",
+    target_os = "macos",
+    target_os = "linux",
+    target_os = "dragonfly",
+    target_os = "freebsd",
+    target_os = "netbsd",
+    target_os = "openbsd"
+)))]
+fn unmodified_logical_key(
+    event: &winit::event::KeyEvent,
+    modifiers: egui::Modifiers,
+) -> Option<Key> {
+    modifiers
+        .is_none()
+        .then(|| lowercase(event.logical_key.clone()))
+}
+
+/// So that caps lock doesn't look like a layout change.
+fn lowercase(key: Key) -> Key {
+    match key {
+        Key::Character(c) => Key::Character(c.to_lowercase().into()),
+        key => key,
+    }
+}
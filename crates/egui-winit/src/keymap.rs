@@ -0,0 +1,94 @@
+use egui::{Key, KeyboardShortcut, Modifiers};
+
+/// Which key presses [`crate::State`] turns into clipboard commands and other shortcuts.
+///
+/// Set it with [`crate::State::set_keymap`], e.g. for Emacs-style bindings:
+///
+/// ```
+/// use egui::{Key, KeyboardShortcut, Modifiers};
+///
+/// let mut keymap = egui_winit::KeymapConfig::default();
+/// keymap.cut.push(KeyboardShortcut::new(Modifiers::CTRL, Key::W));
+/// keymap.copy.push(KeyboardShortcut::new(Modifiers::ALT, Key::W));
+/// keymap.paste.push(KeyboardShortcut::new(Modifiers::CTRL, Key::Y));
+/// keymap.select_all.push(KeyboardShortcut::new(Modifiers::CTRL, Key::H));
+/// ```
+///
+/// The dedicated cut, copy and paste keys found on some keyboards always work.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct KeymapConfig {
+    /// Shortcuts sent to egui as [`egui::Event::Cut`].
+    pub cut: Vec<KeyboardShortcut>,
+
+    /// Shortcuts sent to egui as [`egui::Event::Copy`].
+    pub copy: Vec<KeyboardShortcut>,
+
+    /// Shortcuts that paste the contents of the clipboard, as [`egui::Event::Paste`].
+    pub paste: Vec<KeyboardShortcut>,
+
+    /// Shortcuts that select all text.
+    ///
+    /// egui already treats `Cmd/Ctrl+A` as select-all, so this is empty by default.
+    pub select_all: Vec<KeyboardShortcut>,
+
+    /// Other shortcuts to rewrite: pressing the first is sent to egui as the second.
+    ///
+    /// The rewritten shortcut can in turn be one of the clipboard commands above.
+    pub remap: Vec<(KeyboardShortcut, KeyboardShortcut)>,
+}
+
+impl Default for KeymapConfig {
+    fn default() -> Self {
+        let mut cut = vec![KeyboardShortcut::new(Modifiers::COMMAND, Key::X)];
+        let mut copy = vec![KeyboardShortcut::new(Modifiers::COMMAND, Key::C)];
+        let mut paste = vec![KeyboardShortcut::new(Modifiers::COMMAND, Key::V)];
+
+        if cfg!(target_os = "windows") {
+            cut.push(KeyboardShortcut::new(Modifiers::SHIFT, Key::Delete));
+            copy.push(KeyboardShortcut::new(Modifiers::CTRL, Key::Insert));
+            paste.push(KeyboardShortcut::new(Modifiers::SHIFT, Key::Insert));
+        }
+
+        Self {
+            cut,
+            copy,
+            paste,
+            select_all: Vec::new(),
+            remap: Vec::new(),
+        }
+    }
+}
+
+impl KeymapConfig {
+    /// What egui should see instead of this key press, if it is remapped.
+    pub(crate) fn remapped(&self, modifiers: Modifiers, key: Key) -> Option<KeyboardShortcut> {
+        const SELECT_ALL: KeyboardShortcut = KeyboardShortcut::new(Modifiers::COMMAND, Key::A);
+
+        self.remap
+            .iter()
+            .find(|(from, _)| matches(from, modifiers, key))
+            .map(|(_, to)| *to)
+            .or_else(|| {
+                self.select_all
+                    .iter()
+                    .any(|shortcut| matches(shortcut, modifiers, key))
+                    .then_some(SELECT_ALL)
+            })
+    }
+
+    pub(crate) fn is_cut(&self, modifiers: Modifiers, key: Key) -> bool {
+        key == Key::Cut || self.cut.iter().any(|s| matches(s, modifiers, key))
+    }
+
+    pub(crate) fn is_copy(&self, modifiers: Modifiers, key: Key) -> bool {
+        key == Key::Copy || self.copy.iter().any(|s| matches(s, modifiers, key))
+    }
+
+    pub(crate) fn is_paste(&self, modifiers: Modifiers, key: Key) -> bool {
+        key == Key::Paste || self.paste.iter().any(|s| matches(s, modifiers, key))
+    }
+}
+
+fn matches(shortcut: &KeyboardShortcut, modifiers: Modifiers, key: Key) -> bool {
+    key == shortcut.logical_key && modifiers.matches_logically(shortcut.modifiers)
+}
@@ -9,6 +9,7 @@
 
 #![expect(clippy::manual_range_contains)]
 
+use std::collections::HashMap;
 #[cfg(target_os = "windows")]
 use std::collections::HashSet;
 
@@ -17,13 +18,45 @@ pub use accesskit_winit;
 pub use egui;
 #[cfg(feature = "accesskit")]
 use egui::accesskit;
-use egui::{Pos2, Rect, Theme, Vec2, ViewportBuilder, ViewportCommand, ViewportId, ViewportInfo};
+use egui::{Rect, Theme, Vec2, ViewportBuilder, ViewportCommand, ViewportId, ViewportInfo};
 pub use winit;
 
+mod app;
 pub mod clipboard;
+mod compose;
+mod dead_key;
+mod dropped_files;
+#[cfg(feature = "gamepad")]
+mod gamepad;
+mod host_window;
+mod keyboard_layout;
+mod keymap;
+mod long_press;
+#[cfg(feature = "record")]
+pub mod record;
 mod safe_area;
+#[cfg(all(
+    any(
+        target_os = "linux",
+        target_os = "dragonfly",
+        target_os = "freebsd",
+        target_os = "netbsd",
+        target_os = "openbsd"
+    ),
+    feature = "screenshot_portal"
+))]
+mod screenshot_portal;
+mod text_to_speech;
 mod window_settings;
 
+pub use app::{EguiWindow, MultiWindowApp, MultiWindowHooks, RepaintRequest, Windows};
+#[cfg(feature = "gamepad")]
+pub use gamepad::{GamepadAxis, GamepadButton, GamepadEvent};
+pub use host_window::HostWindow;
+pub use keymap::KeymapConfig;
+pub use long_press::LongPressConfig;
+pub use safe_area::ScreenArea;
+pub use text_to_speech::TextToSpeech;
 pub use window_settings::WindowSettings;
 
 use raw_window_handle::HasDisplayHandle;
@@ -35,20 +68,15 @@ use winit::{
     window::{CursorGrabMode, CustomCursor, Window, WindowButtons, WindowLevel},
 };
 
-pub fn screen_size_in_pixels(window: &Window) -> egui::Vec2 {
-    let size = if cfg!(target_os = "ios") {
-        // `outer_size` Includes the area behind the "dynamic island".
-        // It is up to the eframe user to make sure the dynamic island doesn't cover anything important.
-        // That will be easier once https://github.com/rust-windowing/winit/pull/3890 lands
-        window.outer_size()
-    } else {
-        window.inner_size()
-    };
-    egui::vec2(size.width as f32, size.height as f32)
+/// The size of the area egui paints to, in physical pixels.
+///
+/// See [`ScreenArea`].
+pub fn screen_size_in_pixels(window: &dyn HostWindow) -> egui::Vec2 {
+    window.screen_area().size_in_pixels
 }
 
 /// Calculate the `pixels_per_point` for a given window, given the current egui zoom factor
-pub fn pixels_per_point(egui_ctx: &egui::Context, window: &Window) -> f32 {
+pub fn pixels_per_point(egui_ctx: &egui::Context, window: &dyn HostWindow) -> f32 {
     let native_pixels_per_point = window.scale_factor() as f32;
     let egui_zoom_factor = egui_ctx.zoom_factor();
     egui_zoom_factor * native_pixels_per_point
@@ -72,11 +100,153 @@ pub struct EventResponse {
     pub repaint: bool,
 }
 
+/// What to do with a [`winit::event::WindowEvent`], as decided by a filter set with [`State::set_event_filter`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum FilterResult {
+    /// Let egui handle the event as usual.
+    Pass,
+
+    /// Hide the event from egui, e.g. to block keys while a game has focus.
+    ///
+    /// It is reported as not consumed, so the application can handle it instead.
+    Suppress,
+
+    /// The filter has handled the event itself, e.g. by pushing rewritten events
+    /// to the [`egui::RawInput`] it was given, so egui shouldn't handle it again.
+    ///
+    /// It is reported as consumed, and triggers a repaint.
+    Handled,
+}
+
+/// What [`State`] does with a double-tap gesture, e.g. "smart zoom" on a macOS trackpad.
+///
+/// Set with [`State::set_double_tap_action`].
+#[derive(Clone, Debug, PartialEq)]
+pub enum DoubleTapAction {
+    /// Ignore double-taps.
+    Ignore,
+
+    /// Zoom in by this factor, and back out again on the next double-tap.
+    ///
+    /// This is sent to egui as [`egui::Event::Zoom`].
+    ToggleZoom(f32),
+
+    /// Send these events to egui.
+    Events(Vec<egui::Event>),
+}
+
+impl Default for DoubleTapAction {
+    fn default() -> Self {
+        Self::ToggleZoom(2.0)
+    }
+}
+
+/// How [`State`] translates mouse wheel and trackpad scrolling.
+///
+/// Set with [`State::set_scroll_config`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct ScrollConfig {
+    /// Multiplies scrolling reported in lines, i.e. from a mouse wheel.
+    ///
+    /// egui converts lines to points with [`egui::InputOptions::line_scroll_speed`],
+    /// which applies to all windows.
+    pub line_multiplier: f32,
+
+    /// Turn vertical mouse wheel scrolling into horizontal scrolling while Shift is down.
+    ///
+    /// egui already does this when reading the scroll delta
+    /// (see [`egui::InputOptions::horizontal_scroll_modifier`]),
+    /// so this is only needed if you read the [`egui::Event::MouseWheel`] events yourself.
+    pub shift_scrolls_horizontally: bool,
+
+    /// Invert horizontal scrolling.
+    pub invert_x: bool,
+
+    /// Invert vertical scrolling.
+    ///
+    /// winit reports scrolling with the platform's "natural scrolling" setting already applied,
+    /// and doesn't tell us what it is, so override it by inverting both axes.
+    pub invert_y: bool,
+}
+
+impl Default for ScrollConfig {
+    fn default() -> Self {
+        Self {
+            line_multiplier: 1.0,
+            shift_scrolls_horizontally: false,
+            invert_x: false,
+            invert_y: false,
+        }
+    }
+}
+
+impl ScrollConfig {
+    fn apply(
+        &self,
+        unit: egui::MouseWheelUnit,
+        mut delta: Vec2,
+        modifiers: egui::Modifiers,
+    ) -> Vec2 {
+        if unit == egui::MouseWheelUnit::Line {
+            delta *= self.line_multiplier;
+        }
+        if self.shift_scrolls_horizontally && modifiers.shift && delta.x == 0.0 {
+            delta = Vec2::new(delta.y, 0.0);
+        }
+        if self.invert_x {
+            delta.x = -delta.x;
+        }
+        if self.invert_y {
+            delta.y = -delta.y;
+        }
+        delta
+    }
+}
+
+/// When [`State`] shows the on-screen keyboard for a focused text field.
+///
+/// Set with [`State::set_virtual_keyboard`].
+///
+/// This only has an effect on Android and iOS, where winit shows and hides the on-screen keyboard
+/// together with IME. winit has no API for the touch keyboard of Windows tablets,
+/// so there it is up to the OS.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum VirtualKeyboard {
+    /// Show it whenever a text field has focus.
+    #[default]
+    Always,
+
+    /// Only show it when the text field was focused by touch (or pen), not with a hardware keyboard or mouse.
+    OnTouch,
+
+    /// Never show it, e.g. because the app shows its own keypad.
+    ///
+    /// Without a hardware keyboard there is then no way to type into text fields.
+    Never,
+}
+
+impl VirtualKeyboard {
+    fn should_show(self, pointer_source: egui::PointerSource) -> bool {
+        match self {
+            Self::Always => true,
+            Self::OnTouch => pointer_source != egui::PointerSource::Mouse,
+            Self::Never => false,
+        }
+    }
+}
+
+/// See [`State::set_event_filter`].
+pub type EventFilter =
+    Box<dyn FnMut(&winit::event::WindowEvent, &mut egui::RawInput) -> FilterResult>;
+
 // ----------------------------------------------------------------------------
 
 /// Handles the integration between egui and a winit Window.
 ///
 /// Instantiate one of these per viewport/window.
+///
+/// The window can be anything implementing [`HostWindow`],
+/// so this can also be used by hosts that don't have a winit window.
 pub struct State {
     /// Shared clone.
     egui_ctx: egui::Context,
@@ -88,13 +258,22 @@ pub struct State {
     any_pointer_button_down: bool,
     current_cursor_icon: Option<egui::CursorIcon>,
 
-    /// Cached `CustomCursor` for the last RGBA bitmap pushed through
-    /// `PlatformOutput::cursor_image`. We dedupe by `Arc::as_ptr` so the
-    /// integration only re-uploads the bitmap to the OS when the app
-    /// switches sprite, not every frame the cursor moves. `usize` is the
-    /// raw pointer of the source `Arc<[u8]>` — opaque, only used as a
-    /// cache key.
-    current_custom_cursor: Option<(usize, CustomCursor)>,
+    /// `CustomCursor`s created from `PlatformOutput::cursor_image`, keyed by
+    /// [`CustomCursorKey`]. Keying by content (rather than `Arc` identity)
+    /// means the bitmap is only uploaded to the OS once, even if the app
+    /// rebuilds the same image every frame or switches between a few
+    /// cursors (e.g. brush previews, themed cursors).
+    custom_cursors: HashMap<CustomCursorKey, CustomCursor>,
+
+    /// The last cursor image, with its hash, so we don't re-hash the same
+    /// `Arc<[u8]>` every frame.
+    last_cursor_image: Option<(egui::CustomCursorImage, u64)>,
+
+    /// The entry of `custom_cursors` currently set on the window.
+    current_custom_cursor: Option<CustomCursorKey>,
+
+    /// The rectangle (in points) the cursor is confined to, from `PlatformOutput::pointer_confinement`.
+    pointer_confinement: Option<egui::Rect>,
 
     clipboard: clipboard::Clipboard,
 
@@ -104,17 +283,100 @@ pub struct State {
     /// Creates duplicate touches, if real touch inputs are coming.
     simulate_touch_screen: bool,
 
+    /// Set by [`Self::set_coalesce_pointer_moves`].
+    coalesce_pointer_moves: bool,
+
     /// Is Some(…) when a touch is being translated to a pointer.
     ///
     /// Only one touch will be interpreted as pointer at any time.
     pointer_touch_id: Option<u64>,
 
+    /// Set by [`Self::set_emulate_pointer_from_touch`].
+    emulate_pointer_from_touch: bool,
+
+    /// The last [`egui::Event::PointerSource`] we sent.
+    pointer_source: egui::PointerSource,
+
+    /// Set by [`Self::set_long_press`].
+    long_press_config: Option<LongPressConfig>,
+
+    /// Is the touch emulating the pointer becoming a long-press?
+    long_press: Option<long_press::LongPress>,
+
+    #[cfg(feature = "gamepad")]
+    gamepad: gamepad::GamepadState,
+
+    /// Set by [`Self::set_event_filter`].
+    event_filter: Option<EventFilter>,
+
+    keymap: KeymapConfig,
+
+    scroll_config: ScrollConfig,
+
+    double_tap_action: DoubleTapAction,
+
+    /// Are we zoomed in by [`DoubleTapAction::ToggleZoom`]?
+    double_tap_zoomed_in: bool,
+
+    /// When the user last lifted their fingers from a trackpad scroll.
+    last_wheel_end_time: Option<f64>,
+
+    /// Are we in the momentum scroll that followed it?
+    wheel_momentum: bool,
+
+    /// Set by [`Self::start_recording`].
+    #[cfg(feature = "record")]
+    recording: Option<record::Recording>,
+
     #[cfg(feature = "accesskit")]
     pub accesskit: Option<accesskit_winit::Adapter>,
 
     allow_ime: bool,
     ime_rect_px: Option<egui::Rect>,
 
+    /// Set by [`Self::set_virtual_keyboard`].
+    virtual_keyboard: VirtualKeyboard,
+
+    /// Set by [`Self::set_text_to_speech`].
+    text_to_speech: Option<Box<dyn TextToSpeech>>,
+    ime_purpose: winit::window::ImePurpose,
+
+    /// The dead key (e.g. `´`) that should modify the next typed character.
+    pending_dead_key: Option<char>,
+
+    /// Set by [`Self::set_compose_key`].
+    compose_key: Option<winit::keyboard::KeyCode>,
+
+    compose: compose::Compose,
+
+    keyboard_layout: keyboard_layout::KeyboardLayout,
+
+    #[cfg(all(
+        any(
+            target_os = "linux",
+            target_os = "dragonfly",
+            target_os = "freebsd",
+            target_os = "netbsd",
+            target_os = "openbsd"
+        ),
+        feature = "screenshot_portal"
+    ))]
+    portal_screenshots: screenshot_portal::PortalScreenshots,
+
+    /// Set by [`Self::set_read_dropped_files`].
+    read_dropped_files: Option<u64>,
+
+    dropped_file_reader: dropped_files::DroppedFileReader,
+    hovered_file_sizes: dropped_files::HoveredFileSizes,
+
+    /// Set by [`Self::set_async_clipboard`].
+    async_clipboard: bool,
+
+    async_paste: clipboard::AsyncPaste,
+
+    /// Set by [`Self::set_watch_clipboard`].
+    clipboard_watcher: Option<clipboard::ClipboardWatcher>,
+
     /// Used by [`State::try_on_ime_processed_keyboard_input`] to track key
     /// release events that should be filtered out. See comments in that method
     /// for details.
@@ -122,6 +384,21 @@ pub struct State {
     pressed_processed_physical_keys: HashSet<winit::keyboard::PhysicalKey>,
 }
 
+/// The viewports with an active AccessKit adapter, see [`State::on_accesskit_activated`].
+#[cfg(feature = "accesskit")]
+#[derive(Clone, Default)]
+struct AccessKitViewports(egui::ViewportIdSet);
+
+#[cfg(feature = "accesskit")]
+impl Drop for State {
+    fn drop(&mut self) {
+        if self.accesskit.is_some() {
+            // The window is closing, so its adapter is no longer active:
+            self.on_accesskit_deactivated();
+        }
+    }
+}
+
 impl State {
     /// Construct a new instance
     pub fn new(
@@ -131,6 +408,31 @@ impl State {
         native_pixels_per_point: Option<f32>,
         theme: Option<winit::window::Theme>,
         max_texture_side: Option<usize>,
+    ) -> Self {
+        Self::new_with_clipboard(
+            egui_ctx,
+            viewport_id,
+            display_target,
+            native_pixels_per_point,
+            theme,
+            max_texture_side,
+            clipboard::ClipboardConfig::Auto,
+        )
+    }
+
+    /// Like [`Self::new`], but choosing which clipboard to use.
+    ///
+    /// For instance, use [`clipboard::ClipboardConfig::InApp`] to keep a kiosk app away from the
+    /// OS clipboard, or [`clipboard::ClipboardConfig::Smithay`] to use the Wayland clipboard
+    /// even when the `x11` feature is enabled.
+    pub fn new_with_clipboard(
+        egui_ctx: egui::Context,
+        viewport_id: ViewportId,
+        display_target: &dyn HasDisplayHandle,
+        native_pixels_per_point: Option<f32>,
+        theme: Option<winit::window::Theme>,
+        max_texture_side: Option<usize>,
+        clipboard: clipboard::ClipboardConfig,
     ) -> Self {
         profiling::function_scope!();
 
@@ -149,20 +451,69 @@ impl State {
             pointer_pos_in_points: None,
             any_pointer_button_down: false,
             current_cursor_icon: None,
+            custom_cursors: HashMap::default(),
+            last_cursor_image: None,
             current_custom_cursor: None,
+            pointer_confinement: None,
 
-            clipboard: clipboard::Clipboard::new(
+            clipboard: clipboard::Clipboard::with_config(
                 display_target.display_handle().ok().map(|h| h.as_raw()),
+                clipboard,
             ),
 
             simulate_touch_screen: false,
+            coalesce_pointer_moves: false,
             pointer_touch_id: None,
+            emulate_pointer_from_touch: true,
+            pointer_source: egui::PointerSource::Mouse,
+            long_press_config: None,
+            long_press: None,
+
+            #[cfg(feature = "gamepad")]
+            gamepad: Default::default(),
+
+            event_filter: None,
+            keymap: KeymapConfig::default(),
+            scroll_config: ScrollConfig::default(),
+            double_tap_action: DoubleTapAction::default(),
+            double_tap_zoomed_in: false,
+            last_wheel_end_time: None,
+            wheel_momentum: false,
+
+            #[cfg(feature = "record")]
+            recording: None,
 
             #[cfg(feature = "accesskit")]
             accesskit: None,
 
             allow_ime: false,
             ime_rect_px: None,
+            virtual_keyboard: VirtualKeyboard::Always,
+            text_to_speech: None,
+            ime_purpose: winit::window::ImePurpose::Normal,
+            pending_dead_key: None,
+            compose_key: None,
+            compose: Default::default(),
+            keyboard_layout: Default::default(),
+            #[cfg(all(
+                any(
+                    target_os = "linux",
+                    target_os = "dragonfly",
+                    target_os = "freebsd",
+                    target_os = "netbsd",
+                    target_os = "openbsd"
+                ),
+                feature = "screenshot_portal"
+            ))]
+            portal_screenshots: Default::default(),
+
+            read_dropped_files: None,
+            dropped_file_reader: Default::default(),
+            hovered_file_sizes: Default::default(),
+
+            async_clipboard: false,
+            async_paste: Default::default(),
+            clipboard_watcher: None,
             #[cfg(target_os = "windows")]
             pressed_processed_physical_keys: HashSet::new(),
         };
@@ -173,6 +524,7 @@ impl State {
             .or_default()
             .native_pixels_per_point = native_pixels_per_point;
         slf.egui_input.system_theme = theme.map(to_egui_theme);
+        slf.egui_input.system_cursor_scale = system_cursor_scale();
 
         if let Some(max_texture_side) = max_texture_side {
             slf.set_max_texture_side(max_texture_side);
@@ -212,6 +564,48 @@ impl State {
         self.clipboard.set_text(text);
     }
 
+    /// Read the clipboard on a background thread when the user pastes, instead of blocking.
+    ///
+    /// Reading the clipboard can block for tens of milliseconds, e.g. on Wayland when the
+    /// application owning the clipboard is slow. With this on, the [`egui::Event::PasteFiles`]
+    /// and [`egui::Event::Paste`] instead arrive in a later frame.
+    ///
+    /// Off by default.
+    pub fn set_async_clipboard(&mut self, async_clipboard: bool) {
+        self.async_clipboard = async_clipboard;
+    }
+
+    /// Send [`egui::Event::ClipboardChanged`] when the clipboard changes,
+    /// e.g. for a clipboard manager.
+    ///
+    /// This is checked on a background thread a few times per second,
+    /// so it doesn't slow down the frame.
+    /// On Windows and macOS only the change count of the clipboard is looked at.
+    /// Elsewhere the clipboard text is read and compared, so only changes to the text are noticed.
+    ///
+    /// Off by default.
+    pub fn set_watch_clipboard(&mut self, watch: bool) {
+        if !watch {
+            self.clipboard_watcher = None;
+        } else if self.clipboard_watcher.is_none() {
+            self.clipboard_watcher = clipboard::ClipboardWatcher::start(
+                &self.clipboard,
+                &self.egui_ctx,
+                self.viewport_id,
+            );
+        }
+    }
+
+    /// Fetches HTML from the clipboard and returns it, if there is any.
+    pub fn clipboard_html(&mut self) -> Option<String> {
+        self.clipboard.get_html()
+    }
+
+    /// Places the HTML onto the clipboard, with `alt_text` for applications that only accept plain text.
+    pub fn set_clipboard_html(&mut self, html: String, alt_text: String) {
+        self.clipboard.set_html(html, alt_text);
+    }
+
     /// Returns [`false`] or the last value that [`Window::set_ime_allowed()`] was called with, used for debouncing.
     pub fn allow_ime(&self) -> bool {
         self.allow_ime
@@ -241,6 +635,64 @@ impl State {
         &mut self.egui_input
     }
 
+    /// Ask the XDG desktop portal for a screenshot.
+    ///
+    /// This is a fallback for integrations that can't read back their own frame.
+    /// The [`egui::Event::Screenshot`] shows up in a later call to [`Self::take_egui_input`],
+    /// and a repaint is requested when it arrives.
+    ///
+    /// Returns `false` if this is not supported, i.e. if the `screenshot_portal` feature is off
+    /// or we are not on Linux or a BSD.
+    #[cfg_attr(
+        not(all(
+            any(
+                target_os = "linux",
+                target_os = "dragonfly",
+                target_os = "freebsd",
+                target_os = "netbsd",
+                target_os = "openbsd"
+            ),
+            feature = "screenshot_portal"
+        )),
+        expect(
+            clippy::needless_pass_by_value,
+            clippy::unused_self,
+            reason = "only used when the portal is available"
+        )
+    )]
+    pub fn request_portal_screenshot(&self, user_data: egui::UserData) -> bool {
+        #[cfg(all(
+            any(
+                target_os = "linux",
+                target_os = "dragonfly",
+                target_os = "freebsd",
+                target_os = "netbsd",
+                target_os = "openbsd"
+            ),
+            feature = "screenshot_portal"
+        ))]
+        {
+            self.portal_screenshots
+                .request(&self.egui_ctx, self.viewport_id, user_data);
+            true
+        }
+
+        #[cfg(not(all(
+            any(
+                target_os = "linux",
+                target_os = "dragonfly",
+                target_os = "freebsd",
+                target_os = "netbsd",
+                target_os = "openbsd"
+            ),
+            feature = "screenshot_portal"
+        )))]
+        {
+            _ = user_data;
+            false
+        }
+    }
+
     /// Prepare for a new frame by extracting the accumulated input,
     ///
     /// as well as setting [the time](egui::RawInput::time) and [screen rectangle](egui::RawInput::screen_rect).
@@ -248,32 +700,302 @@ impl State {
     /// You need to set [`egui::RawInput::viewports`] yourself though.
     /// Use [`update_viewport_info`] to update the info for each
     /// viewport.
-    pub fn take_egui_input(&mut self, window: &Window) -> egui::RawInput {
+    pub fn take_egui_input(&mut self, window: &dyn HostWindow) -> egui::RawInput {
         profiling::function_scope!();
 
         self.egui_input.time = Some(self.start_time.elapsed().as_secs_f64());
 
+        let screen_area = window.screen_area();
+        let pixels_per_point = pixels_per_point(&self.egui_ctx, window);
+
         // On Windows, a minimized window will have 0 width and height.
         // See: https://github.com/rust-windowing/winit/issues/208
         // This solves an issue where egui window positions would be changed when minimizing on Windows.
-        let screen_size_in_pixels = screen_size_in_pixels(window);
-        let screen_size_in_points =
-            screen_size_in_pixels / pixels_per_point(&self.egui_ctx, window);
+        self.egui_input.screen_rect = screen_area.rect_in_points(pixels_per_point);
 
-        self.egui_input.screen_rect = (screen_size_in_points.x > 0.0
-            && screen_size_in_points.y > 0.0)
-            .then(|| Rect::from_min_size(Pos2::ZERO, screen_size_in_points));
+        // winit doesn't tell us when the insets change (e.g. when the soft keyboard is shown,
+        // or the device is rotated), so we poll them every frame:
+        if let Some(safe_area_insets) = screen_area.safe_area_insets(pixels_per_point) {
+            self.egui_input.safe_area_insets = Some(safe_area_insets);
+        }
 
         // Tell egui which viewport is now active:
         self.egui_input.viewport_id = self.viewport_id;
 
+        #[cfg(all(
+            any(
+                target_os = "linux",
+                target_os = "dragonfly",
+                target_os = "freebsd",
+                target_os = "netbsd",
+                target_os = "openbsd"
+            ),
+            feature = "screenshot_portal"
+        ))]
+        self.egui_input
+            .events
+            .extend(self.portal_screenshots.arrived());
+
+        self.egui_input
+            .dropped_files
+            .extend(self.dropped_file_reader.arrived());
+        self.hovered_file_sizes
+            .fill_in(&mut self.egui_input.hovered_files);
+
+        for pasted in self.async_paste.arrived() {
+            if let Some(paths) = pasted.files {
+                self.egui_input.events.push(egui::Event::PasteFiles(paths));
+            }
+            if let Some(contents) = pasted.text {
+                self.egui_input.events.extend(paste_event(&contents));
+            }
+        }
+
+        if let Some(watcher) = &self.clipboard_watcher
+            && watcher.take_changed()
+        {
+            self.egui_input.events.push(egui::Event::ClipboardChanged);
+        }
+
+        #[cfg(feature = "gamepad")]
+        if self.gamepad.scroll(
+            self.start_time.elapsed().as_secs_f64(),
+            &mut self.egui_input.events,
+        ) {
+            // Keep scrolling for as long as the stick is held:
+            self.egui_ctx.request_repaint();
+        }
+
+        if let Some(long_press::LongPress::Waiting { start_time, .. }) = self.long_press
+            && let Some(config) = self.long_press_config
+        {
+            let remaining =
+                start_time + config.duration as f64 - self.egui_input.time.unwrap_or_default();
+            if remaining <= 0.0 {
+                self.long_press = Some(long_press::LongPress::Fired);
+                for state in [
+                    winit::event::ElementState::Pressed,
+                    winit::event::ElementState::Released,
+                ] {
+                    self.on_mouse_button_input(state, winit::event::MouseButton::Right);
+                }
+            } else {
+                self.egui_ctx
+                    .request_repaint_after(web_time::Duration::from_secs_f64(remaining));
+            }
+        }
+
         self.egui_input
             .viewports
             .entry(self.viewport_id)
             .or_default()
             .native_pixels_per_point = Some(window.scale_factor() as f32);
 
-        self.egui_input.take()
+        let raw_input = self.egui_input.take();
+
+        #[cfg(feature = "record")]
+        if let Some(recording) = &mut self.recording {
+            recording.frames.push(raw_input.clone());
+        }
+
+        raw_input
+    }
+
+    /// Start recording everything returned by [`Self::take_egui_input`],
+    /// discarding any previous recording.
+    ///
+    /// See [`record`] for how to replay it.
+    #[cfg(feature = "record")]
+    pub fn start_recording(&mut self) {
+        self.recording = Some(Default::default());
+    }
+
+    /// Stop recording, returning what was recorded since [`Self::start_recording`].
+    #[cfg(feature = "record")]
+    pub fn stop_recording(&mut self) -> Option<record::Recording> {
+        self.recording.take()
+    }
+
+    /// Are we recording the input?
+    #[cfg(feature = "record")]
+    pub fn is_recording(&self) -> bool {
+        self.recording.is_some()
+    }
+
+    /// Call this when there is a new gamepad event, e.g. from [`gilrs`](https://docs.rs/gilrs).
+    ///
+    /// The d-pad, left stick and shoulder buttons move keyboard focus,
+    /// [`GamepadButton::South`] activates the focused widget,
+    /// [`GamepadButton::East`] acts like `Escape`,
+    /// and the right stick scrolls like a mouse wheel.
+    ///
+    /// The result can be found in [`Self::egui_input`] and be extracted with [`Self::take_egui_input`].
+    #[cfg(feature = "gamepad")]
+    pub fn on_gamepad_event(&mut self, event: &GamepadEvent) -> EventResponse {
+        let produced_events = self.gamepad.on_event(event, &mut self.egui_input.events);
+        let moves_focus = matches!(
+            event,
+            GamepadEvent::ButtonPressed(GamepadButton::LeftShoulder | GamepadButton::RightShoulder)
+        );
+        EventResponse {
+            repaint: produced_events || matches!(event, GamepadEvent::AxisChanged(..)),
+            consumed: produced_events && (moves_focus || self.egui_ctx.egui_wants_keyboard_input()),
+        }
+    }
+
+    /// Intercept events before [`Self::on_window_event`] translates them.
+    ///
+    /// The filter can let the event through, suppress it, or handle it itself
+    /// by pushing its own events to the [`egui::RawInput`], e.g. to remap mouse buttons:
+    ///
+    /// ```
+    /// # fn example(state: &mut egui_winit::State) {
+    /// use egui_winit::{FilterResult, winit::event::WindowEvent};
+    ///
+    /// state.set_event_filter(Box::new(|event, _raw_input| match event {
+    ///     // Leave the right mouse button to the game:
+    ///     WindowEvent::MouseInput {
+    ///         button: egui_winit::winit::event::MouseButton::Right,
+    ///         ..
+    ///     } => FilterResult::Suppress,
+    ///     _ => FilterResult::Pass,
+    /// }));
+    /// # }
+    /// ```
+    pub fn set_event_filter(&mut self, event_filter: EventFilter) {
+        self.event_filter = Some(event_filter);
+    }
+
+    /// Remove the filter set with [`Self::set_event_filter`].
+    pub fn clear_event_filter(&mut self) {
+        self.event_filter = None;
+    }
+
+    /// Which key presses are turned into clipboard commands and other shortcuts.
+    pub fn keymap(&self) -> &KeymapConfig {
+        &self.keymap
+    }
+
+    /// Change which key presses are turned into clipboard commands and other shortcuts.
+    pub fn set_keymap(&mut self, keymap: KeymapConfig) {
+        self.keymap = keymap;
+    }
+
+    /// Treat mouse input as touches, to debug touch support in egui.
+    ///
+    /// This creates duplicate touches if real touch input is coming in too.
+    pub fn set_simulate_touch_screen(&mut self, simulate_touch_screen: bool) {
+        self.simulate_touch_screen = simulate_touch_screen;
+    }
+
+    /// Should the first finger of a touch also act as the mouse pointer? Defaults to `true`.
+    ///
+    /// Turn this off to only get [`egui::Event::Touch`] for touches, e.g. in a game
+    /// that does its own multi-touch handling and doesn't want synthetic clicks.
+    pub fn set_emulate_pointer_from_touch(&mut self, emulate: bool) {
+        self.emulate_pointer_from_touch = emulate;
+        if !emulate && self.pointer_touch_id.take().is_some() {
+            // Let go of the touch we were emulating the pointer with:
+            if self.long_press.take().is_none() {
+                self.on_mouse_button_input(
+                    winit::event::ElementState::Released,
+                    winit::event::MouseButton::Left,
+                );
+            }
+            self.pointer_pos_in_points = None;
+            self.egui_input.events.push(egui::Event::PointerGone);
+        }
+    }
+
+    /// Read the contents of dropped files into [`egui::DroppedFile::bytes`],
+    /// for files of at most `max_size` bytes.
+    ///
+    /// This also fills in the name, mime type and modification time, like the web backend does,
+    /// so apps can handle drops the same way on native and web.
+    /// The files are read on a background thread, so they arrive in a later frame than the drop.
+    ///
+    /// Off (`None`) by default.
+    pub fn set_read_dropped_files(&mut self, max_size: Option<u64>) {
+        self.read_dropped_files = max_size;
+    }
+
+    /// Turn touches that are held still into secondary clicks, e.g. to open context menus.
+    ///
+    /// Off (`None`) by default.
+    pub fn set_long_press(&mut self, long_press: Option<LongPressConfig>) {
+        self.long_press_config = long_press;
+    }
+
+    /// Speak what happens in the ui, e.g. which widget got focus, with the given text-to-speech engine.
+    ///
+    /// This only happens while [`egui::Options::screen_reader`] is on.
+    /// Prefer AccessKit on platforms that support it,
+    /// since it lets the user's own screen reader do the talking.
+    ///
+    /// Off (`None`) by default.
+    pub fn set_text_to_speech(&mut self, text_to_speech: Option<Box<dyn TextToSpeech>>) {
+        self.text_to_speech = text_to_speech;
+    }
+
+    /// Use this key as a compose key, for typing characters that are not on the keyboard.
+    ///
+    /// Press it, then type two characters, e.g. `e` and `'` for `é`, or `o` and `e` for `œ`.
+    /// Type `u`, a Unicode code point in hex, and space for any character, e.g. `u20ac` for `€`.
+    /// The composed character is sent as [`egui::Event::Text`].
+    ///
+    /// This is for platforms and keyboard layouts without a compose key of their own.
+    ///
+    /// Off (`None`) by default.
+    pub fn set_compose_key(&mut self, compose_key: Option<winit::keyboard::KeyCode>) {
+        self.compose_key = compose_key;
+        self.compose.cancel();
+    }
+
+    /// When to show the on-screen keyboard for a focused text field on Android and iOS.
+    ///
+    /// [`VirtualKeyboard::Always`] by default.
+    pub fn set_virtual_keyboard(&mut self, virtual_keyboard: VirtualKeyboard) {
+        self.virtual_keyboard = virtual_keyboard;
+    }
+
+    /// Merge consecutive [`egui::Event::PointerMoved`] events within a frame into one.
+    ///
+    /// High polling rate mice can move the cursor hundreds of times per frame,
+    /// which bloats [`egui::RawInput`] and slows down egui's input processing.
+    /// Leave this off (the default) if you need the full path of the pointer, e.g. in a drawing app.
+    pub fn set_coalesce_pointer_moves(&mut self, coalesce: bool) {
+        self.coalesce_pointer_moves = coalesce;
+    }
+
+    /// How mouse wheel and trackpad scrolling is translated.
+    pub fn scroll_config(&self) -> &ScrollConfig {
+        &self.scroll_config
+    }
+
+    /// Change the scroll speed, direction, and Shift behavior.
+    pub fn set_scroll_config(&mut self, scroll_config: ScrollConfig) {
+        self.scroll_config = scroll_config;
+    }
+
+    /// Change what a double-tap gesture does, e.g. "smart zoom" on a macOS trackpad.
+    ///
+    /// By default it toggles zooming in by a factor of two.
+    pub fn set_double_tap_action(&mut self, double_tap_action: DoubleTapAction) {
+        self.double_tap_action = double_tap_action;
+        self.double_tap_zoomed_in = false;
+    }
+
+    /// Which logical key the given physical key produces in the current keyboard layout,
+    /// e.g. [`egui::Key::Z`] for [`egui::Key::W`] on AZERTY.
+    ///
+    /// This is only known for keys that have been pressed since the layout last changed
+    /// (see [`egui::Event::KeyboardLayoutChanged`]).
+    pub fn logical_key(&self, physical_key: egui::Key) -> Option<egui::Key> {
+        let (_, logical_key) = self
+            .keyboard_layout
+            .keys()
+            .find(|(key_code, _)| key_from_key_code(*key_code) == Some(physical_key))?;
+        key_from_winit_key(logical_key)
     }
 
     /// Call this when there is a new event.
@@ -281,32 +1003,38 @@ impl State {
     /// The result can be found in [`Self::egui_input`] and be extracted with [`Self::take_egui_input`].
     pub fn on_window_event(
         &mut self,
-        window: &Window,
+        window: &dyn HostWindow,
         event: &winit::event::WindowEvent,
     ) -> EventResponse {
         profiling::function_scope!(short_window_event_description(event));
 
         #[cfg(feature = "accesskit")]
-        if let Some(accesskit) = self.accesskit.as_mut() {
+        if let Some(accesskit) = self.accesskit.as_mut()
+            && let Some(window) = window.winit_window()
+        {
             accesskit.process_event(window, event);
         }
 
-        use winit::event::WindowEvent;
-
-        #[cfg(target_os = "ios")]
-        match &event {
-            WindowEvent::Resized(_)
-            | WindowEvent::ScaleFactorChanged { .. }
-            | WindowEvent::Focused(true)
-            | WindowEvent::Occluded(false) => {
-                // Once winit v0.31 has been released this can be reworked to get the safe area from
-                // `Window::safe_area`, and updated from a new event which is being discussed in
-                // https://github.com/rust-windowing/winit/issues/3911.
-                self.egui_input_mut().safe_area_insets = Some(safe_area::get_safe_area_insets());
+        if let Some(event_filter) = &mut self.event_filter {
+            match event_filter(event, &mut self.egui_input) {
+                FilterResult::Pass => {}
+                FilterResult::Suppress => {
+                    return EventResponse {
+                        repaint: false,
+                        consumed: false,
+                    };
+                }
+                FilterResult::Handled => {
+                    return EventResponse {
+                        repaint: true,
+                        consumed: true,
+                    };
+                }
             }
-            _ => {}
         }
 
+        use winit::event::WindowEvent;
+
         match event {
             WindowEvent::ScaleFactorChanged { scale_factor, .. } => {
                 let native_pixels_per_point = *scale_factor as f32;
@@ -323,6 +1051,7 @@ impl State {
                 }
             }
             WindowEvent::MouseInput { state, button, .. } => {
+                self.set_pointer_source(egui::PointerSource::Mouse);
                 self.on_mouse_button_input(*state, *button);
                 EventResponse {
                     repaint: true,
@@ -337,12 +1066,25 @@ impl State {
                 }
             }
             WindowEvent::CursorMoved { position, .. } => {
+                self.set_pointer_source(egui::PointerSource::Mouse);
                 self.on_cursor_moved(window, *position);
                 EventResponse {
                     repaint: true,
                     consumed: self.egui_ctx.egui_is_using_pointer(),
                 }
             }
+            WindowEvent::CursorEntered { .. } => {
+                if self.egui_ctx.options(|o| o.focus_follows_mouse)
+                    && !window.has_focus()
+                    && let Some(window) = window.winit_window()
+                {
+                    window.focus_window();
+                }
+                EventResponse {
+                    repaint: true,
+                    consumed: false,
+                }
+            }
             WindowEvent::CursorLeft { .. } => {
                 self.pointer_pos_in_points = None;
                 self.egui_input.events.push(egui::Event::PointerGone);
@@ -351,7 +1093,18 @@ impl State {
                     consumed: false,
                 }
             }
-            // WindowEvent::TouchpadPressure {device_id, pressure, stage, ..  } => {} // TODO(emilk)
+            WindowEvent::TouchpadPressure {
+                pressure, stage, ..
+            } => {
+                self.egui_input.events.push(egui::Event::PointerForce {
+                    force: *pressure,
+                    stage: (*stage).clamp(0, u8::MAX.into()) as u8,
+                });
+                EventResponse {
+                    repaint: true,
+                    consumed: self.egui_ctx.egui_wants_pointer_input(),
+                }
+            }
             WindowEvent::Touch(touch) => {
                 self.on_touch(window, touch);
                 let consumed = match touch.phase {
@@ -440,8 +1193,13 @@ impl State {
             WindowEvent::HoveredFile(path) => {
                 self.egui_input.hovered_files.push(egui::HoveredFile {
                     path: Some(path.clone()),
-                    ..Default::default()
+                    mime: guess_mime(path),
+                    size: None, // looked up in the background
+                    // winit doesn't tell us where, so use the last known position:
+                    pos: self.pointer_pos_in_points,
                 });
+                self.hovered_file_sizes
+                    .request(&self.egui_ctx, self.viewport_id, path.clone());
                 EventResponse {
                     repaint: true,
                     consumed: false,
@@ -456,10 +1214,19 @@ impl State {
             }
             WindowEvent::DroppedFile(path) => {
                 self.egui_input.hovered_files.clear();
-                self.egui_input.dropped_files.push(egui::DroppedFile {
-                    path: Some(path.clone()),
-                    ..Default::default()
-                });
+                if let Some(max_size) = self.read_dropped_files {
+                    self.dropped_file_reader.read(
+                        &self.egui_ctx,
+                        self.viewport_id,
+                        path.clone(),
+                        max_size,
+                    );
+                } else {
+                    self.egui_input.dropped_files.push(egui::DroppedFile {
+                        path: Some(path.clone()),
+                        ..Default::default()
+                    });
+                }
                 EventResponse {
                     repaint: true,
                     consumed: false,
@@ -491,24 +1258,46 @@ impl State {
 
             // Things that may require repaint:
             WindowEvent::RedrawRequested
-            | WindowEvent::CursorEntered { .. }
             | WindowEvent::Destroyed
             | WindowEvent::Occluded(_)
             | WindowEvent::Resized(_)
             | WindowEvent::Moved(_)
-            | WindowEvent::TouchpadPressure { .. }
             | WindowEvent::CloseRequested => EventResponse {
                 repaint: true,
                 consumed: false,
             },
 
             // Things we completely ignore:
-            WindowEvent::ActivationTokenDone { .. }
-            | WindowEvent::AxisMotion { .. }
-            | WindowEvent::DoubleTapGesture { .. } => EventResponse {
-                repaint: false,
-                consumed: false,
-            },
+            WindowEvent::ActivationTokenDone { .. } | WindowEvent::AxisMotion { .. } => {
+                EventResponse {
+                    repaint: false,
+                    consumed: false,
+                }
+            }
+
+            WindowEvent::DoubleTapGesture { .. } => {
+                let num_events = self.egui_input.events.len();
+                match &self.double_tap_action {
+                    DoubleTapAction::Ignore => {}
+                    DoubleTapAction::ToggleZoom(zoom_factor) => {
+                        let zoom_factor = if self.double_tap_zoomed_in {
+                            1.0 / zoom_factor
+                        } else {
+                            *zoom_factor
+                        };
+                        self.double_tap_zoomed_in = !self.double_tap_zoomed_in;
+                        self.egui_input.events.push(egui::Event::Zoom(zoom_factor));
+                    }
+                    DoubleTapAction::Events(events) => {
+                        self.egui_input.events.extend(events.iter().cloned());
+                    }
+                }
+                let handled = self.egui_input.events.len() != num_events;
+                EventResponse {
+                    repaint: handled,
+                    consumed: handled && self.egui_ctx.egui_wants_pointer_input(),
+                }
+            }
 
             WindowEvent::PinchGesture { delta, .. } => {
                 // Positive delta values indicate magnification (zooming in).
@@ -541,6 +1330,7 @@ impl State {
                     unit: egui::MouseWheelUnit::Point,
                     delta: Vec2::new(delta.x, delta.y) / pixels_per_point,
                     phase: to_egui_touch_phase(*phase),
+                    momentum: false,
                     modifiers: self.egui_input.modifiers,
                 });
                 EventResponse {
@@ -743,7 +1533,44 @@ impl State {
         }
     }
 
+    /// Call this when there is a new raw [`winit::event::DeviceEvent`].
+    ///
+    /// Raw mouse motion is sent to egui as [`egui::Event::MouseMoved`],
+    /// which keeps working while the cursor is locked or hidden.
+    ///
+    /// Raw mouse wheel and key events are also delivered as [`WindowEvent`]s while the window
+    /// is focused, so they are not sent to egui again here.
+    /// They are still reported as [`EventResponse::consumed`] when egui wants them,
+    /// so a game can route all its device events through this and skip the ones egui is using.
+    pub fn on_device_event(&mut self, event: &winit::event::DeviceEvent) -> EventResponse {
+        use winit::event::DeviceEvent;
+
+        match event {
+            DeviceEvent::MouseMotion { delta } => {
+                let sent = self.on_mouse_motion(*delta);
+                EventResponse {
+                    repaint: sent,
+                    consumed: sent && self.egui_ctx.egui_wants_pointer_input(),
+                }
+            }
+            DeviceEvent::MouseWheel { .. } => EventResponse {
+                repaint: false,
+                consumed: self.is_pointer_in_window() && self.egui_ctx.egui_wants_pointer_input(),
+            },
+            DeviceEvent::Key(_) => EventResponse {
+                repaint: false,
+                consumed: self.egui_input.focused && self.egui_ctx.egui_wants_keyboard_input(),
+            },
+            DeviceEvent::Added
+            | DeviceEvent::Removed
+            | DeviceEvent::Motion { .. }
+            | DeviceEvent::Button { .. } => EventResponse::default(),
+        }
+    }
+
     /// Returns `true` if the event was sent to egui.
+    ///
+    /// See also [`Self::on_device_event`].
     pub fn on_mouse_motion(&mut self, delta: (f64, f64)) -> bool {
         if !self.is_pointer_in_window() && !self.any_pointer_button_down {
             return false;
@@ -776,6 +1603,36 @@ impl State {
             .push(egui::Event::AccessKitActionRequest(request));
     }
 
+    /// Call this when the AccessKit adapter of this viewport requests its initial tree,
+    /// i.e. when an assistive technology starts using this window.
+    ///
+    /// AccessKit is enabled in the [`egui::Context`] for as long as any viewport is active.
+    #[cfg(feature = "accesskit")]
+    pub fn on_accesskit_activated(&self) {
+        self.egui_ctx.data_mut(|data| {
+            data.get_temp_mut_or_default::<AccessKitViewports>(egui::Id::NULL)
+                .0
+                .insert(self.viewport_id);
+        });
+        self.egui_ctx.enable_accesskit();
+    }
+
+    /// Call this when the AccessKit adapter of this viewport is deactivated.
+    ///
+    /// AccessKit is disabled in the [`egui::Context`] once no viewport is active.
+    #[cfg(feature = "accesskit")]
+    pub fn on_accesskit_deactivated(&self) {
+        let was_last_active = self.egui_ctx.data_mut(|data| {
+            let active = &mut data
+                .get_temp_mut_or_default::<AccessKitViewports>(egui::Id::NULL)
+                .0;
+            active.remove(&self.viewport_id) && active.is_empty()
+        });
+        if was_last_active {
+            self.egui_ctx.disable_accesskit();
+        }
+    }
+
     fn on_mouse_button_input(
         &mut self,
         state: winit::event::ElementState,
@@ -823,7 +1680,7 @@ impl State {
 
     fn on_cursor_moved(
         &mut self,
-        window: &Window,
+        window: &dyn HostWindow,
         pos_in_pixels: winit::dpi::PhysicalPosition<f64>,
     ) {
         let pixels_per_point = pixels_per_point(&self.egui_ctx, window);
@@ -832,8 +1689,13 @@ impl State {
             pos_in_pixels.x as f32 / pixels_per_point,
             pos_in_pixels.y as f32 / pixels_per_point,
         );
+        let pos_in_points = self.confine_pointer_pos(window, pos_in_points);
         self.pointer_pos_in_points = Some(pos_in_points);
 
+        for hovered_file in &mut self.egui_input.hovered_files {
+            hovered_file.pos = Some(pos_in_points);
+        }
+
         if self.simulate_touch_screen {
             if self.any_pointer_button_down {
                 self.egui_input
@@ -848,6 +1710,10 @@ impl State {
                     force: None,
                 });
             }
+        } else if self.coalesce_pointer_moves
+            && let Some(egui::Event::PointerMoved(last_pos)) = self.egui_input.events.last_mut()
+        {
+            *last_pos = pos_in_points;
         } else {
             self.egui_input
                 .events
@@ -855,18 +1721,73 @@ impl State {
         }
     }
 
-    fn on_touch(&mut self, window: &Window, touch: &winit::event::Touch) {
+    /// Keep the pointer inside [`Self::pointer_confinement`], moving the OS cursor back if we can.
+    fn confine_pointer_pos(
+        &self,
+        window: &dyn HostWindow,
+        pos_in_points: egui::Pos2,
+    ) -> egui::Pos2 {
+        let Some(rect) = self.pointer_confinement else {
+            return pos_in_points;
+        };
+        let clamped = rect.clamp(pos_in_points);
+        if clamped != pos_in_points
+            && let Some(window) = window.winit_window()
+        {
+            let pixels_per_point = pixels_per_point(&self.egui_ctx, window);
+            // Not supported on Wayland, where we only clamp what egui sees.
+            window
+                .set_cursor_position(PhysicalPosition::new(
+                    pixels_per_point * clamped.x,
+                    pixels_per_point * clamped.y,
+                ))
+                .ok();
+        }
+        clamped
+    }
+
+    /// Tell egui if the pointer is now driven by a different kind of device.
+    fn set_pointer_source(&mut self, source: egui::PointerSource) {
+        if self.pointer_source != source {
+            self.pointer_source = source;
+            self.egui_input
+                .events
+                .push(egui::Event::PointerSource(source));
+        }
+    }
+
+    fn on_touch(&mut self, window: &dyn HostWindow, touch: &winit::event::Touch) {
         let pixels_per_point = pixels_per_point(&self.egui_ctx, window);
+        let pos = egui::pos2(
+            touch.location.x as f32 / pixels_per_point,
+            touch.location.y as f32 / pixels_per_point,
+        );
+
+        if touch.phase == winit::event::TouchPhase::Started
+            && self.emulate_pointer_from_touch
+            && self.pointer_touch_id.is_none()
+        {
+            // This touch is about to become the pointer:
+            let is_pen = matches!(
+                touch.force,
+                Some(winit::event::Force::Calibrated {
+                    altitude_angle: Some(_),
+                    ..
+                })
+            );
+            self.set_pointer_source(if is_pen {
+                egui::PointerSource::Pen
+            } else {
+                egui::PointerSource::Touch
+            });
+        }
 
         // Emit touch event
         self.egui_input.events.push(egui::Event::Touch {
             device_id: egui::TouchDeviceId(egui::epaint::util::hash(touch.device_id)),
             id: egui::TouchId::from(touch.id),
             phase: to_egui_touch_phase(touch.phase),
-            pos: egui::pos2(
-                touch.location.x as f32 / pixels_per_point,
-                touch.location.y as f32 / pixels_per_point,
-            ),
+            pos,
             force: match touch.force {
                 Some(winit::event::Force::Normalized(force)) => Some(force as f32),
                 Some(winit::event::Force::Calibrated {
@@ -877,9 +1798,35 @@ impl State {
                 None => None,
             },
         });
+
+        // winit has no dedicated pen events, but a stylus (e.g. Apple Pencil)
+        // is reported as a touch with a known altitude.
+        // Barrel buttons and erasers are not reported at all.
+        if let Some(winit::event::Force::Calibrated {
+            force,
+            max_possible_force,
+            altitude_angle: Some(altitude_angle),
+        }) = touch.force
+            && matches!(
+                touch.phase,
+                winit::event::TouchPhase::Started | winit::event::TouchPhase::Moved
+            )
+        {
+            self.egui_input.events.push(egui::Event::PointerPen {
+                pos,
+                pressure: (force / max_possible_force).clamp(0.0, 1.0) as f32,
+                tilt: Some((std::f64::consts::FRAC_PI_2 - altitude_angle) as f32),
+                azimuth: None,
+                barrel_button: false,
+                eraser: false,
+            });
+        }
+
         // If we're not yet translating a touch or we're translating this very
         // touch …
-        if self.pointer_touch_id.is_none() || self.pointer_touch_id.unwrap_or_default() == touch.id
+        if self.emulate_pointer_from_touch
+            && (self.pointer_touch_id.is_none()
+                || self.pointer_touch_id.unwrap_or_default() == touch.id)
         {
             // … emit PointerButton resp. PointerMoved events to emulate mouse
             match touch.phase {
@@ -887,20 +1834,59 @@ impl State {
                     self.pointer_touch_id = Some(touch.id);
                     // First move the pointer to the right location
                     self.on_cursor_moved(window, touch.location);
-                    self.on_mouse_button_input(
-                        winit::event::ElementState::Pressed,
-                        winit::event::MouseButton::Left,
-                    );
+                    if let Some(config) = self.long_press_config {
+                        // Don't press yet, or a long-press would also be a click:
+                        self.long_press = Some(long_press::LongPress::Waiting {
+                            start_time: self.start_time.elapsed().as_secs_f64(),
+                            start_pos: pos,
+                        });
+                        self.egui_ctx
+                            .request_repaint_after(web_time::Duration::from_secs_f32(
+                                config.duration,
+                            ));
+                    } else {
+                        self.on_mouse_button_input(
+                            winit::event::ElementState::Pressed,
+                            winit::event::MouseButton::Left,
+                        );
+                    }
                 }
                 winit::event::TouchPhase::Moved => {
+                    if let Some(long_press::LongPress::Waiting { start_pos, .. }) = self.long_press
+                        && let Some(config) = self.long_press_config
+                        && config.max_distance < start_pos.distance(pos)
+                    {
+                        // This is a drag, not a long-press:
+                        self.long_press = None;
+                        self.on_mouse_button_input(
+                            winit::event::ElementState::Pressed,
+                            winit::event::MouseButton::Left,
+                        );
+                    }
                     self.on_cursor_moved(window, touch.location);
                 }
                 winit::event::TouchPhase::Ended => {
                     self.pointer_touch_id = None;
-                    self.on_mouse_button_input(
-                        winit::event::ElementState::Released,
-                        winit::event::MouseButton::Left,
-                    );
+                    match self.long_press.take() {
+                        Some(long_press::LongPress::Waiting { .. }) => {
+                            // A tap:
+                            self.on_mouse_button_input(
+                                winit::event::ElementState::Pressed,
+                                winit::event::MouseButton::Left,
+                            );
+                            self.on_mouse_button_input(
+                                winit::event::ElementState::Released,
+                                winit::event::MouseButton::Left,
+                            );
+                        }
+                        Some(long_press::LongPress::Fired) => {}
+                        None => {
+                            self.on_mouse_button_input(
+                                winit::event::ElementState::Released,
+                                winit::event::MouseButton::Left,
+                            );
+                        }
+                    }
                     // The pointer should vanish completely to not get any
                     // hover effects
                     self.pointer_pos_in_points = None;
@@ -908,6 +1894,7 @@ impl State {
                 }
                 winit::event::TouchPhase::Cancelled => {
                     self.pointer_touch_id = None;
+                    self.long_press = None;
                     self.pointer_pos_in_points = None;
                     self.egui_input.events.push(egui::Event::PointerGone);
                 }
@@ -917,7 +1904,7 @@ impl State {
 
     fn on_mouse_wheel(
         &mut self,
-        window: &Window,
+        window: &dyn HostWindow,
         delta: winit::event::MouseScrollDelta,
         phase: winit::event::TouchPhase,
     ) {
@@ -936,17 +1923,46 @@ impl State {
                     egui::vec2(x as f32, y as f32) / pixels_per_point,
                 ),
             };
+            let momentum = self.wheel_momentum(phase);
             let phase = to_egui_touch_phase(phase);
             let modifiers = self.egui_input.modifiers;
+            let delta = self.scroll_config.apply(unit, delta, modifiers);
             self.egui_input.events.push(egui::Event::MouseWheel {
                 unit,
                 delta,
                 phase,
+                momentum,
                 modifiers,
             });
         }
     }
 
+    /// Is this wheel event part of a momentum scroll?
+    ///
+    /// winit doesn't tell us, but on Mac a trackpad scroll that ends with a fling
+    /// is immediately followed by a second, momentum, scroll with its own start and end.
+    fn wheel_momentum(&mut self, phase: winit::event::TouchPhase) -> bool {
+        /// The largest gap between the end of a scroll and the start of its momentum.
+        const MAX_MOMENTUM_DELAY: f64 = 0.1;
+
+        let time = self.start_time.elapsed().as_secs_f64();
+        match phase {
+            winit::event::TouchPhase::Started => {
+                self.wheel_momentum = self
+                    .last_wheel_end_time
+                    .take()
+                    .is_some_and(|end_time| time - end_time < MAX_MOMENTUM_DELAY);
+                self.wheel_momentum
+            }
+            winit::event::TouchPhase::Moved => self.wheel_momentum,
+            winit::event::TouchPhase::Ended | winit::event::TouchPhase::Cancelled => {
+                let momentum = std::mem::take(&mut self.wheel_momentum);
+                self.last_wheel_end_time = (!momentum).then_some(time);
+                momentum
+            }
+        }
+    }
+
     fn on_keyboard_input(&mut self, event: &winit::event::KeyEvent) {
         let winit::event::KeyEvent {
             // Represents the position of a key independent of the currently active layout.
@@ -968,13 +1984,54 @@ impl State {
 
             state,
 
-            location: _, // e.g. is it on the numpad?
-            repeat: _,   // egui will figure this out for us
+            location,
+            repeat: _, // egui will figure this out for us
             ..
         } = event;
 
         let pressed = *state == winit::event::ElementState::Pressed;
 
+        if pressed
+            && self
+                .keyboard_layout
+                .on_key_press(event, self.egui_input.modifiers)
+        {
+            self.egui_input
+                .events
+                .push(egui::Event::KeyboardLayoutChanged);
+        }
+
+        let is_compose_key = self
+            .compose_key
+            .is_some_and(|key| *physical_key == winit::keyboard::PhysicalKey::Code(key));
+        if pressed && (is_compose_key || !is_modifier_key(winit_logical_key)) {
+            let key_text = text
+                .as_ref()
+                .map(|t| t.as_str())
+                .or_else(|| winit_logical_key.to_text());
+            match self
+                .compose
+                .on_key_press(is_compose_key, winit_logical_key, key_text)
+            {
+                compose::Composed::No => {}
+                compose::Composed::Consumed => return,
+                compose::Composed::Char(c) => {
+                    self.egui_input.events.push(egui::Event::Text(c.into()));
+                    return;
+                }
+            }
+        }
+
+        // A dead key modifies the next key press, but not a modifier key press (think `Shift` for `É`).
+        let dead_key = if !pressed || is_modifier_key(winit_logical_key) {
+            None
+        } else if let winit::keyboard::Key::Dead(dead_key) = winit_logical_key {
+            self.pending_dead_key = *dead_key;
+            None
+        } else {
+            self.pending_dead_key.take()
+        };
+
         let physical_key = if let winit::keyboard::PhysicalKey::Code(keycode) = *physical_key {
             key_from_key_code(keycode)
         } else {
@@ -997,18 +2054,32 @@ impl State {
         // are mapped to the physical keys that normally contain C, X, V, etc.
         // See also: https://github.com/emilk/egui/issues/3653
         if let Some(active_key) = logical_key.or(physical_key) {
+            let remapped = self.keymap.remapped(self.egui_input.modifiers, active_key);
+            let (active_key, modifiers) = remapped
+                .map_or((active_key, self.egui_input.modifiers), |shortcut| {
+                    (shortcut.logical_key, shortcut.modifiers)
+                });
+
             if pressed {
-                if is_cut_command(self.egui_input.modifiers, active_key) {
+                if self.keymap.is_cut(modifiers, active_key) {
                     self.egui_input.events.push(egui::Event::Cut);
                     return;
-                } else if is_copy_command(self.egui_input.modifiers, active_key) {
+                } else if self.keymap.is_copy(modifiers, active_key) {
                     self.egui_input.events.push(egui::Event::Copy);
                     return;
-                } else if is_paste_command(self.egui_input.modifiers, active_key) {
-                    if let Some(contents) = self.clipboard.get() {
-                        let contents = contents.replace("\r\n", "\n");
-                        if !contents.is_empty() {
-                            self.egui_input.events.push(egui::Event::Paste(contents));
+                } else if self.keymap.is_paste(modifiers, active_key) {
+                    if self.async_clipboard {
+                        self.async_paste.request(
+                            self.clipboard.paste_reader(),
+                            &self.egui_ctx,
+                            self.viewport_id,
+                        );
+                    } else {
+                        if let Some(paths) = self.clipboard.get_file_list() {
+                            self.egui_input.events.push(egui::Event::PasteFiles(paths));
+                        }
+                        if let Some(contents) = self.clipboard.get() {
+                            self.egui_input.events.extend(paste_event(&contents));
                         }
                     }
                     return;
@@ -1018,10 +2089,16 @@ impl State {
             self.egui_input.events.push(egui::Event::Key {
                 key: active_key,
                 physical_key,
+                location: to_egui_key_location(*location),
                 pressed,
                 repeat: false, // egui will fill this in for us!
-                modifiers: self.egui_input.modifiers,
+                modifiers,
             });
+
+            if remapped.is_some() {
+                // Don't also type the text of the original key.
+                return;
+            }
         }
 
         if let Some(text) = text
@@ -1040,9 +2117,10 @@ impl State {
                     || self.egui_input.modifiers.command
                     || self.egui_input.modifiers.mac_cmd;
                 if pressed && !is_cmd {
-                    self.egui_input
-                        .events
-                        .push(egui::Event::Text(text.to_owned()));
+                    let text = dead_key
+                        .and_then(|dead_key| dead_key::compose(dead_key, text))
+                        .unwrap_or_else(|| text.to_owned());
+                    self.egui_input.events.push(egui::Event::Text(text));
                 }
             }
         }
@@ -1058,7 +2136,7 @@ impl State {
     /// *
     pub fn handle_platform_output(
         &mut self,
-        window: &Window,
+        window: &dyn HostWindow,
         platform_output: egui::PlatformOutput,
     ) {
         self.handle_platform_output_inner(window, None, platform_output);
@@ -1073,7 +2151,7 @@ impl State {
     /// path still runs.
     pub fn handle_platform_output_with_event_loop(
         &mut self,
-        window: &Window,
+        window: &dyn HostWindow,
         event_loop: &ActiveEventLoop,
         platform_output: egui::PlatformOutput,
     ) {
@@ -1082,19 +2160,35 @@ impl State {
 
     fn handle_platform_output_inner(
         &mut self,
-        window: &Window,
+        window: &dyn HostWindow,
         event_loop: Option<&ActiveEventLoop>,
         platform_output: egui::PlatformOutput,
     ) {
         profiling::function_scope!();
 
+        if let Some(text_to_speech) = &mut self.text_to_speech
+            && self.egui_ctx.options(|o| o.screen_reader)
+        {
+            let description = platform_output.focus_summary.as_ref().map_or_else(
+                || platform_output.events_description(),
+                egui::FocusSummary::text,
+            );
+            if !description.is_empty() {
+                text_to_speech.speak(&description);
+            }
+        }
+
         let egui::PlatformOutput {
             commands,
             cursor_icon,
             cursor_image,
             events: _,                    // handled elsewhere
+            widget_events: _,             // for the app
+            focus_summary: _,             // for the app
+            focused_rect: _,              // magnifiers follow focus through AccessKit instead
             mutable_text_under_cursor: _, // only used in eframe web
             ime,
+            pointer_confinement,
             accesskit_update,
             num_completed_passes: _,    // `egui::Context::run` handles this
             request_discard_reasons: _, // `egui::Context::run` handles this
@@ -1108,6 +2202,9 @@ impl State {
                 egui::OutputCommand::CopyImage(image) => {
                     self.clipboard.set_image(&image);
                 }
+                egui::OutputCommand::CopyFiles(paths) => {
+                    self.clipboard.set_file_list(&paths);
+                }
                 egui::OutputCommand::OpenUrl(open_url) => {
                     open_url_in_browser(&open_url.url);
                 }
@@ -1115,8 +2212,14 @@ impl State {
         }
 
         self.apply_cursor(window, event_loop, cursor_icon, cursor_image.as_ref());
-
-        let allow_ime = ime.is_some();
+        self.apply_pointer_confinement(window, pointer_confinement);
+
+        // On Android and iOS, allowing IME also shows the on-screen keyboard.
+        // Once shown, keep it for as long as a text field has focus:
+        let allow_ime = ime.is_some()
+            && (self.allow_ime
+                || !cfg!(any(target_os = "android", target_os = "ios"))
+                || self.virtual_keyboard.should_show(self.pointer_source));
         let is_toggling_ime = self.allow_ime != allow_ime;
         if is_toggling_ime {
             self.allow_ime = allow_ime;
@@ -1134,7 +2237,9 @@ impl State {
             window.set_ime_allowed(allow_ime);
         }
 
-        if let Some(ime) = ime {
+        if let Some(ime) = ime
+            && allow_ime
+        {
             if !is_toggling_ime && ime.should_interrupt_composition {
                 // TODO(umajho): use a more proper way to interrupt composition
                 // if `winit` provides one in the future.
@@ -1143,8 +2248,16 @@ impl State {
                 window.set_ime_allowed(true);
             }
 
+            let ime_purpose = to_winit_ime_purpose(ime.purpose);
+            if is_toggling_ime || self.ime_purpose != ime_purpose {
+                self.ime_purpose = ime_purpose;
+                profiling::scope!("set_ime_purpose");
+                window.set_ime_purpose(ime_purpose);
+            }
+
             let pixels_per_point = pixels_per_point(&self.egui_ctx, window);
-            let ime_rect_px = pixels_per_point * ime.rect;
+            // Follow the cursor row, so the candidate window stays next to the text being composed:
+            let ime_rect_px = pixels_per_point * ime.cursor_row_rect;
             if self.ime_rect_px != Some(ime_rect_px)
                 || self.egui_ctx.input(|i| !i.events.is_empty())
             {
@@ -1177,13 +2290,37 @@ impl State {
         let _ = accesskit_update;
     }
 
+    fn apply_pointer_confinement(
+        &mut self,
+        window: &dyn HostWindow,
+        pointer_confinement: Option<egui::Rect>,
+    ) {
+        let was_confined = self.pointer_confinement.is_some();
+        self.pointer_confinement = pointer_confinement;
+        if was_confined == pointer_confinement.is_some() {
+            return;
+        }
+
+        if let Some(window) = window.winit_window() {
+            let mode = if pointer_confinement.is_some() {
+                CursorGrabMode::Confined
+            } else {
+                CursorGrabMode::None
+            };
+            if let Err(err) = window.set_cursor_grab(mode) {
+                // E.g. macOS can only lock the cursor, so there we rely on moving it back.
+                log::debug!("Failed to set cursor grab mode {mode:?}: {err}");
+            }
+        }
+    }
+
     /// Apply either a bitmap cursor (preferred when both `cursor_image`
     /// and `event_loop` are `Some`) or the standard `cursor_icon` to the
     /// window. Mirrors the no-flicker dedupe the old `set_cursor_icon`
     /// did, on the appropriate cache key for whichever path is active.
     fn apply_cursor(
         &mut self,
-        window: &Window,
+        window: &dyn HostWindow,
         event_loop: Option<&ActiveEventLoop>,
         cursor_icon: egui::CursorIcon,
         cursor_image: Option<&egui::CustomCursorImage>,
@@ -1203,25 +2340,38 @@ impl State {
         // dropped and we fall through to the icon path — this is the
         // documented fallback for integrations that didn't opt in.
         if let (Some(image), Some(event_loop)) = (cursor_image, event_loop) {
-            let key = std::sync::Arc::as_ptr(&image.rgba).cast::<u8>() as usize;
-            let cached = self
-                .current_custom_cursor
-                .as_ref()
-                .filter(|(k, _)| *k == key)
-                .map(|(_, c)| c.clone());
-
-            let custom = match cached {
-                Some(c) => c,
-                None => match winit::window::CustomCursor::from_rgba(
-                    image.rgba.to_vec(),
-                    image.size[0],
-                    image.size[1],
-                    image.hotspot[0],
-                    image.hotspot[1],
-                ) {
+            let image_hash = match &self.last_cursor_image {
+                Some((last, hash))
+                    if std::sync::Arc::ptr_eq(&last.rgba, &image.rgba)
+                        && last.size == image.size
+                        && last.hotspot == image.hotspot =>
+                {
+                    *hash
+                }
+                _ => {
+                    let hash = egui::epaint::util::hash(image);
+                    self.last_cursor_image = Some((image.clone(), hash));
+                    hash
+                }
+            };
+            let key = CustomCursorKey {
+                image_hash,
+                cursor_scale_bits: self.egui_ctx.cursor_scale().to_bits(),
+            };
+            if self.current_custom_cursor == Some(key) {
+                return;
+            }
+
+            let custom = match self.custom_cursors.get(&key) {
+                Some(c) => c.clone(),
+                None => match custom_cursor_source(&image.scaled(self.egui_ctx.cursor_scale())) {
                     Ok(source) => {
+                        if MAX_CUSTOM_CURSORS <= self.custom_cursors.len() {
+                            // Keep the cache bounded for apps that generate lots of different cursors.
+                            self.custom_cursors.clear();
+                        }
                         let c = event_loop.create_custom_cursor(source);
-                        self.current_custom_cursor = Some((key, c.clone()));
+                        self.custom_cursors.insert(key, c.clone());
                         c
                     }
                     Err(err) => {
@@ -1236,7 +2386,8 @@ impl State {
             };
 
             window.set_cursor_visible(true);
-            window.set_cursor(custom);
+            window.set_cursor(custom.into());
+            self.current_custom_cursor = Some(key);
             // Resync `current_cursor_icon` so the next icon-only path
             // notices a real change rather than dedupe-skipping it.
             self.current_cursor_icon = None;
@@ -1249,7 +2400,7 @@ impl State {
 
     /// Icon-only path, factored out so `apply_cursor` can fall back to it
     /// when the bitmap path bails. Preserves the original dedupe.
-    fn set_cursor_icon_inner(&mut self, window: &Window, cursor_icon: egui::CursorIcon) {
+    fn set_cursor_icon_inner(&mut self, window: &dyn HostWindow, cursor_icon: egui::CursorIcon) {
         if self.current_cursor_icon == Some(cursor_icon) {
             // Prevent flickering near frame boundary when Windows OS tries to control cursor icon for window resizing.
             // On other platforms: just early-out to save CPU.
@@ -1260,13 +2411,22 @@ impl State {
 
         if let Some(winit_cursor_icon) = translate_cursor(cursor_icon) {
             window.set_cursor_visible(true);
-            window.set_cursor(winit_cursor_icon);
+            window.set_cursor(winit_cursor_icon.into());
         } else {
             window.set_cursor_visible(false);
         }
     }
 }
 
+fn to_egui_key_location(location: winit::keyboard::KeyLocation) -> egui::KeyLocation {
+    match location {
+        winit::keyboard::KeyLocation::Numpad => egui::KeyLocation::Numpad,
+        winit::keyboard::KeyLocation::Standard
+        | winit::keyboard::KeyLocation::Left
+        | winit::keyboard::KeyLocation::Right => egui::KeyLocation::Standard,
+    }
+}
+
 fn to_egui_touch_phase(phase: winit::event::TouchPhase) -> egui::TouchPhase {
     match phase {
         winit::event::TouchPhase::Started => egui::TouchPhase::Start,
@@ -1276,6 +2436,84 @@ fn to_egui_touch_phase(phase: winit::event::TouchPhase) -> egui::TouchPhase {
     }
 }
 
+/// How many different [`CustomCursor`]s [`State`] keeps around.
+const MAX_CUSTOM_CURSORS: usize = 32;
+
+/// Identifies a [`CustomCursor`] created from an [`egui::CustomCursorImage`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+struct CustomCursorKey {
+    /// Hash of the image, including its size and hotspot.
+    image_hash: u64,
+
+    /// The bits of the `cursor_scale` the image was scaled by.
+    cursor_scale_bits: u32,
+}
+
+fn custom_cursor_source(
+    image: &egui::CustomCursorImage,
+) -> Result<winit::window::CustomCursorSource, winit::window::BadImage> {
+    winit::window::CustomCursor::from_rgba(
+        image.rgba.to_vec(),
+        image.size[0],
+        image.size[1],
+        image.hotspot[0],
+        image.hotspot[1],
+    )
+}
+
+/// How much larger than normal the OS draws its cursors.
+///
+/// On Linux and BSD, winit picks the size (and theme) of the standard cursors from
+/// `XCURSOR_SIZE` (and `XCURSOR_THEME`), where the default size is 24.
+/// Elsewhere the size is not known.
+fn system_cursor_scale() -> Option<f32> {
+    #[cfg(any(
+        target_os = "linux",
+        target_os = "dragonfly",
+        target_os = "freebsd",
+        target_os = "netbsd",
+        target_os = "openbsd"
+    ))]
+    {
+        let size: u32 = std::env::var("XCURSOR_SIZE").ok()?.parse().ok()?;
+        (size > 0).then(|| size as f32 / 24.0)
+    }
+
+    #[cfg(not(any(
+        target_os = "linux",
+        target_os = "dragonfly",
+        target_os = "freebsd",
+        target_os = "netbsd",
+        target_os = "openbsd"
+    )))]
+    {
+        None
+    }
+}
+
+/// The [`egui::Event::Paste`] for text from the clipboard, unless it is empty.
+fn paste_event(contents: &str) -> Option<egui::Event> {
+    let contents = contents.replace("\r\n", "\n");
+    (!contents.is_empty()).then_some(egui::Event::Paste(contents))
+}
+
+/// The mime type of a hovered file, guessed from its extension.
+fn guess_mime(path: &std::path::Path) -> String {
+    #[cfg(feature = "mime_guess")]
+    {
+        mime_guess2::from_path(path)
+            .first_raw()
+            .unwrap_or_default()
+            .to_owned()
+    }
+
+    #[cfg(not(feature = "mime_guess"))]
+    {
+        _ = path;
+        String::new()
+    }
+}
+
 fn to_egui_theme(theme: winit::window::Theme) -> Theme {
     match theme {
         winit::window::Theme::Dark => Theme::Dark,
@@ -1365,6 +2603,43 @@ pub fn update_viewport_info(
 
     viewport_info.fullscreen = Some(window.fullscreen().is_some());
     viewport_info.focused = Some(window.has_focus());
+
+    if is_init || viewport_info.monitors.is_none() {
+        profiling::scope!("monitors");
+        viewport_info.monitors = Some(
+            window
+                .available_monitors()
+                .map(|monitor| to_egui_monitor(&monitor))
+                .collect(),
+        );
+    }
+
+    viewport_info.window_insets = ScreenArea::of_window(window)
+        .safe_area_insets(pixels_per_point)
+        .map(|insets| insets.0);
+}
+
+fn to_egui_monitor(monitor: &winit::monitor::MonitorHandle) -> egui::MonitorInfo {
+    let size = monitor.size();
+    egui::MonitorInfo {
+        name: monitor.name(),
+        size_in_pixels: [size.width, size.height],
+        native_pixels_per_point: monitor.scale_factor() as f32,
+        refresh_rate_millihertz: monitor.refresh_rate_millihertz(),
+        video_modes: monitor
+            .video_modes()
+            .map(|mode| to_egui_video_mode(&mode))
+            .collect(),
+    }
+}
+
+fn to_egui_video_mode(mode: &winit::monitor::VideoModeHandle) -> egui::VideoMode {
+    let size = mode.size();
+    egui::VideoMode {
+        size_in_pixels: [size.width, size.height],
+        bit_depth: mode.bit_depth(),
+        refresh_rate_millihertz: mode.refresh_rate_millihertz(),
+    }
 }
 
 fn open_url_in_browser(_url: &str) {
@@ -1391,24 +2666,6 @@ fn is_printable_char(chr: char) -> bool {
     !is_in_private_use_area && !chr.is_ascii_control()
 }
 
-fn is_cut_command(modifiers: egui::Modifiers, keycode: egui::Key) -> bool {
-    keycode == egui::Key::Cut
-        || (modifiers.command && keycode == egui::Key::X)
-        || (cfg!(target_os = "windows") && modifiers.shift && keycode == egui::Key::Delete)
-}
-
-fn is_copy_command(modifiers: egui::Modifiers, keycode: egui::Key) -> bool {
-    keycode == egui::Key::Copy
-        || (modifiers.command && keycode == egui::Key::C)
-        || (cfg!(target_os = "windows") && modifiers.ctrl && keycode == egui::Key::Insert)
-}
-
-fn is_paste_command(modifiers: egui::Modifiers, keycode: egui::Key) -> bool {
-    keycode == egui::Key::Paste
-        || (modifiers.command && keycode == egui::Key::V)
-        || (cfg!(target_os = "windows") && modifiers.shift && keycode == egui::Key::Insert)
-}
-
 fn translate_mouse_button(button: winit::event::MouseButton) -> Option<egui::PointerButton> {
     match button {
         winit::event::MouseButton::Left => Some(egui::PointerButton::Primary),
@@ -1420,6 +2677,24 @@ fn translate_mouse_button(button: winit::event::MouseButton) -> Option<egui::Poi
     }
 }
 
+fn is_modifier_key(key: &winit::keyboard::Key) -> bool {
+    use winit::keyboard::NamedKey;
+
+    matches!(
+        key,
+        winit::keyboard::Key::Named(
+            NamedKey::Shift
+                | NamedKey::Control
+                | NamedKey::Alt
+                | NamedKey::AltGraph
+                | NamedKey::Super
+                | NamedKey::Meta
+                | NamedKey::Hyper
+                | NamedKey::CapsLock
+        )
+    )
+}
+
 fn key_from_winit_key(key: &winit::keyboard::Key) -> Option<egui::Key> {
     match key {
         winit::keyboard::Key::Named(named_key) => key_from_named_key(*named_key),
@@ -1679,6 +2954,17 @@ fn translate_cursor(cursor_icon: egui::CursorIcon) -> Option<winit::window::Curs
     }
 }
 
+/// `winit` only distinguishes passwords; the other purposes fall back to a normal keyboard.
+fn to_winit_ime_purpose(purpose: egui::InputPurpose) -> winit::window::ImePurpose {
+    match purpose {
+        egui::InputPurpose::Password => winit::window::ImePurpose::Password,
+        egui::InputPurpose::Normal
+        | egui::InputPurpose::Email
+        | egui::InputPurpose::Number
+        | egui::InputPurpose::Url => winit::window::ImePurpose::Normal,
+    }
+}
+
 // Helpers for egui Viewports
 // ---------------------------------------------------------------------------
 #[derive(PartialEq, Eq, Hash, Debug)]
@@ -1687,6 +2973,59 @@ pub enum ActionRequested {
     Cut,
     Copy,
     Paste,
+
+    /// Show the window once the frame has been painted.
+    ShowWhenReady,
+}
+
+/// The winit window level for an egui one, given whether any window of the app has focus.
+pub fn to_winit_window_level(
+    window_level: egui::viewport::WindowLevel,
+    app_focused: bool,
+) -> WindowLevel {
+    match window_level {
+        egui::viewport::WindowLevel::AlwaysOnBottom => WindowLevel::AlwaysOnBottom,
+        egui::viewport::WindowLevel::AlwaysOnTop => WindowLevel::AlwaysOnTop,
+        egui::viewport::WindowLevel::AlwaysOnTopWhileAppFocused if app_focused => {
+            WindowLevel::AlwaysOnTop
+        }
+        egui::viewport::WindowLevel::AlwaysOnTopWhileAppFocused
+        | egui::viewport::WindowLevel::Normal => WindowLevel::Normal,
+    }
+}
+
+/// Keeps windows with [`egui::viewport::WindowLevel::AlwaysOnTopWhileAppFocused`] on top
+/// only while the app has focus.
+///
+/// winit only tells each window whether _it_ has focus,
+/// so this needs to look at all the windows of the app at once.
+#[derive(Default)]
+pub struct WindowLevels {
+    app_focused: Option<bool>,
+}
+
+impl WindowLevels {
+    /// Call this after processing the viewport commands, with all the windows of the app.
+    ///
+    /// The window levels come from [`ViewportInfo::window_level`].
+    pub fn update<'a>(
+        &mut self,
+        app_focused: bool,
+        windows: impl IntoIterator<Item = (&'a Window, &'a ViewportInfo)>,
+    ) {
+        if self.app_focused == Some(app_focused) {
+            return;
+        }
+        self.app_focused = Some(app_focused);
+
+        for (window, info) in windows {
+            if let Some(window_level @ egui::viewport::WindowLevel::AlwaysOnTopWhileAppFocused) =
+                info.window_level
+            {
+                window.set_window_level(to_winit_window_level(window_level, app_focused));
+            }
+        }
+    }
 }
 
 pub fn process_viewport_commands(
@@ -1776,6 +3115,9 @@ fn process_viewport_command(
         }
         ViewportCommand::Transparent(v) => window.set_transparent(v),
         ViewportCommand::Visible(v) => window.set_visible(v),
+        ViewportCommand::ShowWhenReady => {
+            actions_requested.push(ActionRequested::ShowWhenReady);
+        }
         ViewportCommand::OuterPosition(pos) => {
             window.set_outer_position(PhysicalPosition::new(
                 pixels_per_point * pos.x,
@@ -1838,6 +3180,23 @@ fn process_viewport_command(
                 );
             }
         }
+        ViewportCommand::ExclusiveFullscreen {
+            monitor,
+            video_mode,
+        } => {
+            let mode = window.available_monitors().nth(monitor).and_then(|handle| {
+                handle
+                    .video_modes()
+                    .find(|mode| to_egui_video_mode(mode) == video_mode)
+            });
+            if let Some(mode) = mode {
+                window.set_fullscreen(Some(winit::window::Fullscreen::Exclusive(mode)));
+            } else {
+                log::warn!(
+                    "ViewportCommand::ExclusiveFullscreen: monitor {monitor} does not support {video_mode}"
+                );
+            }
+        }
         ViewportCommand::Decorations(v) => {
             window.set_decorations(v);
             #[cfg(target_os = "windows")]
@@ -1846,11 +3205,16 @@ fn process_viewport_command(
                 window.set_undecorated_shadow(!v);
             }
         }
-        ViewportCommand::WindowLevel(l) => window.set_window_level(match l {
-            egui::viewport::WindowLevel::AlwaysOnBottom => WindowLevel::AlwaysOnBottom,
-            egui::viewport::WindowLevel::AlwaysOnTop => WindowLevel::AlwaysOnTop,
-            egui::viewport::WindowLevel::Normal => WindowLevel::Normal,
-        }),
+        ViewportCommand::WindowLevel(l) => {
+            info.window_level = Some(l);
+            let app_focused = egui_ctx.input(|i| {
+                i.raw
+                    .viewports
+                    .values()
+                    .any(|info| info.focused == Some(true))
+            });
+            window.set_window_level(to_winit_window_level(l, app_focused));
+        }
         ViewportCommand::Icon(icon) => {
             let winit_icon = icon.and_then(|icon| to_winit_icon(&icon));
             window.set_window_icon(winit_icon);
@@ -1870,6 +3234,14 @@ fn process_viewport_command(
             egui::viewport::IMEPurpose::Terminal => winit::window::ImePurpose::Terminal,
             egui::viewport::IMEPurpose::Normal => winit::window::ImePurpose::Normal,
         }),
+        ViewportCommand::SoftKeyboardVisible(visible) => {
+            if cfg!(target_os = "android") {
+                // On Android, winit shows and hides the soft keyboard together with the IME:
+                window.set_ime_allowed(visible);
+            } else {
+                log::debug!("ViewportCommand::SoftKeyboardVisible is only supported on Android");
+            }
+        }
         ViewportCommand::Focus => {
             if !window.has_focus() {
                 window.focus_window();
@@ -1930,6 +3302,13 @@ fn process_viewport_command(
     }
 }
 
+/// Is a window created from this builder hidden until its first frame has been painted?
+///
+/// If so, the integration should queue an [`ActionRequested::ShowWhenReady`] for the new window.
+pub fn is_shown_when_ready(viewport_builder: &ViewportBuilder) -> bool {
+    viewport_builder.show_when_ready == Some(true) && viewport_builder.visible != Some(false)
+}
+
 /// Build and intitlaize a window.
 ///
 /// Wrapper around `create_winit_window_builder` and `apply_viewport_builder_to_window`.
@@ -1986,6 +3365,7 @@ pub fn create_winit_window_attributes(
         icon,
         active,
         visible,
+        show_when_ready,
         close_button,
         minimize_button,
         maximize_button,
@@ -2020,17 +3400,17 @@ pub fn create_winit_window_attributes(
         .with_transparent(transparent.unwrap_or(false))
         .with_decorations(decorations.unwrap_or(true))
         .with_resizable(resizable.unwrap_or(true))
-        .with_visible(visible.unwrap_or(true))
+        .with_visible(visible.unwrap_or(true) && show_when_ready != Some(true))
         .with_maximized(if cfg!(target_os = "ios") {
             true
         } else {
             maximized.unwrap_or(false)
         })
-        .with_window_level(match window_level.unwrap_or_default() {
-            egui::viewport::WindowLevel::AlwaysOnBottom => WindowLevel::AlwaysOnBottom,
-            egui::viewport::WindowLevel::AlwaysOnTop => WindowLevel::AlwaysOnTop,
-            egui::viewport::WindowLevel::Normal => WindowLevel::Normal,
-        })
+        // New windows are usually opened by the app while it has focus:
+        .with_window_level(to_winit_window_level(
+            window_level.unwrap_or_default(),
+            true,
+        ))
         .with_fullscreen(
             fullscreen.and_then(|e| e.then_some(winit::window::Fullscreen::Borderless(None))),
         )
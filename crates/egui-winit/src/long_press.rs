@@ -0,0 +1,36 @@
+/// Turns a touch that is held still into a secondary click, so that context menus work on touchscreens.
+///
+/// Enable with [`crate::State::set_long_press`].
+///
+/// While a touch could still become a long-press, the primary button is not pressed.
+/// It is pressed once the touch moves, or ends as a tap.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct LongPressConfig {
+    /// How long the touch must be held, in seconds.
+    pub duration: f32,
+
+    /// How far the touch may move (in points) and still be held still.
+    pub max_distance: f32,
+}
+
+impl Default for LongPressConfig {
+    fn default() -> Self {
+        Self {
+            duration: 0.5,
+            max_distance: 8.0,
+        }
+    }
+}
+
+/// How far the touch emulating the pointer is in becoming a long-press.
+#[derive(Clone, Copy, Debug)]
+pub(crate) enum LongPress {
+    /// The touch has been held still since `start_time`.
+    Waiting {
+        start_time: f64,
+        start_pos: egui::Pos2,
+    },
+
+    /// The touch has become a secondary click, and the rest of it only moves the pointer.
+    Fired,
+}
@@ -0,0 +1,92 @@
+//! Recording the input egui-winit translates, and replaying it later.
+//!
+//! This is useful for reproducing input bugs (IME, modifiers, …) deterministically:
+//! record a session with [`crate::State::start_recording`], [`Recording::save`] it,
+//! and then [`Recording::load`] and [`Recording::replay`] it in a test.
+//!
+//! ```
+//! # let mut input = egui::RawInput::default();
+//! # input.events.push(egui::Event::Key {
+//! #     key: egui::Key::A,
+//! #     physical_key: None,
+//! #     location: egui::KeyLocation::Standard,
+//! #     pressed: true,
+//! #     repeat: false,
+//! #     modifiers: egui::Modifiers::SHIFT,
+//! # });
+//! # let ron = egui_winit::record::Recording { frames: vec![input] }.to_ron().unwrap();
+//! let recording = egui_winit::record::Recording::from_ron(&ron).unwrap();
+//! assert_eq!(recording.events().count(), 1);
+//!
+//! let ctx = egui::Context::default();
+//! let outputs = recording.replay(&ctx, |ui| {
+//!     ui.text_edit_singleline(&mut String::new());
+//! });
+//! assert_eq!(outputs.len(), recording.frames.len());
+//! ```
+
+use std::path::Path;
+
+/// The input of a number of frames, as produced by [`crate::State::take_egui_input`].
+///
+/// Each frame contains the translated [`egui::Event`]s together with
+/// the time ([`egui::RawInput::time`]) and everything else egui was told that frame.
+#[derive(Clone, Debug, Default, PartialEq, serde::Deserialize, serde::Serialize)]
+pub struct Recording {
+    pub frames: Vec<egui::RawInput>,
+}
+
+impl Recording {
+    /// All the events of all the frames, with the time of the frame they were in.
+    pub fn events(&self) -> impl Iterator<Item = (Option<f64>, &egui::Event)> {
+        self.frames
+            .iter()
+            .flat_map(|frame| frame.events.iter().map(|event| (frame.time, event)))
+    }
+
+    /// Run each recorded frame through `ctx`, returning the output of each.
+    pub fn replay(
+        &self,
+        ctx: &egui::Context,
+        mut run_ui: impl FnMut(&mut egui::Ui),
+    ) -> Vec<egui::FullOutput> {
+        self.frames
+            .iter()
+            .map(|frame| ctx.run_ui(frame.clone(), &mut run_ui))
+            .collect()
+    }
+
+    /// Serialize to [RON](https://github.com/ron-rs/ron).
+    ///
+    /// # Errors
+    /// If serialization fails.
+    pub fn to_ron(&self) -> Result<String, ron::Error> {
+        ron::ser::to_string_pretty(self, ron::ser::PrettyConfig::default())
+    }
+
+    /// Deserialize from [RON](https://github.com/ron-rs/ron).
+    ///
+    /// # Errors
+    /// On invalid RON.
+    pub fn from_ron(ron: &str) -> Result<Self, ron::error::SpannedError> {
+        ron::from_str(ron)
+    }
+
+    /// Save to a RON file.
+    ///
+    /// # Errors
+    /// If serialization or writing the file fails.
+    pub fn save(&self, path: impl AsRef<Path>) -> std::io::Result<()> {
+        let ron = self.to_ron().map_err(std::io::Error::other)?;
+        std::fs::write(path, ron)
+    }
+
+    /// Load from a RON file written by [`Self::save`].
+    ///
+    /// # Errors
+    /// If reading the file fails, or it isn't a valid recording.
+    pub fn load(path: impl AsRef<Path>) -> std::io::Result<Self> {
+        let ron = std::fs::read_to_string(path)?;
+        Self::from_ron(&ron).map_err(std::io::Error::other)
+    }
+}
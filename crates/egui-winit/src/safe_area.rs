@@ -1,28 +1,126 @@
-#[cfg(target_os = "ios")]
-pub use ios::get_safe_area_insets;
+use egui::{Rect, SafeAreaInsets, epaint::MarginF32};
+use winit::window::Window;
+
+/// The area of a window that egui paints to, and the parts of it that are covered by
+/// system UI such as notches, the dynamic island, status bars or navigation bars.
+///
+/// egui paints to the whole area, and tells the app about the covered parts via
+/// [`egui::RawInput::safe_area_insets`] and [`egui::ViewportInfo::window_insets`].
+/// Panels etc. stay out of the covered parts (see [`egui::Context::content_rect`]),
+/// but apps can intentionally paint under them using [`egui::Context::viewport_rect`].
+///
+/// Used by both [`crate::State::take_egui_input`] and [`crate::update_viewport_info`],
+/// so they always agree.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct ScreenArea {
+    /// The size of the whole area egui paints to, in physical pixels.
+    pub size_in_pixels: egui::Vec2,
+
+    /// How much of the area is covered by system UI, in physical pixels.
+    ///
+    /// `None` on platforms that don't report this.
+    pub insets_in_pixels: Option<MarginF32>,
+}
+
+impl ScreenArea {
+    /// The area of a winit window.
+    ///
+    /// On iOS this is the whole screen (including the area behind the notch),
+    /// and the insets are read from the view of the window.
+    /// On Android the insets are computed from the content rect of the activity.
+    pub fn of_window(window: &Window) -> Self {
+        // On iOS, `inner_size` is only the safe area, but we paint to the whole screen.
+        let size = if cfg!(target_os = "ios") {
+            window.outer_size()
+        } else {
+            window.inner_size()
+        };
+
+        #[cfg(target_os = "ios")]
+        let insets_in_pixels =
+            Some(ios::get_safe_area_insets(window).0 * window.scale_factor() as f32);
+        #[cfg(target_os = "android")]
+        let insets_in_pixels = Some(android::get_safe_area_insets(window));
+        #[cfg(not(any(target_os = "ios", target_os = "android")))]
+        let insets_in_pixels = None;
+
+        Self {
+            size_in_pixels: egui::vec2(size.width as f32, size.height as f32),
+            insets_in_pixels,
+        }
+    }
+
+    /// The screen rect in ui points.
+    ///
+    /// `None` if the window has no size, which happens e.g. when minimized on Windows.
+    pub fn rect_in_points(&self, pixels_per_point: f32) -> Option<Rect> {
+        let size = self.size_in_pixels / pixels_per_point;
+        (0.0 < size.x && 0.0 < size.y).then(|| Rect::from_min_size(egui::Pos2::ZERO, size))
+    }
+
+    /// The insets in ui points, if the platform reports them.
+    pub fn safe_area_insets(&self, pixels_per_point: f32) -> Option<SafeAreaInsets> {
+        self.insets_in_pixels
+            .map(|insets| SafeAreaInsets(insets / pixels_per_point))
+    }
+}
 
 #[cfg(target_os = "ios")]
 mod ios {
     use egui::{SafeAreaInsets, epaint::MarginF32};
     use objc2::{ClassType, rc::Retained};
     use objc2_foundation::{MainThreadMarker, NSObjectProtocol};
-    use objc2_ui_kit::{UIApplication, UISceneActivationState, UIWindowScene};
+    use objc2_ui_kit::{
+        UIApplication, UIEdgeInsets, UISceneActivationState, UIView, UIWindowScene,
+    };
+    use raw_window_handle::{HasWindowHandle as _, RawWindowHandle};
+    use winit::window::Window;
 
-    /// Gets the ios safe area insets.
+    /// Gets the ios safe area insets of the given window, in `UIKit` points.
     ///
     /// A safe area defines the area within a view that isn’t covered by a navigation bar, tab bar,
     /// toolbar, or other views a window might provide. Safe areas are essential for avoiding a
     /// device’s interactive and display features, like Dynamic Island on iPhone or the camera
     /// housing on some Mac models.
     ///
+    /// The insets are read from the view of the window, so each viewport gets its own insets,
+    /// and they follow the notch around when the device is rotated to landscape.
+    /// If the view can't be found, the key window of the first foreground scene is used.
+    ///
     /// Once winit v0.31 has been released this can be removed in favor of
     /// `winit::Window::safe_area`.
-    pub fn get_safe_area_insets() -> SafeAreaInsets {
+    pub fn get_safe_area_insets(window: &Window) -> SafeAreaInsets {
         let Some(main_thread_marker) = MainThreadMarker::new() else {
             log::error!("Getting safe area insets needs to be performed on the main thread");
             return SafeAreaInsets::default();
         };
 
+        let view_insets = window
+            .window_handle()
+            .ok()
+            .and_then(|handle| match handle.as_raw() {
+                RawWindowHandle::UiKit(handle) => {
+                    #[expect(unsafe_code)]
+                    // SAFETY: winit gives us a valid `UIView`, which outlives the window handle.
+                    let view = unsafe { handle.ui_view.cast::<UIView>().as_ref() };
+                    Some(view.safeAreaInsets())
+                }
+                _ => None,
+            });
+
+        view_insets
+            .or_else(|| key_window_insets(main_thread_marker))
+            .map_or_else(SafeAreaInsets::default, |insets| {
+                SafeAreaInsets(MarginF32 {
+                    top: insets.top as f32,
+                    left: insets.left as f32,
+                    right: insets.right as f32,
+                    bottom: insets.bottom as f32,
+                })
+            })
+    }
+
+    fn key_window_insets(main_thread_marker: MainThreadMarker) -> Option<UIEdgeInsets> {
         let app = UIApplication::sharedApplication(main_thread_marker);
 
         #[expect(unsafe_code)]
@@ -39,18 +137,37 @@ mod ios {
                     // SAFETY: class kind was checked above with `isKindOfClass`
                     let window_scene = Retained::cast_unchecked::<UIWindowScene>(scene.clone());
                     if let Some(window) = window_scene.keyWindow() {
-                        let insets = window.safeAreaInsets();
-                        return SafeAreaInsets(MarginF32 {
-                            top: insets.top as f32,
-                            left: insets.left as f32,
-                            right: insets.right as f32,
-                            bottom: insets.bottom as f32,
-                        });
+                        return Some(window.safeAreaInsets());
                     }
                 }
             }
         }
 
-        SafeAreaInsets::default()
+        None
+    }
+}
+
+#[cfg(target_os = "android")]
+mod android {
+    use egui::epaint::MarginF32;
+    use winit::{platform::android::WindowExtAndroid as _, window::Window};
+
+    /// Gets the android window insets, in physical pixels.
+    ///
+    /// This is the part of the window that is covered by the status bar, the navigation bar,
+    /// display cutouts and the soft keyboard, computed from the content rect of the activity.
+    ///
+    /// winit doesn't forward `ContentRectChanged` or `InsetsChanged` events yet,
+    /// so this needs to be polled.
+    pub fn get_safe_area_insets(window: &Window) -> MarginF32 {
+        let size = window.inner_size();
+        let content = window.content_rect();
+
+        MarginF32 {
+            left: content.left.max(0) as f32,
+            top: content.top.max(0) as f32,
+            right: (size.width as i32 - content.right).max(0) as f32,
+            bottom: (size.height as i32 - content.bottom).max(0) as f32,
+        }
     }
 }
@@ -0,0 +1,180 @@
+//! Screenshots through the XDG desktop portal, see
+//! <https://flatpak.github.io/xdg-desktop-portal/docs/doc-org.freedesktop.portal.Screenshot.html>.
+//!
+//! On Wayland an app can't capture the screen by itself, so integrations that can't read back
+//! their own frame (like [`crate::MultiWindowApp`]) ask the desktop (GNOME, KDE, …) to take
+//! the screenshot for them.
+
+use std::{
+    collections::HashMap,
+    path::PathBuf,
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        mpsc,
+    },
+};
+
+use egui::{ColorImage, UserData, ViewportId};
+use zbus::zvariant::{OwnedValue, Value};
+
+const PORTAL_DESTINATION: &str = "org.freedesktop.portal.Desktop";
+const PORTAL_PATH: &str = "/org/freedesktop/portal/desktop";
+
+/// Screenshots requested from the portal, delivered as [`egui::Event::Screenshot`]s.
+pub(crate) struct PortalScreenshots {
+    sender: mpsc::Sender<egui::Event>,
+    receiver: mpsc::Receiver<egui::Event>,
+}
+
+impl Default for PortalScreenshots {
+    fn default() -> Self {
+        let (sender, receiver) = mpsc::channel();
+        Self { sender, receiver }
+    }
+}
+
+impl PortalScreenshots {
+    /// Ask the portal for a screenshot, on a background thread.
+    ///
+    /// The portal may show a dialog, so this can take a while.
+    /// Once the screenshot has arrived, a repaint of the viewport is requested.
+    pub fn request(&self, egui_ctx: &egui::Context, viewport_id: ViewportId, user_data: UserData) {
+        let sender = self.sender.clone();
+        let egui_ctx = egui_ctx.clone();
+        let result = std::thread::Builder::new()
+            .name("screenshot_portal".to_owned())
+            .spawn(move || match take_screenshot() {
+                Ok(image) => {
+                    let event = egui::Event::Screenshot {
+                        viewport_id,
+                        user_data,
+                        image: image.into(),
+                    };
+                    if sender.send(event).is_ok() {
+                        egui_ctx.request_repaint_of(viewport_id);
+                    }
+                }
+                Err(err) => {
+                    log::warn!("Failed to take a screenshot through the desktop portal: {err}");
+                }
+            });
+        if let Err(err) = result {
+            log::warn!("Failed to spawn the screenshot thread: {err}");
+        }
+    }
+
+    /// The screenshots that have arrived since last time.
+    pub fn arrived(&self) -> impl Iterator<Item = egui::Event> + '_ {
+        self.receiver.try_iter()
+    }
+}
+
+#[derive(Debug)]
+enum Error {
+    DBus(zbus::Error),
+    Canceled,
+    MissingUri,
+    Image(image::ImageError),
+}
+
+impl std::fmt::Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::DBus(err) => write!(f, "D-Bus error: {err}"),
+            Self::Canceled => write!(f, "the request was canceled"),
+            Self::MissingUri => write!(f, "the portal did not return a file"),
+            Self::Image(err) => write!(f, "failed to load the screenshot: {err}"),
+        }
+    }
+}
+
+impl From<zbus::Error> for Error {
+    fn from(err: zbus::Error) -> Self {
+        Self::DBus(err)
+    }
+}
+
+impl From<image::ImageError> for Error {
+    fn from(err: image::ImageError) -> Self {
+        Self::Image(err)
+    }
+}
+
+fn take_screenshot() -> Result<ColorImage, Error> {
+    profiling::function_scope!();
+
+    static NEXT_TOKEN: AtomicU64 = AtomicU64::new(0);
+    let token = format!("egui_{}", NEXT_TOKEN.fetch_add(1, Ordering::Relaxed));
+
+    let connection = zbus::blocking::Connection::session()?;
+
+    // The portal answers through a `Request` object with a well-known path,
+    // which we must subscribe to _before_ making the call, or we might miss the answer.
+    let sender = connection
+        .unique_name()
+        .map(|name| name.trim_start_matches(':').replace('.', "_"))
+        .unwrap_or_default();
+    let request_path = format!("{PORTAL_PATH}/request/{sender}/{token}");
+    let request = zbus::blocking::Proxy::new(
+        &connection,
+        PORTAL_DESTINATION,
+        request_path.as_str(),
+        "org.freedesktop.portal.Request",
+    )?;
+    let mut responses = request.receive_signal("Response")?;
+
+    let options = HashMap::from([
+        ("handle_token", Value::from(token.as_str())),
+        ("interactive", Value::from(false)),
+    ]);
+    connection.call_method(
+        Some(PORTAL_DESTINATION),
+        PORTAL_PATH,
+        Some("org.freedesktop.portal.Screenshot"),
+        "Screenshot",
+        &("", options),
+    )?;
+
+    let response = responses.next().ok_or(Error::Canceled)?;
+    let (code, results): (u32, HashMap<String, OwnedValue>) = response.body().deserialize()?;
+    if code != 0 {
+        return Err(Error::Canceled);
+    }
+
+    let path = results
+        .get("uri")
+        .and_then(|uri| <&str>::try_from(&**uri).ok())
+        .and_then(file_path_from_uri)
+        .ok_or(Error::MissingUri)?;
+
+    let image = image::ImageReader::open(&path)
+        .map_err(image::ImageError::IoError)?
+        .with_guessed_format()
+        .map_err(image::ImageError::IoError)?
+        .decode()?
+        .into_rgba8();
+    Ok(ColorImage::from_rgba_unmultiplied(
+        [image.width() as usize, image.height() as usize],
+        image.as_raw(),
+    ))
+}
+
+/// `file:///home/user/Screenshot%201.png` -> `/home/user/Screenshot 1.png`
+fn file_path_from_uri(uri: &str) -> Option<PathBuf> {
+    use std::os::unix::ffi::OsStringExt as _;
+
+    let encoded = uri.strip_prefix("file://")?.as_bytes();
+    let mut decoded = Vec::with_capacity(encoded.len());
+    let mut i = 0;
+    while i < encoded.len() {
+        if encoded[i] == b'%' {
+            let hex = std::str::from_utf8(encoded.get(i + 1..i + 3)?).ok()?;
+            decoded.push(u8::from_str_radix(hex, 16).ok()?);
+            i += 3;
+        } else {
+            decoded.push(encoded[i]);
+            i += 1;
+        }
+    }
+    Some(std::ffi::OsString::from_vec(decoded).into())
+}
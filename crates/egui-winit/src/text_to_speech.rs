@@ -0,0 +1,10 @@
+//! Describing the ui out loud, for platforms where AccessKit isn't supported yet.
+
+/// A text-to-speech engine, see [`crate::State::set_text_to_speech`].
+///
+/// This is a fallback for when there is no screen reader to talk to through AccessKit,
+/// and works like the `web_screen_reader` feature of eframe does on the web.
+pub trait TextToSpeech {
+    /// Speak the text out loud, interrupting whatever is currently being spoken.
+    fn speak(&mut self, text: &str);
+}
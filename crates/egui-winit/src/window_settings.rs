@@ -1,7 +1,10 @@
 use egui::ViewportBuilder;
 
 /// Can be used to store native window settings (position and size).
-#[derive(Clone, Copy, Debug, Default)]
+///
+/// The monitor the window was on is stored too, so that the window can be restored
+/// correctly if the monitor has changed scale factor, moved, or was disconnected.
+#[derive(Clone, Debug, Default)]
 #[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
 #[cfg_attr(feature = "serde", serde(default))]
 pub struct WindowSettings {
@@ -17,6 +20,61 @@ pub struct WindowSettings {
 
     /// Inner size of window in logical pixels
     inner_size_points: Option<egui::Vec2>,
+
+    /// The monitor the window was on.
+    monitor: Option<MonitorSettings>,
+}
+
+/// Identifies a monitor across sessions.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+struct MonitorSettings {
+    name: Option<String>,
+
+    /// Position of the monitor in physical pixels.
+    position_pixels: egui::Pos2,
+
+    /// Size of the monitor in physical pixels.
+    size_pixels: egui::Vec2,
+
+    scale_factor: f32,
+}
+
+impl MonitorSettings {
+    fn from_monitor(monitor: &winit::monitor::MonitorHandle) -> Self {
+        let rect = monitor_rect_px(monitor);
+        Self {
+            name: monitor.name(),
+            position_pixels: rect.min,
+            size_pixels: rect.size(),
+            scale_factor: monitor.scale_factor() as f32,
+        }
+    }
+
+    /// Find this monitor among the connected ones.
+    fn find(&self, monitors: &[winit::monitor::MonitorHandle]) -> Option<usize> {
+        let same_name = |monitor: &winit::monitor::MonitorHandle| {
+            self.name.is_some() && monitor.name() == self.name
+        };
+        let same_rect = |monitor: &winit::monitor::MonitorHandle| {
+            let rect = monitor_rect_px(monitor);
+            rect.min == self.position_pixels && rect.size() == self.size_pixels
+        };
+
+        // Several monitors can have the same name (e.g. the same model),
+        // and the position changes when the monitor layout changes:
+        monitors
+            .iter()
+            .position(|m| same_name(m) && m.position() == to_physical_pos(self.position_pixels))
+            .or_else(|| monitors.iter().position(same_name))
+            .or_else(|| monitors.iter().position(same_rect))
+    }
+
+    /// Move a position on this monitor to the same relative place on `new`.
+    fn transfer_pos(&self, new: &Self, pos_px: egui::Pos2) -> egui::Pos2 {
+        let offset_points = (pos_px - self.position_pixels) / self.scale_factor;
+        new.position_pixels + offset_points * new.scale_factor
+    }
 }
 
 impl WindowSettings {
@@ -46,6 +104,10 @@ impl WindowSettings {
                 inner_size_points.width,
                 inner_size_points.height,
             )),
+
+            monitor: window
+                .current_monitor()
+                .map(|monitor| MonitorSettings::from_monitor(&monitor)),
         }
     }
 
@@ -69,7 +131,9 @@ impl WindowSettings {
             self.outer_position_pixels
         };
         if let Some(pos) = pos_px {
-            let monitor_scale_factor = if let Some(inner_size_points) = self.inner_size_points {
+            let monitor_scale_factor = if let Some(monitor) = &self.monitor {
+                monitor.scale_factor
+            } else if let Some(inner_size_points) = self.inner_size_points {
                 find_active_monitor(egui_zoom_factor, event_loop, inner_size_points, &pos)
                     .map_or(1.0, |monitor| monitor.scale_factor() as f32)
             } else {
@@ -90,7 +154,7 @@ impl WindowSettings {
         viewport_builder
     }
 
-    pub fn initialize_window(&self, window: &winit::window::Window) {
+    pub fn initialize_window(&self, egui_zoom_factor: f32, window: &winit::window::Window) {
         if cfg!(target_os = "macos") {
             // Mac sometimes has problems restoring the window to secondary monitors
             // using only `WindowBuilder::with_position`, so we need this extra step:
@@ -98,6 +162,24 @@ impl WindowSettings {
                 window.set_outer_position(winit::dpi::PhysicalPosition { x: pos.x, y: pos.y });
             }
         }
+
+        // The window may have been sized using the scale factor of another monitor
+        // (e.g. the primary one), so make sure it has the right size on the monitor it ended up on:
+        if let Some(inner_size_points) = self.inner_size_points
+            && !self.fullscreen
+            && !self.maximized
+        {
+            let scale_factor = egui_zoom_factor * window.scale_factor() as f32;
+            let wanted_size_px = (inner_size_points * scale_factor).round();
+            let size_px = window.inner_size();
+            let size_px = egui::vec2(size_px.width as f32, size_px.height as f32);
+            if 1.0 < (wanted_size_px - size_px).abs().max_elem() {
+                let _ = window.request_inner_size(winit::dpi::PhysicalSize::new(
+                    wanted_size_px.x,
+                    wanted_size_px.y,
+                ));
+            }
+        }
     }
 
     pub fn clamp_size_to_sane_values(&mut self, largest_monitor_size_points: egui::Vec2) {
@@ -114,17 +196,25 @@ impl WindowSettings {
         }
     }
 
+    /// Make sure the window is restored on a connected monitor.
+    ///
+    /// If the monitor the window was on has moved or changed scale factor,
+    /// the window is moved along with it.
+    /// If the monitor was disconnected, the window is moved to the primary monitor.
     pub fn clamp_position_to_monitors(
         &mut self,
         egui_zoom_factor: f32,
         event_loop: &winit::event_loop::ActiveEventLoop,
     ) {
+        let monitor_disappeared = self.reconcile_monitor(event_loop);
+
         // If the app last ran on two monitors and only one is now connected, then
         // the given position is invalid.
         // If this happens on Mac, the window is clamped into valid area.
         // If this happens on Windows, the window becomes invisible to the user 🤦‍♂️
         // So on Windows we clamp the position to the monitor it is on.
-        if !cfg!(target_os = "windows") {
+        // We also clamp if we know the monitor is gone, since we moved the window ourselves then.
+        if !cfg!(target_os = "windows") && !monitor_disappeared {
             return;
         }
 
@@ -139,6 +229,52 @@ impl WindowSettings {
             clamp_pos_to_monitors(egui_zoom_factor, event_loop, inner_size_points, pos_px);
         }
     }
+
+    /// Move the window along with its monitor, if the monitor moved, changed scale factor,
+    /// or was disconnected.
+    ///
+    /// Returns `true` if the monitor was disconnected.
+    fn reconcile_monitor(&mut self, event_loop: &winit::event_loop::ActiveEventLoop) -> bool {
+        profiling::function_scope!();
+
+        let Some(saved) = self.monitor.clone() else {
+            return false; // From an older version
+        };
+
+        let monitors: Vec<_> = event_loop.available_monitors().collect();
+        let (new_monitor, disappeared) = match saved.find(&monitors) {
+            Some(index) => (monitors.get(index).cloned(), false),
+            None => (
+                event_loop
+                    .primary_monitor()
+                    .or_else(|| monitors.first().cloned()),
+                true,
+            ),
+        };
+        let Some(new_monitor) = new_monitor else {
+            return false; // no monitors 🤷
+        };
+
+        let new = MonitorSettings::from_monitor(&new_monitor);
+        if new != saved {
+            for pos in [
+                &mut self.inner_position_pixels,
+                &mut self.outer_position_pixels,
+            ]
+            .into_iter()
+            .flatten()
+            {
+                *pos = saved.transfer_pos(&new, *pos);
+            }
+            self.monitor = Some(new);
+        }
+
+        disappeared
+    }
+}
+
+fn to_physical_pos(pos: egui::Pos2) -> winit::dpi::PhysicalPosition<i32> {
+    winit::dpi::PhysicalPosition::new(pos.x.round() as i32, pos.y.round() as i32)
 }
 
 fn find_active_monitor(
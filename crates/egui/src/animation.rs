@@ -0,0 +1,340 @@
+//! Tweens and keyframe animations, played with [`crate::Context::tween`].
+//!
+//! For a single value that should follow a target, [`crate::Context::animate_value_with_time`]
+//! is enough. For anything more involved, describe the animation up front instead:
+//!
+//! * A [`Tween`] goes from one value to another with an easing curve, optionally after a delay.
+//! * [`Keyframes`] chain several such segments after each other.
+//! * A tuple of animations plays them in parallel, e.g. `(position_tween, color_keyframes)`.
+//!
+//! ```
+//! # egui::__run_test_ui(|ui| {
+//! use egui::animation::{Keyframes, Tween};
+//! use egui::emath::easing;
+//!
+//! let fade_in = Tween::new(0.0, 1.0).duration(0.3).easing(easing::cubic_out);
+//! let wiggle = Keyframes::new(0.0)
+//!     .then(10.0, 0.1, easing::quadratic_out)
+//!     .then(-10.0, 0.2, easing::quadratic_in_out)
+//!     .then(0.0, 0.1, easing::quadratic_in);
+//!
+//! let id = ui.id().with("intro");
+//! let response = ui.ctx().tween(id, &(fade_in, wiggle)).on_complete(|| {
+//!     // Called once, on the pass the animation finishes.
+//! });
+//! let (opacity, offset) = response.value;
+//! # });
+//! ```
+
+use emath::{Pos2, Rect, Vec2, easing};
+
+use crate::{Color32, Rgba};
+
+/// A value that can be interpolated by a [`Tween`].
+pub trait Tweenable: Copy + Send + Sync + 'static {
+    /// Interpolate from `from` (at `t = 0`) to `to` (at `t = 1`).
+    ///
+    /// `t` can go outside `[0, 1]` for easing curves that overshoot, like [`easing::back_out`].
+    fn interpolate(from: Self, to: Self, t: f32) -> Self;
+}
+
+impl Tweenable for f32 {
+    #[inline]
+    fn interpolate(from: Self, to: Self, t: f32) -> Self {
+        from + (to - from) * t
+    }
+}
+
+impl Tweenable for f64 {
+    #[inline]
+    fn interpolate(from: Self, to: Self, t: f32) -> Self {
+        from + (to - from) * t as Self
+    }
+}
+
+impl Tweenable for Vec2 {
+    #[inline]
+    fn interpolate(from: Self, to: Self, t: f32) -> Self {
+        from + (to - from) * t
+    }
+}
+
+impl Tweenable for Pos2 {
+    #[inline]
+    fn interpolate(from: Self, to: Self, t: f32) -> Self {
+        from.lerp(to, t)
+    }
+}
+
+impl Tweenable for Rect {
+    #[inline]
+    fn interpolate(from: Self, to: Self, t: f32) -> Self {
+        Self::from_min_max(from.min.lerp(to.min, t), from.max.lerp(to.max, t))
+    }
+}
+
+impl Tweenable for Color32 {
+    /// Interpolates in gamma space, like the rest of egui.
+    #[inline]
+    fn interpolate(from: Self, to: Self, t: f32) -> Self {
+        from.lerp_to_gamma(to, t.clamp(0.0, 1.0))
+    }
+}
+
+impl Tweenable for Rgba {
+    #[inline]
+    fn interpolate(from: Self, to: Self, t: f32) -> Self {
+        from + (to + from * -1.0) * t
+    }
+}
+
+/// Something that can be played by [`crate::Context::tween`].
+///
+/// Implemented for [`Tween`], [`Keyframes`], and tuples of animations (played in parallel).
+pub trait Animation {
+    type Value;
+
+    /// Total length of the animation in seconds, including any delay.
+    fn duration(&self) -> f32;
+
+    /// The value `elapsed` seconds after the animation started.
+    ///
+    /// Before the start this is the first value, and after [`Self::duration`] the last.
+    fn sample(&self, elapsed: f32) -> Self::Value;
+}
+
+/// Go from one value to another over some time, with an easing curve.
+///
+/// See the [module docs](self) for an example.
+#[derive(Clone, Copy, Debug)]
+pub struct Tween<T> {
+    from: T,
+    to: T,
+    duration: f32,
+    delay: f32,
+    easing: fn(f32) -> f32,
+}
+
+impl<T: Tweenable> Tween<T> {
+    /// Tween from `from` to `to` in `0.2` seconds, with [`easing::cubic_in_out`].
+    pub fn new(from: T, to: T) -> Self {
+        Self {
+            from,
+            to,
+            duration: 0.2,
+            delay: 0.0,
+            easing: easing::cubic_in_out,
+        }
+    }
+
+    /// How long the tween takes, in seconds.
+    #[inline]
+    pub fn duration(mut self, duration: f32) -> Self {
+        self.duration = duration.max(0.0);
+        self
+    }
+
+    /// Wait this many seconds before starting, staying at the `from` value.
+    ///
+    /// Use this to stagger tweens that play in parallel.
+    #[inline]
+    pub fn delay(mut self, delay: f32) -> Self {
+        self.delay = delay.max(0.0);
+        self
+    }
+
+    /// The easing curve, e.g. one of the functions in [`emath::easing`].
+    #[inline]
+    pub fn easing(mut self, easing: fn(f32) -> f32) -> Self {
+        self.easing = easing;
+        self
+    }
+
+    /// Continue with more segments after this one.
+    pub fn then(self, to: T, duration: f32, easing: fn(f32) -> f32) -> Keyframes<T> {
+        Keyframes::from(self).then(to, duration, easing)
+    }
+}
+
+impl<T: Tweenable> Animation for Tween<T> {
+    type Value = T;
+
+    fn duration(&self) -> f32 {
+        self.delay + self.duration
+    }
+
+    fn sample(&self, elapsed: f32) -> T {
+        let t = if self.duration <= 0.0 {
+            if elapsed < self.delay { 0.0 } else { 1.0 }
+        } else {
+            ((elapsed - self.delay) / self.duration).clamp(0.0, 1.0)
+        };
+        T::interpolate(self.from, self.to, (self.easing)(t))
+    }
+}
+
+/// A chain of tweens, each starting where the previous one ended.
+///
+/// See the [module docs](self) for an example.
+#[derive(Clone, Debug)]
+pub struct Keyframes<T> {
+    start: T,
+    segments: Vec<Tween<T>>,
+}
+
+impl<T: Tweenable> Keyframes<T> {
+    /// Start at this value.
+    pub fn new(start: T) -> Self {
+        Self {
+            start,
+            segments: Vec::new(),
+        }
+    }
+
+    /// Go from the current end value to `to` in `duration` seconds.
+    pub fn then(mut self, to: T, duration: f32, easing: fn(f32) -> f32) -> Self {
+        let from = self.end_value();
+        self.segments
+            .push(Tween::new(from, to).duration(duration).easing(easing));
+        self
+    }
+
+    /// Stay at the current end value for `duration` seconds.
+    pub fn hold(self, duration: f32) -> Self {
+        let value = self.end_value();
+        self.then(value, duration, easing::linear)
+    }
+
+    /// The value the animation ends at.
+    pub fn end_value(&self) -> T {
+        self.segments
+            .last()
+            .map_or(self.start, |segment| segment.to)
+    }
+}
+
+impl<T: Tweenable> From<Tween<T>> for Keyframes<T> {
+    fn from(tween: Tween<T>) -> Self {
+        Self {
+            start: tween.from,
+            segments: vec![tween],
+        }
+    }
+}
+
+impl<T: Tweenable> Animation for Keyframes<T> {
+    type Value = T;
+
+    fn duration(&self) -> f32 {
+        self.segments.iter().map(Animation::duration).sum()
+    }
+
+    fn sample(&self, mut elapsed: f32) -> T {
+        for segment in &self.segments {
+            let duration = segment.duration();
+            if elapsed < duration {
+                return segment.sample(elapsed);
+            }
+            elapsed -= duration;
+        }
+        self.end_value()
+    }
+}
+
+impl<A: Animation + ?Sized> Animation for &A {
+    type Value = A::Value;
+
+    fn duration(&self) -> f32 {
+        (**self).duration()
+    }
+
+    fn sample(&self, elapsed: f32) -> Self::Value {
+        (**self).sample(elapsed)
+    }
+}
+
+macro_rules! impl_parallel_animation {
+    ($($name:ident : $index:tt),+) => {
+        /// Plays all animations at the same time, finishing when the longest one does.
+        impl<$($name: Animation),+> Animation for ($($name,)+) {
+            type Value = ($($name::Value,)+);
+
+            fn duration(&self) -> f32 {
+                0.0_f32 $(.max(self.$index.duration()))+
+            }
+
+            fn sample(&self, elapsed: f32) -> Self::Value {
+                ($(self.$index.sample(elapsed),)+)
+            }
+        }
+    };
+}
+
+impl_parallel_animation!(A: 0, B: 1);
+impl_parallel_animation!(A: 0, B: 1, C: 2);
+impl_parallel_animation!(A: 0, B: 1, C: 2, D: 3);
+
+/// The result of playing an animation with [`crate::Context::tween`].
+#[must_use = "You should use the animated value"]
+#[derive(Clone, Copy, Debug)]
+pub struct TweenResponse<T> {
+    /// The current value of the animation.
+    pub value: T,
+
+    /// Seconds since the animation started.
+    pub elapsed: f32,
+
+    /// Has the animation reached its end?
+    pub finished: bool,
+
+    /// Did the animation reach its end this pass?
+    pub just_finished: bool,
+}
+
+impl<T> TweenResponse<T> {
+    /// Call `on_complete` once, on the pass the animation finishes.
+    #[inline]
+    pub fn on_complete(self, on_complete: impl FnOnce()) -> Self {
+        if self.just_finished {
+            on_complete();
+        }
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn keyframes_chain_segments() {
+        let keyframes = Keyframes::new(0.0_f32)
+            .then(10.0, 1.0, easing::linear)
+            .hold(0.5)
+            .then(0.0, 1.0, easing::linear);
+
+        assert_eq!(keyframes.duration(), 2.5);
+        assert_eq!(keyframes.sample(-1.0), 0.0);
+        assert_eq!(keyframes.sample(0.5), 5.0);
+        assert_eq!(keyframes.sample(1.25), 10.0);
+        assert_eq!(keyframes.sample(2.0), 5.0);
+        assert_eq!(keyframes.sample(10.0), 0.0);
+    }
+
+    #[test]
+    fn parallel_animations_finish_with_the_longest() {
+        let animation = (
+            Tween::new(0.0_f32, 1.0)
+                .duration(1.0)
+                .easing(easing::linear),
+            Tween::new(Vec2::ZERO, Vec2::splat(2.0))
+                .delay(1.0)
+                .duration(1.0)
+                .easing(easing::linear),
+        );
+
+        assert_eq!(animation.duration(), 2.0);
+        assert_eq!(animation.sample(0.5), (0.5, Vec2::ZERO));
+        assert_eq!(animation.sample(1.5), (1.0, Vec2::splat(1.0)));
+    }
+}
@@ -7,6 +7,7 @@ use crate::{
 pub(crate) struct AnimationManager {
     bools: IdMap<BoolAnim>,
     values: IdMap<ValueAnim>,
+    tweens: IdMap<TweenAnim>,
 }
 
 #[derive(Clone, Debug)]
@@ -25,6 +26,14 @@ struct ValueAnim {
     toggle_time: f64,
 }
 
+#[derive(Clone, Debug)]
+struct TweenAnim {
+    start_time: f64,
+
+    /// Have we reported the tween as finished?
+    finished: bool,
+}
+
 impl AnimationManager {
     /// See [`crate::Context::animate_bool`] for documentation
     pub fn animate_bool(
@@ -107,4 +116,23 @@ impl AnimationManager {
             }
         }
     }
+
+    /// See [`crate::Context::tween`] for documentation.
+    ///
+    /// Returns `(elapsed, finished, just_finished)`.
+    pub fn tween(&mut self, input: &InputState, id: Id, duration: f32) -> (f32, bool, bool) {
+        let anim = self.tweens.entry(id).or_insert(TweenAnim {
+            start_time: input.time,
+            finished: false,
+        });
+        let elapsed = (input.time - anim.start_time) as f32;
+        let finished = duration <= elapsed;
+        let just_finished = finished && !anim.finished;
+        anim.finished = finished;
+        (elapsed, finished, just_finished)
+    }
+
+    pub fn restart_tween(&mut self, id: Id) {
+        self.tweens.remove(&id);
+    }
 }
@@ -6,7 +6,8 @@ use emath::GuiRounding as _;
 
 use crate::{
     Align2, Context, Id, InnerResponse, LayerId, Layout, NumExt as _, Order, Pos2, Rect, Response,
-    Sense, Ui, UiBuilder, UiKind, UiStackInfo, Vec2, WidgetRect, WidgetWithState, emath, pos2,
+    Sense, Transition, Ui, UiBuilder, UiKind, UiStackInfo, Vec2, WidgetRect, WidgetWithState,
+    emath, pos2,
 };
 
 /// State of an [`Area`] that is persisted between frames.
@@ -119,7 +120,7 @@ pub struct Area {
     pivot: Align2,
     anchor: Option<(Align2, Vec2)>,
     new_pos: Option<Pos2>,
-    fade_in: bool,
+    transition: Option<Transition>,
     layout: Layout,
     sizing_pass: bool,
 }
@@ -146,7 +147,7 @@ impl Area {
             new_pos: None,
             pivot: Align2::LEFT_TOP,
             anchor: None,
-            fade_in: true,
+            transition: None,
             layout: Layout::default(),
             sizing_pass: false,
         }
@@ -346,10 +347,23 @@ impl Area {
 
     /// If `true`, quickly fade in the area.
     ///
+    /// `false` is the same as `.transition(Transition::NONE)`,
+    /// and `true` goes back to the default transition from the [`crate::Style`].
+    ///
     /// Default: `true`.
     #[inline]
     pub fn fade_in(mut self, fade_in: bool) -> Self {
-        self.fade_in = fade_in;
+        self.transition = (!fade_in).then_some(Transition::NONE);
+        self
+    }
+
+    /// How the area appears when it becomes visible.
+    ///
+    /// Defaults to [`crate::Style::window_transition`] for windows
+    /// and [`crate::Style::popup_transition`] for everything else.
+    #[inline]
+    pub fn transition(mut self, transition: Transition) -> Self {
+        self.transition = Some(transition);
         self
     }
 
@@ -398,7 +412,11 @@ pub(crate) struct Prepared {
     /// without having one frame where the window is wrongly positioned or sized.
     sizing_pass: bool,
 
-    fade_in: bool,
+    transition: Transition,
+
+    /// How far along the enter transition is (1 = done), set by [`Self::content_ui`].
+    visible: f32,
+
     layout: Layout,
 }
 
@@ -431,11 +449,20 @@ impl Area {
             anchor,
             constrain,
             constrain_rect,
-            fade_in,
+            transition,
             layout,
             sizing_pass: force_sizing_pass,
         } = self;
 
+        let transition = transition.unwrap_or_else(|| {
+            let style = ctx.global_style();
+            if info.kind == Some(UiKind::Window) {
+                style.window_transition
+            } else {
+                style.popup_transition
+            }
+        });
+
         let constrain_rect = constrain_rect.unwrap_or_else(|| ctx.content_rect());
 
         let layer_id = LayerId::new(order, id);
@@ -577,7 +604,8 @@ impl Area {
             constrain,
             constrain_rect,
             sizing_pass,
-            fade_in,
+            transition,
+            visible: 1.0,
             layout,
         }
     }
@@ -627,16 +655,17 @@ impl Prepared {
         let mut ui = Ui::new(ctx.clone(), self.layer_id.id, ui_builder);
         ui.set_clip_rect(self.constrain_rect); // Don't paint outside our bounds
 
-        if self.fade_in
+        if self.transition != Transition::NONE
             && let Some(last_became_visible_at) = self.state.last_became_visible_at
         {
             let age =
                 ctx.input(|i| (i.time - last_became_visible_at) as f32 + i.predicted_dt / 2.0);
-            let opacity =
+            let visible =
                 crate::remap_clamp(age, 0.0..=ctx.global_style().animation_time, 0.0..=1.0);
-            let opacity = emath::easing::quadratic_out(opacity); // slow fade-out = quick fade-in
-            ui.multiply_opacity(opacity);
-            if opacity < 1.0 {
+            let visible = emath::easing::quadratic_out(visible); // slow fade-out = quick fade-in
+            ui.multiply_opacity(self.transition.opacity(visible));
+            self.visible = visible;
+            if visible < 1.0 {
                 ctx.request_repaint();
             }
         }
@@ -660,6 +689,8 @@ impl Prepared {
             mut state,
             move_response: mut response,
             sizing_pass,
+            transition,
+            visible,
             ..
         } = self;
 
@@ -679,6 +710,14 @@ impl Prepared {
 
         ctx.memory_mut(|m| m.areas_mut().set_state(layer_id, state));
 
+        if visible < 1.0 {
+            let reduce_motion = ctx.global_style().reduce_motion;
+            ctx.transform_layer_shapes(
+                layer_id,
+                transition.transform(visible, final_rect, reduce_motion),
+            );
+        }
+
         if sizing_pass {
             // If we didn't know the size, we were likely drawing the area in the wrong place.
             ctx.request_repaint();
@@ -1,7 +1,7 @@
 use crate::{
     AsIdSalt, Context, Id, IdSalt, InnerResponse, NumExt as _, Rect, Response, Sense, Stroke,
-    TextStyle, TextWrapMode, Ui, UiBuilder, UiKind, UiStackInfo, WidgetInfo, WidgetText,
-    WidgetType, emath, epaint, pos2, remap, remap_clamp, vec2,
+    TextStyle, TextWrapMode, Transition, Ui, UiBuilder, UiKind, UiStackInfo, WidgetInfo,
+    WidgetText, WidgetType, emath, epaint, pos2, remap, remap_clamp, vec2,
 };
 use emath::GuiRounding as _;
 use epaint::{Shape, StrokeKind};
@@ -25,13 +25,17 @@ pub(crate) struct InnerState {
 pub struct CollapsingState {
     id: Id,
     state: InnerState,
+    transition: Option<Transition>,
 }
 
 impl CollapsingState {
     pub fn load(ctx: &Context, id: Id) -> Option<Self> {
         ctx.data_mut(|d| {
-            d.get_persisted::<InnerState>(id)
-                .map(|state| Self { id, state })
+            d.get_persisted::<InnerState>(id).map(|state| Self {
+                id,
+                state,
+                transition: None,
+            })
         })
     }
 
@@ -54,9 +58,19 @@ impl CollapsingState {
                 open: default_open,
                 open_height: None,
             },
+            transition: None,
         })
     }
 
+    /// How the body appears and disappears while opening and closing.
+    ///
+    /// Default: [`crate::Style::collapsing_transition`].
+    #[inline]
+    pub fn with_transition(mut self, transition: Transition) -> Self {
+        self.transition = Some(transition);
+        self
+    }
+
     pub fn is_open(&self) -> bool {
         self.state.open
     }
@@ -71,9 +85,13 @@ impl CollapsingState {
     }
 
     /// 0 for closed, 1 for open, with tweening
+    ///
+    /// Jumps straight to 0 or 1 with [`crate::Style::reduce_motion`].
     pub fn openness(&self, ctx: &Context) -> f32 {
         if ctx.memory(|mem| mem.everything_is_visible()) {
             1.0
+        } else if ctx.global_style().reduce_motion {
+            if self.state.open { 1.0 } else { 0.0 }
         } else {
             ctx.animate_bool_responsive(self.id, self.state.open)
         }
@@ -213,8 +231,32 @@ impl CollapsingState {
                     child_ui.set_clip_rect(clip_rect);
                 }
 
+                let transition = self
+                    .transition
+                    .unwrap_or(child_ui.style().collapsing_transition);
+                child_ui.multiply_opacity(transition.opacity(openness));
+                let layer_id = child_ui.layer_id();
+                let start_idx = child_ui.ctx().graphics(|g| {
+                    g.get(layer_id)
+                        .map_or(crate::layers::ShapeIdx(0), |l| l.next_idx())
+                });
+
                 let ret = add_body(child_ui);
 
+                if transition.has_motion() {
+                    let body_rect = Rect::from_min_size(
+                        child_ui.min_rect().min,
+                        vec2(child_ui.min_rect().width(), full_height),
+                    );
+                    let transform =
+                        transition.transform(openness, body_rect, child_ui.style().reduce_motion);
+                    child_ui.ctx().graphics_mut(|g| {
+                        let list = g.entry(layer_id);
+                        let end_idx = list.next_idx();
+                        list.transform_range(start_idx, end_idx, transform);
+                    });
+                }
+
                 let mut min_rect = child_ui.min_rect();
                 self.state.open_height = Some(min_rect.height());
                 if child_ui.should_close() {
@@ -384,6 +426,7 @@ pub struct CollapsingHeader {
     selected: bool,
     show_background: bool,
     icon: Option<IconPainter>,
+    transition: Option<Transition>,
 }
 
 impl CollapsingHeader {
@@ -406,6 +449,7 @@ impl CollapsingHeader {
             selected: false,
             show_background: false,
             icon: None,
+            transition: None,
         }
     }
 
@@ -459,6 +503,15 @@ impl CollapsingHeader {
         self
     }
 
+    /// How the body appears and disappears while opening and closing.
+    ///
+    /// Default: [`crate::Style::collapsing_transition`].
+    #[inline]
+    pub fn transition(mut self, transition: Transition) -> Self {
+        self.transition = Some(transition);
+        self
+    }
+
     /// Use the provided function to render a different [`CollapsingHeader`] icon.
     /// Defaults to a triangle that animates as the [`CollapsingHeader`] opens and closes.
     ///
@@ -505,6 +558,7 @@ impl CollapsingHeader {
             selectable,
             selected,
             show_background,
+            transition,
         } = self;
 
         // TODO(emilk): horizontal layout, with icon and text as labels. Insert background behind using Frame.
@@ -539,6 +593,9 @@ impl CollapsingHeader {
         );
 
         let mut state = CollapsingState::load_with_default_open(ui.ctx(), id, default_open);
+        if let Some(transition) = transition {
+            state = state.with_transition(transition);
+        }
         if let Some(open) = open {
             if open != state.is_open() {
                 state.toggle(ui);
@@ -7,15 +7,28 @@
 //! - [`Response::context_menu`]
 //!
 //! See [`MenuBar`] for an example.
+//!
+//! ## Keyboard
+//! Menu entries created with [`MenuItem`], [`MenuButton::with_mnemonic`]
+//! and [`SubMenuButton::with_mnemonic`] can have a _mnemonic_:
+//! a letter marked with `&` in their text (e.g. `"&File"`), which is shown underlined.
+//! * In a [`MenuBar`], <kbd>Alt</kbd> + the mnemonic opens the menu,
+//!   and pressing and releasing <kbd>Alt</kbd> on its own focuses the first menu.
+//! * In an open menu, pressing the mnemonic activates the entry.
+//!   Typing anything else selects the first entry that starts with the typed text.
 
 use crate::style::StyleModifier;
 use crate::{
-    Button, Color32, Context, Frame, Id, InnerResponse, IntoAtoms, Layout, PointerButton, Popup,
-    PopupCloseBehavior, Response, Style, Ui, UiBuilder, UiKind, UiStack, UiStackInfo, Widget as _,
+    Button, Color32, Context, Event, Frame, Id, InnerResponse, IntoAtoms, Key, KeyboardShortcut,
+    Layout, Modifiers, PointerButton, Popup, PopupCloseBehavior, Response, RichText, Style, Ui,
+    UiBuilder, UiKind, UiStack, UiStackInfo, Widget, WidgetText, response::Flags, text::LayoutJob,
 };
 use emath::{Align, RectAlign, Vec2, vec2};
 use epaint::Stroke;
 
+/// Typed text older than this many seconds is forgotten by the type-ahead selection.
+const TYPE_AHEAD_TIMEOUT: f64 = 1.0;
+
 /// Apply a menu style to the [`Style`].
 ///
 /// Mainly removes the background stroke and the inactive background fill.
@@ -137,6 +150,47 @@ pub struct MenuState {
     /// The currently open sub menu in this menu.
     pub open_item: Option<Id>,
     last_visible_pass: u64,
+
+    /// The entries that can be selected with the keyboard, shown this pass.
+    items: Vec<KeyboardItem>,
+
+    /// The entries of the previous pass.
+    ///
+    /// Keyboard input is handled by the first entry of a pass, before the others are shown,
+    /// so it is matched against these.
+    prev_items: Vec<KeyboardItem>,
+
+    /// The pass [`Self::items`] are from.
+    items_pass: u64,
+
+    /// Text typed for type-ahead selection.
+    type_ahead: String,
+
+    /// When [`Self::type_ahead`] was last typed.
+    type_ahead_time: f64,
+
+    /// Entry to activate this pass, because its mnemonic was pressed.
+    activate: Option<Id>,
+
+    /// Entry to focus this pass, because of type-ahead selection.
+    focus: Option<Id>,
+
+    /// Was alt held down in the previous pass?
+    alt_down: bool,
+
+    /// Has alt been held down without pressing anything else?
+    alt_alone: bool,
+}
+
+#[derive(Clone)]
+struct KeyboardItem {
+    id: Id,
+
+    /// The text of the entry, without the mnemonic marker.
+    label: String,
+
+    /// Lowercase.
+    mnemonic: Option<char>,
 }
 
 impl MenuState {
@@ -156,6 +210,15 @@ impl MenuState {
             let mut state = data.get_temp(state_id).unwrap_or(Self {
                 open_item: None,
                 last_visible_pass: pass_nr,
+                items: Vec::new(),
+                prev_items: Vec::new(),
+                items_pass: pass_nr,
+                type_ahead: String::new(),
+                type_ahead_time: f64::NEG_INFINITY,
+                activate: None,
+                focus: None,
+                alt_down: false,
+                alt_alone: false,
             });
             // If the menu was closed for at least a frame, reset the open item
             if state.last_visible_pass + 1 < pass_nr {
@@ -193,6 +256,146 @@ impl MenuState {
             Self::from_id(ctx, submenu_id, |state| state.last_visible_pass != pass_nr)
         })
     }
+
+    /// Start a new pass of keyboard-selectable entries, if needed.
+    fn begin_items_pass(&mut self, pass_nr: u64) -> bool {
+        if self.items_pass == pass_nr {
+            return false;
+        }
+        self.prev_items = std::mem::take(&mut self.items);
+        self.items_pass = pass_nr;
+        self.activate = None;
+        self.focus = None;
+        true
+    }
+
+    /// Handle text typed in an open menu: activate a mnemonic, or select by type-ahead.
+    fn on_typed_text(&mut self, typed: &str, time: f64) {
+        for c in typed.chars().flat_map(char::to_lowercase) {
+            if let Some(item) = self.prev_items.iter().find(|item| item.mnemonic == Some(c)) {
+                self.activate = Some(item.id);
+                self.type_ahead.clear();
+                return;
+            }
+        }
+
+        if TYPE_AHEAD_TIMEOUT < time - self.type_ahead_time {
+            self.type_ahead.clear();
+        }
+        if self.type_ahead.is_empty() && typed.trim().is_empty() {
+            return; // Space is for clicking the focused entry
+        }
+        self.type_ahead
+            .extend(typed.chars().flat_map(char::to_lowercase));
+        self.type_ahead_time = time;
+
+        let type_ahead = &self.type_ahead;
+        self.focus = self
+            .prev_items
+            .iter()
+            .find(|item| item.label.to_lowercase().starts_with(type_ahead.as_str()))
+            .map(|item| item.id);
+    }
+}
+
+/// Split off the mnemonic marker from a menu entry text.
+///
+/// `"&File"` becomes `("File", Some(0))`, where `0` is the byte index of the mnemonic.
+/// `"&&"` is a literal `&`.
+fn parse_mnemonic(text: &str) -> (String, Option<usize>) {
+    let mut label = String::with_capacity(text.len());
+    let mut mnemonic = None;
+    let mut chars = text.chars().peekable();
+    while let Some(c) = chars.next() {
+        if c == '&' {
+            match chars.peek() {
+                Some('&') => {
+                    chars.next();
+                }
+                Some(next) if mnemonic.is_none() && next.is_alphanumeric() => {
+                    mnemonic = Some(label.len());
+                    continue;
+                }
+                _ => {}
+            }
+        }
+        label.push(c);
+    }
+    (label, mnemonic)
+}
+
+/// The text of a menu entry, with the mnemonic underlined if `underline` is set.
+fn mnemonic_label(ui: &Ui, label: &str, mnemonic: Option<usize>, underline: bool) -> WidgetText {
+    let Some(index) = mnemonic.filter(|_| underline) else {
+        return label.into();
+    };
+    let (before, rest) = label.split_at(index);
+    let char_len = rest.chars().next().map_or(0, char::len_utf8);
+    let (letter, after) = rest.split_at(char_len);
+
+    let mut job = LayoutJob::default();
+    for (text, underline) in [(before, false), (letter, true), (after, false)] {
+        let mut text = RichText::new(text);
+        if underline {
+            text = text.underline();
+        }
+        text.append_to(
+            &mut job,
+            ui.style(),
+            crate::FontSelection::Default,
+            Align::Center,
+        );
+    }
+    job.into()
+}
+
+/// Register a keyboard-selectable entry with the menu it is in.
+///
+/// Returns `true` if the entry should be activated, because its mnemonic was pressed.
+fn register_keyboard_item(ui: &Ui, id: Id, label: &str, mnemonic: Option<usize>) -> bool {
+    let ctx = ui.ctx();
+    let pass_nr = ctx.cumulative_pass_nr();
+    let (time, typed, plain) = ui.input(|i| {
+        let typed: String = i
+            .events
+            .iter()
+            .filter_map(|event| match event {
+                Event::Text(text) => Some(text.as_str()),
+                _ => None,
+            })
+            .collect();
+        // Alt and command are for menu bar mnemonics and shortcuts:
+        let plain = !i.modifiers.alt && !i.modifiers.command;
+        (i.time, typed, plain)
+    });
+    let listen = plain && is_in_menu(ui) && !typed.is_empty() && !ctx.text_edit_focused();
+
+    let item = KeyboardItem {
+        id,
+        label: label.to_owned(),
+        mnemonic: mnemonic
+            .and_then(|index| label[index..].chars().next())
+            .and_then(|c| c.to_lowercase().next()),
+    };
+
+    let (activate, focus) = MenuState::from_ui(ui, |state, _| {
+        if state.begin_items_pass(pass_nr) && listen && state.open_item.is_none() {
+            state.on_typed_text(&typed, time);
+        }
+        state.items.push(item);
+        (state.activate == Some(id), state.focus == Some(id))
+    });
+
+    if focus {
+        ctx.memory_mut(|mem| mem.request_focus(id));
+    }
+    activate
+}
+
+/// Should mnemonics be underlined in this [`Ui`]?
+fn show_mnemonics(ui: &Ui) -> bool {
+    // Always in open menus, but in menu bars only while alt is held down:
+    is_in_menu(ui) || ui.input(|i| i.modifiers.alt)
 }
 
 /// Horizontal menu bar where you can add [`MenuButton`]s.
@@ -274,6 +477,8 @@ impl MenuBar {
                     let height = ui.spacing().interact_size.y;
                     ui.set_min_size(vec2(ui.available_width(), height));
 
+                    Self::handle_alt(ui);
+
                     content(ui)
                 },
             )
@@ -282,6 +487,39 @@ impl MenuBar {
     }
 }
 
+impl MenuBar {
+    /// Pressing and releasing alt on its own focuses the first menu in the bar.
+    fn handle_alt(ui: &Ui) {
+        let pass_nr = ui.ctx().cumulative_pass_nr();
+        let (alt, pressed_something) = ui.input(|i| {
+            let pressed_something = i.pointer.any_pressed()
+                || i.events
+                    .iter()
+                    .any(|event| matches!(event, Event::Key { pressed: true, .. }));
+            (i.modifiers.alt, pressed_something)
+        });
+
+        let focus = MenuState::from_ui(ui, |state, _| {
+            state.begin_items_pass(pass_nr);
+            let released_alone = state.alt_down && !alt && state.alt_alone;
+            if alt && !state.alt_down {
+                state.alt_alone = true;
+            }
+            if pressed_something {
+                state.alt_alone = false;
+            }
+            state.alt_down = alt;
+            released_alone
+                .then(|| state.prev_items.first().map(|item| item.id))
+                .flatten()
+        });
+
+        if let Some(id) = focus {
+            ui.memory_mut(|mem| mem.request_focus(id));
+        }
+    }
+}
+
 /// A thin wrapper around a [`Button`] that shows a [`Popup::menu`] when clicked.
 ///
 /// The only thing this does is search for the current menu config (if set via [`MenuBar`]).
@@ -290,6 +528,9 @@ impl MenuBar {
 pub struct MenuButton<'a> {
     pub button: Button<'a>,
     pub config: Option<MenuConfig>,
+
+    /// The text with the `&` mnemonic marker, see [`Self::with_mnemonic`].
+    mnemonic_text: Option<String>,
 }
 
 impl<'a> MenuButton<'a> {
@@ -297,6 +538,16 @@ impl<'a> MenuButton<'a> {
         Self::from_button(Button::new(atoms.into_atoms()))
     }
 
+    /// A menu button with a mnemonic, e.g. `"&File"`.
+    ///
+    /// In a [`MenuBar`], <kbd>Alt</kbd> + the mnemonic opens the menu.
+    /// See the [module docs](crate::containers::menu) for more.
+    pub fn with_mnemonic(text: impl Into<String>) -> Self {
+        let mut button = Self::new(());
+        button.mnemonic_text = Some(text.into());
+        button
+    }
+
     /// Set the config for the menu.
     #[inline]
     pub fn config(mut self, config: MenuConfig) -> Self {
@@ -310,6 +561,7 @@ impl<'a> MenuButton<'a> {
         Self {
             button,
             config: None,
+            mnemonic_text: None,
         }
     }
 
@@ -319,8 +571,32 @@ impl<'a> MenuButton<'a> {
         ui: &mut Ui,
         content: impl FnOnce(&mut Ui) -> R,
     ) -> (Response, Option<InnerResponse<R>>) {
-        let response = self.button.ui(ui);
-        let mut config = self.config.unwrap_or_else(|| MenuConfig::find(ui));
+        let Self {
+            button,
+            config,
+            mnemonic_text,
+        } = self;
+
+        let response = if let Some(mnemonic_text) = mnemonic_text {
+            let (label, mnemonic) = parse_mnemonic(&mnemonic_text);
+            let text = mnemonic_label(ui, &label, mnemonic, show_mnemonics(ui));
+            let response = Button::new(text).ui(ui);
+            register_keyboard_item(ui, response.id, &label, mnemonic);
+
+            let mnemonic_key = mnemonic
+                .and_then(|index| label[index..].chars().next())
+                .and_then(|c| Key::from_name(c.encode_utf8(&mut [0; 4])));
+            if let Some(key) = mnemonic_key
+                && ui.input_mut(|i| i.consume_key(Modifiers::ALT, key))
+            {
+                Popup::open_id(ui.ctx(), Popup::default_response_id(&response));
+            }
+            response
+        } else {
+            button.ui(ui)
+        };
+
+        let mut config = config.unwrap_or_else(|| MenuConfig::find(ui));
         config.bar = false;
         let inner = Popup::menu(&response)
             .close_behavior(config.close_behavior)
@@ -333,10 +609,78 @@ impl<'a> MenuButton<'a> {
     }
 }
 
+/// A menu entry with an optional mnemonic and keyboard shortcut.
+///
+/// The mnemonic is marked with `&` in the text and shown underlined.
+/// Pressing it while the menu is open activates the entry, as if it was clicked.
+/// The shortcut is shown in a right-aligned column, but you need to handle it yourself,
+/// e.g. with [`crate::InputState::consume_shortcut`].
+///
+/// ```
+/// # egui::__run_test_ui(|ui| {
+/// use egui::{Key, KeyboardShortcut, Modifiers, containers::menu::MenuItem};
+///
+/// const SAVE: KeyboardShortcut = KeyboardShortcut::new(Modifiers::COMMAND, Key::S);
+///
+/// if ui.add(MenuItem::new("&Save").shortcut(SAVE)).clicked()
+///     || ui.input_mut(|i| i.consume_shortcut(&SAVE))
+/// {
+///     // Save
+/// }
+/// # });
+/// ```
+#[must_use = "You should put this widget in a ui with `ui.add(widget);`"]
+pub struct MenuItem {
+    text: String,
+    shortcut: Option<KeyboardShortcut>,
+}
+
+impl MenuItem {
+    /// The text may contain a mnemonic marked with `&`, e.g. `"&Open…"`. Use `&&` for a literal `&`.
+    pub fn new(text: impl Into<String>) -> Self {
+        Self {
+            text: text.into(),
+            shortcut: None,
+        }
+    }
+
+    /// Show this keyboard shortcut in the right column of the menu.
+    #[inline]
+    pub fn shortcut(mut self, shortcut: KeyboardShortcut) -> Self {
+        self.shortcut = Some(shortcut);
+        self
+    }
+}
+
+impl Widget for MenuItem {
+    fn ui(self, ui: &mut Ui) -> Response {
+        let Self { text, shortcut } = self;
+
+        let (label, mnemonic) = parse_mnemonic(&text);
+        let mut button = Button::new(mnemonic_label(ui, &label, mnemonic, show_mnemonics(ui)));
+        if let Some(shortcut) = shortcut {
+            button = button.shortcut_text(ui.ctx().format_shortcut(&shortcut));
+        }
+        let mut response = button.ui(ui);
+
+        if register_keyboard_item(ui, response.id, &label, mnemonic) && ui.is_enabled() {
+            response.flags.set(Flags::FAKE_PRIMARY_CLICKED, true);
+            if MenuConfig::find(ui).close_behavior == PopupCloseBehavior::CloseOnClick {
+                ui.close();
+            }
+        }
+
+        response
+    }
+}
+
 /// A submenu button that shows a [`SubMenu`] if a [`Button`] is hovered.
 pub struct SubMenuButton<'a> {
     pub button: Button<'a>,
     pub sub_menu: SubMenu,
+
+    /// The text with the `&` mnemonic marker, see [`Self::with_mnemonic`].
+    mnemonic_text: Option<String>,
 }
 
 impl<'a> SubMenuButton<'a> {
@@ -347,6 +691,16 @@ impl<'a> SubMenuButton<'a> {
         Self::from_button(Button::new(atoms.into_atoms()).right_text("⏵"))
     }
 
+    /// A submenu button with a mnemonic, e.g. `"&Recent files"`.
+    ///
+    /// Pressing the mnemonic in the open parent menu opens the submenu.
+    /// See the [module docs](crate::containers::menu) for more.
+    pub fn with_mnemonic(text: impl Into<String>) -> Self {
+        let mut button = Self::new(());
+        button.mnemonic_text = Some(text.into());
+        button
+    }
+
     /// Create a new submenu button from a [`Button`].
     ///
     /// Use [`Button::right_text`] and [`SubMenuButton::RIGHT_ARROW`] to add the default right
@@ -355,6 +709,7 @@ impl<'a> SubMenuButton<'a> {
         Self {
             button,
             sub_menu: SubMenu::default(),
+            mnemonic_text: None,
         }
     }
 
@@ -382,10 +737,21 @@ impl<'a> SubMenuButton<'a> {
         if open {
             ui.style_mut().visuals.widgets.inactive = ui.style().visuals.widgets.open;
         }
-        let response = self.button.ui(ui);
+        let mut response = if let Some(mnemonic_text) = &self.mnemonic_text {
+            let (label, mnemonic) = parse_mnemonic(mnemonic_text);
+            let text = mnemonic_label(ui, &label, mnemonic, show_mnemonics(ui));
+            let mut response = Button::new(text).right_text(Self::RIGHT_ARROW).ui(ui);
+            if register_keyboard_item(ui, response.id, &label, mnemonic) {
+                response.flags.set(Flags::FAKE_PRIMARY_CLICKED, true);
+            }
+            response
+        } else {
+            self.button.ui(ui)
+        };
         ui.style_mut().visuals.widgets.inactive = inactive;
 
         let popup_response = self.sub_menu.show(ui, &response, content);
+        response.flags -= Flags::FAKE_PRIMARY_CLICKED;
 
         (response, popup_response)
     }
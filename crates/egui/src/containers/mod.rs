@@ -9,12 +9,14 @@ mod combo_box;
 pub mod frame;
 pub mod menu;
 pub mod modal;
+mod overflow_box;
 pub mod panel;
 mod popup;
 pub(crate) mod resize;
 mod scene;
 pub mod scroll_area;
 mod sides;
+mod sticky_section;
 mod tooltip;
 pub(crate) mod window;
 
@@ -25,12 +27,14 @@ pub use {
     combo_box::*,
     frame::Frame,
     modal::{Modal, ModalResponse},
+    overflow_box::{Overflow, OverflowBox},
     panel::*,
     popup::*,
     resize::Resize,
     scene::{DragPanButtons, Scene},
-    scroll_area::ScrollArea,
+    scroll_area::{ScrollArea, ScrollBarMark, ScrollPropagation, WheelAxisPolicy},
     sides::Sides,
+    sticky_section::StickySection,
     tooltip::*,
-    window::{Window, WindowDrag},
+    window::{Window, WindowDrag, WindowTile},
 };
@@ -0,0 +1,186 @@
+use emath::{Pos2, Rect, Vec2, vec2};
+use epaint::{Color32, Mesh};
+
+use crate::{AsIdSalt, IdSalt, InnerResponse, ScrollArea, Sense, TextWrapMode, Ui, UiBuilder};
+
+/// What to do with content that does not fit in its container.
+///
+/// See [`OverflowBox`] and [`crate::Label::overflow`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub enum Overflow {
+    /// The content is painted outside the container, and the parent [`Ui`] grows to fit it.
+    ///
+    /// This is the normal egui behavior.
+    #[default]
+    Visible,
+
+    /// The content is cut off at the edges of the container.
+    Clip,
+
+    /// Like [`Self::Clip`], but text that does not fit is truncated with an `…`.
+    ///
+    /// This sets [`crate::Style::wrap_mode`] to [`TextWrapMode::Truncate`] for the contents.
+    Ellipsis,
+
+    /// Like [`Self::Clip`], but the content fades into the background towards the edges it overflows.
+    Fade,
+
+    /// Show scroll bars if the content does not fit.
+    Scroll,
+}
+
+/// A container with a maximum size, and an [`Overflow`] policy for contents that are larger than that.
+///
+/// The container shrinks to its contents if they are smaller than the maximum size.
+/// Unless the overflow is [`Overflow::Visible`], the parent [`Ui`] never grows past the maximum size.
+///
+/// ```
+/// # egui::__run_test_ui(|ui| {
+/// egui::containers::OverflowBox::new(egui::vec2(120.0, 40.0))
+///     .overflow(egui::containers::Overflow::Fade)
+///     .show(ui, |ui| {
+///         ui.label("This is a very long text that will not fit in the box, so it fades out");
+///     });
+/// # });
+/// ```
+#[must_use = "You should call .show()"]
+#[derive(Clone, Copy, Debug)]
+pub struct OverflowBox {
+    id_salt: Option<IdSalt>,
+    max_size: Vec2,
+    overflow: Overflow,
+    fade_width: f32,
+}
+
+impl OverflowBox {
+    /// The container will be at most this large.
+    ///
+    /// Use [`f32::INFINITY`] for a dimension to only limit the other one.
+    #[inline]
+    pub fn new(max_size: Vec2) -> Self {
+        Self {
+            id_salt: None,
+            max_size,
+            overflow: Overflow::default(),
+            fade_width: 16.0,
+        }
+    }
+
+    /// A source for the unique [`crate::Id`], e.g. `.id_salt("second_box")`.
+    ///
+    /// Only needed for [`Overflow::Scroll`] if there are multiple scrolling boxes in the same [`Ui`].
+    #[inline]
+    pub fn id_salt(mut self, id_salt: impl AsIdSalt) -> Self {
+        self.id_salt = Some(IdSalt::new(id_salt));
+        self
+    }
+
+    /// What to do with content that does not fit.
+    ///
+    /// Default: [`Overflow::Visible`].
+    #[inline]
+    pub fn overflow(mut self, overflow: Overflow) -> Self {
+        self.overflow = overflow;
+        self
+    }
+
+    /// How wide the gradient is for [`Overflow::Fade`].
+    ///
+    /// Default: `16.0`.
+    #[inline]
+    pub fn fade_width(mut self, fade_width: f32) -> Self {
+        self.fade_width = fade_width;
+        self
+    }
+
+    pub fn show<R>(self, ui: &mut Ui, add_contents: impl FnOnce(&mut Ui) -> R) -> InnerResponse<R> {
+        let Self {
+            id_salt,
+            max_size,
+            overflow,
+            fade_width,
+        } = self;
+
+        match overflow {
+            Overflow::Visible => ui.allocate_ui(max_size, add_contents),
+
+            Overflow::Scroll => {
+                let mut scroll_area = ScrollArea::both()
+                    .max_width(max_size.x)
+                    .max_height(max_size.y);
+                if let Some(id_salt) = id_salt {
+                    scroll_area = scroll_area.id_salt(id_salt);
+                }
+                let output = scroll_area.show(ui, add_contents);
+                let response = ui.interact(
+                    output.inner_rect,
+                    output.id.with("__overflow_box"),
+                    Sense::hover(),
+                );
+                InnerResponse::new(output.inner, response)
+            }
+
+            Overflow::Clip | Overflow::Ellipsis | Overflow::Fade => {
+                let max_rect = Rect::from_min_size(ui.available_rect_before_wrap().min, max_size);
+                let mut content_ui = ui.new_child(UiBuilder::new().max_rect(max_rect));
+                content_ui.shrink_clip_rect(max_rect);
+                if overflow == Overflow::Ellipsis {
+                    content_ui.style_mut().wrap_mode = Some(TextWrapMode::Truncate);
+                }
+
+                let inner = add_contents(&mut content_ui);
+                let content_rect = content_ui.min_rect();
+                let rect = Rect::from_min_max(
+                    max_rect.min,
+                    content_rect.max.min(max_rect.max).max(max_rect.min),
+                );
+
+                if overflow == Overflow::Fade {
+                    paint_fade(ui, rect, content_rect, fade_width, ui.stack().bg_color());
+                }
+
+                let response = ui.allocate_rect(rect, Sense::hover());
+                InnerResponse::new(inner, response)
+            }
+        }
+    }
+}
+
+/// Fade the edges of `rect` that `content_rect` overflows into `bg_color`.
+fn paint_fade(ui: &Ui, rect: Rect, content_rect: Rect, fade_width: f32, bg_color: Color32) {
+    let fade_width = fade_width.min(rect.width() / 2.0).min(rect.height() / 2.0);
+    if fade_width <= 0.0 {
+        return;
+    }
+
+    let mut mesh = Mesh::default();
+    let mut add_gradient = |inner: [Pos2; 2], outer_offset: Vec2| {
+        let idx = mesh.vertices.len() as u32;
+        mesh.colored_vertex(inner[0], Color32::TRANSPARENT);
+        mesh.colored_vertex(inner[1], Color32::TRANSPARENT);
+        mesh.colored_vertex(inner[0] + outer_offset, bg_color);
+        mesh.colored_vertex(inner[1] + outer_offset, bg_color);
+        mesh.add_triangle(idx, idx + 1, idx + 2);
+        mesh.add_triangle(idx + 1, idx + 2, idx + 3);
+    };
+
+    if rect.right() < content_rect.right() {
+        let x = rect.right() - fade_width;
+        add_gradient(
+            [Pos2::new(x, rect.top()), Pos2::new(x, rect.bottom())],
+            vec2(fade_width, 0.0),
+        );
+    }
+    if rect.bottom() < content_rect.bottom() {
+        let y = rect.bottom() - fade_width;
+        add_gradient(
+            [Pos2::new(rect.left(), y), Pos2::new(rect.right(), y)],
+            vec2(0.0, fade_width),
+        );
+    }
+
+    if !mesh.is_empty() {
+        ui.painter_at(rect).add(mesh);
+    }
+}
@@ -782,6 +782,11 @@ impl Panel {
         // `Frame::show` returns the panel's (shifted) _outer_ rect, including margin & border.
         let shifted_outer_rect = inner_response.response.rect;
         let visible_outer_rect = shifted_outer_rect.intersect(max_rect);
+        parent_ui.ctx().pass_state_mut(|state| {
+            state
+                .panel_rects
+                .push((id, parent_ui.layer_id(), visible_outer_rect));
+        });
 
         {
             let mut cursor = parent_ui.cursor();
@@ -1100,6 +1105,11 @@ impl CentralPanel {
 
         // Use up space in the parent:
         ui.advance_cursor_after_rect(response.response.rect);
+        ui.ctx().pass_state_mut(|state| {
+            state
+                .panel_rects
+                .push((panel_ui.id(), ui.layer_id(), response.response.rect));
+        });
 
         response
     }
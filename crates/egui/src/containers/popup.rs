@@ -4,7 +4,7 @@ use emath::{Align, Pos2, Rect, RectAlign, Vec2, vec2};
 
 use crate::{
     Area, AreaState, Context, Frame, Id, InnerResponse, Key, LayerId, Layout, Order, Response,
-    Sense, Ui, UiKind, UiStackInfo,
+    Sense, Transition, Ui, UiKind, UiStackInfo,
     containers::menu::{MenuConfig, MenuState, menu_style},
     style::StyleModifier,
 };
@@ -183,6 +183,7 @@ pub struct Popup<'a> {
     layout: Layout,
     frame: Option<Frame>,
     style: StyleModifier,
+    transition: Option<Transition>,
 }
 
 impl<'a> Popup<'a> {
@@ -205,6 +206,7 @@ impl<'a> Popup<'a> {
             layout: Layout::default(),
             frame: None,
             style: StyleModifier::default(),
+            transition: None,
         }
     }
 
@@ -408,6 +410,15 @@ impl<'a> Popup<'a> {
         self
     }
 
+    /// How the popup appears when opened.
+    ///
+    /// Default: [`crate::Style::popup_transition`].
+    #[inline]
+    pub fn transition(mut self, transition: Transition) -> Self {
+        self.transition = Some(transition);
+        self
+    }
+
     /// Get the [`Context`]
     pub fn ctx(&self) -> &Context {
         &self.ctx
@@ -549,6 +560,7 @@ impl<'a> Popup<'a> {
             layout,
             frame,
             style,
+            transition,
         } = self;
 
         if kind != PopupKind::Tooltip {
@@ -584,6 +596,9 @@ impl<'a> Popup<'a> {
         if let Some(width) = width {
             area = area.default_width(width);
         }
+        if let Some(transition) = transition {
+            area = area.transition(transition);
+        }
 
         let mut response = area.show(&ctx, |ui| {
             style.apply(ui.style_mut());
@@ -34,6 +34,14 @@ impl State {
     pub fn store(self, ctx: &Context, id: Id) {
         ctx.data_mut(|d| d.insert_persisted(id, self));
     }
+
+    /// Jump to a new size without an interactive resize (e.g. when a [`crate::Window`] is tiled).
+    ///
+    /// Unlike [`Self::requested_size`] this also lets the region shrink below the size of last frame's content.
+    pub(crate) fn set_size(&mut self, size: Vec2) {
+        self.desired_size = size;
+        self.last_content_size = size;
+    }
 }
 
 /// A region that can be resized by dragging the bottom right corner.
@@ -1,6 +1,6 @@
 use core::f32;
 
-use emath::{GuiRounding as _, Pos2};
+use emath::{GuiRounding as _, NumExt as _, Pos2};
 
 use crate::{
     InnerResponse, LayerId, PointerButton, Rangef, Rect, Response, Sense, Ui, UiBuilder, Vec2,
@@ -41,6 +41,10 @@ fn fit_to_rect_in_scene(
 /// * Supports zooming
 /// * Has no scroll bars
 /// * Has no limits on the scrolling
+///
+/// Zooming (with a pinch gesture or cmd/ctrl-scroll) scales the content of the scene,
+/// anchored at the pointer, and leaves the [`crate::Context::zoom_factor`] of the rest of the UI alone.
+/// Scrolling inside the scene pans it, and is not passed on to any surrounding [`crate::ScrollArea`].
 #[derive(Clone, Debug)]
 #[must_use = "You should call .show()"]
 pub struct Scene {
@@ -48,6 +52,7 @@ pub struct Scene {
     sense: Sense,
     max_inner_size: Vec2,
     drag_pan_buttons: DragPanButtons,
+    pan_inertia: bool,
 }
 
 /// Specifies which pointer buttons can be used to pan the scene by dragging.
@@ -80,6 +85,7 @@ impl Default for Scene {
             sense: Sense::click_and_drag(),
             max_inner_size: Vec2::splat(1000.0),
             drag_pan_buttons: DragPanButtons::all(),
+            pan_inertia: false,
         }
     }
 }
@@ -130,6 +136,15 @@ impl Scene {
         self
     }
 
+    /// Keep panning for a bit after the user lets go of a drag, slowing down until it stops.
+    ///
+    /// The default is `false`.
+    #[inline]
+    pub fn pan_inertia(mut self, pan_inertia: bool) -> Self {
+        self.pan_inertia = pan_inertia;
+        self
+    }
+
     /// `scene_rect` contains the view bounds of the inner [`Ui`].
     ///
     /// `scene_rect` will be mutated by any panning/zooming done by the user.
@@ -240,6 +255,10 @@ impl Scene {
             resp.mark_changed();
         }
 
+        if self.pan_inertia {
+            apply_pan_inertia(ui, resp, dragged, to_global);
+        }
+
         if let Some(mouse_pos) = ui.input(|i| i.pointer.latest_pos())
             && resp.contains_pointer()
         {
@@ -272,6 +291,42 @@ impl Scene {
             // Pan:
             *to_global = TSTransform::from_translation(pan_delta) * *to_global;
             resp.mark_changed();
+
+            // Don't also scroll any surrounding `ScrollArea`:
+            ui.input_mut(|i| i.smooth_scroll_delta = Vec2::ZERO);
         }
     }
 }
+
+/// Keep moving with the velocity of the pointer after a drag, with friction.
+fn apply_pan_inertia(ui: &Ui, resp: &mut Response, dragged: bool, to_global: &mut TSTransform) {
+    let id = resp.id.with("pan_velocity");
+
+    let mut velocity = if dragged {
+        // Screen points per second:
+        ui.input(|i| i.pointer.velocity())
+    } else if ui.input(|i| i.pointer.any_pressed()) {
+        // Grab the scene to stop it.
+        Vec2::ZERO
+    } else {
+        ui.data(|data| data.get_temp(id)).unwrap_or_default()
+    };
+
+    if !dragged && velocity != Vec2::ZERO {
+        let stop_speed = 20.0; // Points per second.
+        let friction_coeff = 1000.0; // Points per second squared.
+
+        let dt = ui.input(|i| i.stable_dt).at_most(0.1);
+        let speed = velocity.length();
+        if speed < stop_speed || speed < friction_coeff * dt {
+            velocity = Vec2::ZERO;
+        } else {
+            to_global.translation += velocity * dt;
+            velocity -= friction_coeff * dt * velocity.normalized();
+            resp.mark_changed();
+            ui.ctx().request_repaint();
+        }
+    }
+
+    ui.data_mut(|data| data.insert_temp(id, velocity));
+}
@@ -11,13 +11,21 @@ use crate::{
     AsIdSalt, Context, CursorIcon, Id, IdSalt, NumExt as _, Pos2, Rangef, Rect, Response, Sense,
     Ui, UiBuilder, UiKind, UiStackInfo, Vec2, Vec2b, WidgetInfo, emath, epaint, lerp, pass_state,
     pos2, remap, remap_clamp,
+    style::{self, ScrollEasing, ScrollInterrupt},
 };
 
+/// The key of the [`UiStackInfo::tags`] value with the [`Rect`] that the content
+/// of a [`ScrollArea`] is visible in (excluding the scroll bars).
+pub(crate) const VIEWPORT_TAG: &str = "egui_scroll_area_viewport";
+
 #[derive(Clone, Copy, Debug)]
 #[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
 struct ScrollingToTarget {
     animation_time_span: (f64, f64),
     target_offset: f32,
+
+    #[cfg_attr(feature = "serde", serde(default))]
+    easing: ScrollEasing,
 }
 
 #[derive(Clone, Copy, Debug)]
@@ -53,6 +61,35 @@ pub struct State {
 
     /// Area that can be dragged. This is the size of the content from the last frame.
     interact_rect: Option<Rect>,
+
+    /// The widget used for scroll anchoring, and where it was relative to the content.
+    #[cfg_attr(feature = "serde", serde(skip))]
+    anchor: Option<(Id, Vec2)>,
+
+    /// How far the content has been pulled past its ends, before the rubber-band resistance.
+    /// Only used with [`ScrollArea::elastic_overscroll`].
+    #[cfg_attr(feature = "serde", serde(skip))]
+    overscroll: Vec2,
+
+    /// Was the content pulled far enough to refresh when it is let go?
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pull_to_refresh_armed: bool,
+
+    /// Did we arrive at a scroll target this pass?
+    #[cfg_attr(feature = "serde", serde(skip))]
+    scroll_target_reached: Vec2b,
+
+    /// How far the current gesture has pushed against the ends, for [`ScrollPropagation::AfterThreshold`].
+    #[cfg_attr(feature = "serde", serde(skip))]
+    edge_push: Vec2,
+
+    /// The offset last pass, to tell when we scrolled, for [`crate::style::ScrollStyle::auto_hide_delay`].
+    #[cfg_attr(feature = "serde", serde(skip))]
+    previous_offset: Vec2,
+
+    /// When the offset last changed.
+    #[cfg_attr(feature = "serde", serde(skip))]
+    last_scroll_time: f64,
 }
 
 impl Default for State {
@@ -67,6 +104,13 @@ impl Default for State {
             scroll_start_offset_from_top_left: [None; 2],
             scroll_stuck_to_end: Vec2b::TRUE,
             interact_rect: None,
+            anchor: None,
+            overscroll: Vec2::ZERO,
+            pull_to_refresh_armed: false,
+            scroll_target_reached: Vec2b::FALSE,
+            edge_push: Vec2::ZERO,
+            previous_offset: Vec2::ZERO,
+            last_scroll_time: f64::NEG_INFINITY,
         }
     }
 }
@@ -84,6 +128,18 @@ impl State {
     pub fn velocity(&self) -> Vec2 {
         self.vel
     }
+
+    /// Is there an ongoing animated scroll, e.g. from [`Ui::scroll_to_rect`]?
+    pub fn is_scrolling_to_target(&self) -> bool {
+        self.offset_target.iter().any(Option::is_some)
+    }
+
+    /// Did an animated scroll (e.g. from [`Ui::scroll_to_rect`]) arrive at its target this pass?
+    ///
+    /// Use this to do something once the scrolling is done.
+    pub fn scroll_target_reached(&self) -> bool {
+        self.scroll_target_reached.any()
+    }
 }
 
 pub struct ScrollAreaOutput<R> {
@@ -102,6 +158,17 @@ pub struct ScrollAreaOutput<R> {
 
     /// Where on the screen the content is (excludes scroll bars).
     pub inner_rect: Rect,
+
+    /// How far the content is pulled down past its top, as a fraction of the distance set with
+    /// [`ScrollArea::pull_to_refresh`], in `0..=1`.
+    ///
+    /// Always zero when pull-to-refresh is not enabled.
+    pub pull_progress: f32,
+
+    /// The content was pulled all the way and let go: time to refresh!
+    ///
+    /// See [`ScrollArea::pull_to_refresh`].
+    pub refresh_requested: bool,
 }
 
 /// Indicate whether the horizontal and vertical scroll bars must be always visible, hidden or visible when needed.
@@ -141,6 +208,99 @@ impl ScrollBarVisibility {
     ];
 }
 
+/// A small colored mark on the track of a [`ScrollArea`] scroll bar.
+///
+/// Useful for showing where search hits, errors or bookmarks are in the content,
+/// like code editors do. Clicking a mark scrolls to it.
+///
+/// See [`ScrollArea::vertical_scroll_bar_marks`] and [`ScrollArea::horizontal_scroll_bar_marks`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct ScrollBarMark {
+    /// Where in the content the mark is,
+    /// from `0.0` (top/left) to `1.0` (bottom/right).
+    pub position: f32,
+
+    /// The color of the mark.
+    pub color: Color32,
+}
+
+impl ScrollBarMark {
+    #[inline]
+    pub fn new(position: f32, color: impl Into<Color32>) -> Self {
+        Self {
+            position,
+            color: color.into(),
+        }
+    }
+}
+
+/// How a [`ScrollArea`] maps mouse wheel input to its scroll directions.
+///
+/// Most mice only have a vertical wheel. To scroll horizontally with one of those,
+/// hold [`crate::InputOptions::horizontal_scroll_modifier`] (Shift by default) while turning it.
+/// On macOS the OS itself reports Shift+wheel as horizontal scrolling, which works the same.
+///
+/// See [`ScrollArea::wheel_axis_policy`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub enum WheelAxisPolicy {
+    /// Scroll in the direction the wheel reports:
+    /// vertically, or horizontally while Shift is held.
+    #[default]
+    AsReported,
+
+    /// Like [`Self::AsReported`], but if only one direction is enabled,
+    /// any wheel input scrolls in that direction, with or without Shift.
+    ///
+    /// This is what [`crate::Style::always_scroll_the_only_direction`] turns on.
+    OnlyEnabledDirection,
+
+    /// Swap the directions: the wheel scrolls horizontally, and vertically while Shift is held.
+    ///
+    /// Useful for areas that mostly scroll sideways, like timelines and tab strips.
+    /// Meant for mice; a trackpad will also have its directions swapped.
+    Swapped,
+}
+
+/// What a [`ScrollArea`] inside another one does with scrolling it has no room for,
+/// i.e. when it is already scrolled all the way to one end.
+///
+/// This applies to the mouse wheel, to dragging the content,
+/// and to scrolling with [`Ui::scroll_with_delta`] (e.g. from the keyboard).
+///
+/// See [`ScrollArea::scroll_propagation`].
+#[derive(Clone, Copy, Debug, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub enum ScrollPropagation {
+    /// Pass the scrolling on to the parent [`ScrollArea`] as soon as this one is at its end.
+    #[default]
+    AtEdge,
+
+    /// Only pass the scrolling on after this many points of it has been pushed against the end
+    /// during the same gesture, so that a fast scroll doesn't carry over to the parent by accident.
+    AfterThreshold(f32),
+
+    /// Never pass the scrolling on.
+    Never,
+}
+
+impl ScrollPropagation {
+    /// How much of `leftover` to pass on to the parent,
+    /// given how much has already been pushed against the end during this gesture.
+    fn passed_on(self, leftover: f32, edge_push: &mut f32) -> f32 {
+        match self {
+            Self::AtEdge => leftover,
+            Self::AfterThreshold(threshold) => {
+                let pushed_before = *edge_push;
+                *edge_push += leftover.abs();
+                let passed = (*edge_push - threshold.max(pushed_before)).max(0.0);
+                leftover.signum() * passed.min(leftover.abs())
+            }
+            Self::Never => 0.0,
+        }
+    }
+}
+
 /// When [`ScrollArea`] should let the user scroll by dragging the content.
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Default)]
 #[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
@@ -351,6 +511,8 @@ pub struct ScrollArea {
     on_drag_cursor: Option<CursorIcon>,
     scroll_source: ScrollSource,
     wheel_scroll_multiplier: Vec2,
+    wheel_axis_policy: Option<WheelAxisPolicy>,
+    scroll_propagation: ScrollPropagation,
 
     content_margin: Option<Margin>,
 
@@ -361,6 +523,14 @@ pub struct ScrollArea {
 
     /// If false, `scroll_to_*` functions will not be animated
     animated: bool,
+
+    /// Marks on the horizontal and vertical scroll bars.
+    scroll_bar_marks: [Vec<ScrollBarMark>; 2],
+
+    scroll_anchoring: bool,
+    elastic_overscroll: bool,
+    pull_to_refresh: Option<f32>,
+    minimap_width: Option<f32>,
 }
 
 impl ScrollArea {
@@ -406,9 +576,16 @@ impl ScrollArea {
             on_drag_cursor: None,
             scroll_source: ScrollSource::default(),
             wheel_scroll_multiplier: Vec2::splat(1.0),
+            wheel_axis_policy: None,
+            scroll_propagation: ScrollPropagation::AtEdge,
             content_margin: None,
             stick_to_end: Vec2b::FALSE,
             animated: true,
+            scroll_bar_marks: Default::default(),
+            scroll_anchoring: false,
+            elastic_overscroll: false,
+            pull_to_refresh: None,
+            minimap_width: None,
         }
     }
 
@@ -595,6 +772,25 @@ impl ScrollArea {
         self
     }
 
+    /// How mouse wheel input maps to the scroll directions, e.g. whether Shift+wheel scrolls horizontally.
+    ///
+    /// The default is [`WheelAxisPolicy::OnlyEnabledDirection`] if
+    /// [`crate::Style::always_scroll_the_only_direction`] is set, else [`WheelAxisPolicy::AsReported`].
+    #[inline]
+    pub fn wheel_axis_policy(mut self, policy: WheelAxisPolicy) -> Self {
+        self.wheel_axis_policy = Some(policy);
+        self
+    }
+
+    /// What to do with scrolling that this area has no room for, when it is inside another [`ScrollArea`].
+    ///
+    /// Default: [`ScrollPropagation::AtEdge`].
+    #[inline]
+    pub fn scroll_propagation(mut self, scroll_propagation: ScrollPropagation) -> Self {
+        self.scroll_propagation = scroll_propagation;
+        self
+    }
+
     /// For each axis, should the containing area shrink if the content is small?
     ///
     /// * If `true`, egui will add blank space outside the scroll area.
@@ -656,6 +852,116 @@ impl ScrollArea {
         self.stick_to_end[1] = stick;
         self
     }
+
+    /// Keep the visible content from jumping when something above it changes size,
+    /// e.g. when an image finishes loading or a row is expanded.
+    ///
+    /// The first widget that is fully in view is used as the anchor,
+    /// and the scroll offset is adjusted so that it stays put on screen.
+    /// Nothing is adjusted while scrolled all the way to the top (or left).
+    ///
+    /// Default: `false`.
+    #[inline]
+    pub fn scroll_anchoring(mut self, scroll_anchoring: bool) -> Self {
+        self.scroll_anchoring = scroll_anchoring;
+        self
+    }
+
+    /// Let the content be pulled past its ends with a trackpad or by dragging it,
+    /// with increasing resistance, and spring back when let go.
+    ///
+    /// This also lets content that fits be dragged, as is customary on mobile.
+    ///
+    /// Default: `false`.
+    #[inline]
+    pub fn elastic_overscroll(mut self, elastic_overscroll: bool) -> Self {
+        self.elastic_overscroll = elastic_overscroll;
+        self
+    }
+
+    /// Refresh when the content is pulled down past its top by at least `distance` points and let go.
+    ///
+    /// How far the content is pulled is reported in [`ScrollAreaOutput::pull_progress`],
+    /// which you can use to show an indicator, and [`ScrollAreaOutput::refresh_requested`]
+    /// is set when it is let go far enough.
+    ///
+    /// This turns on [`Self::elastic_overscroll`].
+    ///
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// # fn reload() {}
+    /// let output = egui::ScrollArea::vertical()
+    ///     .pull_to_refresh(64.0)
+    ///     .show(ui, |ui| {
+    ///         ui.label("Pull me down");
+    ///     });
+    /// if output.refresh_requested {
+    ///     reload();
+    /// }
+    /// # });
+    /// ```
+    #[inline]
+    pub fn pull_to_refresh(mut self, distance: f32) -> Self {
+        self.elastic_overscroll = true;
+        self.pull_to_refresh = Some(distance);
+        self
+    }
+
+    /// Show a scaled-down copy of the content in a strip of this width to the right of the content,
+    /// with the visible part highlighted.
+    ///
+    /// Click or drag in the minimap to scroll there.
+    /// If the scaled-down content is taller than the minimap, the minimap scrolls along with the content.
+    ///
+    /// The minimap reuses the shapes painted by the content this pass,
+    /// so with [`Self::show_rows`] and [`Self::show_viewport`] it only shows what was painted.
+    #[inline]
+    pub fn minimap(mut self, width: f32) -> Self {
+        self.minimap_width = Some(width);
+        self
+    }
+
+    /// Show small colored marks on the horizontal scroll bar,
+    /// e.g. for search hits, errors or bookmarks.
+    ///
+    /// Clicking a mark scrolls to it.
+    #[inline]
+    pub fn horizontal_scroll_bar_marks(
+        mut self,
+        marks: impl IntoIterator<Item = ScrollBarMark>,
+    ) -> Self {
+        self.scroll_bar_marks[0] = marks.into_iter().collect();
+        self
+    }
+
+    /// Show small colored marks on the vertical scroll bar,
+    /// e.g. for search hits, errors or bookmarks.
+    ///
+    /// Clicking a mark scrolls to it.
+    ///
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// let lines = 1000;
+    /// let errors = [17, 345, 812];
+    /// egui::ScrollArea::vertical()
+    ///     .vertical_scroll_bar_marks(errors.map(|line| {
+    ///         egui::ScrollBarMark::new(line as f32 / lines as f32, egui::Color32::RED)
+    ///     }))
+    ///     .show(ui, |ui| {
+    ///         for line in 0..lines {
+    ///             ui.label(format!("Line {line}"));
+    ///         }
+    ///     });
+    /// # });
+    /// ```
+    #[inline]
+    pub fn vertical_scroll_bar_marks(
+        mut self,
+        marks: impl IntoIterator<Item = ScrollBarMark>,
+    ) -> Self {
+        self.scroll_bar_marks[1] = marks.into_iter().collect();
+        self
+    }
 }
 
 struct Prepared {
@@ -695,16 +1001,35 @@ struct Prepared {
 
     scroll_source: ScrollSource,
     wheel_scroll_multiplier: Vec2,
+    wheel_axis_policy: WheelAxisPolicy,
+    scroll_propagation: ScrollPropagation,
     stick_to_end: Vec2b,
 
     /// If there was a scroll target before the [`ScrollArea`] was added this frame, it's
     /// not for us to handle so we save it and restore it after this [`ScrollArea`] is done.
     saved_scroll_target: [Option<pass_state::ScrollTarget>; 2],
 
+    /// Same as [`Self::saved_scroll_target`], but for the scroll delta.
+    saved_scroll_delta: (Vec2, style::ScrollAnimation),
+
     /// The response from dragging the background (if enabled)
     background_drag_response: Option<Response>,
 
     animated: bool,
+    scroll_bar_marks: [Vec<ScrollBarMark>; 2],
+
+    scroll_anchoring: bool,
+
+    /// How many widgets there were on the layer before the content was added.
+    num_widgets_before_content: usize,
+
+    elastic_overscroll: bool,
+    pull_to_refresh: Option<f32>,
+
+    minimap_width: Option<f32>,
+
+    /// Where the shapes of the content start in the paint list of the layer.
+    content_shapes_start: usize,
 }
 
 impl ScrollArea {
@@ -723,13 +1048,28 @@ impl ScrollArea {
             on_drag_cursor,
             scroll_source,
             wheel_scroll_multiplier,
+            wheel_axis_policy,
+            scroll_propagation,
             content_margin: _, // Used elsewhere
             stick_to_end,
             animated,
+            scroll_bar_marks,
+            scroll_anchoring,
+            elastic_overscroll,
+            pull_to_refresh,
+            minimap_width,
         } = self;
 
         let ctx = ui.ctx().clone();
 
+        let wheel_axis_policy = wheel_axis_policy.unwrap_or_else(|| {
+            if ui.style().always_scroll_the_only_direction {
+                WheelAxisPolicy::OnlyEnabledDirection
+            } else {
+                WheelAxisPolicy::AsReported
+            }
+        });
+
         let id_salt = id_salt.unwrap_or_else(|| IdSalt::new("scroll_area"));
         let id = ui.make_persistent_id(id_salt);
         ctx.check_for_id_clash(
@@ -739,6 +1079,7 @@ impl ScrollArea {
         );
         let mut state = State::load(&ctx, id).unwrap_or_default();
 
+        state.scroll_target_reached = Vec2b::FALSE;
         state.offset.x = offset_x.unwrap_or(state.offset.x);
         state.offset.y = offset_y.unwrap_or(state.offset.y);
 
@@ -754,11 +1095,15 @@ impl ScrollArea {
         );
 
         let scroll_style = ui.spacing().scroll;
-        let current_bar_use = if scroll_style.floating {
+        let mut current_bar_use = if scroll_style.floating {
             show_bars.to_vec2().yx() * scroll_style.allocated_width()
         } else {
             show_bars_factor.yx() * scroll_style.allocated_width()
         };
+        if let Some(minimap_width) = minimap_width {
+            // The minimap goes between the content and the vertical scroll bar:
+            current_bar_use.x += minimap_width;
+        }
 
         let available_outer = ui.available_rect_before_wrap();
 
@@ -795,7 +1140,9 @@ impl ScrollArea {
             }
         }
 
-        let content_max_rect = Rect::from_min_size(inner_rect.min - state.offset, content_max_size);
+        let overscroll = rubber_band(state.overscroll, inner_size);
+        let content_max_rect =
+            Rect::from_min_size(inner_rect.min - state.offset - overscroll, content_max_size);
 
         // Round to pixels to avoid widgets appearing to "float" when scrolling fractional amounts:
         let content_max_rect = content_max_rect
@@ -804,7 +1151,9 @@ impl ScrollArea {
 
         let mut content_ui = ui.new_child(
             UiBuilder::new()
-                .ui_stack_info(UiStackInfo::new(UiKind::ScrollArea))
+                .ui_stack_info(
+                    UiStackInfo::new(UiKind::ScrollArea).with_tag_value(VIEWPORT_TAG, inner_rect),
+                )
                 .max_rect(content_max_rect),
         );
 
@@ -831,7 +1180,7 @@ impl ScrollArea {
 
         let background_drag_response = if scroll_source.drag.enabled(ui.ctx())
             && ui.is_enabled()
-            && state.content_is_too_large.any()
+            && (state.content_is_too_large.any() || elastic_overscroll)
         {
             // Drag contents to scroll (for touch screens mostly).
             // We must do this BEFORE adding content to the `ScrollArea`,
@@ -908,13 +1257,14 @@ impl ScrollArea {
                     // Arrived
                     state.offset[d] = scroll_target.target_offset;
                     state.offset_target[d] = None;
+                    state.scroll_target_reached[d] = true;
                 } else {
                     // Move towards target
                     let t = emath::interpolation_factor(
                         scroll_target.animation_time_span,
                         ui.input(|i| i.time),
                         dt,
-                        emath::ease_in_ease_out,
+                        |t| scroll_target.easing.ease(t),
                     );
                     if t < 1.0 {
                         state.offset[d] =
@@ -924,14 +1274,29 @@ impl ScrollArea {
                         // Arrived
                         state.offset[d] = scroll_target.target_offset;
                         state.offset_target[d] = None;
+                        state.scroll_target_reached[d] = true;
                     }
                 }
             }
         }
 
-        let saved_scroll_target = content_ui
-            .ctx()
-            .pass_state_mut(|state| std::mem::take(&mut state.scroll_target));
+        let (saved_scroll_target, saved_scroll_delta) = content_ui.ctx().pass_state_mut(|state| {
+            (
+                std::mem::take(&mut state.scroll_target),
+                std::mem::replace(
+                    &mut state.scroll_delta,
+                    (Vec2::ZERO, style::ScrollAnimation::none()),
+                ),
+            )
+        });
+
+        let num_widgets_before_content =
+            ctx.pass_state(|pass_state| pass_state.widgets.get_layer(ui.layer_id()).count());
+        let content_shapes_start = ctx.graphics(|graphics| {
+            graphics
+                .get(ui.layer_id())
+                .map_or(0, |paint_list| paint_list.next_idx().0)
+        });
 
         Prepared {
             id,
@@ -947,10 +1312,20 @@ impl ScrollArea {
             viewport,
             scroll_source,
             wheel_scroll_multiplier,
+            wheel_axis_policy,
+            scroll_propagation,
             stick_to_end,
             saved_scroll_target,
+            saved_scroll_delta,
             background_drag_response,
             animated,
+            scroll_bar_marks,
+            scroll_anchoring,
+            num_widgets_before_content,
+            elastic_overscroll,
+            pull_to_refresh,
+            minimap_width,
+            content_shapes_start,
         }
     }
 
@@ -1046,20 +1421,22 @@ impl ScrollArea {
             })
             .inner;
 
-        let (content_size, state) = prepared.end(ui);
+        let (content_size, state, pull_progress, refresh_requested) = prepared.end(ui);
         ScrollAreaOutput {
             inner,
             id,
             state,
             content_size,
             inner_rect,
+            pull_progress,
+            refresh_requested,
         }
     }
 }
 
 impl Prepared {
-    /// Returns content size and state
-    fn end(self, ui: &mut Ui) -> (Vec2, State) {
+    /// Returns content size, state, pull progress and whether to refresh
+    fn end(self, ui: &mut Ui) -> (Vec2, State, f32, bool) {
         let Self {
             id,
             mut state,
@@ -1071,23 +1448,56 @@ impl Prepared {
             scroll_bar_visibility,
             scroll_bar_rect,
             content_ui,
-            viewport: _,
+            viewport,
             scroll_source,
             wheel_scroll_multiplier,
+            wheel_axis_policy,
+            scroll_propagation,
             stick_to_end,
             saved_scroll_target,
+            saved_scroll_delta,
             background_drag_response,
             animated,
+            scroll_bar_marks,
+            scroll_anchoring,
+            num_widgets_before_content,
+            elastic_overscroll,
+            pull_to_refresh,
+            minimap_width,
+            content_shapes_start,
         } = self;
 
         let content_size = content_ui.min_size();
 
+        // The size the overscroll was scaled by in `begin`, before any auto-shrinking:
+        let rubber_band_size = inner_rect.size();
+
+        if scroll_anchoring {
+            // Where the content was laid out this pass:
+            let content_origin = inner_rect.min
+                - viewport.min.to_vec2()
+                - rubber_band(state.overscroll, rubber_band_size);
+            apply_scroll_anchoring(
+                ui,
+                &mut state,
+                direction_enabled,
+                inner_rect,
+                content_origin,
+                num_widgets_before_content,
+            );
+        }
+
         let scroll_delta = content_ui
             .ctx()
             .pass_state_mut(|state| std::mem::take(&mut state.scroll_delta));
 
         let mut had_explicit_scroll_adjustment = Vec2b::FALSE;
 
+        // How far we can scroll, and what we couldn't scroll ourselves and pass on to our parent:
+        let scroll_range = (content_size - inner_rect.size()).at_least(Vec2::ZERO);
+        let mut propagated_delta = Vec2::ZERO;
+        let mut pushed_against_edge = Vec2b::FALSE;
+
         for d in 0..2 {
             // PassState::scroll_delta is inverted from the way we apply the delta, so we need to negate it.
             let mut delta = -scroll_delta.0[d];
@@ -1100,6 +1510,7 @@ impl Prepared {
                 .pass_state_mut(|state| state.scroll_target[d].take());
 
             if direction_enabled[d] {
+                let is_scroll_to_target = scroll_target.is_some();
                 if let Some(target) = scroll_target {
                     let pass_state::ScrollTarget {
                         range,
@@ -1138,14 +1549,31 @@ impl Prepared {
                 }
 
                 if delta != 0.0 {
-                    let target_offset = state.offset[d] + delta;
+                    let mut target_offset = state.offset[d] + delta;
+
+                    if !is_scroll_to_target {
+                        let leftover = target_offset - target_offset.clamp(0.0, scroll_range[d]);
+                        if leftover != 0.0 {
+                            let passed =
+                                scroll_propagation.passed_on(leftover, &mut state.edge_push[d]);
+                            propagated_delta[d] += passed;
+                            target_offset -= passed;
+                            pushed_against_edge[d] = true;
+                        }
+                    }
+
+                    let running_animation = state.offset_target[d]
+                        .as_mut()
+                        .filter(|_| animation.interrupt == ScrollInterrupt::Blend);
 
                     if !animated {
                         state.offset[d] = target_offset;
-                    } else if let Some(animation) = &mut state.offset_target[d] {
+                        state.offset_target[d] = None;
+                        state.scroll_target_reached[d] = true;
+                    } else if let Some(running_animation) = running_animation {
                         // For instance: the user is continuously calling `ui.scroll_to_cursor`,
                         // so we don't want to reset the animation, but perhaps update the target:
-                        animation.target_offset = target_offset;
+                        running_animation.target_offset = target_offset;
                     } else {
                         // The further we scroll, the more time we take.
                         let now = ui.input(|i| i.time);
@@ -1154,6 +1582,7 @@ impl Prepared {
                         state.offset_target[d] = Some(ScrollingToTarget {
                             animation_time_span: (now, now + animation_duration as f64),
                             target_offset,
+                            easing: animation.easing,
                         });
                     }
                     ui.request_repaint();
@@ -1165,13 +1594,16 @@ impl Prepared {
             }
         }
 
-        // Restore scroll target meant for ScrollAreas up the stack (if any)
+        // Restore scroll target and delta meant for ScrollAreas up the stack (if any)
         ui.ctx().pass_state_mut(|state| {
             for d in 0..2 {
                 if saved_scroll_target[d].is_some() {
                     state.scroll_target[d] = saved_scroll_target[d].clone();
                 }
             }
+            if saved_scroll_delta.0 != Vec2::ZERO {
+                state.scroll_delta = saved_scroll_delta;
+            }
         });
 
         let inner_rect = {
@@ -1214,9 +1646,39 @@ impl Prepared {
             && ui.ctx().dragged_id().is_none()
             || is_dragging_background;
 
+        // Is the user holding on to the content, so that it can be pulled past its ends?
+        let is_holding = elastic_overscroll
+            && (is_dragging_background
+                || scroll_source.mouse_wheel
+                    && is_hovering_outer_rect
+                    && ui.input(|i| i.is_trackpad_scrolling()));
+
         if scroll_source.mouse_wheel && ui.is_enabled() && is_hovering_outer_rect {
-            let always_scroll_enabled_direction = ui.style().always_scroll_the_only_direction
+            let always_scroll_enabled_direction = wheel_axis_policy
+                == WheelAxisPolicy::OnlyEnabledDirection
                 && direction_enabled[0] != direction_enabled[1];
+            let wheel_delta = |input: &crate::InputState| {
+                let delta = input.smooth_scroll_delta();
+                if wheel_axis_policy == WheelAxisPolicy::Swapped {
+                    delta.yx()
+                } else {
+                    delta
+                }
+            };
+            // Only leave `fraction` of the scroll delta for the parent scroll to use:
+            let keep_wheel_delta = |ui: &Ui, d: usize, fraction: f32| {
+                ui.input_mut(|input| {
+                    if always_scroll_enabled_direction {
+                        input.smooth_scroll_delta *= fraction;
+                    } else if wheel_axis_policy == WheelAxisPolicy::Swapped {
+                        input.smooth_scroll_delta[1 - d] *= fraction;
+                    } else {
+                        input.smooth_scroll_delta[d] *= fraction;
+                    }
+                });
+            };
+            // Clear scroll delta so no parent scroll will use it:
+            let consume_wheel_delta = |ui: &Ui, d: usize| keep_wheel_delta(ui, d, 0.0);
             for d in 0..2 {
                 if direction_enabled[d] {
                     let scroll_delta = ui.input(|input| {
@@ -1224,7 +1686,7 @@ impl Prepared {
                             // no bidirectional scrolling; allow horizontal scrolling without pressing shift
                             input.smooth_scroll_delta()[0] + input.smooth_scroll_delta()[1]
                         } else {
-                            input.smooth_scroll_delta()[d]
+                            wheel_delta(input)[d]
                         }
                     });
                     let scroll_delta = scroll_delta * wheel_scroll_multiplier[d];
@@ -1232,25 +1694,76 @@ impl Prepared {
                     let scrolling_up = state.offset[d] > 0.0 && scroll_delta > 0.0;
                     let scrolling_down = state.offset[d] < max_offset[d] && scroll_delta < 0.0;
 
-                    if scrolling_up || scrolling_down {
-                        state.offset[d] -= scroll_delta;
+                    let pulling = is_holding && scroll_delta != 0.0;
 
-                        // Clear scroll delta so no parent scroll will use it:
-                        ui.input_mut(|input| {
-                            if always_scroll_enabled_direction {
-                                input.smooth_scroll_delta = Vec2::ZERO;
-                            } else {
-                                input.smooth_scroll_delta[d] = 0.0;
-                            }
-                        });
+                    if scrolling_up || scrolling_down || pulling {
+                        state.offset[d] -= scroll_delta;
+                        consume_wheel_delta(ui, d);
 
                         state.scroll_stuck_to_end[d] = false;
                         state.offset_target[d] = None;
+                    } else if scroll_delta != 0.0 {
+                        // We are at the end, so the parent may have it, if we allow it:
+                        pushed_against_edge[d] = true;
+                        let passed =
+                            scroll_propagation.passed_on(-scroll_delta, &mut state.edge_push[d]);
+                        // Like when dragging, only pass on what is over the threshold:
+                        keep_wheel_delta(ui, d, passed / -scroll_delta);
                     }
                 }
             }
         }
 
+        if is_dragging_background && !elastic_overscroll {
+            for d in 0..2 {
+                if direction_enabled[d] {
+                    let offset = state.offset[d];
+                    let leftover = offset - offset.clamp(0.0, max_offset[d].max(0.0));
+                    if leftover != 0.0 {
+                        let passed =
+                            scroll_propagation.passed_on(leftover, &mut state.edge_push[d]);
+                        propagated_delta[d] += passed;
+                        state.offset[d] -= passed;
+                        pushed_against_edge[d] = true;
+                    }
+                }
+            }
+        }
+
+        // A new gesture starts over with pushing against the ends:
+        let is_gesturing = is_dragging_background || ui.input(|i| i.is_scrolling());
+        for d in 0..2 {
+            if !pushed_against_edge[d] && !is_gesturing {
+                state.edge_push[d] = 0.0;
+            }
+        }
+
+        if propagated_delta != Vec2::ZERO {
+            // PassState::scroll_delta is inverted from the way we apply the delta:
+            ui.scroll_with_delta_animation(-propagated_delta, style::ScrollAnimation::none());
+        }
+
+        let mut refresh_requested = false;
+        if elastic_overscroll {
+            apply_elastic_overscroll(ui, &mut state, direction_enabled, max_offset, is_holding);
+
+            if let Some(distance) = pull_to_refresh {
+                let pulled = -rubber_band(state.overscroll, rubber_band_size).y;
+                if is_holding {
+                    state.pull_to_refresh_armed = distance <= pulled;
+                } else if state.pull_to_refresh_armed {
+                    state.pull_to_refresh_armed = false;
+                    refresh_requested = true;
+                }
+            }
+        } else {
+            state.overscroll = Vec2::ZERO;
+        }
+        let pull_progress = pull_to_refresh.map_or(0.0, |distance| {
+            let pulled = -rubber_band(state.overscroll, rubber_band_size).y;
+            (pulled / distance).clamp(0.0, 1.0)
+        });
+
         let show_scroll_this_frame = match scroll_bar_visibility {
             ScrollBarVisibility::AlwaysHidden => Vec2b::FALSE,
             ScrollBarVisibility::VisibleWhenNeeded => content_is_too_large,
@@ -1272,6 +1785,23 @@ impl Prepared {
 
         paint_fade_areas_impl(ui, inner_rect, content_size, state.offset);
 
+        // Should floating scroll bars be active, as opposed to dormant?
+        let now = ui.input(|i| i.time);
+        if state.offset != state.previous_offset {
+            state.last_scroll_time = now;
+        }
+        let bars_active = if let Some(auto_hide_delay) = scroll_style.auto_hide_delay {
+            let time_since_scroll = (now - state.last_scroll_time) as f32;
+            let recently_scrolled = time_since_scroll < auto_hide_delay;
+            if recently_scrolled {
+                ui.ctx()
+                    .request_repaint_after_secs(auto_hide_delay - time_since_scroll);
+            }
+            recently_scrolled
+        } else {
+            is_hovering_outer_rect
+        };
+
         // Paint the bars:
         let scroll_bar_rect = scroll_bar_rect.unwrap_or(inner_rect);
         for d in 0..2 {
@@ -1321,7 +1851,19 @@ impl Prepared {
                 outer_rect.with_min_x(max_cross - full_width)
             };
 
-            let sense = if scroll_source.scroll_bar && ui.is_enabled() {
+            let bars_active_t = ui
+                .ctx()
+                .animate_bool_responsive(id.with((d, "bars_active")), bars_active);
+
+            // Don't take clicks from the content with scroll bars that have faded out completely:
+            let is_hidden = scroll_style.floating
+                && scroll_style.auto_hide_delay.is_some()
+                && bars_active_t == 0.0
+                && !state.scroll_bar_interaction[d]
+                && scroll_style.dormant_handle_opacity == 0.0
+                && scroll_style.dormant_background_opacity == 0.0;
+
+            let sense = if scroll_source.scroll_bar && ui.is_enabled() && !is_hidden {
                 Sense::CLICK | Sense::DRAG
             } else {
                 Sense::hover()
@@ -1335,10 +1877,13 @@ impl Prepared {
 
             response.widget_info(|| WidgetInfo::new(crate::WidgetType::ScrollBar));
 
+            // Hidden scroll bars ignore the pointer until they show up again.
+            let is_hovering_bar = response.hovered() && !is_hidden;
+
             // top/bottom of a horizontal scroll (d==0).
             // left/rigth of a vertical scroll (d==1).
             let cross = if scroll_style.floating {
-                let is_hovering_bar_area = response.hovered() || state.scroll_bar_interaction[d];
+                let is_hovering_bar_area = is_hovering_bar || state.scroll_bar_interaction[d];
 
                 let is_hovering_bar_area_t = ui
                     .ctx()
@@ -1398,14 +1943,40 @@ impl Prepared {
                 }
             };
 
+            let mark_pos = |mark: &ScrollBarMark| {
+                lerp(
+                    scroll_bar_rect.min[d]..=scroll_bar_rect.max[d],
+                    mark.position.clamp(0.0, 1.0),
+                )
+            };
+
             let handle_rect = calculate_handle_rect(d, &state.offset);
 
-            state.scroll_bar_interaction[d] = response.hovered() || response.dragged();
+            state.scroll_bar_interaction[d] = is_hovering_bar || response.dragged();
 
             if let Some(pointer_pos) = response.interact_pointer_pos() {
                 let scroll_start_offset_from_top_left = state.scroll_start_offset_from_top_left[d]
                     .get_or_insert_with(|| {
-                        if handle_rect.contains(pointer_pos) {
+                        let interact_radius = ui.style().interaction.interact_radius;
+                        let clicked_mark = scroll_bar_marks[d]
+                            .iter()
+                            .map(|mark| (mark, (mark_pos(mark) - pointer_pos[d]).abs()))
+                            .filter(|(_, distance)| *distance <= interact_radius)
+                            .min_by(|(_, a), (_, b)| a.total_cmp(b))
+                            .map(|(mark, _)| mark);
+
+                        if let Some(mark) =
+                            clicked_mark.filter(|_| !handle_rect.contains(pointer_pos))
+                        {
+                            // Jump so the mark is in the middle of the view,
+                            // and keep dragging from there:
+                            let target_offset = (mark.position * content_size[d]
+                                - 0.5 * inner_rect.size()[d])
+                                .clamp(0.0, max_offset[d].max(0.0));
+                            let mut new_offset = state.offset;
+                            new_offset[d] = target_offset;
+                            pointer_pos[d] - calculate_handle_rect(d, &new_offset).min[d]
+                        } else if handle_rect.contains(pointer_pos) {
                             pointer_pos[d] - handle_rect.min[d]
                         } else {
                             let handle_top_pos_at_bottom =
@@ -1448,7 +2019,7 @@ impl Prepared {
                 let visuals = if scroll_source.scroll_bar && ui.is_enabled() {
                     // Pick visuals based on interaction with the handle.
                     // Remember that the response is for the whole scroll bar!
-                    let is_hovering_handle = response.hovered()
+                    let is_hovering_handle = is_hovering_bar
                         && ui.input(|i| {
                             i.pointer
                                 .latest_pos()
@@ -1467,17 +2038,13 @@ impl Prepared {
                 };
 
                 let handle_opacity = if scroll_style.floating {
-                    if response.hovered() || response.dragged() {
+                    if is_hovering_bar || response.dragged() {
                         scroll_style.interact_handle_opacity
                     } else {
-                        let is_hovering_outer_rect_t = ui.ctx().animate_bool_responsive(
-                            id.with((d, "is_hovering_outer_rect")),
-                            is_hovering_outer_rect,
-                        );
                         lerp(
                             scroll_style.dormant_handle_opacity
                                 ..=scroll_style.active_handle_opacity,
-                            is_hovering_outer_rect_t,
+                            bars_active_t,
                         )
                     }
                 } else {
@@ -1485,9 +2052,9 @@ impl Prepared {
                 };
 
                 let background_opacity = if scroll_style.floating {
-                    if response.hovered() || response.dragged() {
+                    if is_hovering_bar || response.dragged() {
                         scroll_style.interact_background_opacity
-                    } else if is_hovering_outer_rect {
+                    } else if bars_active {
                         scroll_style.active_background_opacity
                     } else {
                         scroll_style.dormant_background_opacity
@@ -1517,9 +2084,42 @@ impl Prepared {
                     visuals.corner_radius,
                     handle_color.gamma_multiply(handle_opacity),
                 ));
+                // Marks, on top of the handle so they are always visible:
+                let mark_length = 2.0;
+                for mark in &scroll_bar_marks[d] {
+                    let along = Rangef::point(mark_pos(mark)).expand(0.5 * mark_length);
+                    let mark_rect = if d == 0 {
+                        Rect::from_x_y_ranges(along, outer_scroll_bar_rect.y_range())
+                    } else {
+                        Rect::from_x_y_ranges(outer_scroll_bar_rect.x_range(), along)
+                    };
+                    ui.painter().rect_filled(
+                        mark_rect,
+                        0.0,
+                        mark.color
+                            .gamma_multiply(background_opacity.max(handle_opacity)),
+                    );
+                }
             }
         }
 
+        if let Some(minimap_width) = minimap_width {
+            let minimap_rect = Rect::from_min_size(
+                inner_rect.right_top(),
+                Vec2::new(minimap_width, inner_rect.height()),
+            );
+            show_minimap(
+                ui,
+                id.with("minimap"),
+                &mut state,
+                direction_enabled,
+                minimap_rect,
+                content_ui.min_rect(),
+                inner_rect.size(),
+                content_shapes_start,
+            );
+        }
+
         if show_scroll_this_frame != state.show_scroll {
             ui.request_repaint();
         }
@@ -1527,6 +2127,7 @@ impl Prepared {
         let available_offset = content_size - inner_rect.size();
         state.offset = state.offset.min(available_offset);
         state.offset = state.offset.max(Vec2::ZERO);
+        state.previous_offset = state.offset;
 
         let suppress_stuck_recompute = Vec2b::new(
             had_explicit_scroll_adjustment[0] && state.offset_target[0].is_some(),
@@ -1555,10 +2156,198 @@ impl Prepared {
 
         state.store(ui.ctx(), id);
 
-        (content_size, state)
+        (content_size, state, pull_progress, refresh_requested)
+    }
+}
+
+/// Move any offset past the ends of the content into the overscroll while the user is holding on,
+/// or let the overscroll spring back once they let go.
+fn apply_elastic_overscroll(
+    ui: &Ui,
+    state: &mut State,
+    direction_enabled: Vec2b,
+    max_offset: Vec2,
+    is_holding: bool,
+) {
+    let dt = ui.input(|i| i.stable_dt).at_most(0.1);
+
+    for d in 0..2 {
+        if !direction_enabled[d] {
+            state.overscroll[d] = 0.0;
+            continue;
+        }
+
+        if is_holding {
+            let pos = state.offset[d] + state.overscroll[d];
+            state.offset[d] = pos.clamp(0.0, max_offset[d].max(0.0));
+            state.overscroll[d] = pos - state.offset[d];
+        } else if state.overscroll[d] != 0.0 {
+            let spring_speed = 12.0; // How quickly to spring back, per second.
+            state.overscroll[d] *= (-spring_speed * dt).exp();
+            if state.overscroll[d].abs() < 0.5 {
+                state.overscroll[d] = 0.0;
+            }
+            ui.request_repaint();
+        }
     }
 }
 
+/// How far the content moves when pulled `overscroll` past its ends:
+/// less and less the further it is pulled, but never more than `size`.
+fn rubber_band(overscroll: Vec2, size: Vec2) -> Vec2 {
+    let resistance = 0.55;
+    let band = |overscroll: f32, size: f32| {
+        if size <= 0.0 {
+            0.0
+        } else {
+            let pulled = 1.0 - 1.0 / (overscroll.abs() * resistance / size + 1.0);
+            overscroll.signum() * pulled * size
+        }
+    };
+    Vec2::new(band(overscroll.x, size.x), band(overscroll.y, size.y))
+}
+
+/// Paint the content scaled down into `minimap_rect`, with the visible part highlighted,
+/// and scroll to wherever the user clicks or drags in it.
+#[expect(clippy::too_many_arguments)]
+fn show_minimap(
+    ui: &Ui,
+    id: Id,
+    state: &mut State,
+    direction_enabled: Vec2b,
+    minimap_rect: Rect,
+    content_rect: Rect,
+    inner_size: Vec2,
+    content_shapes_start: usize,
+) {
+    if !content_rect.is_positive() || !minimap_rect.is_positive() {
+        return;
+    }
+
+    // Fit the width, but don't scale up small content more than a minimap should:
+    let scale = (minimap_rect.width() / content_rect.width()).at_most(0.25);
+    let scaled_content_size = scale * content_rect.size();
+    let indicator_size = (scale * inner_size).min(minimap_rect.size());
+    let max_offset = (content_rect.size() - inner_size).at_least(Vec2::ZERO);
+
+    // How far the indicator moves per point of scrolling. If the scaled-down content doesn't fit,
+    // the minimap scrolls too, so that the indicator can reach the end of the minimap.
+    let mut indicator_speed = Vec2::splat(scale);
+    for d in 0..2 {
+        if minimap_rect.size()[d] < scaled_content_size[d] && 0.0 < max_offset[d] {
+            indicator_speed[d] = (minimap_rect.size()[d] - indicator_size[d]) / max_offset[d];
+        }
+    }
+
+    let response = ui.interact(minimap_rect, id, Sense::click_and_drag());
+    if response.is_pointer_button_down_on()
+        && let Some(pointer_pos) = response.interact_pointer_pos()
+    {
+        // Center the view on the pointer:
+        for d in 0..2 {
+            if direction_enabled[d] && 0.0 < indicator_speed[d] {
+                let indicator_min = pointer_pos[d] - 0.5 * indicator_size[d];
+                state.offset[d] = ((indicator_min - minimap_rect.min[d]) / indicator_speed[d])
+                    .clamp(0.0, max_offset[d]);
+                state.offset_target[d] = None;
+                state.vel[d] = 0.0;
+                state.scroll_stuck_to_end[d] = false;
+            }
+        }
+    }
+
+    let indicator_rect = Rect::from_min_size(
+        minimap_rect.min + indicator_speed * state.offset,
+        indicator_size,
+    );
+
+    // How far the minimap itself is scrolled:
+    let minimap_scroll = (Vec2::splat(scale) - indicator_speed) * state.offset;
+    let content_transform = emath::TSTransform::new(
+        minimap_rect.min.to_vec2() - minimap_scroll - scale * content_rect.min.to_vec2(),
+        scale,
+    );
+
+    let content_shapes = ui.ctx().graphics(|graphics| {
+        graphics
+            .get(ui.layer_id())
+            .map_or_else(Vec::new, |paint_list| {
+                paint_list
+                    .all_entries()
+                    .skip(content_shapes_start)
+                    .filter(|clipped| !matches!(clipped.shape, Shape::Callback(_)))
+                    .map(|clipped| clipped.shape.clone())
+                    .collect()
+            })
+    });
+
+    let visuals = ui.visuals();
+    let painter = ui.painter_at(minimap_rect);
+    painter.rect_filled(minimap_rect, 0.0, visuals.extreme_bg_color);
+    painter.extend(content_shapes.into_iter().map(|mut shape| {
+        shape.transform(content_transform);
+        shape
+    }));
+    painter.rect(
+        indicator_rect,
+        0.0,
+        visuals.selection.bg_fill.gamma_multiply(0.3),
+        visuals.widgets.noninteractive.bg_stroke,
+        epaint::StrokeKind::Inside,
+    );
+}
+
+/// Compensate the scroll offset for any movement of the anchor widget since last pass,
+/// and pick the anchor for the next one.
+fn apply_scroll_anchoring(
+    ui: &Ui,
+    state: &mut State,
+    direction_enabled: Vec2b,
+    inner_rect: Rect,
+    content_origin: Pos2,
+    num_widgets_before_content: usize,
+) {
+    let (anchor_rect, new_anchor) = ui.ctx().pass_state(|pass_state| {
+        let anchor_rect = state
+            .anchor
+            .and_then(|(id, _)| pass_state.widgets.get(id))
+            .map(|w| w.rect);
+
+        // The first widget that starts inside the view:
+        let new_anchor = pass_state
+            .widgets
+            .get_layer(ui.layer_id())
+            .skip(num_widgets_before_content)
+            .filter(|w| w.rect.is_positive() && inner_rect.contains(w.rect.min))
+            .min_by(|a, b| {
+                (a.rect.min.y, a.rect.min.x)
+                    .partial_cmp(&(b.rect.min.y, b.rect.min.x))
+                    .unwrap_or(std::cmp::Ordering::Equal)
+            })
+            .map(|w| (w.id, w.rect.min - content_origin));
+
+        (anchor_rect, new_anchor)
+    });
+
+    if let (Some((_, anchor_pos)), Some(anchor_rect)) = (state.anchor, anchor_rect) {
+        let shift = (anchor_rect.min - content_origin) - anchor_pos;
+        let mut shifted = false;
+        for d in 0..2 {
+            // Nothing to keep in place when at the very start.
+            if direction_enabled[d] && state.offset[d] > 0.0 && shift[d].abs() > 0.5 {
+                state.offset[d] += shift[d];
+                shifted = true;
+            }
+        }
+        if shifted {
+            // Lay out again with the new offset, so the content doesn't jump for a frame:
+            ui.ctx().request_discard("ScrollArea anchoring");
+        }
+    }
+
+    state.anchor = new_anchor;
+}
+
 /// Paint fade-out gradients at the top and/or bottom of a scroll area to
 /// indicate that more content is available beyond the visible region.
 fn paint_fade_areas_impl(ui: &Ui, inner_rect: Rect, content_size: Vec2, offset: Vec2) {
@@ -0,0 +1,126 @@
+use emath::{Rect, Vec2, pos2};
+
+use crate::{AsIdSalt, Frame, IdSalt, InnerResponse, Ui, UiBuilder, containers::scroll_area};
+
+/// A section with a header that sticks to the top of the surrounding [`crate::ScrollArea`]
+/// while the rest of the section is visible.
+///
+/// When the next section scrolls up to it, the header is pushed out of view by the next header.
+/// Useful for settings pages and grouped lists.
+///
+/// The header is painted on top of the body, so it gets a background ([`Self::header_frame`]).
+/// Only works in a top-down layout.
+///
+/// ```
+/// # egui::__run_test_ui(|ui| {
+/// egui::ScrollArea::vertical().show(ui, |ui| {
+///     for group in ["Fruit", "Vegetables", "Nuts"] {
+///         egui::StickySection::new(group).show(
+///             ui,
+///             |ui| {
+///                 ui.strong(group);
+///             },
+///             |ui| {
+///                 for i in 0..20 {
+///                     ui.label(format!("{group} #{i}"));
+///                 }
+///             },
+///         );
+///     }
+/// });
+/// # });
+/// ```
+#[must_use = "You should call .show()"]
+#[derive(Clone, Debug)]
+pub struct StickySection {
+    id_salt: IdSalt,
+    header_frame: Option<Frame>,
+}
+
+impl StickySection {
+    /// The `id_salt` must be unique among the sections in the same [`Ui`].
+    pub fn new(id_salt: impl AsIdSalt) -> Self {
+        Self {
+            id_salt: IdSalt::new(id_salt),
+            header_frame: None,
+        }
+    }
+
+    /// The frame around the header.
+    ///
+    /// It should have a background fill, to hide the body scrolling behind the header.
+    /// The default has the fill of [`crate::Visuals::panel_fill`].
+    #[inline]
+    pub fn header_frame(mut self, header_frame: Frame) -> Self {
+        self.header_frame = Some(header_frame);
+        self
+    }
+
+    pub fn show<R>(
+        self,
+        ui: &mut Ui,
+        add_header: impl FnOnce(&mut Ui),
+        add_body: impl FnOnce(&mut Ui) -> R,
+    ) -> InnerResponse<R> {
+        let Self {
+            id_salt,
+            header_frame,
+        } = self;
+
+        let id = ui.make_persistent_id(id_salt);
+        let header_frame =
+            header_frame.unwrap_or_else(|| Frame::NONE.fill(ui.visuals().panel_fill));
+
+        ui.scope(|ui| {
+            // The header is shown after the body, so we reserve the size it had last frame.
+            let header_height: Option<f32> = ui.data(|data| data.get_temp(id));
+            let header_top = ui.cursor().min.y;
+            let (header_rect, _) = ui.allocate_exact_size(
+                Vec2::new(ui.available_width(), header_height.unwrap_or_default()),
+                crate::Sense::hover(),
+            );
+
+            let inner = add_body(ui);
+
+            let section_bottom = ui.min_rect().bottom();
+            let height = header_rect.height();
+            let sticky_top = visible_top(ui).min(section_bottom - height).max(header_top);
+
+            let mut header_ui = ui.new_child(UiBuilder::new().id_salt(id.with("header")).max_rect(
+                Rect::from_min_max(
+                    pos2(header_rect.left(), sticky_top),
+                    pos2(header_rect.right(), f32::INFINITY),
+                ),
+            ));
+            header_frame.show(&mut header_ui, |ui| {
+                ui.set_min_width(ui.available_width());
+                add_header(ui);
+            });
+
+            let new_height = header_ui.min_rect().height();
+            if header_height != Some(new_height) {
+                ui.data_mut(|data| data.insert_temp(id, new_height));
+                ui.ctx()
+                    .request_discard("StickySection header size changed");
+            }
+
+            inner
+        })
+    }
+}
+
+/// The top of the visible part of the surrounding [`crate::ScrollArea`], if any.
+fn visible_top(ui: &Ui) -> f32 {
+    let viewport = ui
+        .stack()
+        .iter()
+        .find_map(|stack| {
+            stack
+                .info
+                .tags
+                .get_downcast::<Rect>(scroll_area::VIEWPORT_TAG)
+        })
+        .copied();
+    let clip_top = ui.clip_rect().top();
+    viewport.map_or(clip_top, |viewport| viewport.top().max(clip_top))
+}
@@ -1,9 +1,9 @@
 use crate::pass_state::PerWidgetTooltipState;
 use crate::{
     AreaState, Context, Id, InnerResponse, LayerId, Layout, Order, Popup, PopupAnchor, PopupKind,
-    Response, Sense,
+    Response, Sense, TooltipPosition,
 };
-use emath::Vec2;
+use emath::{Pos2, Vec2};
 
 pub struct Tooltip<'a> {
     pub popup: Popup<'a>,
@@ -13,6 +13,24 @@ pub struct Tooltip<'a> {
 
     /// The id of the widget that owns this tooltip.
     parent_widget: Id,
+
+    /// If set, only show the tooltip when this widget is hovered.
+    trigger: Option<HoverTrigger>,
+
+    position: TooltipPosition,
+
+    /// Overrides [`crate::style::Interaction::tooltip_delay`].
+    delay: Option<f32>,
+
+    /// Overrides [`crate::style::Interaction::tooltip_hide_delay`].
+    hide_delay: Option<f32>,
+}
+
+struct HoverTrigger {
+    response: Response,
+
+    /// Show the tooltip if the widget is enabled (`true`) or disabled (`false`).
+    enabled: bool,
 }
 
 impl Tooltip<'_> {
@@ -32,51 +50,100 @@ impl Tooltip<'_> {
                 .sense(Sense::hover()),
             parent_layer,
             parent_widget,
+            trigger: None,
+            position: TooltipPosition::Widget,
+            delay: None,
+            hide_delay: None,
         }
     }
 
     /// Show a tooltip for a widget. Always open (as long as this function is called).
     pub fn for_widget(response: &Response) -> Self {
+        let style = response.ctx.global_style();
+        let width = style
+            .spacing
+            .tooltip_ui_width
+            .unwrap_or(style.spacing.tooltip_width);
         let popup = Popup::from_response(response)
             .kind(PopupKind::Tooltip)
-            .gap(4.0)
-            .width(response.ctx.global_style().spacing.tooltip_width)
+            .width(width)
             .sense(Sense::hover());
         Self {
             popup,
             parent_layer: response.layer_id,
             parent_widget: response.id,
+            trigger: None,
+            position: TooltipPosition::Widget,
+            delay: None,
+            hide_delay: None,
         }
+        .position(style.interaction.tooltip_position)
     }
 
     /// Show a tooltip when hovering an enabled widget.
     pub fn for_enabled(response: &Response) -> Self {
         let mut tooltip = Self::for_widget(response);
-        tooltip.popup = tooltip
-            .popup
-            .open(response.enabled() && Self::should_show_tooltip(response, true));
+        tooltip.trigger = Some(HoverTrigger {
+            response: response.clone(),
+            enabled: true,
+        });
         tooltip
     }
 
     /// Show a tooltip when hovering a disabled widget.
     pub fn for_disabled(response: &Response) -> Self {
         let mut tooltip = Self::for_widget(response);
-        tooltip.popup = tooltip
-            .popup
-            .open(!response.enabled() && Self::should_show_tooltip(response, true));
+        tooltip.trigger = Some(HoverTrigger {
+            response: response.clone(),
+            enabled: false,
+        });
         tooltip
     }
 
     /// Show the tooltip at the pointer position.
+    ///
+    /// Shorthand for `.position(TooltipPosition::FollowPointer)`.
+    #[inline]
+    pub fn at_pointer(self) -> Self {
+        self.position(TooltipPosition::FollowPointer)
+    }
+
+    /// Where to show the tooltip.
+    ///
+    /// This also sets the [`Self::gap`], so that the pointer doesn't cover the tooltip.
+    ///
+    /// Default: [`crate::style::Interaction::tooltip_position`].
+    #[inline]
+    pub fn position(mut self, position: TooltipPosition) -> Self {
+        self.position = position;
+        self.popup = self.popup.gap(match position {
+            TooltipPosition::Widget => 4.0,
+            TooltipPosition::FollowPointer | TooltipPosition::Pointer => 12.0,
+        });
+        self
+    }
+
+    /// Seconds to wait before showing the tooltip.
+    ///
+    /// Default: [`crate::style::Interaction::tooltip_delay`].
+    #[inline]
+    pub fn delay(mut self, seconds: f32) -> Self {
+        self.delay = Some(seconds);
+        self
+    }
+
+    /// Seconds to keep the tooltip open after the pointer leaves the widget.
+    ///
+    /// Default: [`crate::style::Interaction::tooltip_hide_delay`].
     #[inline]
-    pub fn at_pointer(mut self) -> Self {
-        self.popup = self.popup.at_pointer();
+    pub fn hide_delay(mut self, seconds: f32) -> Self {
+        self.hide_delay = Some(seconds);
         self
     }
 
     /// Set the gap between the tooltip and the anchor
     ///
-    /// Default: 5.0
+    /// Default: 4.0, or 12.0 when shown at the pointer.
     #[inline]
     pub fn gap(mut self, gap: f32) -> Self {
         self.popup = self.popup.gap(gap);
@@ -91,6 +158,8 @@ impl Tooltip<'_> {
     }
 
     /// Set the width of the tooltip
+    ///
+    /// Default: [`crate::style::Spacing::tooltip_ui_width`].
     #[inline]
     pub fn width(mut self, width: f32) -> Self {
         self.popup = self.popup.width(width);
@@ -103,12 +172,47 @@ impl Tooltip<'_> {
             mut popup,
             parent_layer,
             parent_widget,
+            trigger,
+            position,
+            delay,
+            hide_delay,
         } = self;
 
+        if let Some(HoverTrigger { response, enabled }) = trigger {
+            let interaction = &response.ctx.global_style().interaction;
+            let open = response.enabled() == enabled
+                && Self::should_show_tooltip_with_delays(
+                    &response,
+                    true,
+                    delay.unwrap_or(interaction.tooltip_delay),
+                    hide_delay.unwrap_or(interaction.tooltip_hide_delay),
+                );
+            popup = popup.open(open);
+        }
+
         if !popup.is_open() {
             return None;
         }
 
+        match position {
+            TooltipPosition::Widget => {}
+            TooltipPosition::FollowPointer => {
+                popup = popup.at_pointer();
+            }
+            TooltipPosition::Pointer => {
+                let ctx = popup.ctx().clone();
+                let pos_id = parent_widget.with("tooltip_pointer_pos");
+                let mut pos = ctx.data(|d| d.get_temp::<Pos2>(pos_id));
+                if pos.is_none() || !Self::was_tooltip_open_last_frame(&ctx, parent_widget) {
+                    pos = ctx.pointer_hover_pos().or(pos);
+                }
+                if let Some(pos) = pos {
+                    ctx.data_mut(|d| d.insert_temp(pos_id, pos));
+                    popup = popup.anchor(pos);
+                }
+            }
+        }
+
         let rect = popup.get_anchor_rect()?;
 
         let mut state = popup.ctx().pass_state_mut(|fs| {
@@ -197,6 +301,55 @@ impl Tooltip<'_> {
     /// Argument `allow_interactive_tooltip` controls whether mouse can interact with tooltip that
     /// contains interactive widgets
     pub fn should_show_tooltip(response: &Response, allow_interactive_tooltip: bool) -> bool {
+        let interaction = &response.ctx.global_style().interaction;
+        Self::should_show_tooltip_with_delays(
+            response,
+            allow_interactive_tooltip,
+            interaction.tooltip_delay,
+            interaction.tooltip_hide_delay,
+        )
+    }
+
+    fn should_show_tooltip_with_delays(
+        response: &Response,
+        allow_interactive_tooltip: bool,
+        tooltip_delay: f32,
+        tooltip_hide_delay: f32,
+    ) -> bool {
+        let last_shown_id = response.id.with("tooltip_last_wanted");
+        let now = response.ctx.input(|i| i.time);
+
+        if Self::should_show_tooltip_now(response, allow_interactive_tooltip, tooltip_delay) {
+            response
+                .ctx
+                .data_mut(|d| d.insert_temp::<f64>(last_shown_id, now));
+            return true;
+        }
+
+        // Keep the tooltip open for a while after the pointer leaves the widget,
+        // unless the user clicked somewhere:
+        if 0.0 < tooltip_hide_delay
+            && response.is_tooltip_open()
+            && !response.ctx.input(|i| i.pointer.any_pressed())
+        {
+            let last_wanted = response.ctx.data(|d| d.get_temp::<f64>(last_shown_id));
+            let time_since_wanted = last_wanted.map_or(f32::INFINITY, |t| (now - t) as f32);
+            if time_since_wanted < tooltip_hide_delay {
+                response
+                    .ctx
+                    .request_repaint_after_secs(tooltip_hide_delay - time_since_wanted);
+                return true;
+            }
+        }
+
+        false
+    }
+
+    fn should_show_tooltip_now(
+        response: &Response,
+        allow_interactive_tooltip: bool,
+        tooltip_delay: f32,
+    ) -> bool {
         if response.ctx.memory(|mem| mem.everything_is_visible()) {
             return true;
         }
@@ -213,7 +366,6 @@ impl Tooltip<'_> {
 
         let style = response.ctx.global_style();
 
-        let tooltip_delay = style.interaction.tooltip_delay;
         let tooltip_grace_time = style.interaction.tooltip_grace_time;
 
         let (
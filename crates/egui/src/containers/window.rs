@@ -4,6 +4,7 @@ use emath::GuiRounding as _;
 use epaint::CornerRadiusF32;
 
 use crate::collapsing_header::CollapsingState;
+use crate::localization::BuiltinString;
 use crate::*;
 
 use super::scroll_area::{DragScroll, ScrollBarVisibility, ScrollSource};
@@ -57,6 +58,99 @@ impl WindowDrag {
     }
 }
 
+/// A part of the available area a [`Window`] can be tiled to by dragging it to an edge.
+///
+/// See [`Window::snap_zones`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub enum WindowTile {
+    LeftHalf,
+    RightHalf,
+    TopHalf,
+    BottomHalf,
+    TopLeftQuarter,
+    TopRightQuarter,
+    BottomLeftQuarter,
+    BottomRightQuarter,
+}
+
+impl WindowTile {
+    /// How close (in points) the pointer must be to an edge to snap to it.
+    const EDGE_DISTANCE: f32 = 16.0;
+
+    /// How close (in points) the pointer must be to a corner, along the edge, to snap to a quarter.
+    const CORNER_DISTANCE: f32 = 64.0;
+
+    /// The part of `available` this tile covers.
+    pub fn rect_in(self, available: Rect) -> Rect {
+        let center = available.center();
+        let (x_range, y_range) = match self {
+            Self::LeftHalf => (available.min.x..=center.x, available.y_range().into()),
+            Self::RightHalf => (center.x..=available.max.x, available.y_range().into()),
+            Self::TopHalf => (available.x_range().into(), available.min.y..=center.y),
+            Self::BottomHalf => (available.x_range().into(), center.y..=available.max.y),
+            Self::TopLeftQuarter => (available.min.x..=center.x, available.min.y..=center.y),
+            Self::TopRightQuarter => (center.x..=available.max.x, available.min.y..=center.y),
+            Self::BottomLeftQuarter => (available.min.x..=center.x, center.y..=available.max.y),
+            Self::BottomRightQuarter => (center.x..=available.max.x, center.y..=available.max.y),
+        };
+        Rect::from_x_y_ranges(x_range, y_range).round_ui()
+    }
+
+    /// The tile whose snap zone contains `pointer`, if any.
+    pub fn at(pointer: Pos2, available: Rect) -> Option<Self> {
+        let near = |distance: f32, limit: f32| distance.abs() <= limit;
+        let left = near(pointer.x - available.min.x, Self::EDGE_DISTANCE);
+        let right = near(available.max.x - pointer.x, Self::EDGE_DISTANCE);
+        let top = near(pointer.y - available.min.y, Self::EDGE_DISTANCE);
+        let bottom = near(available.max.y - pointer.y, Self::EDGE_DISTANCE);
+        let corner_left = near(pointer.x - available.min.x, Self::CORNER_DISTANCE);
+        let corner_right = near(available.max.x - pointer.x, Self::CORNER_DISTANCE);
+        let corner_top = near(pointer.y - available.min.y, Self::CORNER_DISTANCE);
+        let corner_bottom = near(available.max.y - pointer.y, Self::CORNER_DISTANCE);
+
+        if (left && corner_top) || (top && corner_left) {
+            Some(Self::TopLeftQuarter)
+        } else if (right && corner_top) || (top && corner_right) {
+            Some(Self::TopRightQuarter)
+        } else if (left && corner_bottom) || (bottom && corner_left) {
+            Some(Self::BottomLeftQuarter)
+        } else if (right && corner_bottom) || (bottom && corner_right) {
+            Some(Self::BottomRightQuarter)
+        } else if left {
+            Some(Self::LeftHalf)
+        } else if right {
+            Some(Self::RightHalf)
+        } else if top {
+            Some(Self::TopHalf)
+        } else if bottom {
+            Some(Self::BottomHalf)
+        } else {
+            None
+        }
+    }
+}
+
+/// Persisted tiling state of a [`Window`] with [`Window::snap_zones`] enabled.
+#[derive(Clone, Copy, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+struct SnapState {
+    tile: Option<WindowTile>,
+
+    /// Outer size of the window before it was tiled, restored when it is dragged out again.
+    restore_size: Option<Vec2>,
+}
+
+impl SnapState {
+    fn load(ctx: &Context, id: Id) -> Self {
+        ctx.data_mut(|d| d.get_persisted(id)).unwrap_or_default()
+    }
+
+    fn store(self, ctx: &Context, id: Id) {
+        ctx.data_mut(|d| d.insert_persisted(id, self));
+    }
+}
+
 /// Builder for a floating window which can be dragged, closed, collapsed, resized and scrolled (off by default).
 ///
 /// You can customize:
@@ -92,6 +186,8 @@ pub struct Window<'a> {
     fade_out: bool,
     auto_sized: bool,
     drag_area: WindowDrag,
+    snap_zones: bool,
+    transition: Option<Transition>,
 }
 
 impl<'a> Window<'a> {
@@ -117,6 +213,8 @@ impl<'a> Window<'a> {
             fade_out: true,
             auto_sized: false,
             drag_area: WindowDrag::default(),
+            snap_zones: false,
+            transition: None,
         }
     }
 
@@ -215,6 +313,21 @@ impl<'a> Window<'a> {
         self
     }
 
+    /// Tile the window when it is dragged to an edge of the available area.
+    ///
+    /// Releasing the window near an edge tiles it to that half of the area,
+    /// and near a corner to that quarter ([`WindowTile`]). A preview of the tile is shown while dragging.
+    /// Dragging a tiled window away restores its previous size, and resizing it un-tiles it.
+    ///
+    /// The tiled state is persisted, and the window follows the available area when it changes.
+    ///
+    /// Default: `false`.
+    #[inline]
+    pub fn snap_zones(mut self, snap_zones: bool) -> Self {
+        self.snap_zones = snap_zones;
+        self
+    }
+
     /// `order(Order::Foreground)` for a Window that should always be on top
     #[inline]
     pub fn order(mut self, order: Order) -> Self {
@@ -242,6 +355,16 @@ impl<'a> Window<'a> {
         self
     }
 
+    /// How the window appears, and disappears when closed with [`Self::open`].
+    ///
+    /// Default: [`crate::Style::window_transition`].
+    #[inline]
+    pub fn transition(mut self, transition: Transition) -> Self {
+        self.area = self.area.transition(transition);
+        self.transition = Some(transition);
+        self
+    }
+
     /// Usage: `Window::new(…).mutate(|w| w.resize = w.resize.auto_expand_width(true))`
     // TODO(emilk): I'm not sure this is a good interface for this.
     #[inline]
@@ -557,6 +680,8 @@ impl Window<'_> {
             fade_out,
             auto_sized,
             drag_area: drag_area_setting,
+            snap_zones,
+            transition,
         } = self;
 
         // `Window::movable(false)` (and `Area::movable(false)`) and
@@ -624,12 +749,33 @@ impl Window<'_> {
 
         let is_explicitly_closed = matches!(open, Some(false));
         let is_open = !is_explicitly_closed || ctx.memory(|mem| mem.everything_is_visible());
+
+        if ctx.options(|o| o.widget_events) {
+            let was_open = ctx.data_mut(|d| {
+                std::mem::replace(d.get_temp_mut_or(area.id.with("was_open"), false), is_open)
+            });
+            if was_open != is_open {
+                let kind = if is_open {
+                    WidgetEventKind::WindowOpened
+                } else {
+                    WidgetEventKind::WindowClosed
+                };
+                ctx.widget_event(area.id, kind, || {
+                    WidgetInfo::labeled(
+                        WidgetType::Window,
+                        true,
+                        title.text().as_deref().unwrap_or(""),
+                    )
+                });
+            }
+        }
+        let transition = transition.unwrap_or(style.window_transition);
         let opacity = ctx.animate_bool_with_easing(
             area.id.with("fade-out"),
             is_open,
             emath::easing::cubic_out,
         );
-        if opacity <= 0.0 {
+        if opacity <= 0.0 || (!is_open && transition == Transition::NONE) {
             return None;
         }
 
@@ -674,6 +820,55 @@ impl Window<'_> {
             )
         });
 
+        let snap_id = area_id.with("snap");
+        let mut snap = SnapState::default();
+        let is_being_moved = snap_zones && {
+            let move_id = match effective_drag {
+                WindowDrag::TitleBar => Some(area_id.with("__title_click")),
+                WindowDrag::Anywhere => Some(area_id.with("move")),
+                WindowDrag::Off | WindowDrag::OnTouch => None,
+            };
+            move_id
+                .and_then(|id| ctx.read_response(id))
+                .is_some_and(|response| response.dragged())
+        };
+        if snap_zones {
+            snap = SnapState::load(ctx, snap_id);
+            let margins = window_frame.total_margin().sum();
+            let constrain_rect = area.constrain_rect();
+            let state = area.state_mut();
+
+            if let Some(tile) = snap.tile {
+                let new_rect = if is_being_moved {
+                    // Dragged out of the tile: restore the old size under the pointer.
+                    let rect = state.rect();
+                    let restore_size = snap.restore_size.unwrap_or_else(|| rect.size());
+                    let pointer = ctx.input(|i| i.pointer.interact_pos()).unwrap_or(rect.min);
+                    let t = (pointer.x - rect.min.x) / rect.width().at_least(1.0);
+                    let left = pointer.x - t * restore_size.x;
+                    snap = SnapState::default();
+                    Rect::from_min_size(pos2(left, rect.min.y), restore_size)
+                } else {
+                    tile.rect_in(constrain_rect)
+                };
+
+                if state.rect() != new_rect {
+                    state.set_left_top_pos(new_rect.min);
+                    if let Some(mut resize_state) = resize::State::load(ctx, resize_id) {
+                        resize_state.set_size((new_rect.size() - margins).at_least(Vec2::ZERO));
+                        resize_state.store(ctx, resize_id);
+                    }
+                }
+            }
+
+            if is_being_moved
+                && let Some(pointer) = ctx.input(|i| i.pointer.interact_pos())
+                && let Some(tile) = WindowTile::at(pointer, constrain_rect)
+            {
+                paint_snap_preview(ctx, area_id, tile.rect_in(constrain_rect));
+            }
+        }
+
         {
             // Prevent window from becoming larger than the constrain rect.
             // `resize.max_size` is still in outer-window coordinates here, matching `constrain_rect`.
@@ -701,7 +896,7 @@ impl Window<'_> {
             // `Area` already takes care of fade-in animations,
             // so we only need to handle fade-out animations here.
         } else if fade_out {
-            area_content_ui.multiply_opacity(opacity);
+            area_content_ui.multiply_opacity(transition.opacity(opacity));
         }
 
         let content_inner = {
@@ -751,6 +946,10 @@ impl Window<'_> {
                 window_frame,
             );
 
+            if resize_interaction.any_dragged() {
+                snap.tile = None;
+            }
+
             paint_resize_corner(
                 &area_content_ui,
                 &possible,
@@ -780,8 +979,32 @@ impl Window<'_> {
             outer_response.inner
         };
 
+        let area_constrain_rect = area.constrain_rect();
         let full_response = area.end(ctx, area_content_ui);
 
+        if !is_open && fade_out {
+            ctx.transform_layer_shapes(
+                area_layer_id,
+                transition.transform(opacity, full_response.rect, style.reduce_motion),
+            );
+        }
+
+        if snap_zones {
+            let move_response = match effective_drag {
+                WindowDrag::TitleBar => ctx.read_response(area_id.with("__title_click")),
+                WindowDrag::Anywhere => Some(full_response.clone()),
+                WindowDrag::Off | WindowDrag::OnTouch => None,
+            };
+            if move_response.is_some_and(|response| response.drag_stopped())
+                && let Some(pointer) = ctx.input(|i| i.pointer.interact_pos())
+                && let Some(tile) = WindowTile::at(pointer, area_constrain_rect)
+            {
+                snap.restore_size = Some(full_response.rect.size());
+                snap.tile = Some(tile);
+            }
+            snap.store(ctx, snap_id);
+        }
+
         if full_response.should_close()
             && let Some(open) = open
         {
@@ -796,6 +1019,22 @@ impl Window<'_> {
     }
 }
 
+/// Show where a [`Window`] will be tiled if it is released now.
+fn paint_snap_preview(ctx: &Context, area_id: Id, tile_rect: Rect) {
+    let visuals = &ctx.global_style().visuals;
+    let painter = ctx.layer_painter(LayerId::new(
+        Order::Foreground,
+        area_id.with("snap_preview"),
+    ));
+    painter.rect(
+        tile_rect.shrink(visuals.selection.stroke.width),
+        visuals.window_corner_radius,
+        visuals.selection.bg_fill.gamma_multiply(0.25),
+        visuals.selection.stroke,
+        StrokeKind::Inside,
+    );
+}
+
 fn paint_resize_corner(
     ui: &Ui,
     possible: &PossibleInteractions,
@@ -1367,12 +1606,14 @@ fn title_ui(
             child_ui.auto_id_with("collapse_button"),
             Sense::click(),
         );
+        // Localize outside of `widget_info`, which may be called while the context is locked:
+        let label = child_ui.ctx().localize(if collapsing.is_open() {
+            BuiltinString::WindowCollapse
+        } else {
+            BuiltinString::WindowExpand
+        });
         icon_response.widget_info(|| {
-            WidgetInfo::labeled(
-                WidgetType::Button,
-                child_ui.is_enabled(),
-                if collapsing.is_open() { "Hide" } else { "Show" },
-            )
+            WidgetInfo::labeled(WidgetType::Button, child_ui.is_enabled(), label.clone())
         });
         if icon_response.clicked() {
             collapsing.toggle(&child_ui);
@@ -1454,8 +1695,10 @@ fn title_ui(
 fn close_button(ui: &mut Ui, rect: Rect) -> Response {
     let close_id = ui.auto_id_with("window_close_button");
     let response = ui.interact(rect, close_id, Sense::click());
+    // Localize outside of `widget_info`, which may be called while the context is locked:
+    let label = ui.ctx().localize(BuiltinString::WindowClose);
     response
-        .widget_info(|| WidgetInfo::labeled(WidgetType::Button, ui.is_enabled(), "Close window"));
+        .widget_info(|| WidgetInfo::labeled(WidgetType::Button, ui.is_enabled(), label.clone()));
 
     ui.expand_to_include_rect(response.rect);
 
@@ -30,6 +30,7 @@ use crate::{
     interaction::InteractionSnapshot,
     layers::GraphicLayers,
     load::{self, Bytes, Loaders, SizedTexture},
+    localization::BuiltinString,
     memory::{Options, Theme},
     os::OperatingSystem,
     output::FullOutput,
@@ -372,6 +373,7 @@ struct ContextImpl {
     animation_manager: AnimationManager,
 
     plugins: plugin::Plugins,
+    memory_migrations: crate::memory::MemoryMigrations,
     safe_area: SafeAreaInsets,
 
     /// All viewports share the same texture manager and texture namespace.
@@ -397,6 +399,8 @@ struct ContextImpl {
 
     request_repaint_callback: Option<Box<dyn Fn(RequestRepaintInfo) + Send + Sync>>,
 
+    localizer: Option<Box<crate::localization::Localizer>>,
+
     viewport_parents: ViewportIdMap<ViewportId>,
     viewports: ViewportIdMap<ViewportState>,
 
@@ -417,6 +421,12 @@ impl ContextImpl {
             .unwrap_or_default();
         let ids = ViewportIdPair::from_self_and_parent(viewport_id, parent_id);
 
+        // Persisted memory may have been loaded since the last pass:
+        self.memory_migrations.migrate(&mut self.memory);
+
+        let safe_area_changed = new_raw_input
+            .safe_area_insets
+            .is_some_and(|safe_area| safe_area != self.safe_area);
         if let Some(safe_area) = new_raw_input.safe_area_insets {
             self.safe_area = safe_area;
         }
@@ -465,6 +475,10 @@ impl ContextImpl {
 
         viewport.this_pass.begin_pass();
 
+        // The on-screen keyboard (or some other system UI) appeared or went away.
+        // Make sure the focused widget (e.g. a `TextEdit`) stays visible:
+        viewport.this_pass.scroll_focused_into_view = safe_area_changed;
+
         {
             let mut layers: Vec<LayerId> = viewport.prev_pass.widgets.layer_ids().collect();
             layers.sort_by(|&a, &b| self.memory.areas().compare_order(a, b));
@@ -1230,8 +1244,17 @@ impl Context {
 
         self.write(|ctx| {
             use crate::{Align, pass_state::ScrollTarget, style::ScrollAnimation};
+            let has_focus = ctx.memory.has_focus(res.id);
+            let animation = ctx.memory.options.style().scroll_animation;
             let viewport = ctx.viewport_for(ctx.viewport_id());
 
+            if viewport.this_pass.scroll_focused_into_view && has_focus {
+                viewport.this_pass.scroll_target = [
+                    Some(ScrollTarget::new(res.rect.x_range(), None, animation)),
+                    Some(ScrollTarget::new(res.rect.y_range(), None, animation)),
+                ];
+            }
+
             viewport
                 .input
                 .consume_accesskit_action_requests(res.id, |request| {
@@ -1515,6 +1538,24 @@ impl Context {
         }
     }
 
+    /// Record a [`crate::WidgetEvent`] in [`PlatformOutput::widget_events`],
+    /// if [`crate::Options::widget_events`] is enabled.
+    ///
+    /// Widgets that call [`Response::widget_info`] get this automatically.
+    pub fn widget_event(
+        &self,
+        id: Id,
+        kind: crate::WidgetEventKind,
+        make_info: impl FnOnce() -> crate::WidgetInfo,
+    ) {
+        if self.options(|options| options.widget_events) {
+            let info = make_info();
+            self.output_mut(|o| {
+                o.widget_events.push(crate::WidgetEvent { id, kind, info });
+            });
+        }
+    }
+
     /// Get a full-screen painter for a new or existing layer
     pub fn layer_painter(&self, layer_id: LayerId) -> Painter {
         let content_rect = self.content_rect();
@@ -1592,6 +1633,28 @@ impl Context {
         self.output_mut(|o| o.cursor_image = image);
     }
 
+    /// How much the integration should scale custom cursor images (see [`Self::set_cursor_image`]) by.
+    ///
+    /// This is [`crate::Options::cursor_scale`] if set,
+    /// else the OS cursor size reported in [`crate::RawInput::system_cursor_scale`],
+    /// else `1.0`.
+    pub fn cursor_scale(&self) -> f32 {
+        let system_cursor_scale = self.input(|i| i.raw.system_cursor_scale);
+        self.options(|o| o.cursor_scale)
+            .or(system_cursor_scale)
+            .unwrap_or(1.0)
+    }
+
+    /// Keep the OS cursor inside this rectangle (in global points) for this frame.
+    ///
+    /// Call this every frame for as long as the cursor should stay confined,
+    /// e.g. while a color picker or joystick is being dragged.
+    ///
+    /// See [`PlatformOutput::pointer_confinement`] and [`crate::Response::confine_pointer`].
+    pub fn confine_pointer(&self, rect: Rect) {
+        self.output_mut(|o| o.pointer_confinement = Some(rect));
+    }
+
     /// Add a command to [`PlatformOutput::commands`],
     /// for the integration to execute at the end of the frame.
     pub fn send_cmd(&self, cmd: crate::OutputCommand) {
@@ -1628,6 +1691,13 @@ impl Context {
         self.send_cmd(crate::OutputCommand::CopyImage(image));
     }
 
+    /// Copy the given files to the system clipboard, to be pasted into e.g. a file manager.
+    ///
+    /// This is not supported on web.
+    pub fn copy_files(&self, paths: Vec<std::path::PathBuf>) {
+        self.send_cmd(crate::OutputCommand::CopyFiles(paths));
+    }
+
     fn can_show_modifier_symbols(&self) -> bool {
         let ModifierNames {
             alt,
@@ -1898,6 +1968,46 @@ impl Context {
         self.write(|ctx| ctx.request_repaint_callback = Some(callback));
     }
 
+    /// Translate the strings that egui's own widgets show, e.g. in the color picker.
+    ///
+    /// The localizer returns `None` for strings it has no translation for,
+    /// in which case the English default is used.
+    /// It must not call back into the [`Context`].
+    ///
+    /// Any new call overrides the previous localizer.
+    /// See [`crate::localization`] for more.
+    pub fn set_localizer(
+        &self,
+        localizer: impl Fn(BuiltinString) -> Option<String> + Send + Sync + 'static,
+    ) {
+        let localizer = Box::new(localizer);
+        self.write(|ctx| ctx.localizer = Some(localizer));
+    }
+
+    /// Translate the strings that egui's own widgets show, using key/value pairs.
+    ///
+    /// The keys are given by [`BuiltinString::key`], e.g. `("color_picker.hue", "Farbton")`.
+    /// Strings missing from the map are shown in English.
+    ///
+    /// This is a convenience for [`Self::set_localizer`].
+    pub fn set_translations(&self, translations: impl IntoIterator<Item = (String, String)>) {
+        let translations = translations.into_iter().collect();
+        self.set_localizer(crate::localization::localizer_from_translations(
+            translations,
+        ));
+    }
+
+    /// The text to show for one of egui's built-in strings.
+    ///
+    /// This is translated if a localizer has been set with [`Self::set_localizer`].
+    pub fn localize(&self, string: BuiltinString) -> Cow<'static, str> {
+        let translated = self.read(|ctx| ctx.localizer.as_ref().map(|localizer| localizer(string)));
+        match translated {
+            Some(Some(translated)) => Cow::Owned(translated),
+            _ => Cow::Borrowed(string.english()),
+        }
+    }
+
     /// Request to discard the visual output of this pass,
     /// and to immediately do another one.
     ///
@@ -1972,7 +2082,9 @@ impl Context {
 
     /// Register a [`Plugin`](plugin::Plugin)
     ///
-    /// Plugins are called in the order they are added.
+    /// Plugins are called in the order they are added,
+    /// except where [`Plugin::run_after`](plugin::Plugin::run_after) or
+    /// [`Plugin::run_before`](plugin::Plugin::run_before) say otherwise.
     ///
     /// A plugin of the same type can only be added once (further calls with the same type will be ignored).
     /// This way it's convenient to add plugins in `eframe::run_simple_native`.
@@ -1986,6 +2098,32 @@ impl Context {
         }
     }
 
+    /// Enable or disable the plugin of type `T`.
+    ///
+    /// The hooks of a disabled plugin are not called, but it stays registered,
+    /// and can still be accessed with e.g. [`Self::with_plugin`].
+    /// It is safe to call this from within a plugin hook; it takes effect from the next hook.
+    ///
+    /// Disabling a plugin that isn't registered (yet) is remembered.
+    pub fn set_plugin_enabled<T: plugin::Plugin + 'static>(&self, enabled: bool) {
+        self.write(|ctx| {
+            ctx.plugins
+                .set_enabled(std::any::TypeId::of::<T>(), enabled);
+        });
+    }
+
+    /// Is the plugin of type `T` registered and enabled?
+    ///
+    /// See [`Self::set_plugin_enabled`].
+    pub fn is_plugin_enabled<T: plugin::Plugin + 'static>(&self) -> bool {
+        self.read(|ctx| ctx.plugins.is_enabled(std::any::TypeId::of::<T>()))
+    }
+
+    /// The names of all registered plugins, in the order their hooks are called.
+    pub fn plugin_names(&self) -> Vec<&'static str> {
+        self.read(|ctx| ctx.plugins.names())
+    }
+
     /// Call the provided closure with the plugin of type `T`, if it was registered.
     ///
     /// Returns `None` if the plugin was not registered.
@@ -2379,6 +2517,10 @@ impl Context {
             crate::gui_zoom::zoom_with_keyboard(self);
         }
 
+        if self.options(|o| o.focus_follows_mouse) {
+            crate::focus_follows_mouse::update(self);
+        }
+
         for shortcut in self.options(|o| o.quit_shortcuts.clone()) {
             if self.input_mut(|i| i.consume_shortcut(&shortcut)) {
                 self.send_viewport_cmd(ViewportCommand::Close);
@@ -2583,6 +2725,24 @@ impl ContextImpl {
 
         let mut platform_output: PlatformOutput = std::mem::take(&mut viewport.output);
 
+        if let Some(summary) = &mut platform_output.focus_summary {
+            summary.position = viewport.this_pass.widgets.focusable_position(summary.id);
+        }
+
+        platform_output.focused_rect = self
+            .memory
+            .focused()
+            .and_then(|id| viewport.this_pass.widgets.get(id))
+            .map(|widget| {
+                let to_global = self
+                    .memory
+                    .to_global
+                    .get(&widget.layer_id)
+                    .copied()
+                    .unwrap_or_default();
+                to_global * widget.rect
+            });
+
         if self.memory.should_interrupt_ime()
             && let Some(ime) = &mut platform_output.ime
         {
@@ -2766,7 +2926,10 @@ impl Context {
         // it takes to tessellate them, so it is not a worth optimization.
 
         self.write(|ctx| {
-            let tessellation_options = ctx.memory.options.tessellation_options;
+            let options = &ctx.memory.options;
+            let tessellation_options = options
+                .pixel_snapping
+                .tessellation_options(options.tessellation_options);
             let texture_atlas = if let Some(fonts) = ctx.fonts.as_ref() {
                 fonts.texture_atlas()
             } else {
@@ -3176,12 +3339,82 @@ impl Context {
         animated_value
     }
 
+    /// Play an [`Animation`](crate::animation::Animation), e.g. a [`Tween`](crate::animation::Tween)
+    /// or [`Keyframes`](crate::animation::Keyframes), and return its current value.
+    ///
+    /// The animation starts the first time this is called with `id`, and then stays at its end
+    /// value once finished, until restarted with [`Self::restart_tween`].
+    /// Pass the same animation each pass; changing it changes the curve but not the start time.
+    ///
+    /// The function will call [`Self::request_repaint()`] while the animation is playing.
+    ///
+    /// See [`crate::animation`] for an example.
+    #[track_caller] // To track repaint cause
+    pub fn tween<A: crate::animation::Animation>(
+        &self,
+        id: Id,
+        animation: &A,
+    ) -> crate::animation::TweenResponse<A::Value> {
+        let duration = animation.duration();
+        let (elapsed, finished, just_finished) = self.write(|ctx| {
+            ctx.animation_manager.tween(
+                &ctx.viewports.entry(ctx.viewport_id()).or_default().input,
+                id,
+                duration,
+            )
+        });
+        if !finished {
+            self.request_repaint();
+        }
+
+        crate::animation::TweenResponse {
+            value: animation.sample(elapsed),
+            elapsed,
+            finished,
+            just_finished,
+        }
+    }
+
+    /// Make the animation played with [`Self::tween`] with this `id` start over next time.
+    pub fn restart_tween(&self, id: Id) {
+        self.write(|ctx| ctx.animation_manager.restart_tween(id));
+    }
+
     /// Clear memory of any animations.
     pub fn clear_animations(&self) {
         self.write(|ctx| ctx.animation_manager = Default::default());
     }
 }
 
+/// ## Persistence
+impl Context {
+    /// Register a migration that upgrades persisted [`Memory`] to `version`.
+    ///
+    /// At the start of each pass, all migrations with a version newer than [`Memory::version`]
+    /// are run, oldest first, and [`Memory::version`] is set to the version of the last one.
+    /// This means migrations registered after the memory was loaded (e.g. in your app creator)
+    /// still run before any widget reads the memory.
+    ///
+    /// Memory saved before the first migration was registered has version `0`.
+    /// Fresh memory also starts at version `0`, so migrations should do nothing
+    /// if there is nothing to migrate.
+    ///
+    /// ```
+    /// # let ctx = egui::Context::default();
+    /// // Version 1: the settings window was renamed.
+    /// ctx.add_memory_migration(1, |memory| {
+    ///     memory.rename_area(egui::Id::new("Settings"), egui::Id::new("Preferences"));
+    /// });
+    /// ```
+    pub fn add_memory_migration(
+        &self,
+        version: u32,
+        migration: impl Fn(&mut Memory) + Send + Sync + 'static,
+    ) {
+        self.write(|ctx| ctx.memory_migrations.add(version, Arc::new(migration)));
+    }
+}
+
 impl Context {
     /// Show a ui for settings (style and tessellation options).
     pub fn settings_ui(&self, ui: &mut Ui) {
@@ -3416,6 +3649,8 @@ impl Context {
         let mut byte_loaders = vec![];
         let mut image_loaders = vec![];
         let mut texture_loaders = vec![];
+        let num_queued;
+        let num_running;
 
         {
             let loaders = self.loaders();
@@ -3424,8 +3659,12 @@ impl Context {
                 bytes,
                 image,
                 texture,
+                scheduler,
             } = loaders.as_ref();
 
+            num_queued = scheduler.num_queued();
+            num_running = scheduler.num_running();
+
             for loader in bytes.lock().iter() {
                 byte_loaders.push(LoaderInfo {
                     id: loader.id().to_owned(),
@@ -3471,6 +3710,8 @@ impl Context {
         loaders_ui(ui, "byte", &byte_loaders);
         loaders_ui(ui, "image", &image_loaders);
         loaders_ui(ui, "texture", &texture_loaders);
+
+        ui.label(format!("{num_running} loads running, {num_queued} queued"));
     }
 
     /// Shows the contents of [`Self::memory`].
@@ -3487,6 +3728,28 @@ impl Context {
         ui.label(format!(
             "{num_state} widget states stored (of which {num_serialized} are serialized)."
         ));
+        ui.collapsing("Widget states by type", |ui| {
+            let (stats, pass_nr) = self.data(|d| (d.stats_per_type(), d.pass_nr()));
+            Grid::new("widget_state_stats")
+                .striped(true)
+                .show(ui, |ui| {
+                    ui.strong("Type");
+                    ui.strong("Count");
+                    ui.strong("Bytes");
+                    ui.strong("Idle passes");
+                    ui.end_row();
+                    for stats in stats {
+                        ui.monospace(stats.type_name.unwrap_or("(serialized)"));
+                        ui.label(stats.count.to_string());
+                        ui.label(stats.size_in_bytes.to_string());
+                        ui.label((pass_nr - stats.oldest_touched_pass).to_string())
+                            .on_hover_text(
+                                "Passes since the least recently used value was accessed",
+                            );
+                        ui.end_row();
+                    }
+                });
+        });
 
         ui.horizontal(|ui| {
             ui.label(format!(
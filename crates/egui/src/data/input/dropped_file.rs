@@ -6,14 +6,22 @@ pub struct DroppedFile {
     pub path: Option<std::path::PathBuf>,
 
     /// Name of the file. Set by the `eframe` web backend.
+    ///
+    /// Also set by `egui-winit` when reading dropped files (see `State::set_read_dropped_files`).
     pub name: String,
 
     /// With the `eframe` web backend, this is set to the mime-type of the file (if available).
+    ///
+    /// Also set by `egui-winit` when reading dropped files, if its `mime_guess` feature is enabled.
     pub mime: String,
 
     /// Set by the `eframe` web backend.
+    ///
+    /// Also set by `egui-winit` when reading dropped files.
     pub last_modified: Option<std::time::SystemTime>,
 
     /// Set by the `eframe` web backend.
+    ///
+    /// Also set by `egui-winit` when reading dropped files that are small enough.
     pub bytes: Option<std::sync::Arc<[u8]>>,
 }
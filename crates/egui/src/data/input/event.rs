@@ -6,7 +6,8 @@ use crate::{
 };
 
 use super::{
-    ImeEvent, Modifiers, MouseWheelUnit, PointerButton, TouchDeviceId, TouchId, TouchPhase,
+    ImeEvent, KeyLocation, Modifiers, MouseWheelUnit, PointerButton, PointerSource, TouchDeviceId,
+    TouchId, TouchPhase,
 };
 
 /// An input event generated by the integration.
@@ -24,6 +25,19 @@ pub enum Event {
     /// The integration detected a "paste" event (e.g. Cmd+V).
     Paste(String),
 
+    /// The integration detected a "paste" event, and the clipboard contains files,
+    /// e.g. copied in Explorer, Finder or Nautilus.
+    ///
+    /// This comes before any [`Self::Paste`] of the text on the clipboard,
+    /// which is often the names of the files.
+    PasteFiles(Vec<std::path::PathBuf>),
+
+    /// The contents of the system clipboard changed, e.g. because the user copied something
+    /// in another application.
+    ///
+    /// Only sent by integrations that watch the clipboard, see `egui_winit::State::set_watch_clipboard`.
+    ClipboardChanged,
+
     /// Text input, e.g. via keyboard.
     ///
     /// When the user presses enter/return, do not send a [`Text`](Event::Text) (just [`Key::Enter`]).
@@ -53,6 +67,14 @@ pub enum Event {
         /// `eframe` does not (yet) implement this on web.
         physical_key: Option<Key>,
 
+        /// Where on the keyboard the key is.
+        ///
+        /// This lets you tell e.g. numpad `Enter` from the main `Enter`,
+        /// which both have [`Key::Enter`] as their `key`.
+        ///
+        /// Integrations that can't tell should use [`KeyLocation::Standard`].
+        location: KeyLocation,
+
         /// Was it pressed or released?
         pressed: bool,
 
@@ -141,6 +163,57 @@ pub enum Event {
         force: Option<f32>,
     },
 
+    /// A stylus or pen touched or moved on the screen.
+    ///
+    /// Like [`Self::Touch`], report this *in addition to*
+    /// [`Self::PointerMoved`], [`Self::PointerButton`] and [`Self::PointerGone`],
+    /// and send [`Self::PointerGone`] when the pen leaves.
+    ///
+    /// There can be many of these per frame, so drawing apps should iterate over all of them
+    /// rather than only looking at [`crate::PointerState::pen`].
+    PointerPen {
+        /// Where is the tip of the pen?
+        pos: Pos2,
+
+        /// How hard the pen is pressed, from 0.0 (hovering) to 1.0 (maximum pressure).
+        pressure: f32,
+
+        /// How far the pen is tilted away from perpendicular to the screen, in radians.
+        ///
+        /// `0` is upright, and `π/2` is lying flat on the screen.
+        /// `None` if the platform does not report it.
+        tilt: Option<f32>,
+
+        /// The direction the pen is tilted towards, in radians clockwise from the positive x-axis.
+        ///
+        /// `None` if the platform does not report it.
+        azimuth: Option<f32>,
+
+        /// Is the barrel (side) button of the pen held down?
+        barrel_button: bool,
+
+        /// Is the eraser end of the pen being used?
+        eraser: bool,
+    },
+
+    /// How hard a force-sensitive touchpad (e.g. on a Mac) is pressed.
+    ///
+    /// Can be used for force-click previews, or pressure-sensitive widgets.
+    /// See also [`crate::PointerState::force`].
+    PointerForce {
+        /// From 0.0 (not pressed) to 1.0 (maximum pressure).
+        force: f32,
+
+        /// The click level: 0 is no click, 1 a normal click, and 2 a force click.
+        stage: u8,
+    },
+
+    /// The pointer events that follow come from this kind of device.
+    ///
+    /// Only sent when it changes, so until the first one, assume [`PointerSource::Mouse`].
+    /// See [`crate::PointerState::source`].
+    PointerSource(PointerSource),
+
     /// A raw mouse wheel event as sent by the backend.
     ///
     /// Used for scrolling.
@@ -164,6 +237,13 @@ pub enum Event {
         /// If unknown set this to [`TouchPhase::Move`].
         phase: TouchPhase,
 
+        /// Is this momentum (kinetic) scrolling, i.e. the platform continuing the scroll
+        /// after the user lifted their fingers from the trackpad?
+        ///
+        /// A momentum scroll has its own [`TouchPhase::Start`] and [`TouchPhase::End`].
+        /// If unknown set this to `false`.
+        momentum: bool,
+
         /// The state of the modifier keys at the time of the event.
         modifiers: Modifiers,
     },
@@ -171,6 +251,14 @@ pub enum Event {
     /// The native window gained or lost focused (e.g. the user clicked alt-tab).
     WindowFocused(bool),
 
+    /// The keyboard layout changed, e.g. from QWERTY to AZERTY.
+    ///
+    /// The same physical keys now produce different [`Key`]s,
+    /// so this is a good time to refresh any shortcut hints you show.
+    ///
+    /// See [`crate::InputState::keyboard_layout_changed`].
+    KeyboardLayoutChanged,
+
     /// An assistive technology (e.g. screen reader) requested an action.
     AccessKitActionRequest(accesskit::ActionRequest),
 
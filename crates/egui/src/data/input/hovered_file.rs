@@ -1,3 +1,5 @@
+use crate::emath::Pos2;
+
 /// A file about to be dropped into egui.
 #[derive(Clone, Debug, Default, PartialEq, Eq)]
 #[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
@@ -6,5 +8,18 @@ pub struct HoveredFile {
     pub path: Option<std::path::PathBuf>,
 
     /// With the `eframe` web backend, this is set to the mime-type of the file (if available).
+    ///
+    /// The `egui-winit` backend guesses it from the file extension, if its `mime_guess` feature is enabled.
     pub mime: String,
+
+    /// The size of the file in bytes, if known.
+    ///
+    /// Set by the `egui-winit` backend, usually a frame or so after the file starts being hovered.
+    pub size: Option<u64>,
+
+    /// Where the file is being hovered, in points, if known.
+    ///
+    /// Not all platforms report the pointer position during a drag,
+    /// so this may be where the pointer entered the window.
+    pub pos: Option<Pos2>,
 }
@@ -0,0 +1,13 @@
+/// Where on the keyboard a key is, for keys that appear more than once.
+///
+/// See [`crate::Event::Key::location`].
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub enum KeyLocation {
+    /// On the main part of the keyboard.
+    #[default]
+    Standard,
+
+    /// On the numeric keypad, e.g. numpad `Enter` or the numpad digits.
+    Numpad,
+}
@@ -5,11 +5,13 @@ mod event;
 mod event_filter;
 mod hovered_file;
 mod ime_event;
+mod key_location;
 mod keyboard_shortcut;
 mod modifier_names;
 mod modifiers;
 mod mouse_wheel_unit;
 mod pointer_button;
+mod pointer_source;
 mod raw_input;
 mod safe_area_insets;
 mod touch;
@@ -21,13 +23,15 @@ pub use self::{
     event_filter::EventFilter,
     hovered_file::HoveredFile,
     ime_event::ImeEvent,
+    key_location::KeyLocation,
     keyboard_shortcut::KeyboardShortcut,
     modifier_names::ModifierNames,
     modifiers::Modifiers,
     mouse_wheel_unit::MouseWheelUnit,
     pointer_button::{NUM_POINTER_BUTTONS, PointerButton},
+    pointer_source::PointerSource,
     raw_input::RawInput,
     safe_area_insets::SafeAreaInsets,
     touch::{TouchDeviceId, TouchId, TouchPhase},
-    viewport_info::{ViewportEvent, ViewportInfo},
+    viewport_info::{MonitorInfo, VideoMode, ViewportEvent, ViewportInfo},
 };
@@ -0,0 +1,18 @@
+/// What kind of device is driving the pointer.
+///
+/// See [`crate::Event::PointerSource`] and [`crate::PointerState::source`].
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub enum PointerSource {
+    /// A mouse or trackpad.
+    #[default]
+    Mouse,
+
+    /// A finger on a touchscreen.
+    ///
+    /// Widgets can use this to e.g. show draggable selection handles, since there is no hover.
+    Touch,
+
+    /// A stylus or pen.
+    Pen,
+}
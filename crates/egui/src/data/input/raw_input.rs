@@ -81,6 +81,15 @@ pub struct RawInput {
     ///
     /// `None` means "don't know".
     pub system_theme: Option<Theme>,
+
+    /// How much larger than normal the OS draws its cursors,
+    /// e.g. because of a large-cursor accessibility setting.
+    ///
+    /// `1.0` is the default size, `2.0` is twice as large.
+    /// `None` means "don't know".
+    ///
+    /// See [`crate::Context::cursor_scale`].
+    pub system_cursor_scale: Option<f32>,
 }
 
 impl Default for RawInput {
@@ -98,6 +107,7 @@ impl Default for RawInput {
             dropped_files: Default::default(),
             focused: true, // integrations opt into global focus tracking
             system_theme: None,
+            system_cursor_scale: None,
             safe_area_insets: Default::default(),
         }
     }
@@ -133,6 +143,7 @@ impl RawInput {
             dropped_files: std::mem::take(&mut self.dropped_files),
             focused: self.focused,
             system_theme: self.system_theme,
+            system_cursor_scale: self.system_cursor_scale,
         }
     }
 
@@ -151,6 +162,7 @@ impl RawInput {
             mut dropped_files,
             focused,
             system_theme,
+            system_cursor_scale,
             safe_area_insets: safe_area,
         } = newer;
 
@@ -166,6 +178,7 @@ impl RawInput {
         self.dropped_files.append(&mut dropped_files);
         self.focused = focused;
         self.system_theme = system_theme;
+        self.system_cursor_scale = system_cursor_scale;
         self.safe_area_insets = safe_area;
     }
 }
@@ -185,6 +198,7 @@ impl RawInput {
             dropped_files,
             focused,
             system_theme,
+            system_cursor_scale,
             safe_area_insets: safe_area,
         } = self;
 
@@ -215,6 +229,7 @@ impl RawInput {
         ui.label(format!("dropped_files: {}", dropped_files.len()));
         ui.label(format!("focused: {focused}"));
         ui.label(format!("system_theme: {system_theme:?}"));
+        ui.label(format!("system_cursor_scale: {system_cursor_scale:?}"));
         ui.label(format!("safe_area: {safe_area:?}"));
         ui.scope(|ui| {
             ui.set_min_height(150.0);
@@ -84,6 +84,84 @@ pub struct ViewportInfo {
     /// Not all platforms support this.
     /// On platforms that don't, this will be `None` or `Some(false)`.
     pub occluded: Option<bool>,
+
+    /// The window level last set with [`crate::ViewportBuilder::with_window_level`]
+    /// or [`crate::ViewportCommand::WindowLevel`], if any.
+    pub window_level: Option<crate::viewport::WindowLevel>,
+
+    /// How much of the window is covered by system UI, in ui points.
+    ///
+    /// This includes status and navigation bars, display cutouts,
+    /// and the on-screen keyboard when it is showing.
+    /// The same insets are also given as [`RawInput::safe_area_insets`](crate::RawInput::safe_area_insets).
+    ///
+    /// Use this to pad e.g. a top panel so that it isn't covered by a notch or the dynamic island,
+    /// including in landscape orientation.
+    ///
+    /// Only reported on Android and iOS; `None` elsewhere.
+    pub window_insets: Option<crate::epaint::MarginF32>,
+
+    /// The connected monitors.
+    ///
+    /// The index into this is the one used by [`crate::ViewportCommand::SetMonitor`]
+    /// and [`crate::ViewportCommand::ExclusiveFullscreen`].
+    ///
+    /// Listing the video modes can be slow, so this is only queried when the window is created.
+    pub monitors: Option<std::sync::Arc<[MonitorInfo]>>,
+}
+
+/// A monitor, see [`ViewportInfo::monitors`].
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct MonitorInfo {
+    /// Name of the monitor, if known.
+    pub name: Option<String>,
+
+    /// Current resolution, in physical pixels.
+    pub size_in_pixels: [u32; 2],
+
+    /// The OS native pixels-per-point of the monitor.
+    pub native_pixels_per_point: f32,
+
+    /// Current refresh rate, if known.
+    pub refresh_rate_millihertz: Option<u32>,
+
+    /// The video modes the monitor supports in exclusive fullscreen.
+    pub video_modes: Vec<VideoMode>,
+}
+
+/// A resolution, bit depth and refresh rate that a monitor supports,
+/// for [`crate::ViewportCommand::ExclusiveFullscreen`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct VideoMode {
+    /// Resolution, in physical pixels.
+    pub size_in_pixels: [u32; 2],
+
+    /// Bits per pixel, e.g. `32`.
+    pub bit_depth: u16,
+
+    /// E.g. `60_000` for 60 Hz.
+    pub refresh_rate_millihertz: u32,
+}
+
+impl VideoMode {
+    /// Refresh rate in Hz.
+    pub fn refresh_rate_hz(&self) -> f32 {
+        self.refresh_rate_millihertz as f32 / 1000.0
+    }
+}
+
+impl std::fmt::Display for VideoMode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let [width, height] = self.size_in_pixels;
+        write!(
+            f,
+            "{width}×{height} @ {} Hz, {} bit",
+            self.refresh_rate_hz(),
+            self.bit_depth
+        )
+    }
 }
 
 impl ViewportInfo {
@@ -127,6 +205,9 @@ impl ViewportInfo {
             fullscreen: self.fullscreen,
             focused: self.focused,
             occluded: self.occluded,
+            window_level: self.window_level,
+            window_insets: self.window_insets,
+            monitors: self.monitors.clone(),
         }
     }
 
@@ -144,6 +225,9 @@ impl ViewportInfo {
             fullscreen,
             focused,
             occluded,
+            window_level,
+            window_insets,
+            monitors,
         } = self;
 
         crate::Grid::new("viewport_info").show(ui, |ui| {
@@ -195,6 +279,24 @@ impl ViewportInfo {
             ui.label(opt_as_str(occluded));
             ui.end_row();
 
+            ui.label("Window level:");
+            ui.label(opt_as_str(window_level));
+            ui.end_row();
+
+            ui.label("Window insets:");
+            ui.label(opt_as_str(window_insets));
+            ui.end_row();
+
+            ui.label("Monitors:");
+            ui.label(monitors.as_ref().map_or(String::new(), |monitors| {
+                let names: Vec<_> = monitors
+                    .iter()
+                    .map(|m| m.name.as_deref().unwrap_or("?"))
+                    .collect();
+                names.join(", ")
+            }));
+            ui.end_row();
+
             let visible = self.visible();
 
             ui.label("Visible:");
@@ -4,7 +4,7 @@ use std::ops::Range;
 
 use epaint::text::CharIndex;
 
-use crate::{OrderedViewportIdMap, RepaintCause, ViewportOutput, WidgetType};
+use crate::{Id, OrderedViewportIdMap, RepaintCause, ViewportOutput, WidgetType};
 
 /// What egui emits each frame from [`crate::Context::run_ui`].
 ///
@@ -84,8 +84,46 @@ pub struct IMEOutput {
     /// This is a very thin rectangle.
     pub cursor_rect: crate::Rect,
 
+    /// The row of the primary cursor, from the start of the ongoing IME composition
+    /// (if it is on the same row) to the cursor.
+    ///
+    /// It is kept within the visible part of the [`crate::TextEdit`],
+    /// and is where the IME candidate window should be placed next to.
+    pub cursor_row_rect: crate::Rect,
+
     /// Whether any ongoing IME composition should be interrupted.
     pub should_interrupt_composition: bool,
+
+    /// What kind of text is being edited, so that e.g. an on-screen keyboard can pick a fitting layout.
+    pub purpose: InputPurpose,
+}
+
+/// What kind of text a [`crate::TextEdit`] is for.
+///
+/// This is a hint to the integration, used e.g. to show the right on-screen keyboard
+/// on mobile devices. It does not restrict what can be typed.
+///
+/// Set with [`crate::TextEdit::input_purpose`].
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub enum InputPurpose {
+    /// Any text.
+    #[default]
+    Normal,
+
+    /// A password, or other secret.
+    ///
+    /// Set automatically by [`crate::TextEdit::password`].
+    Password,
+
+    /// An e-mail address.
+    Email,
+
+    /// A number, possibly with decimals.
+    Number,
+
+    /// A URL.
+    Url,
 }
 
 /// Commands that the egui integration should execute at the end of a frame.
@@ -102,6 +140,9 @@ pub enum OutputCommand {
     /// Put this image to the system clipboard.
     CopyImage(crate::ColorImage),
 
+    /// Put these files to the system clipboard, to be pasted into e.g. a file manager.
+    CopyFiles(Vec<std::path::PathBuf>),
+
     /// Open this url in a browser.
     OpenUrl(OpenUrl),
 }
@@ -133,6 +174,25 @@ pub struct PlatformOutput {
     /// Events that may be useful to e.g. a screen reader.
     pub events: Vec<OutputEvent>,
 
+    /// Semantic events about widgets, e.g. for analytics or macro recording.
+    ///
+    /// Only collected if [`crate::Options::widget_events`] is enabled.
+    pub widget_events: Vec<WidgetEvent>,
+
+    /// Set on the pass where a widget gained keyboard focus, describing that widget.
+    ///
+    /// Useful for apps that voice their own ui with a text-to-speech engine,
+    /// without relying on a full screen reader. See [`FocusSummary::text`].
+    pub focus_summary: Option<FocusSummary>,
+
+    /// Where the widget with keyboard focus is, in screen coordinates (points).
+    ///
+    /// Set on every pass while a widget has focus.
+    /// The integrations don't forward this anywhere: screen magnifiers such as Windows Magnifier
+    /// and macOS Zoom already follow focus through AccessKit. It is here for apps that want
+    /// to drive a magnifier or similar without AccessKit.
+    pub focused_rect: Option<crate::Rect>,
+
     /// Is there a mutable [`TextEdit`](crate::TextEdit) under the cursor?
     /// Use by `eframe` web to show/hide mobile keyboard and IME agent.
     pub mutable_text_under_cursor: bool,
@@ -145,6 +205,17 @@ pub struct PlatformOutput {
     /// events (see [`crate::Memory::owns_ime_events`]).
     pub ime: Option<IMEOutput>,
 
+    /// Keep the OS cursor inside this rectangle (in points), e.g. while dragging
+    /// a color picker, a virtual joystick or a game viewport embedded in a tool.
+    ///
+    /// The integration grabs the cursor and moves it back whenever it leaves the rectangle.
+    /// Where the cursor can't be moved (e.g. on Wayland), the pointer position reported
+    /// to egui is clamped to the rectangle instead.
+    ///
+    /// This must be requested each frame, with [`crate::Context::confine_pointer`]
+    /// or [`crate::Response::confine_pointer`], and is released the first frame it is not.
+    pub pointer_confinement: Option<crate::Rect>,
+
     /// The difference in the widget tree since last frame.
     ///
     /// NOTE: this needs to be per-viewport.
@@ -193,8 +264,12 @@ impl PlatformOutput {
             cursor_icon,
             cursor_image,
             mut events,
+            mut widget_events,
+            focus_summary,
+            focused_rect,
             mutable_text_under_cursor,
             ime,
+            pointer_confinement,
             accesskit_update,
             num_completed_passes,
             mut request_discard_reasons,
@@ -204,8 +279,12 @@ impl PlatformOutput {
         self.cursor_icon = cursor_icon;
         self.cursor_image = cursor_image;
         self.events.append(&mut events);
+        self.widget_events.append(&mut widget_events);
+        self.focus_summary = focus_summary.or_else(|| self.focus_summary.take());
+        self.focused_rect = focused_rect;
         self.mutable_text_under_cursor = mutable_text_under_cursor;
         self.ime = ime.or(self.ime);
+        self.pointer_confinement = pointer_confinement.or(self.pointer_confinement);
         self.num_completed_passes += num_completed_passes;
         self.request_discard_reasons
             .append(&mut request_discard_reasons);
@@ -292,16 +371,55 @@ pub enum UserAttentionType {
 /// `u16` to match winit's native types and avoid a lossy cast in the
 /// integration layer.
 ///
-/// `Arc<[u8]>` is used so integrations can dedupe / cache by pointer
-/// identity (`Arc::ptr_eq`) and avoid re-uploading the same bitmap to
-/// the OS every frame.
-#[derive(Clone, PartialEq, Eq)]
+/// `Arc<[u8]>` is used so integrations can cheaply tell that the image
+/// hasn't changed (`Arc::ptr_eq`). Integrations also cache by content hash,
+/// so it is fine to rebuild the same image every frame, and to switch
+/// between several cursors.
+#[derive(Clone, PartialEq, Eq, Hash)]
 pub struct CustomCursorImage {
     pub rgba: std::sync::Arc<[u8]>,
     pub size: [u16; 2],
     pub hotspot: [u16; 2],
 }
 
+impl CustomCursorImage {
+    /// Resize the image (and hotspot) by the given factor, e.g. [`crate::Context::cursor_scale`].
+    ///
+    /// Uses nearest-neighbor sampling, to keep pixel-art cursors crisp.
+    pub fn scaled(&self, scale: f32) -> Self {
+        let [w, h] = self.size.map(usize::from);
+        let new_size = self
+            .size
+            .map(|side| ((side as f32 * scale).round() as u16).max(1));
+        if new_size == self.size || self.rgba.len() != 4 * w * h {
+            return self.clone();
+        }
+
+        let [new_w, new_h] = new_size.map(usize::from);
+        let mut rgba = Vec::with_capacity(4 * new_w * new_h);
+        for y in 0..new_h {
+            let src_y = (y * h / new_h).min(h - 1);
+            for x in 0..new_w {
+                let src_x = (x * w / new_w).min(w - 1);
+                let i = 4 * (src_y * w + src_x);
+                rgba.extend_from_slice(&self.rgba[i..i + 4]);
+            }
+        }
+
+        let hotspot = [0, 1].map(|i| {
+            let hotspot =
+                (self.hotspot[i] as f32 * new_size[i] as f32 / self.size[i] as f32).round() as u16;
+            hotspot.min(new_size[i] - 1)
+        });
+
+        Self {
+            rgba: rgba.into(),
+            size: new_size,
+            hotspot,
+        }
+    }
+}
+
 impl std::fmt::Debug for CustomCursorImage {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         f.debug_struct("CustomCursorImage")
@@ -532,6 +650,95 @@ impl std::fmt::Debug for OutputEvent {
     }
 }
 
+/// A semantic event about a widget, in [`PlatformOutput::widget_events`].
+///
+/// Only collected if [`crate::Options::widget_events`] is enabled.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct WidgetEvent {
+    /// The [`Id`] of the widget (or window).
+    pub id: Id,
+
+    /// What happened.
+    pub kind: WidgetEventKind,
+
+    /// The widget, after the event.
+    pub info: WidgetInfo,
+}
+
+/// A concise description of the widget that just gained keyboard focus,
+/// in [`PlatformOutput::focus_summary`].
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct FocusSummary {
+    /// The [`Id`] of the focused widget.
+    pub id: Id,
+
+    /// The focused widget.
+    pub info: WidgetInfo,
+
+    /// Position among the focusable widgets in the same parent [`crate::Ui`],
+    /// as `(position, count)` with the position starting at 1.
+    ///
+    /// `None` if the widget is the only one.
+    pub position: Option<(usize, usize)>,
+}
+
+impl FocusSummary {
+    /// Describe the focused widget for a text-to-speech system,
+    /// e.g. `"Dark mode: checked checkbox, 2 of 5"`.
+    pub fn text(&self) -> String {
+        let description = self.info.description();
+        if let Some((position, count)) = self.position {
+            format!("{description}, {position} of {count}")
+        } else {
+            description
+        }
+    }
+}
+
+/// What happened in a [`WidgetEvent`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub enum WidgetEventKind {
+    /// The widget was clicked.
+    Clicked,
+
+    /// The widget was double-clicked.
+    DoubleClicked,
+
+    /// The widget was triple-clicked.
+    TripleClicked,
+
+    /// The widget gained keyboard focus.
+    FocusGained,
+
+    /// The text selection in the widget changed.
+    TextSelectionChanged,
+
+    /// The value of the widget changed, e.g. a checkbox was toggled or a slider dragged.
+    ValueChanged,
+
+    /// A [`crate::Window`] was opened, or shown for the first time.
+    WindowOpened,
+
+    /// A [`crate::Window`] was closed, e.g. with its close button.
+    WindowClosed,
+}
+
+impl From<&OutputEvent> for WidgetEventKind {
+    fn from(event: &OutputEvent) -> Self {
+        match event {
+            OutputEvent::Clicked(_) => Self::Clicked,
+            OutputEvent::DoubleClicked(_) => Self::DoubleClicked,
+            OutputEvent::TripleClicked(_) => Self::TripleClicked,
+            OutputEvent::FocusGained(_) => Self::FocusGained,
+            OutputEvent::TextSelectionChanged(_) => Self::TextSelectionChanged,
+            OutputEvent::ValueChanged(_) => Self::ValueChanged,
+        }
+    }
+}
+
 /// Describes a widget such as a [`crate::Button`] or a [`crate::TextEdit`].
 #[derive(Clone, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
@@ -0,0 +1,77 @@
+//! Moving keyboard focus to whatever window or panel the pointer is over.
+//!
+//! See [`crate::Options::focus_follows_mouse`].
+
+use crate::{Context, Id, IdMap, LayerId, Order, Pos2, Rect, Vec2};
+
+/// The window or panel containing `pos` on the given layer.
+///
+/// All panels share the background layer, so they are told apart by their rects.
+fn region_at(panel_rects: &[(Id, LayerId, Rect)], layer_id: LayerId, pos: Pos2) -> Id {
+    panel_rects
+        .iter()
+        .rev()
+        .find(|(_, panel_layer, rect)| *panel_layer == layer_id && rect.contains(pos))
+        .map_or(layer_id.id, |(id, _, _)| *id)
+}
+
+/// Move focus to the window or panel under the pointer, if it is not already there.
+///
+/// The widget that loses focus is remembered, so its window or panel can give it
+/// focus back once the pointer returns.
+///
+/// Only a moving pointer moves focus, so keyboard navigation is not undone
+/// by a pointer resting somewhere else.
+pub(crate) fn update(ctx: &Context) {
+    let Some(pos) = ctx.input(|i| {
+        let moved = i.pointer.delta() != Vec2::ZERO && !i.pointer.any_down();
+        i.pointer.hover_pos().filter(|_| moved)
+    }) else {
+        return;
+    };
+
+    let Some(hovered_layer) = ctx.layer_id_at(pos) else {
+        return;
+    };
+    if !matches!(hovered_layer.order, Order::Background | Order::Middle) {
+        // Hovering a popup or tooltip should not steal focus from whatever opened it.
+        return;
+    }
+
+    let focused = ctx.memory(|mem| mem.focused());
+    let (hovered_region, focused_region) = ctx.pass_state(|pass_state| {
+        let hovered_region = region_at(&pass_state.panel_rects, hovered_layer, pos);
+        let focused_region = focused
+            .and_then(|id| pass_state.widgets.get(id))
+            .map(|w| region_at(&pass_state.panel_rects, w.layer_id, w.rect.center()));
+        (hovered_region, focused_region)
+    });
+
+    if focused_region == Some(hovered_region) {
+        return;
+    }
+
+    let storage_id = Id::new("focus_follows_mouse");
+    let remembered = ctx.data_mut(|data| {
+        let remembered = data.get_temp_mut_or_default::<IdMap<Id>>(storage_id);
+        if let (Some(id), Some(region)) = (focused, focused_region) {
+            remembered.insert(region, id);
+        }
+        remembered.get(&hovered_region).copied()
+    });
+    // The remembered widget may no longer be shown:
+    let remembered =
+        remembered.filter(|id| ctx.pass_state(|pass_state| pass_state.widgets.contains(*id)));
+
+    match (remembered, focused) {
+        (Some(id), _) => {
+            ctx.memory_mut(|mem| mem.request_focus(id));
+            ctx.request_repaint();
+        }
+        (None, Some(id)) => {
+            ctx.memory_mut(|mem| mem.surrender_focus(id));
+            ctx.request_repaint();
+        }
+        (None, None) => {}
+    }
+}
@@ -9,7 +9,7 @@ use crate::{
 use crate::{
     data::input::{
         Event, EventFilter, KeyboardShortcut, Modifiers, NUM_POINTER_BUTTONS, PointerButton,
-        RawInput, TouchDeviceId, ViewportInfo,
+        PointerSource, RawInput, TouchDeviceId, ViewportInfo,
     },
     input_state::wheel_state::WheelState,
 };
@@ -417,6 +417,7 @@ impl InputState {
                     unit,
                     delta,
                     phase,
+                    momentum,
                     modifiers,
                 } => {
                     self.wheel.on_wheel_event(
@@ -426,6 +427,7 @@ impl InputState {
                         *unit,
                         *delta,
                         *phase,
+                        *momentum,
                         *modifiers,
                     );
                 }
@@ -636,6 +638,22 @@ impl InputState {
         self.wheel.is_scrolling()
     }
 
+    /// True while the fingers are on a trackpad that is scrolling,
+    /// i.e. between the start and end of a trackpad scroll gesture.
+    ///
+    /// This is false during the momentum scrolling that follows, see [`Self::is_scroll_momentum`].
+    pub fn is_trackpad_scrolling(&self) -> bool {
+        self.wheel.status == wheel_state::Status::InTouch && !self.wheel.momentum
+    }
+
+    /// True while the platform is scrolling on its own after the user lifted their fingers
+    /// from the trackpad, i.e. the decay of a fling.
+    ///
+    /// Only reported by some backends, see [`crate::Event::MouseWheel::momentum`].
+    pub fn is_scroll_momentum(&self) -> bool {
+        self.wheel.status == wheel_state::Status::InTouch && self.wheel.momentum
+    }
+
     /// How long has it been (in seconds) since the last scroll event?
     #[inline(always)]
     pub fn time_since_last_scroll(&self) -> f32 {
@@ -781,6 +799,16 @@ impl InputState {
         })
     }
 
+    /// Did the keyboard layout change this frame?
+    ///
+    /// If so, refresh any shortcut hints you show, since the same physical keys
+    /// now produce different [`Key`]s.
+    pub fn keyboard_layout_changed(&self) -> bool {
+        self.events
+            .iter()
+            .any(|event| matches!(event, Event::KeyboardLayoutChanged))
+    }
+
     /// Also known as device pixel ratio, > 1 for high resolution screens.
     #[inline(always)]
     pub fn pixels_per_point(&self) -> f32 {
@@ -969,6 +997,31 @@ impl PointerEvent {
     }
 }
 
+/// The latest state of a stylus or pen, from [`Event::PointerPen`].
+///
+/// See [`PointerState::pen`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct PenState {
+    /// Where is the tip of the pen?
+    pub pos: Pos2,
+
+    /// How hard the pen is pressed, from 0.0 (hovering) to 1.0 (maximum pressure).
+    pub pressure: f32,
+
+    /// How far the pen is tilted away from perpendicular to the screen, in radians, if known.
+    pub tilt: Option<f32>,
+
+    /// The direction the pen is tilted towards, in radians clockwise from the positive x-axis, if known.
+    pub azimuth: Option<f32>,
+
+    /// Is the barrel (side) button of the pen held down?
+    pub barrel_button: bool,
+
+    /// Is the eraser end of the pen being used?
+    pub eraser: bool,
+}
+
 /// Mouse or touch state.
 ///
 /// To access the methods of [`PointerState`] you can use the [`crate::Context::input`] function
@@ -1057,6 +1110,15 @@ pub struct PointerState {
     /// All button events that occurred this frame
     pub(crate) pointer_events: Vec<PointerEvent>,
 
+    /// The latest pen state, if a pen is on (or hovering over) the screen.
+    pen: Option<PenState>,
+
+    /// The latest [`Event::PointerForce`], as `(force, stage)`.
+    force: Option<(f32, u8)>,
+
+    /// The latest [`Event::PointerSource`].
+    source: PointerSource,
+
     /// Input state management configuration.
     ///
     /// This gets copied from `egui::Options` at the start of each frame for convenience.
@@ -1084,6 +1146,9 @@ impl Default for PointerState {
             last_last_click_time: f64::NEG_INFINITY,
             last_move_time: f64::NEG_INFINITY,
             pointer_events: vec![],
+            pen: None,
+            force: None,
+            source: PointerSource::Mouse,
             options: Default::default(),
         }
     }
@@ -1197,8 +1262,33 @@ impl PointerState {
 
                     self.down[button as usize] = pressed; // must be done after the above call to `could_any_button_be_click`
                 }
+                Event::PointerPen {
+                    pos,
+                    pressure,
+                    tilt,
+                    azimuth,
+                    barrel_button,
+                    eraser,
+                } => {
+                    self.pen = Some(PenState {
+                        pos: *pos,
+                        pressure: *pressure,
+                        tilt: *tilt,
+                        azimuth: *azimuth,
+                        barrel_button: *barrel_button,
+                        eraser: *eraser,
+                    });
+                }
+                Event::PointerForce { force, stage } => {
+                    self.force = Some((*force, *stage));
+                }
+                Event::PointerSource(source) => {
+                    self.source = *source;
+                }
                 Event::PointerGone => {
                     self.latest_pos = None;
+                    self.pen = None;
+                    self.force = None;
                     // When dragging a slider and the mouse leaves the viewport, we still want the drag to work,
                     // so we don't treat this as a `PointerEvent::Released`.
                     // NOTE: we do NOT clear `self.interact_pos` here. It will be cleared next frame.
@@ -1265,6 +1355,40 @@ impl PointerState {
         self.motion
     }
 
+    /// The latest state of the stylus or pen, if the pointer is one.
+    ///
+    /// This is only the last sample of the frame.
+    /// For drawing, iterate over all [`Event::PointerPen`] events instead.
+    #[inline(always)]
+    pub fn pen(&self) -> Option<PenState> {
+        self.pen
+    }
+
+    /// How hard a force-sensitive touchpad is pressed, from 0.0 to 1.0.
+    ///
+    /// `None` if the integration doesn't report it (see [`Event::PointerForce`]).
+    #[inline(always)]
+    pub fn force(&self) -> Option<f32> {
+        self.force.map(|(force, _)| force)
+    }
+
+    /// The click level of a force-sensitive touchpad:
+    /// 0 is no click, 1 a normal click, and 2 a force click.
+    ///
+    /// `None` if the integration doesn't report it (see [`Event::PointerForce`]).
+    #[inline(always)]
+    pub fn force_stage(&self) -> Option<u8> {
+        self.force.map(|(_, stage)| stage)
+    }
+
+    /// What kind of device is driving the pointer: a mouse, a finger, or a pen.
+    ///
+    /// Always [`PointerSource::Mouse`] if the integration doesn't report it (see [`Event::PointerSource`]).
+    #[inline(always)]
+    pub fn source(&self) -> PointerSource {
+        self.source
+    }
+
     /// Current velocity of pointer.
     ///
     /// This is smoothed over a few frames,
@@ -1669,6 +1793,9 @@ impl PointerState {
             last_last_click_time,
             pointer_events,
             last_move_time,
+            pen,
+            force,
+            source,
             options: _,
         } = self;
 
@@ -1695,5 +1822,8 @@ impl PointerState {
         ui.label(format!("last_last_click_time: {last_last_click_time:#?}"));
         ui.label(format!("last_move_time: {last_move_time:#?}"));
         ui.label(format!("pointer_events: {pointer_events:?}"));
+        ui.label(format!("pen: {pen:?}"));
+        ui.label(format!("force: {force:?}"));
+        ui.label(format!("source: {source:?}"));
     }
 }
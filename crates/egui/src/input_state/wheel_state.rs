@@ -43,6 +43,9 @@ pub struct WheelState {
     /// but we are in a kinetic scroll or in a smoothed scroll.
     pub status: Status,
 
+    /// Is the current [`Status::InTouch`] scroll momentum scrolling, after the user let go?
+    pub momentum: bool,
+
     /// The modifiers at the start of the scroll.
     pub modifiers: Modifiers,
 
@@ -71,6 +74,7 @@ impl Default for WheelState {
     fn default() -> Self {
         Self {
             status: Status::Static,
+            momentum: false,
             modifiers: Default::default(),
             last_wheel_event: f64::NEG_INFINITY,
             unprocessed_wheel_delta: Vec2::ZERO,
@@ -89,12 +93,14 @@ impl WheelState {
         unit: MouseWheelUnit,
         delta: Vec2,
         phase: TouchPhase,
+        momentum: bool,
         latest_modifiers: Modifiers,
     ) {
         self.last_wheel_event = time;
         match phase {
             crate::TouchPhase::Start => {
                 self.status = Status::InTouch;
+                self.momentum = momentum;
                 self.modifiers = latest_modifiers;
             }
             crate::TouchPhase::Move => {
@@ -151,6 +157,7 @@ impl WheelState {
             }
             crate::TouchPhase::End | crate::TouchPhase::Cancel => {
                 self.status = Status::Static;
+                self.momentum = false;
                 self.modifiers = Default::default();
                 self.unprocessed_wheel_delta = Default::default();
                 self.smooth_wheel_delta = Default::default();
@@ -198,6 +205,7 @@ impl WheelState {
     pub fn ui(&self, ui: &mut crate::Ui) {
         let Self {
             status,
+            momentum,
             modifiers,
             last_wheel_event,
             unprocessed_wheel_delta,
@@ -213,6 +221,10 @@ impl WheelState {
                 ui.monospace(format!("{status:?}"));
                 ui.end_row();
 
+                ui.label("momentum");
+                ui.monospace(momentum.to_string());
+                ui.end_row();
+
                 ui.label("modifiers");
                 ui.monospace(format!("{modifiers:?}"));
                 ui.end_row();
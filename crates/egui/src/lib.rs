@@ -388,6 +388,7 @@
 #![expect(clippy::float_cmp)]
 #![expect(clippy::manual_range_contains)]
 
+pub mod animation;
 mod animation_manager;
 mod atomics;
 pub mod cache;
@@ -396,6 +397,7 @@ mod context;
 mod data;
 pub mod debug_text;
 mod drag_and_drop;
+mod focus_follows_mouse;
 pub(crate) mod grid;
 pub mod gui_zoom;
 mod hit_test;
@@ -407,6 +409,7 @@ pub mod introspection;
 pub mod layers;
 mod layout;
 pub mod load;
+pub mod localization;
 mod memory;
 pub mod os;
 mod painter;
@@ -461,14 +464,18 @@ pub mod text {
 
 pub use self::{
     atomics::*,
-    containers::{menu::MenuBar, *},
+    containers::{
+        menu::{MenuBar, MenuItem},
+        *,
+    },
     context::{Context, RepaintCause, RequestRepaintInfo},
     data::{
         Key, UserData,
         input::*,
         output::{
-            self, CursorIcon, CustomCursorImage, FullOutput, OpenUrl, OutputCommand,
-            PlatformOutput, UserAttentionType, WidgetInfo,
+            self, CursorIcon, CustomCursorImage, FocusSummary, FullOutput, InputPurpose, OpenUrl,
+            OutputCommand, PlatformOutput, UserAttentionType, WidgetEvent, WidgetEventKind,
+            WidgetInfo,
         },
     },
     drag_and_drop::DragAndDrop,
@@ -476,16 +483,20 @@ pub use self::{
     grid::Grid,
     id::{AsId, Id, IdMap, IdSet},
     id_salt::{AsIdSalt, IdSalt},
-    input_state::{InputOptions, InputState, MultiTouchInfo, PointerState, SurrenderFocusOn},
+    input_state::{
+        InputOptions, InputState, MultiTouchInfo, PenState, PointerState, SurrenderFocusOn,
+    },
     layers::{LayerId, Order},
     layout::*,
     load::SizeHint,
-    memory::{FocusDirection, Memory, Options, Theme, ThemePreference},
+    memory::{
+        FocusDirection, Memory, MemoryMigration, Options, PixelSnapping, Theme, ThemePreference,
+    },
     painter::Painter,
     plugin::Plugin,
     response::{InnerResponse, Response},
     sense::Sense,
-    style::{FontSelection, Spacing, Style, TextStyle, Visuals},
+    style::{FontSelection, Spacing, Style, TextStyle, TooltipPosition, Transition, Visuals},
     text::{Galley, TextFormat},
     ui::Ui,
     ui_builder::{IdSource, UiBuilder},
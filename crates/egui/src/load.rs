@@ -47,12 +47,18 @@
 //! would cache each file read. A [`TextureLoader`] would cache each combination
 //! of `(URI, TextureOptions)`, and so on.
 //!
+//! Loaders that do slow work in the background (e.g. decoding or downloading images)
+//! should hand it to the [`LoadScheduler`] in [`Loaders::scheduler`],
+//! which limits how many loads run at once, starts the most important ones first,
+//! and cancels loads of images that are no longer shown.
+//!
 //! Each URI will be passed through the loaders as a plain `&str`.
 //! The loaders are free to derive as much meaning from the URI as they wish to.
 //! For example, a loader may determine that it doesn't support loading a specific URI
 //! if the protocol does not match what it expects.
 
 mod bytes_loader;
+mod scheduler;
 mod texture_loader;
 
 use std::{
@@ -69,7 +75,11 @@ use epaint::{ColorImage, TextureHandle, TextureId, Vec2, mutex::Mutex, textures:
 
 use crate::Context;
 
-pub use self::{bytes_loader::DefaultBytesLoader, texture_loader::DefaultTextureLoader};
+pub use self::{
+    bytes_loader::DefaultBytesLoader,
+    scheduler::{LoadPermit, LoadPriority, LoadScheduler},
+    texture_loader::DefaultTextureLoader,
+};
 
 /// Represents a failed attempt at loading an image.
 #[derive(Clone, Debug, PartialEq, Eq)]
@@ -378,6 +388,12 @@ pub enum ImagePoll {
         size: Option<Vec2>,
     },
 
+    /// A low resolution preview of the image is ready, but the full image is still loading.
+    ///
+    /// The preview is shown scaled up to [`ColorImage::source_size`],
+    /// so set that to the size of the full image.
+    Preview { image: Arc<ColorImage> },
+
     /// Image is loaded.
     Ready { image: Arc<ColorImage> },
 }
@@ -603,6 +619,7 @@ pub struct Loaders {
     pub bytes: Mutex<Vec<BytesLoaderImpl>>,
     pub image: Mutex<Vec<ImageLoaderImpl>>,
     pub texture: Mutex<Vec<TextureLoaderImpl>>,
+    pub scheduler: Arc<LoadScheduler>,
 }
 
 impl Default for Loaders {
@@ -613,6 +630,7 @@ impl Default for Loaders {
             image: Mutex::new(Vec::new()),
             // By default we only include `DefaultTextureLoader`.
             texture: Mutex::new(vec![Arc::new(DefaultTextureLoader::default())]),
+            scheduler: Default::default(),
             include,
         }
     }
@@ -626,6 +644,7 @@ impl Loaders {
             bytes,
             image,
             texture,
+            scheduler,
         } = self;
 
         include.end_pass(pass_index);
@@ -638,5 +657,6 @@ impl Loaders {
         for loader in texture.lock().iter() {
             loader.end_pass(pass_index);
         }
+        scheduler.end_pass(pass_index);
     }
 }
@@ -0,0 +1,330 @@
+use std::sync::Arc;
+
+use super::{HashMap, Mutex};
+
+/// How urgently an image should be loaded, compared to other images.
+///
+/// Set it with [`crate::Image::load_priority`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum LoadPriority {
+    /// Load after everything else, e.g. images that are likely to be shown soon.
+    Low,
+
+    #[default]
+    Normal,
+
+    /// Load before everything else, e.g. the image the user is looking at.
+    High,
+}
+
+/// Given to a load started by the [`LoadScheduler`].
+///
+/// Drop it when the load has finished (or failed), to let the next load start.
+pub struct LoadPermit {
+    state: Arc<Mutex<State>>,
+}
+
+impl Drop for LoadPermit {
+    fn drop(&mut self) {
+        let mut state = self.state.lock();
+        state.num_running = state.num_running.saturating_sub(1);
+    }
+}
+
+struct QueuedLoad {
+    uri: String,
+    start: Box<dyn FnOnce(LoadPermit) + Send>,
+    cancel: Box<dyn FnOnce() + Send>,
+
+    /// Used to keep the order stable for loads of the same priority.
+    sequence_nr: u64,
+}
+
+/// What the widgets showing a uri want.
+#[derive(Clone, Copy)]
+struct Request {
+    /// Highest priority requested in [`Self::last_seen`].
+    priority: LoadPriority,
+
+    /// The last pass in which a widget asked for this uri.
+    last_seen: u64,
+
+    /// The last pass in which a widget asked for this uri, and was visible.
+    last_visible: Option<u64>,
+}
+
+struct State {
+    /// The current pass.
+    pass_index: u64,
+    max_concurrent: usize,
+    cancel_after_passes: Option<u64>,
+    num_running: usize,
+    next_sequence_nr: u64,
+    queue: Vec<QueuedLoad>,
+    requests: HashMap<String, Request>,
+}
+
+impl State {
+    /// `None` if the load should not be started yet.
+    fn effective_priority(&self, uri: &str) -> Option<LoadPriority> {
+        match self.requests.get(uri) {
+            // Loads not tied to a widget (e.g. from [`crate::Context::try_load_image`]) just run:
+            None => Some(LoadPriority::Normal),
+            Some(request) => {
+                let visible_recently = request
+                    .last_visible
+                    .is_some_and(|pass| self.pass_index <= pass + 1);
+                visible_recently.then_some(request.priority)
+            }
+        }
+    }
+
+    fn should_cancel(&self, uri: &str) -> bool {
+        let Some(cancel_after_passes) = self.cancel_after_passes else {
+            return false;
+        };
+        self.requests
+            .get(uri)
+            .is_some_and(|request| request.last_seen + cancel_after_passes < self.pass_index)
+    }
+}
+
+/// Decides when slow loads (e.g. decoding or downloading an image) may start.
+///
+/// Loaders hand their slow work to [`Self::schedule`] instead of starting it right away.
+/// At the end of each pass, the scheduler then:
+/// * cancels queued loads of images that no widget has asked for in a while
+///   (see [`Self::set_cancel_after_passes`]),
+/// * starts the queued loads of visible images, highest [`LoadPriority`] first,
+///   until [`Self::max_concurrent`] loads are running.
+///
+/// Loads of images that are laid out but not visible (e.g. scrolled out of view)
+/// stay queued until they become visible.
+/// Loads that no widget has asked for (e.g. from [`crate::Context::try_load_image`])
+/// start with [`LoadPriority::Normal`].
+///
+/// Widgets tell the scheduler what they want with [`Self::request`].
+/// [`crate::Image`] does this for you.
+pub struct LoadScheduler {
+    state: Arc<Mutex<State>>,
+}
+
+impl Default for LoadScheduler {
+    fn default() -> Self {
+        Self {
+            state: Arc::new(Mutex::new(State {
+                pass_index: 0,
+                max_concurrent: 8,
+                cancel_after_passes: Some(2),
+                num_running: 0,
+                next_sequence_nr: 0,
+                queue: Vec::new(),
+                requests: Default::default(),
+            })),
+        }
+    }
+}
+
+impl LoadScheduler {
+    /// The maximum number of loads that may run at the same time.
+    ///
+    /// Default: 8.
+    pub fn max_concurrent(&self) -> usize {
+        self.state.lock().max_concurrent
+    }
+
+    /// See [`Self::max_concurrent`].
+    pub fn set_max_concurrent(&self, max_concurrent: usize) {
+        self.state.lock().max_concurrent = max_concurrent.max(1);
+    }
+
+    /// Queued loads are canceled if no widget has asked for their uri in this many passes.
+    ///
+    /// Loads that have already started are never canceled.
+    /// `None` means never cancel.
+    ///
+    /// Default: `Some(2)`.
+    pub fn cancel_after_passes(&self) -> Option<u64> {
+        self.state.lock().cancel_after_passes
+    }
+
+    /// See [`Self::cancel_after_passes`].
+    pub fn set_cancel_after_passes(&self, cancel_after_passes: Option<u64>) {
+        self.state.lock().cancel_after_passes = cancel_after_passes;
+    }
+
+    /// A widget wants to show `uri` this pass.
+    ///
+    /// If the widget asks for the same uri several times in a pass,
+    /// the highest priority and visibility wins.
+    pub fn request(&self, uri: &str, priority: LoadPriority, visible: bool) {
+        let mut state = self.state.lock();
+        let pass_index = state.pass_index;
+        let request = state.requests.entry(uri.to_owned()).or_insert(Request {
+            priority,
+            last_seen: pass_index,
+            last_visible: None,
+        });
+        if request.last_seen == pass_index {
+            request.priority = request.priority.max(priority);
+        } else {
+            request.priority = priority;
+        }
+        request.last_seen = pass_index;
+        if visible {
+            request.last_visible = Some(pass_index);
+        }
+    }
+
+    /// Queue a slow load of `uri`.
+    ///
+    /// `start` is called at the end of a pass, once the load is allowed to start.
+    /// It is given a [`LoadPermit`], which it should drop when the load is done.
+    ///
+    /// `cancel` is called instead if the load is canceled before it started.
+    /// It should forget the pending `uri`, so that it is queued again if it is asked for again.
+    ///
+    /// Both are called while the [`crate::Context`] is locked, so they may not use it.
+    /// They may clone it though, e.g. to request a repaint from another thread.
+    pub fn schedule(
+        &self,
+        uri: &str,
+        start: impl FnOnce(LoadPermit) + Send + 'static,
+        cancel: impl FnOnce() + Send + 'static,
+    ) {
+        let mut state = self.state.lock();
+        let sequence_nr = state.next_sequence_nr;
+        state.next_sequence_nr += 1;
+        state.queue.push(QueuedLoad {
+            uri: uri.to_owned(),
+            start: Box::new(start),
+            cancel: Box::new(cancel),
+            sequence_nr,
+        });
+    }
+
+    /// Number of loads waiting to start.
+    pub fn num_queued(&self) -> usize {
+        self.state.lock().queue.len()
+    }
+
+    /// Number of loads that have started, but not yet dropped their [`LoadPermit`].
+    pub fn num_running(&self) -> usize {
+        self.state.lock().num_running
+    }
+
+    /// Cancel and start queued loads. Called by egui at the end of each pass.
+    pub fn end_pass(&self, pass_index: u64) {
+        profiling::function_scope!();
+
+        let mut to_start = Vec::new();
+
+        let to_cancel = {
+            let mut state = self.state.lock();
+            state.pass_index = pass_index;
+
+            let queue = std::mem::take(&mut state.queue);
+            let (to_cancel, mut keep): (Vec<_>, Vec<_>) = queue
+                .into_iter()
+                .partition(|load| state.should_cancel(&load.uri));
+
+            keep.sort_by_key(|load| {
+                (
+                    std::cmp::Reverse(state.effective_priority(&load.uri)),
+                    load.sequence_nr,
+                )
+            });
+
+            for load in keep {
+                if state.num_running < state.max_concurrent
+                    && state.effective_priority(&load.uri).is_some()
+                {
+                    state.num_running += 1;
+                    to_start.push(load);
+                } else {
+                    state.queue.push(load);
+                }
+            }
+
+            // Forget about uris no widget has asked for in a while:
+            let forget_after = state.cancel_after_passes.unwrap_or(60).max(60);
+            state
+                .requests
+                .retain(|_, request| pass_index <= request.last_seen + forget_after);
+
+            state.pass_index = pass_index + 1;
+
+            to_cancel
+        };
+
+        for load in to_cancel {
+            log::trace!("Canceled loading {:?}", load.uri);
+            (load.cancel)();
+        }
+
+        for load in to_start {
+            log::trace!("Started loading {:?}", load.uri);
+            (load.start)(LoadPermit {
+                state: Arc::clone(&self.state),
+            });
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn schedule(scheduler: &LoadScheduler, uri: &'static str, log: &Arc<Mutex<Vec<String>>>) {
+        let started = Arc::clone(log);
+        let canceled = Arc::clone(log);
+        scheduler.schedule(
+            uri,
+            move |permit| {
+                started.lock().push(format!("start {uri}"));
+                drop(permit);
+            },
+            move || canceled.lock().push(format!("cancel {uri}")),
+        );
+    }
+
+    #[test]
+    fn priority_visibility_and_cancellation() {
+        let scheduler = LoadScheduler::default();
+        let log = Arc::new(Mutex::new(Vec::new()));
+
+        scheduler.request("low", LoadPriority::Low, true);
+        scheduler.request("high", LoadPriority::High, true);
+        scheduler.request("hidden", LoadPriority::High, false);
+        scheduler.request("gone", LoadPriority::Normal, true);
+        for uri in ["low", "high", "hidden", "gone", "untracked"] {
+            schedule(&scheduler, uri, &log);
+        }
+
+        scheduler.set_max_concurrent(2);
+        scheduler.end_pass(0);
+        assert_eq!(*log.lock(), ["start high", "start gone"]);
+        log.lock().clear();
+
+        // "gone" is no longer shown, but has already started.
+        for pass_index in 1..5 {
+            scheduler.request("low", LoadPriority::Low, true);
+            scheduler.request("hidden", LoadPriority::High, false);
+            scheduler.end_pass(pass_index);
+        }
+        assert_eq!(*log.lock(), ["start untracked", "start low"]);
+        assert_eq!(
+            scheduler.num_queued(),
+            1,
+            "hidden image waits until it is visible"
+        );
+
+        log.lock().clear();
+        for pass_index in 5..10 {
+            scheduler.end_pass(pass_index);
+        }
+        assert_eq!(*log.lock(), ["cancel hidden"]);
+        assert_eq!(scheduler.num_queued(), 0);
+        assert_eq!(scheduler.num_running(), 0);
+    }
+}
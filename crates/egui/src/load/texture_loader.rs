@@ -21,6 +21,9 @@ struct Entry {
     source_size: Vec2,
 
     handle: TextureHandle,
+
+    /// Is this a low resolution preview of an image that is still loading?
+    is_preview: bool,
 }
 
 #[derive(Default)]
@@ -70,38 +73,56 @@ impl TextureLoader for DefaultTextureLoader {
 
         if let Some(texture) = bucket.get_mut(&svg_size_hint) {
             texture.last_used = *pass_index;
-            let texture = SizedTexture::new(texture.handle.id(), texture.source_size);
-            Ok(TexturePoll::Ready { texture })
-        } else {
-            match ctx.try_load_image(uri, size_hint)? {
-                ImagePoll::Pending { size } => Ok(TexturePoll::Pending { size }),
-                ImagePoll::Ready { image } => {
-                    let source_size = image.source_size;
-                    let handle = ctx.load_texture(uri, image, texture_options);
-                    let texture = SizedTexture::new(handle.id(), source_size);
-                    bucket.insert(
-                        svg_size_hint,
-                        Entry {
-                            last_used: *pass_index,
-                            source_size,
-                            handle,
-                        },
-                    );
-                    let reduce_texture_memory = ctx.options(|o| o.reduce_texture_memory);
-                    if reduce_texture_memory {
-                        let loaders = ctx.loaders();
-                        loaders.include.forget(uri);
-                        for loader in loaders.bytes.lock().iter().rev() {
-                            loader.forget(uri);
-                        }
-                        for loader in loaders.image.lock().iter().rev() {
-                            loader.forget(uri);
-                        }
-                    }
-                    Ok(TexturePoll::Ready { texture })
+            if !texture.is_preview {
+                let texture = SizedTexture::new(texture.handle.id(), texture.source_size);
+                return Ok(TexturePoll::Ready { texture });
+            }
+        }
+
+        let (image, is_preview) = match ctx.try_load_image(uri, size_hint)? {
+            ImagePoll::Pending { size } => {
+                return Ok(match bucket.get(&svg_size_hint) {
+                    // Keep showing the preview:
+                    Some(preview) => TexturePoll::Ready {
+                        texture: SizedTexture::new(preview.handle.id(), preview.source_size),
+                    },
+                    None => TexturePoll::Pending { size },
+                });
+            }
+            ImagePoll::Preview { image } => {
+                if let Some(preview) = bucket.get(&svg_size_hint) {
+                    let texture = SizedTexture::new(preview.handle.id(), preview.source_size);
+                    return Ok(TexturePoll::Ready { texture });
                 }
+                (image, true)
+            }
+            ImagePoll::Ready { image } => (image, false),
+        };
+
+        let source_size = image.source_size;
+        let handle = ctx.load_texture(uri, image, texture_options);
+        let texture = SizedTexture::new(handle.id(), source_size);
+        bucket.insert(
+            svg_size_hint,
+            Entry {
+                last_used: *pass_index,
+                source_size,
+                handle,
+                is_preview,
+            },
+        );
+        let reduce_texture_memory = ctx.options(|o| o.reduce_texture_memory);
+        if reduce_texture_memory && !is_preview {
+            let loaders = ctx.loaders();
+            loaders.include.forget(uri);
+            for loader in loaders.bytes.lock().iter().rev() {
+                loader.forget(uri);
+            }
+            for loader in loaders.image.lock().iter().rev() {
+                loader.forget(uri);
             }
         }
+        Ok(TexturePoll::Ready { texture })
     }
 
     fn forget(&self, uri: &str) {
@@ -0,0 +1,138 @@
+//! Translating the strings that egui's own widgets show to the user.
+//!
+//! By default egui shows all its built-in strings in English.
+//! To translate them, use [`crate::Context::set_localizer`] or [`crate::Context::set_translations`].
+//!
+//! ```
+//! # let ctx = egui::Context::default();
+//! use egui::localization::BuiltinString;
+//!
+//! ctx.set_localizer(|string| match string {
+//!     BuiltinString::ColorPickerHue => Some("Farbton".to_owned()),
+//!     _ => None, // Fall back to English
+//! });
+//! assert_eq!(ctx.localize(BuiltinString::ColorPickerHue), "Farbton");
+//! assert_eq!(ctx.localize(BuiltinString::ColorPickerAlpha), "Alpha");
+//! ```
+
+/// Translates a [`BuiltinString`], or returns `None` to use the English default.
+pub type Localizer = dyn Fn(BuiltinString) -> Option<String> + Send + Sync;
+
+/// A user-visible string that is built into egui.
+///
+/// Each string has a stable [`Self::key`], suitable for key/value translation files,
+/// and an [`Self::english`] default.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum BuiltinString {
+    /// Tooltip on the preview of the selected color in the color picker.
+    ColorPickerSelectedColor,
+
+    /// Label in front of the blending mode choice in the color picker.
+    ColorPickerBlending,
+
+    /// The normal (non-additive) blending mode in the color picker.
+    ColorPickerNormalBlending,
+
+    /// The additive blending mode in the color picker.
+    ColorPickerAdditiveBlending,
+
+    /// Tooltip on the hue slider of the color picker.
+    ColorPickerHue,
+
+    /// Tooltip on the alpha slider of the color picker.
+    ColorPickerAlpha,
+
+    /// Tooltip on the button that copies the color values.
+    ColorPickerCopyValues,
+
+    /// Tooltip on a color button that opens the color picker.
+    ColorPickerEdit,
+
+    /// Tooltip on the button that toggles to showing color values in 0-255 gamma space.
+    ColorSpaceGammaByte,
+
+    /// Tooltip on the button that toggles to showing color values in 0-1 linear space.
+    ColorSpaceLinear,
+
+    /// Accessibility label of the button that collapses a [`crate::Window`].
+    WindowCollapse,
+
+    /// Accessibility label of the button that expands a collapsed [`crate::Window`].
+    WindowExpand,
+
+    /// Accessibility label of the close button of a [`crate::Window`].
+    WindowClose,
+}
+
+impl BuiltinString {
+    /// All built-in strings.
+    pub const ALL: &'static [Self] = &[
+        Self::ColorPickerSelectedColor,
+        Self::ColorPickerBlending,
+        Self::ColorPickerNormalBlending,
+        Self::ColorPickerAdditiveBlending,
+        Self::ColorPickerHue,
+        Self::ColorPickerAlpha,
+        Self::ColorPickerCopyValues,
+        Self::ColorPickerEdit,
+        Self::ColorSpaceGammaByte,
+        Self::ColorSpaceLinear,
+        Self::WindowCollapse,
+        Self::WindowExpand,
+        Self::WindowClose,
+    ];
+
+    /// A stable identifier, e.g. `"color_picker.hue"`.
+    pub fn key(self) -> &'static str {
+        match self {
+            Self::ColorPickerSelectedColor => "color_picker.selected_color",
+            Self::ColorPickerBlending => "color_picker.blending",
+            Self::ColorPickerNormalBlending => "color_picker.normal_blending",
+            Self::ColorPickerAdditiveBlending => "color_picker.additive_blending",
+            Self::ColorPickerHue => "color_picker.hue",
+            Self::ColorPickerAlpha => "color_picker.alpha",
+            Self::ColorPickerCopyValues => "color_picker.copy_values",
+            Self::ColorPickerEdit => "color_picker.edit",
+            Self::ColorSpaceGammaByte => "color_space.gamma_byte",
+            Self::ColorSpaceLinear => "color_space.linear",
+            Self::WindowCollapse => "window.collapse",
+            Self::WindowExpand => "window.expand",
+            Self::WindowClose => "window.close",
+        }
+    }
+
+    /// The default English text.
+    pub fn english(self) -> &'static str {
+        match self {
+            Self::ColorPickerSelectedColor => "Selected color",
+            Self::ColorPickerBlending => "Blending:",
+            Self::ColorPickerNormalBlending => "Normal",
+            Self::ColorPickerAdditiveBlending => "Additive",
+            Self::ColorPickerHue => "Hue",
+            Self::ColorPickerAlpha => "Alpha",
+            Self::ColorPickerCopyValues => "Click to copy color values",
+            Self::ColorPickerEdit => "Click to edit color",
+            Self::ColorSpaceGammaByte => "Showing color values in 0-255 gamma space",
+            Self::ColorSpaceLinear => "Showing color values in 0-1 linear space",
+            Self::WindowCollapse => "Hide",
+            Self::WindowExpand => "Show",
+            Self::WindowClose => "Close window",
+        }
+    }
+}
+
+/// Use key/value pairs as a [`Localizer`], with keys from [`BuiltinString::key`].
+pub(crate) fn localizer_from_translations(
+    translations: ahash::HashMap<String, String>,
+) -> impl Fn(BuiltinString) -> Option<String> + Send + Sync {
+    move |string| translations.get(string.key()).cloned()
+}
+
+#[test]
+fn test_unique_keys() {
+    let mut keys: Vec<&str> = BuiltinString::ALL.iter().map(|s| s.key()).collect();
+    keys.sort_unstable();
+    keys.dedup();
+    assert_eq!(keys.len(), BuiltinString::ALL.len());
+}
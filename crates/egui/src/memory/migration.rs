@@ -0,0 +1,75 @@
+use std::sync::Arc;
+
+use super::Memory;
+
+/// A callback that upgrades persisted [`Memory`] to a newer version.
+///
+/// See [`crate::Context::add_memory_migration`].
+pub type MemoryMigration = Arc<dyn Fn(&mut Memory) + Send + Sync>;
+
+/// The registered [`MemoryMigration`]s, sorted by version.
+#[derive(Clone, Default)]
+pub(crate) struct MemoryMigrations {
+    migrations: Vec<(u32, MemoryMigration)>,
+}
+
+impl MemoryMigrations {
+    pub fn add(&mut self, version: u32, migration: MemoryMigration) {
+        let index = self.migrations.partition_point(|(v, _)| *v <= version);
+        self.migrations.insert(index, (version, migration));
+    }
+
+    /// Run all migrations newer than [`Memory::version`], oldest first.
+    pub fn migrate(&self, memory: &mut Memory) {
+        for (version, migration) in &self.migrations {
+            if memory.version < *version {
+                log::debug!(
+                    "Migrating egui memory from version {} to {version}",
+                    memory.version
+                );
+                migration(memory);
+                memory.version = *version;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Id;
+
+    #[test]
+    fn migrations_run_in_order_once() {
+        let mut migrations = MemoryMigrations::default();
+        migrations.add(
+            2,
+            Arc::new(|mem| {
+                let old: u32 = mem.data.get_temp(Id::new("value")).unwrap_or_default();
+                mem.data.insert_temp(Id::new("value"), old * 10);
+            }),
+        );
+        migrations.add(
+            1,
+            Arc::new(|mem| {
+                let old: u32 = mem.data.get_temp(Id::new("value")).unwrap_or_default();
+                mem.data.insert_temp(Id::new("value"), old + 1);
+            }),
+        );
+
+        let mut memory = Memory::default();
+        memory.data.insert_temp(Id::new("value"), 1_u32);
+
+        migrations.migrate(&mut memory);
+        assert_eq!(memory.version, 2);
+        assert_eq!(memory.data.get_temp::<u32>(Id::new("value")), Some(20));
+
+        migrations.migrate(&mut memory);
+        assert_eq!(memory.data.get_temp::<u32>(Id::new("value")), Some(20));
+
+        memory.version = 1;
+        memory.data.insert_temp(Id::new("value"), 1_u32);
+        migrations.migrate(&mut memory);
+        assert_eq!(memory.data.get_temp::<u32>(Id::new("value")), Some(10));
+    }
+}
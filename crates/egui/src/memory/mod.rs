@@ -10,7 +10,12 @@ use crate::{
     ViewportIdMap, ViewportIdSet, area, vec2,
 };
 
+mod migration;
+mod pixel_snapping;
 mod theme;
+pub use migration::MemoryMigration;
+pub(crate) use migration::MemoryMigrations;
+pub use pixel_snapping::PixelSnapping;
 pub use theme::{Theme, ThemePreference};
 
 // ----------------------------------------------------------------------------
@@ -28,6 +33,12 @@ pub use theme::{Theme, ThemePreference};
 #[cfg_attr(feature = "persistence", derive(serde::Deserialize, serde::Serialize))]
 #[cfg_attr(feature = "persistence", serde(default))]
 pub struct Memory {
+    /// The version of the persisted data.
+    ///
+    /// This is `0` for data saved before any migration was registered,
+    /// and is bumped by [`crate::Context::add_memory_migration`] after each migration has run.
+    pub version: u32,
+
     /// Global egui options.
     pub options: Options,
 
@@ -126,6 +137,7 @@ pub struct Memory {
 impl Default for Memory {
     fn default() -> Self {
         let mut slf = Self {
+            version: Default::default(),
             options: Default::default(),
             data: Default::default(),
             caches: Default::default(),
@@ -308,6 +320,43 @@ pub struct Options {
     ///
     /// Default is `false`.
     pub reduce_texture_memory: bool,
+
+    /// If `true`, egui collects semantic events about widgets (clicks, value changes,
+    /// windows opening and closing, …) in [`crate::PlatformOutput::widget_events`].
+    ///
+    /// Useful for analytics, macro recording or tests.
+    /// Read them at the end of your frame with [`crate::Context::output`].
+    ///
+    /// Default is `false`.
+    pub widget_events: bool,
+
+    /// If `true`, keyboard focus follows the mouse pointer.
+    ///
+    /// The integration gives keyboard focus to a viewport as soon as the pointer enters it,
+    /// and within a viewport focus moves between windows and panels as the pointer hovers them.
+    /// Each window and panel remembers its last focused widget, which gets focus back
+    /// when the pointer returns.
+    ///
+    /// Default is `false`.
+    pub focus_follows_mouse: bool,
+
+    /// How widgets and shapes are aligned to the physical pixel grid.
+    ///
+    /// Consider [`PixelSnapping::Layout`] if thin lines look blurry on fractional scale factors.
+    ///
+    /// Default is [`PixelSnapping::Paint`].
+    pub pixel_snapping: PixelSnapping,
+
+    /// How much to scale custom cursor images (see [`crate::Context::set_cursor_image`]) by.
+    ///
+    /// `None` means following the size of the OS cursors,
+    /// as reported in [`crate::RawInput::system_cursor_scale`],
+    /// so that large-cursor accessibility settings also apply to egui cursors.
+    ///
+    /// See [`crate::Context::cursor_scale`].
+    ///
+    /// Default is `None`.
+    pub cursor_scale: Option<f32>,
 }
 
 impl Default for Options {
@@ -335,6 +384,10 @@ impl Default for Options {
             // Input:
             input_options: Default::default(),
             reduce_texture_memory: false,
+            widget_events: false,
+            focus_follows_mouse: false,
+            pixel_snapping: PixelSnapping::default(),
+            cursor_scale: None,
         }
     }
 }
@@ -391,6 +444,10 @@ impl Options {
             warn_on_id_clash,
             input_options,
             reduce_texture_memory,
+            widget_events,
+            focus_follows_mouse,
+            pixel_snapping,
+            cursor_scale,
         } = self;
 
         use crate::Widget as _;
@@ -422,6 +479,28 @@ impl Options {
                 ui.checkbox(warn_on_id_clash, "Warn if two widgets have the same Id");
 
                 ui.checkbox(reduce_texture_memory, "Reduce texture memory");
+
+                ui.checkbox(widget_events, "Collect widget events");
+                ui.checkbox(focus_follows_mouse, "Focus follows mouse");
+
+                pixel_snapping.radio_buttons(ui);
+
+                ui.horizontal(|ui| {
+                    let mut follow_system = cursor_scale.is_none();
+                    if ui
+                        .checkbox(&mut follow_system, "Cursor size follows the system")
+                        .changed()
+                    {
+                        *cursor_scale = if follow_system { None } else { Some(1.0) };
+                    }
+                    if let Some(cursor_scale) = cursor_scale {
+                        ui.add(
+                            crate::DragValue::new(cursor_scale)
+                                .range(0.5..=4.0)
+                                .speed(0.01),
+                        );
+                    }
+                });
             });
 
         CollapsingHeader::new("🎑 Style")
@@ -777,6 +856,8 @@ impl Memory {
 
         self.requested_interrupt_ime = false;
 
+        self.data.begin_pass();
+
         // self.interactions  is handled elsewhere
 
         self.options.begin_pass(new_raw_input);
@@ -818,6 +899,19 @@ impl Memory {
         self.areas.entry(self.viewport_id).or_default()
     }
 
+    /// Move the remembered position and size of an [`Area`](crate::containers::area::Area),
+    /// such as a `Window`, from one [`Id`] to another, in all viewports.
+    ///
+    /// Useful in a [`MemoryMigration`] when the id of a window changes.
+    pub fn rename_area(&mut self, from: Id, to: Id) {
+        #[expect(clippy::iter_over_hash_type)] // Each viewport is independent
+        for areas in self.areas.values_mut() {
+            if let Some(state) = areas.areas.remove(&from) {
+                areas.areas.insert(to, state);
+            }
+        }
+    }
+
     /// Top-most layer at the given position.
     pub fn layer_id_at(&self, pos: Pos2) -> Option<LayerId> {
         let layer_id = self.areas().layer_id_at(pos, &self.to_global)?;
@@ -0,0 +1,90 @@
+use emath::{GuiRounding as _, Rect};
+use epaint::TessellationOptions;
+
+/// How widgets and shapes are aligned to the physical pixel grid.
+///
+/// Layout is always rounded to [`emath::GUI_ROUNDING`] of a point, which only lines up with
+/// the physical pixels if [`crate::Context::pixels_per_point`] is a whole number.
+/// On fractional scales (e.g. 1.25 or 1.5, as reported by Wayland's fractional-scale protocol)
+/// widget edges can end up in the middle of a pixel, making thin borders look blurry.
+///
+/// The pixel grid is that of [`crate::Context::pixels_per_point`], i.e. the actual scale factor
+/// of the surface multiplied by the [`crate::Context::zoom_factor`].
+///
+/// Set with [`crate::Options::pixel_snapping`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub enum PixelSnapping {
+    /// Never snap: paint everything exactly where it was laid out.
+    ///
+    /// This overrides the rounding options in [`TessellationOptions`].
+    Off,
+
+    /// Snap text, rectangles and axis-aligned line segments to pixels when painting,
+    /// as configured in [`TessellationOptions`].
+    #[default]
+    Paint,
+
+    /// Like [`Self::Paint`], but also snap the rectangles allocated by layout to pixels,
+    /// so that neighboring widgets, frames and separators share the same pixel edges.
+    ///
+    /// Recommended for fractional scale factors.
+    Layout,
+}
+
+impl PixelSnapping {
+    /// Round a rectangle allocated by layout according to this policy.
+    pub fn round_layout_rect(self, rect: Rect, pixels_per_point: f32) -> Rect {
+        match self {
+            Self::Off | Self::Paint => rect,
+            Self::Layout => {
+                // Round back to `GUI_ROUNDING` so that sizing calculations stay exact.
+                // This is off by at most 1/64 of a point, which is not visible.
+                rect.round_to_pixels(pixels_per_point).round_ui()
+            }
+        }
+    }
+
+    /// The options to tessellate with, given the ones set by the user.
+    pub fn tessellation_options(self, mut options: TessellationOptions) -> TessellationOptions {
+        if self == Self::Off {
+            options.round_text_to_pixels = false;
+            options.round_line_segments_to_pixels = false;
+            options.round_rects_to_pixels = false;
+        }
+        options
+    }
+
+    /// Show radio buttons to switch between the policies.
+    pub fn radio_buttons(&mut self, ui: &mut crate::Ui) {
+        ui.horizontal(|ui| {
+            ui.label("Pixel snapping:");
+            ui.radio_value(self, Self::Off, "Off");
+            ui.radio_value(self, Self::Paint, "Paint");
+            ui.radio_value(self, Self::Layout, "Layout");
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn layout_snapping_at_fractional_scale() {
+        let pixels_per_point = 1.25;
+        let rect = Rect::from_min_max([10.5, 3.0].into(), [20.25, 7.5].into());
+
+        assert_eq!(
+            PixelSnapping::Paint.round_layout_rect(rect, pixels_per_point),
+            rect
+        );
+
+        let snapped = PixelSnapping::Layout.round_layout_rect(rect, pixels_per_point);
+        assert_eq!(snapped, snapped.round_ui());
+        for value in [snapped.min.x, snapped.min.y, snapped.max.x, snapped.max.y] {
+            let pixels = value * pixels_per_point;
+            assert!((pixels - pixels.round()).abs() <= 0.5 / 32.0 * pixels_per_point);
+        }
+    }
+}
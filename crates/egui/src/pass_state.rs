@@ -199,6 +199,11 @@ pub struct PassState {
 
     pub tooltips: TooltipPassState,
 
+    /// The outer rect of every panel shown this pass, in the order they were shown.
+    ///
+    /// Used by [`crate::Options::focus_follows_mouse`].
+    pub panel_rects: Vec<(Id, LayerId, Rect)>,
+
     /// What the root UI had available at the end of the previous pass.
     ///
     /// Only set if [`crate::Context::run_ui`] has been called.
@@ -223,6 +228,10 @@ pub struct PassState {
     /// as when swiping down on a touch-screen or track-pad with natural scrolling.
     pub scroll_delta: (Vec2, style::ScrollAnimation),
 
+    /// The safe area changed this pass (e.g. the on-screen keyboard was shown),
+    /// so the focused widget should scroll itself into view.
+    pub scroll_focused_into_view: bool,
+
     pub accesskit_state: Option<AccessKitPassState>,
 
     /// Highlight these widgets the next pass.
@@ -239,10 +248,12 @@ impl Default for PassState {
             widgets: Default::default(),
             layers: Default::default(),
             tooltips: Default::default(),
+            panel_rects: Default::default(),
             root_ui_available_rect: None,
             root_ui_min_rect: None,
             scroll_target: [None, None],
             scroll_delta: (Vec2::default(), style::ScrollAnimation::none()),
+            scroll_focused_into_view: false,
             accesskit_state: None,
             highlight_next_pass: Default::default(),
 
@@ -259,11 +270,13 @@ impl PassState {
             used_ids,
             widgets,
             tooltips,
+            panel_rects,
             layers,
             root_ui_available_rect,
             root_ui_min_rect,
             scroll_target,
             scroll_delta,
+            scroll_focused_into_view,
             accesskit_state,
             highlight_next_pass,
 
@@ -274,11 +287,13 @@ impl PassState {
         used_ids.clear();
         widgets.clear();
         tooltips.clear();
+        panel_rects.clear();
         layers.clear();
         *root_ui_available_rect = None;
         *root_ui_min_rect = None;
         *scroll_target = [None, None];
         *scroll_delta = Default::default();
+        *scroll_focused_into_view = false;
 
         #[cfg(debug_assertions)]
         {
@@ -1,5 +1,5 @@
 use crate::{Context, FullOutput, RawInput, Ui};
-use ahash::HashMap;
+use ahash::{HashMap, HashSet};
 use epaint::mutex::{Mutex, MutexGuard};
 use std::sync::Arc;
 
@@ -7,15 +7,34 @@ use std::sync::Arc;
 ///
 /// Add plugins via [`Context::add_plugin`].
 ///
+/// Plugins are called in the order they were added,
+/// unless they ask to run before or after other plugins with [`Self::run_after`] and [`Self::run_before`].
+/// A plugin can be disabled at runtime with [`Context::set_plugin_enabled`].
+///
 /// Plugins should not hold a reference to the [`Context`], since this would create a cycle
 /// (which would prevent the [`Context`] from being dropped).
 #[expect(unused_variables)]
 pub trait Plugin: Send + Sync + std::any::Any + 'static {
     /// Plugin name.
     ///
-    /// Used when profiling.
+    /// Used when profiling, and by other plugins to refer to this one in
+    /// [`Self::run_after`] and [`Self::run_before`].
     fn debug_name(&self) -> &'static str;
 
+    /// The names of plugins whose hooks should be called before the hooks of this plugin.
+    ///
+    /// Plugins that aren't registered are ignored.
+    fn run_after(&self) -> &[&'static str] {
+        &[]
+    }
+
+    /// The names of plugins whose hooks should be called after the hooks of this plugin.
+    ///
+    /// Plugins that aren't registered are ignored.
+    fn run_before(&self) -> &[&'static str] {
+        &[]
+    }
+
     /// Called once, when the plugin is registered.
     ///
     /// Useful to e.g. register image loaders.
@@ -132,13 +151,6 @@ impl PluginHandle {
     }
 }
 
-/// User-registered plugins.
-#[derive(Clone, Default)]
-pub(crate) struct Plugins {
-    plugins: HashMap<std::any::TypeId, Arc<Mutex<PluginHandle>>>,
-    plugins_ordered: PluginsOrdered,
-}
-
 #[derive(Clone, Default)]
 pub(crate) struct PluginsOrdered(Vec<Arc<Mutex<PluginHandle>>>);
 
@@ -191,11 +203,40 @@ impl PluginsOrdered {
     }
 }
 
+/// A registered plugin, with the information needed to order it.
+#[derive(Clone)]
+struct PluginEntry {
+    type_id: std::any::TypeId,
+    name: &'static str,
+    run_after: Vec<&'static str>,
+    run_before: Vec<&'static str>,
+    handle: Arc<Mutex<PluginHandle>>,
+}
+
+/// User-registered plugins.
+#[derive(Clone, Default)]
+pub(crate) struct Plugins {
+    plugins: HashMap<std::any::TypeId, Arc<Mutex<PluginHandle>>>,
+
+    /// In the order the hooks are called.
+    ordered: Vec<PluginEntry>,
+
+    /// Cached [`Self::ordered`], without the disabled plugins.
+    plugins_ordered: PluginsOrdered,
+
+    disabled: HashSet<std::any::TypeId>,
+}
+
 impl Plugins {
     pub fn ordered_plugins(&self) -> PluginsOrdered {
         self.plugins_ordered.clone()
     }
 
+    /// The names of the plugins, in the order their hooks are called.
+    pub fn names(&self) -> Vec<&'static str> {
+        self.ordered.iter().map(|entry| entry.name).collect()
+    }
+
     /// Remember to call [`Plugin::setup`] on the plugin after adding it.
     ///
     /// Will not add the plugin if a plugin of the same type already exists.
@@ -203,14 +244,24 @@ impl Plugins {
     pub fn add(&mut self, handle: Arc<Mutex<PluginHandle>>) -> bool {
         profiling::scope!("plugins", "add");
 
-        let type_id = handle.lock().plugin_type_id();
-
-        if self.plugins.contains_key(&type_id) {
+        let entry = {
+            let plugin = handle.lock();
+            PluginEntry {
+                type_id: plugin.plugin_type_id(),
+                name: plugin.plugin.debug_name(),
+                run_after: plugin.plugin.run_after().to_vec(),
+                run_before: plugin.plugin.run_before().to_vec(),
+                handle: Arc::clone(&handle),
+            }
+        };
+
+        if self.plugins.contains_key(&entry.type_id) {
             return false;
         }
 
-        self.plugins.insert(type_id, Arc::clone(&handle));
-        self.plugins_ordered.0.push(handle);
+        self.plugins.insert(entry.type_id, handle);
+        self.ordered.push(entry);
+        self.sort();
 
         true
     }
@@ -218,6 +269,63 @@ impl Plugins {
     pub fn get(&self, type_id: std::any::TypeId) -> Option<Arc<Mutex<PluginHandle>>> {
         self.plugins.get(&type_id).cloned()
     }
+
+    pub fn is_enabled(&self, type_id: std::any::TypeId) -> bool {
+        self.plugins.contains_key(&type_id) && !self.disabled.contains(&type_id)
+    }
+
+    pub fn set_enabled(&mut self, type_id: std::any::TypeId, enabled: bool) {
+        let changed = if enabled {
+            self.disabled.remove(&type_id)
+        } else {
+            self.disabled.insert(type_id)
+        };
+        if changed {
+            self.update_plugins_ordered();
+        }
+    }
+
+    /// Sort the plugins so that [`Plugin::run_after`] and [`Plugin::run_before`] are respected,
+    /// keeping the order in which they were added where possible.
+    fn sort(&mut self) {
+        profiling::function_scope!();
+
+        // `a` must run before `b`:
+        let must_run_before = |a: &PluginEntry, b: &PluginEntry| {
+            b.run_after.contains(&a.name) || a.run_before.contains(&b.name)
+        };
+
+        let mut remaining = std::mem::take(&mut self.ordered);
+        while !remaining.is_empty() {
+            let next = remaining
+                .iter()
+                .position(|entry| {
+                    !remaining.iter().any(|other| {
+                        other.type_id != entry.type_id && must_run_before(other, entry)
+                    })
+                })
+                .unwrap_or_else(|| {
+                    log::warn!(
+                        "egui plugins have cyclic dependencies: {:?}",
+                        remaining.iter().map(|entry| entry.name).collect::<Vec<_>>()
+                    );
+                    0
+                });
+            self.ordered.push(remaining.remove(next));
+        }
+
+        self.update_plugins_ordered();
+    }
+
+    fn update_plugins_ordered(&mut self) {
+        self.plugins_ordered = PluginsOrdered(
+            self.ordered
+                .iter()
+                .filter(|entry| !self.disabled.contains(&entry.type_id))
+                .map(|entry| Arc::clone(&entry.handle))
+                .collect(),
+        );
+    }
 }
 
 /// Generic event callback.
@@ -252,3 +360,61 @@ impl Plugin for CallbackPlugin {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    type Log = Arc<Mutex<Vec<&'static str>>>;
+
+    macro_rules! test_plugin {
+        ($ty:ident, $name:literal, after: $after:expr, before: $before:expr) => {
+            struct $ty(Log);
+
+            impl Plugin for $ty {
+                fn debug_name(&self) -> &'static str {
+                    $name
+                }
+
+                fn run_after(&self) -> &[&'static str] {
+                    $after
+                }
+
+                fn run_before(&self) -> &[&'static str] {
+                    $before
+                }
+
+                fn on_begin_pass(&mut self, _ui: &mut Ui) {
+                    self.0.lock().push($name);
+                }
+            }
+        };
+    }
+
+    test_plugin!(Toasts, "toasts", after: &["dock"], before: &[]);
+    test_plugin!(Dock, "dock", after: &[], before: &[]);
+    test_plugin!(Inspector, "inspector", after: &[], before: &["dock"]);
+
+    #[test]
+    fn plugins_are_ordered_by_dependencies() {
+        let ctx = Context::default();
+        let log = Log::default();
+        ctx.add_plugin(Toasts(Arc::clone(&log)));
+        ctx.add_plugin(Dock(Arc::clone(&log)));
+        ctx.add_plugin(Inspector(Arc::clone(&log)));
+
+        let names = ctx.plugin_names();
+        let position = |name| names.iter().position(|n| *n == name).unwrap();
+        assert!(position("inspector") < position("dock"));
+        assert!(position("dock") < position("toasts"));
+
+        _ = ctx.run_ui(Default::default(), |_| {});
+        assert_eq!(*log.lock(), ["inspector", "dock", "toasts"]);
+
+        log.lock().clear();
+        ctx.set_plugin_enabled::<Dock>(false);
+        assert!(!ctx.is_plugin_enabled::<Dock>());
+        _ = ctx.run_ui(Default::default(), |_| {});
+        assert_eq!(*log.lock(), ["inspector", "toasts"]);
+    }
+}
@@ -376,6 +376,28 @@ impl Response {
         self.ctx.memory_mut(|mem| mem.surrender_focus(self.id));
     }
 
+    /// Keep the OS cursor inside this widget for this frame.
+    ///
+    /// Call this every frame for as long as the cursor should stay confined:
+    ///
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// let response = ui.allocate_response(egui::vec2(100.0, 100.0), egui::Sense::drag());
+    /// if response.dragged() {
+    ///     response.confine_pointer();
+    /// }
+    /// # });
+    /// ```
+    ///
+    /// See [`crate::Context::confine_pointer`].
+    pub fn confine_pointer(&self) {
+        let mut rect = self.interact_rect;
+        if let Some(to_global) = self.ctx.layer_transform_to_global(self.layer_id) {
+            rect = to_global * rect;
+        }
+        self.ctx.confine_pointer(rect);
+    }
+
     /// Did a drag on this widget begin this frame?
     ///
     /// This is only true if the widget sense drags.
@@ -655,10 +677,7 @@ impl Response {
 
     /// Like `on_hover_ui`, but show the ui next to cursor.
     pub fn on_hover_ui_at_pointer(self, add_contents: impl FnOnce(&mut Ui)) -> Self {
-        Tooltip::for_enabled(&self)
-            .at_pointer()
-            .gap(12.0)
-            .show(add_contents);
+        Tooltip::for_enabled(&self).at_pointer().show(add_contents);
         self
     }
 
@@ -849,6 +868,16 @@ impl Response {
     pub fn widget_info(&self, make_info: impl Fn() -> crate::WidgetInfo) {
         use crate::output::OutputEvent;
 
+        if self.gained_focus() {
+            // The position among its siblings is filled in at the end of the pass.
+            let summary = crate::FocusSummary {
+                id: self.id,
+                info: make_info(),
+                position: None,
+            };
+            self.ctx.output_mut(|o| o.focus_summary = Some(summary));
+        }
+
         let event = if self.clicked() {
             Some(OutputEvent::Clicked(make_info()))
         } else if self.double_clicked() {
@@ -864,7 +893,14 @@ impl Response {
         };
 
         if let Some(event) = event {
+            let is_value_changed = matches!(event, OutputEvent::ValueChanged(_));
             self.output_event(event);
+
+            // Clicking a checkbox etc. also changes its value:
+            if !is_value_changed && self.changed() {
+                self.ctx
+                    .widget_event(self.id, crate::WidgetEventKind::ValueChanged, &make_info);
+            }
         } else {
             self.ctx.accesskit_node_builder(self.id, |builder| {
                 self.fill_accesskit_node_from_widget_info(builder, make_info());
@@ -882,6 +918,9 @@ impl Response {
         self.ctx
             .register_widget_info(self.id, || event.widget_info().clone());
 
+        self.ctx
+            .widget_event(self.id, (&event).into(), || event.widget_info().clone());
+
         self.ctx.output_mut(|o| o.events.push(event));
     }
 
@@ -988,6 +1027,54 @@ impl Response {
         self
     }
 
+    /// Offer a custom action for this widget to assistive technology,
+    /// e.g. "Increment by 10" or "Open in browser".
+    ///
+    /// Call this every pass. Returns `true` if assistive technology requested the action this pass.
+    ///
+    /// See also [`Self::on_accesskit_action_request`].
+    pub fn accesskit_custom_action(&self, description: impl Into<String>) -> bool {
+        let description = description.into();
+
+        // Derived from the description, so it stays the same between passes.
+        let action_id = Id::new(&description).value() as i32;
+
+        self.ctx.accesskit_node_builder(self.id, |builder| {
+            builder.add_action(accesskit::Action::CustomAction);
+            builder.push_custom_action(accesskit::CustomAction {
+                id: action_id,
+                description: description.into(),
+            });
+        });
+
+        self.ctx.input(|i| {
+            i.accesskit_action_requests(self.id, accesskit::Action::CustomAction)
+                .any(|request| request.data == Some(accesskit::ActionData::CustomAction(action_id)))
+        })
+    }
+
+    /// Offer a custom action for this widget to assistive technology,
+    /// and call `on_request` when it is requested.
+    ///
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// # let mut value = 0;
+    /// let response = ui
+    ///     .add(egui::DragValue::new(&mut value))
+    ///     .on_accesskit_action_request("Increment by 10", || value += 10);
+    /// # });
+    /// ```
+    pub fn on_accesskit_action_request(
+        self,
+        description: impl Into<String>,
+        on_request: impl FnOnce(),
+    ) -> Self {
+        if self.accesskit_custom_action(description) {
+            on_request();
+        }
+        self
+    }
+
     /// Response to secondary clicks (right-clicks) by showing the given menu.
     ///
     /// Make sure the widget senses clicks (e.g. [`crate::Button`] does, [`crate::Label`] does not).
@@ -11,7 +11,8 @@ use crate::{
     ComboBox, CursorIcon, FontFamily, FontId, Grid, Margin, Response, RichText, TextWrapMode,
     WidgetText,
     ecolor::Color32,
-    emath::{Rangef, Rect, Vec2, pos2, vec2},
+    emath::{Rangef, Rect, TSTransform, Vec2, pos2, vec2},
+    localization::BuiltinString,
     reset_button_with,
 };
 
@@ -60,6 +61,45 @@ impl PartialEq for NumberFormatter {
     }
 }
 
+/// How to parse the text the user typed into e.g. a [`crate::DragValue`] into a number.
+///
+/// The default is [`emath::evaluate_expression`], so users can type things like `1920/2` or `2*pi`.
+#[derive(Clone)]
+pub struct NumberParser(Arc<dyn 'static + Sync + Send + Fn(&str) -> Option<f64>>);
+
+impl NumberParser {
+    /// The parser returns `None` if the text is not a valid number.
+    #[inline]
+    pub fn new(parser: impl 'static + Sync + Send + Fn(&str) -> Option<f64>) -> Self {
+        Self(Arc::new(parser))
+    }
+
+    /// Parse the given text into a number, or `None` if it is invalid.
+    #[inline]
+    pub fn parse(&self, text: &str) -> Option<f64> {
+        (self.0)(text)
+    }
+}
+
+impl Default for NumberParser {
+    fn default() -> Self {
+        Self::new(emath::evaluate_expression)
+    }
+}
+
+impl std::fmt::Debug for NumberParser {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("NumberParser")
+    }
+}
+
+impl PartialEq for NumberParser {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.0, &other.0)
+    }
+}
+
 // ----------------------------------------------------------------------------
 
 /// Alias for a [`FontId`] (font of a certain size).
@@ -296,6 +336,13 @@ pub struct Style {
     #[cfg_attr(feature = "serde", serde(skip))]
     pub number_formatter: NumberFormatter,
 
+    /// How to parse the text typed into e.g. a [`crate::DragValue`] when the edit is committed.
+    ///
+    /// Defaults to evaluating simple math expressions, see [`emath::evaluate_expression`].
+    /// You can override this to e.g. accept units, or a decimal comma.
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub number_parser: NumberParser,
+
     /// If set, labels, buttons, etc. will use this to determine whether to wrap or truncate the
     /// text at the right edge of the [`Ui`] they are in, or to extend it. By default, this is
     /// `None`.
@@ -316,6 +363,21 @@ pub struct Style {
     /// How many seconds a typical animation should last.
     pub animation_time: f32,
 
+    /// Avoid animations that move or scale things, e.g. for users who get motion sick.
+    ///
+    /// [`Transition`]s then only fade, and collapsing headers open and close instantly.
+    pub reduce_motion: bool,
+
+    /// How [`crate::Window`]s appear and disappear.
+    pub window_transition: Transition,
+
+    /// How popups, menus, tooltips and other [`crate::Area`]s appear.
+    pub popup_transition: Transition,
+
+    /// How the body of a [`crate::CollapsingHeader`] appears and disappears,
+    /// on top of opening and closing it gradually.
+    pub collapsing_transition: Transition,
+
     /// Options to help debug why egui behaves strangely.
     ///
     /// Only available in debug builds.
@@ -330,7 +392,9 @@ pub struct Style {
     /// Show the URL of hyperlinks in a tooltip when hovered.
     pub url_in_tooltip: bool,
 
-    /// If true and scrolling is enabled for only one direction, allow horizontal scrolling without pressing shift
+    /// If true and scrolling is enabled for only one direction, allow horizontal scrolling without pressing shift.
+    ///
+    /// Can be overridden per [`crate::ScrollArea`] with [`crate::ScrollArea::wheel_axis_policy`].
     pub always_scroll_the_only_direction: bool,
 
     /// The animation that should be used when scrolling a [`crate::ScrollArea`] using e.g. [`Ui::scroll_to_rect`].
@@ -443,6 +507,13 @@ pub struct Spacing {
     /// Width of a tooltip (`on_hover_ui`, `on_hover_text` etc).
     pub tooltip_width: f32,
 
+    /// Width of a tooltip with custom content (`on_hover_ui` etc).
+    ///
+    /// Text tooltips always wrap at [`Self::tooltip_width`],
+    /// but tooltips with e.g. images or tables may want more room.
+    /// `None` means [`Self::tooltip_width`].
+    pub tooltip_ui_width: Option<f32>,
+
     /// The default wrapping width of a menu.
     ///
     /// Items longer than this will wrap to a new line.
@@ -481,10 +552,11 @@ impl Spacing {
 
 /// Controls the spacing and visuals of a [`crate::ScrollArea`].
 ///
-/// There are three presets to chose from:
+/// There are four presets to chose from:
 /// * [`Self::solid`]
 /// * [`Self::thin`]
 /// * [`Self::floating`]
+/// * [`Self::overlay`]
 #[derive(Clone, Copy, Debug, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
 #[cfg_attr(feature = "serde", serde(default))]
@@ -575,6 +647,19 @@ pub struct ScrollStyle {
     /// Solid scroll bars are always opaque.
     pub interact_handle_opacity: f32,
 
+    /// If set, floating scroll bars are only active while scrolling,
+    /// and fade out this many seconds after the scrolling stops,
+    /// even if the user is still hovering the scroll area.
+    ///
+    /// If not set, they are active whenever the user hovers the scroll area.
+    ///
+    /// Hovering the scroll bars while they are shown keeps them active, and expands them.
+    /// Once they have faded out completely, they ignore the pointer,
+    /// so they don't take any clicks from the content below.
+    ///
+    /// This is only for floating scroll bars.
+    pub auto_hide_delay: Option<f32>,
+
     pub fade: ScrollFadeStyle,
 }
 
@@ -607,6 +692,8 @@ impl ScrollStyle {
             active_handle_opacity: 0.6,
             interact_handle_opacity: 1.0,
 
+            auto_hide_delay: None,
+
             fade: Default::default(),
         }
     }
@@ -648,6 +735,23 @@ impl ScrollStyle {
         }
     }
 
+    /// Thin scroll bars on top of the content that show up while scrolling, fade out soon after,
+    /// and expand when you hover them, like on macOS and mobile.
+    pub fn overlay() -> Self {
+        Self {
+            floating: true,
+            bar_width: 10.0,
+            floating_width: 4.0,
+            foreground_color: true,
+            floating_allocated_width: 0.0,
+            dormant_background_opacity: 0.0,
+            dormant_handle_opacity: 0.0,
+            active_background_opacity: 0.0,
+            auto_hide_delay: Some(1.0),
+            ..Self::solid()
+        }
+    }
+
     /// Width of a solid vertical scrollbar, or height of a horizontal scroll bar, when it is at its widest.
     pub fn allocated_width(&self) -> f32 {
         if self.floating {
@@ -663,6 +767,7 @@ impl ScrollStyle {
             ui.selectable_value(self, Self::solid(), "Solid");
             ui.selectable_value(self, Self::thin(), "Thin");
             ui.selectable_value(self, Self::floating(), "Floating");
+            ui.selectable_value(self, Self::overlay(), "Overlay");
         });
 
         ui.collapsing("Details", |ui| {
@@ -692,6 +797,8 @@ impl ScrollStyle {
             active_handle_opacity,
             interact_handle_opacity,
 
+            auto_hide_delay,
+
             fade,
         } = self;
 
@@ -760,6 +867,23 @@ impl ScrollStyle {
                 opacity_ui(ui, interact_handle_opacity);
                 ui.end_row();
             });
+
+            ui.horizontal(|ui| {
+                let mut auto_hide = auto_hide_delay.is_some();
+                ui.checkbox(&mut auto_hide, "Hide when not scrolling");
+                match (auto_hide, auto_hide_delay.as_mut()) {
+                    (true, Some(delay)) => {
+                        ui.add(
+                            DragValue::new(delay)
+                                .speed(0.01)
+                                .range(0.0..=10.0)
+                                .suffix(" s"),
+                        );
+                    }
+                    (true, None) => *auto_hide_delay = Some(1.0),
+                    (false, _) => *auto_hide_delay = None,
+                }
+            });
         } else {
             ui.horizontal(|ui| {
                 ui.add(DragValue::new(bar_inner_margin).range(0.0..=32.0));
@@ -817,6 +941,114 @@ impl ScrollFadeStyle {
 
 // ----------------------------------------------------------------------------
 
+/// How a container animates when it is shown or hidden.
+///
+/// Used by [`crate::Area`] (and so [`crate::Window`] and [`crate::Popup`]) and
+/// [`crate::CollapsingHeader`]. The defaults are set with [`Style::window_transition`],
+/// [`Style::popup_transition`] and [`Style::collapsing_transition`].
+///
+/// The transition lasts [`Style::animation_time`].
+/// With [`Style::reduce_motion`], only the fade is played.
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+pub struct Transition {
+    /// Fade the opacity in and out.
+    pub fade: bool,
+
+    /// Offset (in points) to slide in from and out to.
+    pub slide: Vec2,
+
+    /// Scale to grow from and shrink to, around the center. `1.0` means no scaling.
+    pub scale: f32,
+}
+
+impl Default for Transition {
+    fn default() -> Self {
+        Self::FADE
+    }
+}
+
+impl Transition {
+    /// Appear and disappear instantly.
+    pub const NONE: Self = Self {
+        fade: false,
+        slide: Vec2::ZERO,
+        scale: 1.0,
+    };
+
+    /// Fade in and out.
+    pub const FADE: Self = Self {
+        fade: true,
+        ..Self::NONE
+    };
+
+    /// Fade in while sliding in from `offset`, and the reverse when hiding.
+    pub fn slide(offset: impl Into<Vec2>) -> Self {
+        Self::FADE.with_slide(offset)
+    }
+
+    /// Fade in while growing from `scale`, and the reverse when hiding.
+    pub fn scale(scale: f32) -> Self {
+        Self::FADE.with_scale(scale)
+    }
+
+    #[inline]
+    pub fn with_fade(mut self, fade: bool) -> Self {
+        self.fade = fade;
+        self
+    }
+
+    #[inline]
+    pub fn with_slide(mut self, offset: impl Into<Vec2>) -> Self {
+        self.slide = offset.into();
+        self
+    }
+
+    #[inline]
+    pub fn with_scale(mut self, scale: f32) -> Self {
+        self.scale = scale;
+        self
+    }
+
+    /// Does this transition move or scale the container?
+    pub fn has_motion(&self) -> bool {
+        self.slide != Vec2::ZERO || self.scale != 1.0
+    }
+
+    /// The opacity when the container is `visible` (0 = hidden, 1 = fully shown).
+    pub fn opacity(&self, visible: f32) -> f32 {
+        if self.fade { visible } else { 1.0 }
+    }
+
+    /// How to transform a container covering `rect` when it is `visible` (0 = hidden, 1 = fully shown).
+    ///
+    /// Returns [`TSTransform::IDENTITY`] with `reduce_motion`.
+    pub fn transform(&self, visible: f32, rect: Rect, reduce_motion: bool) -> TSTransform {
+        if reduce_motion || !self.has_motion() {
+            return TSTransform::IDENTITY;
+        }
+        let hidden = 1.0 - visible;
+        let scaling = emath::lerp(self.scale..=1.0, visible);
+        let center = rect.center().to_vec2();
+        TSTransform::new(center - scaling * center + hidden * self.slide, scaling)
+    }
+
+    pub fn ui(&mut self, ui: &mut crate::Ui) {
+        let Self { fade, slide, scale } = self;
+        ui.horizontal(|ui| {
+            ui.checkbox(fade, "Fade");
+            ui.label("Slide:");
+            ui.add(DragValue::new(&mut slide.x).speed(1.0).prefix("x: "));
+            ui.add(DragValue::new(&mut slide.y).speed(1.0).prefix("y: "));
+            ui.label("Scale:");
+            ui.add(DragValue::new(scale).speed(0.01).range(0.0..=2.0));
+        });
+    }
+}
+
+// ----------------------------------------------------------------------------
+
 /// Scroll animation configuration, used when programmatically scrolling somewhere (e.g. with `[crate::Ui::scroll_to_cursor]`).
 ///
 /// The animation duration is calculated based on the distance to be scrolled via `[ScrollAnimation::points_per_second]`
@@ -830,6 +1062,12 @@ pub struct ScrollAnimation {
 
     /// The min / max scroll duration.
     pub duration: Rangef,
+
+    /// How the scrolling speeds up and slows down.
+    pub easing: ScrollEasing,
+
+    /// What happens if a new scroll target is set while this animation is still running.
+    pub interrupt: ScrollInterrupt,
 }
 
 impl Default for ScrollAnimation {
@@ -837,6 +1075,8 @@ impl Default for ScrollAnimation {
         Self {
             points_per_second: 1000.0,
             duration: Rangef::new(0.1, 0.3),
+            easing: ScrollEasing::default(),
+            interrupt: ScrollInterrupt::default(),
         }
     }
 }
@@ -847,6 +1087,7 @@ impl ScrollAnimation {
         Self {
             points_per_second,
             duration,
+            ..Default::default()
         }
     }
 
@@ -855,6 +1096,7 @@ impl ScrollAnimation {
         Self {
             points_per_second: f32::INFINITY,
             duration: Rangef::new(0.0, 0.0),
+            ..Default::default()
         }
     }
 
@@ -863,9 +1105,24 @@ impl ScrollAnimation {
         Self {
             points_per_second: f32::INFINITY,
             duration: Rangef::new(t, t),
+            ..Default::default()
         }
     }
 
+    /// Use a different easing curve.
+    #[inline]
+    pub fn with_easing(mut self, easing: ScrollEasing) -> Self {
+        self.easing = easing;
+        self
+    }
+
+    /// Choose what happens when a new target is set while still scrolling.
+    #[inline]
+    pub fn with_interrupt(mut self, interrupt: ScrollInterrupt) -> Self {
+        self.interrupt = interrupt;
+        self
+    }
+
     pub fn ui(&mut self, ui: &mut crate::Ui) {
         crate::Grid::new("scroll_animation").show(ui, |ui| {
             ui.label("Scroll animation:");
@@ -894,10 +1151,87 @@ impl ScrollAnimation {
             );
             ui.label("seconds");
             ui.end_row();
+
+            ui.label("Easing:");
+            ComboBox::from_id_salt("scroll_easing")
+                .selected_text(format!("{:?}", self.easing))
+                .show_ui(ui, |ui| {
+                    for easing in ScrollEasing::ALL {
+                        ui.selectable_value(&mut self.easing, easing, format!("{easing:?}"));
+                    }
+                });
+            ui.end_row();
+
+            ui.label("When interrupted:");
+            ui.horizontal(|ui| {
+                ui.radio_value(&mut self.interrupt, ScrollInterrupt::Blend, "Blend")
+                    .on_hover_text("Keep going, but towards the new target");
+                ui.radio_value(&mut self.interrupt, ScrollInterrupt::Restart, "Restart")
+                    .on_hover_text("Start a new animation towards the new target");
+            });
+            ui.end_row();
         });
     }
 }
 
+/// The easing curve of a [`ScrollAnimation`].
+///
+/// See [`emath::easing`] for what they look like.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub enum ScrollEasing {
+    /// Constant speed.
+    Linear,
+
+    /// Start slow, end slow.
+    #[default]
+    EaseInOut,
+
+    /// Start fast, end slow.
+    QuadraticOut,
+
+    /// Start fast, end slower than [`Self::QuadraticOut`].
+    CubicOut,
+
+    /// Start very fast, end very slow.
+    ExponentialOut,
+}
+
+impl ScrollEasing {
+    pub const ALL: [Self; 5] = [
+        Self::Linear,
+        Self::EaseInOut,
+        Self::QuadraticOut,
+        Self::CubicOut,
+        Self::ExponentialOut,
+    ];
+
+    /// Map the time `t` in `0..=1` to how far along the scroll is, also in `0..=1`.
+    pub fn ease(self, t: f32) -> f32 {
+        let t = t.clamp(0.0, 1.0);
+        match self {
+            Self::Linear => emath::easing::linear(t),
+            Self::EaseInOut => emath::ease_in_ease_out(t),
+            Self::QuadraticOut => emath::easing::quadratic_out(t),
+            Self::CubicOut => emath::easing::cubic_out(t),
+            Self::ExponentialOut => emath::easing::exponential_out(t),
+        }
+    }
+}
+
+/// What a running [`ScrollAnimation`] does when a new scroll target is set,
+/// e.g. when [`crate::Ui::scroll_to_cursor`] is called again before it has arrived.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub enum ScrollInterrupt {
+    /// Keep the current timing, but smoothly head for the new target instead.
+    #[default]
+    Blend,
+
+    /// Cancel the current animation, and start a new one from where we are.
+    Restart,
+}
+
 // ----------------------------------------------------------------------------
 
 /// How and when interaction happens.
@@ -930,6 +1264,12 @@ pub struct Interaction {
     /// This lets the user quickly move over some dead space to hover the next thing.
     pub tooltip_grace_time: f32,
 
+    /// Keep a tooltip open for this many seconds after the pointer leaves its widget.
+    pub tooltip_hide_delay: f32,
+
+    /// Where to show tooltips.
+    pub tooltip_position: TooltipPosition,
+
     /// Can you select the text on a [`crate::Label`] by default?
     pub selectable_labels: bool,
 
@@ -940,6 +1280,21 @@ pub struct Interaction {
     pub multi_widget_text_select: bool,
 }
 
+/// Where to show tooltips, see [`Interaction::tooltip_position`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub enum TooltipPosition {
+    /// Next to the hovered widget.
+    #[default]
+    Widget,
+
+    /// Next to the mouse pointer, following it as it moves.
+    FollowPointer,
+
+    /// Next to where the mouse pointer was when the tooltip opened.
+    Pointer,
+}
+
 /// Look and feel of the text cursor.
 #[derive(Clone, Debug, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
@@ -1427,11 +1782,16 @@ impl Default for Style {
             text_styles: default_text_styles(),
             drag_value_text_style: TextStyle::Button,
             number_formatter: NumberFormatter(Arc::new(emath::format_with_decimals_in_range)),
+            number_parser: NumberParser::default(),
             wrap_mode: None,
             spacing: Spacing::default(),
             interaction: Interaction::default(),
             visuals: Visuals::default(),
             animation_time: 0.2,
+            reduce_motion: false,
+            window_transition: Transition::FADE,
+            popup_transition: Transition::FADE,
+            collapsing_transition: Transition::NONE,
             #[cfg(debug_assertions)]
             debug: Default::default(),
             explanation_tooltips: false,
@@ -1461,6 +1821,7 @@ impl Default for Spacing {
             icon_spacing: 4.0,
             default_area_size: vec2(600.0, 400.0),
             tooltip_width: 500.0,
+            tooltip_ui_width: None,
             menu_width: 400.0,
             menu_spacing: 2.0,
             combo_height: 200.0,
@@ -1479,6 +1840,8 @@ impl Default for Interaction {
             show_tooltips_only_when_still: true,
             tooltip_delay: 0.5,
             tooltip_grace_time: 0.2,
+            tooltip_hide_delay: 0.0,
+            tooltip_position: TooltipPosition::Widget,
             selectable_labels: true,
             multi_widget_text_select: true,
         }
@@ -1783,11 +2146,16 @@ impl Style {
             text_styles,
             drag_value_text_style,
             number_formatter: _, // can't change callbacks in the UI
+            number_parser: _,    // can't change callbacks in the UI
             wrap_mode,
             spacing,
             interaction,
             visuals,
             animation_time,
+            reduce_motion,
+            window_transition,
+            popup_transition,
+            collapsing_transition,
             #[cfg(debug_assertions)]
             debug,
             explanation_tooltips,
@@ -1889,6 +2257,23 @@ impl Style {
                     .suffix(" s"),
             );
             ui.end_row();
+
+            ui.label("Reduce motion");
+            ui.checkbox(reduce_motion, "")
+                .on_hover_text("Only fade things in and out, without moving or scaling them");
+            ui.end_row();
+
+            ui.label("Window transition");
+            window_transition.ui(ui);
+            ui.end_row();
+
+            ui.label("Popup transition");
+            popup_transition.ui(ui);
+            ui.end_row();
+
+            ui.label("Collapsing transition");
+            collapsing_transition.ui(ui);
+            ui.end_row();
         });
 
         ui.collapsing("🔠 Text styles", |ui| text_styles_ui(ui, text_styles));
@@ -1950,6 +2335,7 @@ impl Spacing {
             icon_spacing,
             default_area_size,
             tooltip_width,
+            tooltip_ui_width,
             menu_width,
             menu_spacing,
             indent_ends_with_horizontal_line,
@@ -2015,6 +2401,20 @@ impl Spacing {
                 ui.add(DragValue::new(tooltip_width).range(0.0..=1000.0));
                 ui.end_row();
 
+                ui.label("Custom tooltip width")
+                    .on_hover_text("Width of tooltips with custom content, e.g. images");
+                ui.horizontal(|ui| {
+                    let mut custom = tooltip_ui_width.is_some();
+                    ui.checkbox(&mut custom, "");
+                    if custom {
+                        let width = tooltip_ui_width.get_or_insert(*tooltip_width);
+                        ui.add(DragValue::new(width).range(0.0..=1000.0));
+                    } else {
+                        *tooltip_ui_width = None;
+                    }
+                });
+                ui.end_row();
+
                 ui.label("Default menu width");
                 ui.add(DragValue::new(menu_width).range(0.0..=1000.0));
                 ui.end_row();
@@ -2072,6 +2472,8 @@ impl Interaction {
             show_tooltips_only_when_still,
             tooltip_delay,
             tooltip_grace_time,
+            tooltip_hide_delay,
+            tooltip_position,
             selectable_labels,
             multi_widget_text_select,
         } = self;
@@ -2116,6 +2518,29 @@ impl Interaction {
                         .suffix(" s"),
                 );
                 ui.end_row();
+
+                ui.label("Tooltip hide delay").on_hover_text(
+                    "Keep a tooltip open for this many seconds after the pointer leaves its widget",
+                );
+                ui.add(
+                    DragValue::new(tooltip_hide_delay)
+                        .range(0.0..=1.0)
+                        .speed(0.05)
+                        .suffix(" s"),
+                );
+                ui.end_row();
+
+                ui.label("Tooltip position");
+                ui.horizontal(|ui| {
+                    ui.selectable_value(tooltip_position, TooltipPosition::Widget, "Widget");
+                    ui.selectable_value(
+                        tooltip_position,
+                        TooltipPosition::FollowPointer,
+                        "Follow pointer",
+                    );
+                    ui.selectable_value(tooltip_position, TooltipPosition::Pointer, "Pointer");
+                });
+                ui.end_row();
             });
 
         ui.checkbox(
@@ -2736,10 +3161,10 @@ pub enum NumericColorSpace {
 
 impl NumericColorSpace {
     pub fn toggle_button_ui(&mut self, ui: &mut Ui) -> crate::Response {
-        let tooltip = match self {
-            Self::GammaByte => "Showing color values in 0-255 gamma space",
-            Self::Linear => "Showing color values in 0-1 linear space",
-        };
+        let tooltip = ui.ctx().localize(match self {
+            Self::GammaByte => BuiltinString::ColorSpaceGammaByte,
+            Self::Linear => BuiltinString::ColorSpaceLinear,
+        });
 
         let mut response = ui.button(self.to_string()).on_hover_text(tooltip);
         if response.clicked() {
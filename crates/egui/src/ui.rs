@@ -1237,9 +1237,10 @@ impl Ui {
     /// Returns where to put the widget.
     fn allocate_space_impl(&mut self, desired_size: Vec2) -> Rect {
         let item_spacing = self.spacing().item_spacing;
-        let frame_rect = self.placer.next_space(desired_size, item_spacing);
+        let frame_rect = self.snap_layout_rect(self.placer.next_space(desired_size, item_spacing));
         debug_assert!(!frame_rect.any_nan(), "frame_rect is nan in allocate_space");
-        let widget_rect = self.placer.justify_and_align(frame_rect, desired_size);
+        let widget_rect =
+            self.snap_layout_rect(self.placer.justify_and_align(frame_rect, desired_size));
 
         self.placer
             .advance_after_rects(frame_rect, widget_rect, item_spacing);
@@ -1249,12 +1250,18 @@ impl Ui {
         widget_rect
     }
 
+    /// Round a rect allocated by layout, according to [`crate::Options::pixel_snapping`].
+    fn snap_layout_rect(&self, rect: Rect) -> Rect {
+        let pixel_snapping = self.ctx().options(|o| o.pixel_snapping);
+        pixel_snapping.round_layout_rect(rect, self.ctx().pixels_per_point())
+    }
+
     /// Allocate a specific part of the [`Ui`].
     ///
     /// Ignore the layout of the [`Ui`]: just put my widget here!
     /// The layout cursor will advance to past this `rect`.
     pub fn allocate_rect(&mut self, rect: Rect, sense: Sense) -> Response {
-        let rect = rect.round_ui();
+        let rect = self.snap_layout_rect(rect.round_ui());
         let id = self.advance_cursor_after_rect(rect);
         self.interact(rect, id, sense)
     }
@@ -1262,7 +1269,7 @@ impl Ui {
     /// Allocate a rect without interacting with it.
     pub fn advance_cursor_after_rect(&mut self, rect: Rect) -> Id {
         debug_assert!(!rect.any_nan(), "rect is nan in advance_cursor_after_rect");
-        let rect = rect.round_ui();
+        let rect = self.snap_layout_rect(rect.round_ui());
 
         let item_spacing = self.spacing().item_spacing;
         self.placer.advance_after_rects(rect, rect, item_spacing);
@@ -1401,6 +1408,26 @@ impl Ui {
     }
 
     /// Same as [`Self::scroll_to_rect`], but allows you to specify the [`style::ScrollAnimation`].
+    ///
+    /// Use [`crate::scroll_area::State::scroll_target_reached`] to find out when the scrolling is done.
+    ///
+    /// ```
+    /// # use egui::{Align, style::{ScrollAnimation, ScrollEasing, ScrollInterrupt}};
+    /// # egui::__run_test_ui(|ui| {
+    /// let output = egui::ScrollArea::vertical().show(ui, |ui| {
+    ///     let response = ui.button("Center on me, slowly.");
+    ///     if response.clicked() {
+    ///         let animation = ScrollAnimation::duration(1.0)
+    ///             .with_easing(ScrollEasing::CubicOut)
+    ///             .with_interrupt(ScrollInterrupt::Restart);
+    ///         ui.scroll_to_rect_animation(response.rect, Some(Align::Center), animation);
+    ///     }
+    /// });
+    /// if output.state.scroll_target_reached() {
+    ///     // …
+    /// }
+    /// # });
+    /// ```
     pub fn scroll_to_rect_animation(
         &self,
         rect: Rect,
@@ -2147,6 +2174,53 @@ impl Ui {
         crate::Frame::group(self.style()).show(self, add_contents)
     }
 
+    /// Add empty space around the contents, with a separate size for each side.
+    ///
+    /// Use this instead of faking asymmetric spacing with [`Self::add_space`] calls.
+    ///
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// ui.with_margin(egui::Margin::ZERO.with_top(16).with_left(8), |ui| {
+    ///     ui.heading("Indented heading with extra space above");
+    /// });
+    /// # });
+    /// ```
+    ///
+    /// See also [`Frame::inner_margin`] and [`Frame::outer_margin`].
+    pub fn with_margin<R>(
+        &mut self,
+        margin: impl Into<Margin>,
+        add_contents: impl FnOnce(&mut Ui) -> R,
+    ) -> InnerResponse<R> {
+        crate::Frame::NONE
+            .inner_margin(margin)
+            .show(self, add_contents)
+    }
+
+    /// Use a different [`crate::style::Spacing::item_spacing`] for the contents.
+    ///
+    /// The spacing is inherited by all children, unless they override it again.
+    ///
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// ui.with_item_spacing(egui::vec2(4.0, 12.0), |ui| {
+    ///     ui.label("Tightly packed horizontally,");
+    ///     ui.label("but loosely packed vertically.");
+    /// });
+    /// # });
+    /// ```
+    pub fn with_item_spacing<R>(
+        &mut self,
+        item_spacing: impl Into<Vec2>,
+        add_contents: impl FnOnce(&mut Ui) -> R,
+    ) -> InnerResponse<R> {
+        let item_spacing = item_spacing.into();
+        self.scope(|ui| {
+            ui.spacing_mut().item_spacing = item_spacing;
+            add_contents(ui)
+        })
+    }
+
     /// Create a child Ui with an explicit [`Id`].
     ///
     /// ```
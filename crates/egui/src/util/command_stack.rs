@@ -0,0 +1,404 @@
+//! Undo and redo for applications, built from reversible commands.
+//!
+//! See [`CommandStack`].
+
+use std::collections::VecDeque;
+
+use crate::{Context, Id, Key, KeyboardShortcut, Modifiers};
+
+/// <kbd>Cmd/Ctrl</kbd> + <kbd>Z</kbd>
+pub const UNDO_SHORTCUT: KeyboardShortcut = KeyboardShortcut::new(Modifiers::COMMAND, Key::Z);
+
+/// <kbd>Cmd/Ctrl</kbd> + <kbd>Shift</kbd> + <kbd>Z</kbd>
+pub const REDO_SHORTCUT: KeyboardShortcut =
+    KeyboardShortcut::new(Modifiers::COMMAND.plus(Modifiers::SHIFT), Key::Z);
+
+/// <kbd>Cmd/Ctrl</kbd> + <kbd>Y</kbd>, an alternative to [`REDO_SHORTCUT`].
+pub const REDO_SHORTCUT_ALT: KeyboardShortcut = KeyboardShortcut::new(Modifiers::COMMAND, Key::Y);
+
+/// A reversible change to some `Target`, e.g. your application state.
+///
+/// Commands are run through a [`CommandStack`], which keeps track of them for undo and redo.
+pub trait Command<Target> {
+    /// Apply the change. Called when the command is executed, and again on redo.
+    fn apply(&mut self, target: &mut Target);
+
+    /// Revert what [`Self::apply`] did. Called on undo.
+    fn revert(&mut self, target: &mut Target);
+
+    /// A short description, e.g. `"Move shape"`, for showing "Undo Move shape" in a menu.
+    fn name(&self) -> String {
+        String::new()
+    }
+
+    /// Commands with the same coalesce id, executed in quick succession,
+    /// are undone and redone together as one step.
+    ///
+    /// Use this for continuous changes, like dragging a slider or typing,
+    /// e.g. by returning the [`Id`] of the widget doing the change.
+    ///
+    /// See [`Settings::coalesce_time`].
+    fn coalesce_id(&self) -> Option<Id> {
+        None
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct Settings {
+    /// Maximum number of undo steps.
+    ///
+    /// Default: `100`
+    pub max_undos: usize,
+
+    /// Commands with the same [`Command::coalesce_id`] are merged into one undo step
+    /// if they are executed less than this many seconds apart.
+    ///
+    /// Default value: `1.0` seconds.
+    pub coalesce_time: f32,
+}
+
+impl Default for Settings {
+    fn default() -> Self {
+        Self {
+            max_undos: 100,
+            coalesce_time: 1.0,
+        }
+    }
+}
+
+/// One undo step.
+struct Step<Target> {
+    /// In the order they were applied.
+    commands: Vec<Box<dyn Command<Target>>>,
+
+    coalesce_id: Option<Id>,
+
+    /// When a command was last added to this step.
+    time: f64,
+
+    /// Unique for every change, used for dirty-state tracking.
+    version: u64,
+}
+
+/// Undo and redo for commands, i.e. changes you make to your application state.
+///
+/// Unlike the [`super::undoer::Undoer`], which compares snapshots of the whole state,
+/// this only stores the changes, so it works well for large states.
+///
+/// ```
+/// use egui::util::command_stack::{Command, CommandStack};
+///
+/// struct Add(i32);
+///
+/// impl Command<i32> for Add {
+///     fn apply(&mut self, target: &mut i32) {
+///         *target += self.0;
+///     }
+///
+///     fn revert(&mut self, target: &mut i32) {
+///         *target -= self.0;
+///     }
+/// }
+///
+/// let mut value = 0;
+/// let mut stack = CommandStack::default();
+/// stack.execute(0.0, &mut value, Add(2));
+/// assert_eq!(value, 2);
+/// stack.undo(&mut value);
+/// assert_eq!(value, 0);
+/// stack.redo(&mut value);
+/// assert_eq!(value, 2);
+/// ```
+///
+/// Use [`Self::handle_shortcuts`] to undo and redo with the usual keyboard shortcuts,
+/// and [`Self::mark_clean`] and [`Self::is_dirty`] to track unsaved changes.
+pub struct CommandStack<Target> {
+    settings: Settings,
+
+    /// New steps are added to the back.
+    undos: VecDeque<Step<Target>>,
+
+    /// Steps that were undone, the most recent at the back.
+    /// Gets cleared every time a new command is executed.
+    redos: Vec<Step<Target>>,
+
+    /// Version of the latest undo step (`0` for none) when [`Self::mark_clean`] was called.
+    ///
+    /// `None` if that state can no longer be reached.
+    clean_version: Option<u64>,
+
+    next_version: u64,
+
+    /// Should the next command start a new step, even if it could be coalesced?
+    break_coalescing: bool,
+}
+
+impl<Target> std::fmt::Debug for CommandStack<Target> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let Self { undos, redos, .. } = self;
+        f.debug_struct("CommandStack")
+            .field("undo count", &undos.len())
+            .field("redo count", &redos.len())
+            .field("dirty", &self.is_dirty())
+            .finish()
+    }
+}
+
+impl<Target> Default for CommandStack<Target> {
+    #[inline]
+    fn default() -> Self {
+        Self::with_settings(Settings::default())
+    }
+}
+
+impl<Target> CommandStack<Target> {
+    /// Create a new [`CommandStack`] with the given [`Settings`].
+    pub fn with_settings(settings: Settings) -> Self {
+        Self {
+            settings,
+            undos: VecDeque::new(),
+            redos: Vec::new(),
+            clean_version: Some(0),
+            next_version: 1,
+            break_coalescing: false,
+        }
+    }
+
+    /// Apply the command to the target, and remember it for undo.
+    ///
+    /// This clears the redo history.
+    ///
+    /// * `current_time`: current time in seconds, e.g. [`crate::InputState::time`].
+    ///   Used for coalescing, see [`Command::coalesce_id`].
+    pub fn execute(
+        &mut self,
+        current_time: f64,
+        target: &mut Target,
+        mut command: impl Command<Target> + 'static,
+    ) {
+        command.apply(target);
+
+        if self
+            .redos
+            .drain(..)
+            .any(|step| Some(step.version) == self.clean_version)
+        {
+            self.clean_version = None; // The clean state can no longer be reached
+        }
+
+        let version = self.next_version;
+        self.next_version += 1;
+
+        let coalesce_id = command.coalesce_id();
+        let coalesce = !std::mem::take(&mut self.break_coalescing);
+        if let Some(step) = self.undos.back_mut()
+            && coalesce
+            && coalesce_id.is_some()
+            && step.coalesce_id == coalesce_id
+            && current_time - step.time < self.settings.coalesce_time as f64
+        {
+            step.commands.push(Box::new(command));
+            step.time = current_time;
+            step.version = version;
+            return;
+        }
+
+        self.undos.push_back(Step {
+            commands: vec![Box::new(command)],
+            coalesce_id,
+            time: current_time,
+            version,
+        });
+        while self.undos.len() > self.settings.max_undos {
+            self.undos.pop_front();
+        }
+    }
+
+    /// Make sure the next command starts a new undo step, even if it could be coalesced
+    /// with the previous one.
+    ///
+    /// Call this e.g. when the user stops dragging.
+    pub fn break_coalescing(&mut self) {
+        self.break_coalescing = true;
+    }
+
+    pub fn has_undo(&self) -> bool {
+        !self.undos.is_empty()
+    }
+
+    pub fn has_redo(&self) -> bool {
+        !self.redos.is_empty()
+    }
+
+    /// The [`Command::name`] of what would be undone.
+    pub fn undo_name(&self) -> Option<String> {
+        self.undos.back().map(Self::step_name)
+    }
+
+    /// The [`Command::name`] of what would be redone.
+    pub fn redo_name(&self) -> Option<String> {
+        self.redos.last().map(Self::step_name)
+    }
+
+    fn step_name(step: &Step<Target>) -> String {
+        step.commands
+            .last()
+            .map(|command| command.name())
+            .unwrap_or_default()
+    }
+
+    /// Revert the latest undo step. Returns `false` if there was nothing to undo.
+    pub fn undo(&mut self, target: &mut Target) -> bool {
+        let Some(mut step) = self.undos.pop_back() else {
+            return false;
+        };
+        for command in step.commands.iter_mut().rev() {
+            command.revert(target);
+        }
+        self.redos.push(step);
+        self.break_coalescing = true;
+        true
+    }
+
+    /// Re-apply the latest undone step. Returns `false` if there was nothing to redo.
+    pub fn redo(&mut self, target: &mut Target) -> bool {
+        let Some(mut step) = self.redos.pop() else {
+            return false;
+        };
+        for command in &mut step.commands {
+            command.apply(target);
+        }
+        self.undos.push_back(step);
+        self.break_coalescing = true;
+        true
+    }
+
+    /// Forget all undo and redo history.
+    ///
+    /// The current state is considered clean afterwards.
+    pub fn clear(&mut self) {
+        self.undos.clear();
+        self.redos.clear();
+        self.clean_version = Some(0);
+        self.break_coalescing = false;
+    }
+
+    /// Mark the current state as clean, e.g. after saving it.
+    pub fn mark_clean(&mut self) {
+        self.clean_version = Some(self.current_version());
+        self.break_coalescing = true;
+    }
+
+    /// Has the state changed since the last call to [`Self::mark_clean`]?
+    ///
+    /// Undoing back to the clean state makes it clean again.
+    pub fn is_dirty(&self) -> bool {
+        self.clean_version != Some(self.current_version())
+    }
+
+    fn current_version(&self) -> u64 {
+        self.undos.back().map_or(0, |step| step.version)
+    }
+
+    /// Undo or redo if the user pressed [`UNDO_SHORTCUT`], [`REDO_SHORTCUT`] or [`REDO_SHORTCUT_ALT`].
+    ///
+    /// Does nothing while a [`crate::TextEdit`] has keyboard focus,
+    /// since it uses the same shortcuts for its own undo.
+    ///
+    /// Returns `true` if something was undone or redone.
+    pub fn handle_shortcuts(&mut self, ctx: &Context, target: &mut Target) -> bool {
+        if ctx.text_edit_focused() {
+            return false;
+        }
+
+        // Check redo first, since the undo shortcut would also match it:
+        let (redo, undo) = ctx.input_mut(|i| {
+            let redo = i.consume_shortcut(&REDO_SHORTCUT) || i.consume_shortcut(&REDO_SHORTCUT_ALT);
+            let undo = i.consume_shortcut(&UNDO_SHORTCUT);
+            (redo, undo)
+        });
+
+        let mut changed = false;
+        if redo {
+            changed |= self.redo(target);
+        }
+        if undo {
+            changed |= self.undo(target);
+        }
+        changed
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct Push(char, Option<Id>);
+
+    impl Command<String> for Push {
+        fn apply(&mut self, target: &mut String) {
+            target.push(self.0);
+        }
+
+        fn revert(&mut self, target: &mut String) {
+            target.pop();
+        }
+
+        fn coalesce_id(&self) -> Option<Id> {
+            self.1
+        }
+    }
+
+    #[test]
+    fn coalescing() {
+        let id = Some(Id::new("typing"));
+        let mut text = String::new();
+        let mut stack = CommandStack::default();
+
+        stack.execute(0.0, &mut text, Push('a', id));
+        stack.execute(0.5, &mut text, Push('b', id));
+        stack.execute(2.0, &mut text, Push('c', id)); // too late
+        stack.execute(2.1, &mut text, Push('d', None));
+        assert_eq!(text, "abcd");
+
+        assert!(stack.undo(&mut text));
+        assert_eq!(text, "abc");
+        assert!(stack.undo(&mut text));
+        assert_eq!(text, "ab");
+        assert!(stack.undo(&mut text));
+        assert_eq!(text, "");
+        assert!(!stack.undo(&mut text));
+
+        assert!(stack.redo(&mut text));
+        assert_eq!(text, "ab");
+    }
+
+    #[test]
+    fn dirty_tracking() {
+        let mut text = String::new();
+        let mut stack = CommandStack::default();
+        assert!(!stack.is_dirty());
+
+        stack.execute(0.0, &mut text, Push('a', None));
+        assert!(stack.is_dirty());
+        stack.mark_clean();
+        assert!(!stack.is_dirty());
+
+        stack.execute(1.0, &mut text, Push('b', None));
+        assert!(stack.is_dirty());
+        stack.undo(&mut text);
+        assert!(!stack.is_dirty());
+        stack.undo(&mut text);
+        assert!(stack.is_dirty());
+        stack.redo(&mut text);
+        assert!(!stack.is_dirty());
+
+        // Executing a new command while the clean state is in the redo history loses it:
+        stack.undo(&mut text);
+        stack.execute(2.0, &mut text, Push('c', None));
+        stack.undo(&mut text);
+        assert_eq!(text, "");
+        assert!(stack.is_dirty());
+    }
+}
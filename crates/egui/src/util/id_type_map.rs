@@ -3,7 +3,13 @@
 // For non-serializable types, these simply return `None`.
 // This will also allow users to pick their own serialization format per type.
 
-use std::{any::Any, sync::Arc};
+use std::{
+    any::Any,
+    sync::{
+        Arc,
+        atomic::{AtomicU64, Ordering},
+    },
+};
 // -----------------------------------------------------------------------------------------------
 
 /// Like [`std::any::TypeId`], but can be serialized and deserialized.
@@ -87,6 +93,12 @@ enum Element {
         /// None if non-serializable type.
         #[cfg(feature = "persistence")]
         serialize_fn: Option<Serializer>,
+
+        /// [`std::any::type_name`] of the value.
+        type_name: &'static str,
+
+        /// The [`IdTypeMap::pass_nr`] when the value was last accessed.
+        last_touched: AtomicU64,
     },
 
     /// A serialized value
@@ -101,11 +113,15 @@ impl Clone for Element {
                 clone_fn,
                 #[cfg(feature = "persistence")]
                 serialize_fn,
+                type_name,
+                last_touched,
             } => Self::Value {
                 value: clone_fn(value),
                 clone_fn: *clone_fn,
                 #[cfg(feature = "persistence")]
                 serialize_fn: *serialize_fn,
+                type_name,
+                last_touched: AtomicU64::new(last_touched.load(Ordering::Relaxed)),
             },
 
             Self::Serialized(element) => Self::Serialized(element.clone()),
@@ -116,9 +132,16 @@ impl Clone for Element {
 impl std::fmt::Debug for Element {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match &self {
-            Self::Value { value, .. } => f
+            Self::Value {
+                value,
+                type_name,
+                last_touched,
+                ..
+            } => f
                 .debug_struct("Element::Value")
                 .field("type_id", &(**value).type_id())
+                .field("type_name", type_name)
+                .field("last_touched", last_touched)
                 .finish_non_exhaustive(),
             Self::Serialized(SerializedElement {
                 type_id,
@@ -137,7 +160,7 @@ impl std::fmt::Debug for Element {
 impl Element {
     /// Create a value that won't be persisted.
     #[inline]
-    pub(crate) fn new_temp<T: 'static + Any + Clone + Send + Sync>(t: T) -> Self {
+    pub(crate) fn new_temp<T: 'static + Any + Clone + Send + Sync>(t: T, pass_nr: u64) -> Self {
         Self::Value {
             value: Box::new(t),
             clone_fn: |x| {
@@ -148,12 +171,14 @@ impl Element {
             },
             #[cfg(feature = "persistence")]
             serialize_fn: None,
+            type_name: std::any::type_name::<T>(),
+            last_touched: AtomicU64::new(pass_nr),
         }
     }
 
     /// Create a value that will be persisted.
     #[inline]
-    pub(crate) fn new_persisted<T: SerializableAny>(t: T) -> Self {
+    pub(crate) fn new_persisted<T: SerializableAny>(t: T, pass_nr: u64) -> Self {
         Self::Value {
             value: Box::new(t),
             clone_fn: |x| {
@@ -169,6 +194,8 @@ impl Element {
                 let x = x.downcast_ref::<T>().unwrap();
                 ron::to_string(x).ok()
             }),
+            type_name: std::any::type_name::<T>(),
+            last_touched: AtomicU64::new(pass_nr),
         }
     }
 
@@ -181,6 +208,36 @@ impl Element {
         }
     }
 
+    /// [`std::any::type_name`] of the value, unless it is still serialized.
+    fn type_name(&self) -> Option<&'static str> {
+        match self {
+            Self::Value { type_name, .. } => Some(type_name),
+            Self::Serialized(_) => None,
+        }
+    }
+
+    /// Serialized values count as last touched when they were loaded, i.e. in pass `0`.
+    fn last_touched(&self) -> u64 {
+        match self {
+            Self::Value { last_touched, .. } => last_touched.load(Ordering::Relaxed),
+            Self::Serialized(_) => 0,
+        }
+    }
+
+    fn touch(&self, pass_nr: u64) {
+        if let Self::Value { last_touched, .. } = self {
+            last_touched.store(pass_nr, Ordering::Relaxed);
+        }
+    }
+
+    /// Shallow size of a value, or the size of its serialized form.
+    fn size_in_bytes(&self) -> usize {
+        match self {
+            Self::Value { value, .. } => std::mem::size_of_val::<dyn Any + Send + Sync>(&**value),
+            Self::Serialized(SerializedElement { ron, .. }) => ron.len(),
+        }
+    }
+
     pub fn is_temp(&self) -> bool {
         match self {
             #[cfg(feature = "persistence")]
@@ -212,16 +269,17 @@ impl Element {
     #[inline]
     pub(crate) fn get_temp_mut_or_insert_with<T: 'static + Any + Clone + Send + Sync>(
         &mut self,
+        pass_nr: u64,
         insert_with: impl FnOnce() -> T,
     ) -> &mut T {
         match self {
             Self::Value { value, .. } => {
                 if !value.is::<T>() {
-                    *self = Self::new_temp(insert_with());
+                    *self = Self::new_temp(insert_with(), pass_nr);
                 }
             }
             Self::Serialized(_) => {
-                *self = Self::new_temp(insert_with());
+                *self = Self::new_temp(insert_with(), pass_nr);
             }
         }
 
@@ -236,23 +294,27 @@ impl Element {
     #[inline]
     pub(crate) fn get_persisted_mut_or_insert_with<T: SerializableAny>(
         &mut self,
+        pass_nr: u64,
         insert_with: impl FnOnce() -> T,
     ) -> &mut T {
         match self {
             Self::Value { value, .. } => {
                 if !value.is::<T>() {
-                    *self = Self::new_persisted(insert_with());
+                    *self = Self::new_persisted(insert_with(), pass_nr);
                 }
             }
 
             #[cfg(feature = "persistence")]
             Self::Serialized(SerializedElement { ron, .. }) => {
-                *self = Self::new_persisted(from_ron_str::<T>(ron).unwrap_or_else(insert_with));
+                *self = Self::new_persisted(
+                    from_ron_str::<T>(ron).unwrap_or_else(insert_with),
+                    pass_nr,
+                );
             }
 
             #[cfg(not(feature = "persistence"))]
             Self::Serialized(_) => {
-                *self = Self::new_persisted(insert_with());
+                *self = Self::new_persisted(insert_with(), pass_nr);
             }
         }
 
@@ -264,13 +326,16 @@ impl Element {
         }
     }
 
-    pub(crate) fn get_mut_persisted<T: SerializableAny>(&mut self) -> Option<&mut T> {
+    pub(crate) fn get_mut_persisted<T: SerializableAny>(
+        &mut self,
+        #[cfg_attr(not(feature = "persistence"), expect(unused_variables))] pass_nr: u64,
+    ) -> Option<&mut T> {
         match self {
             Self::Value { value, .. } => value.downcast_mut(),
 
             #[cfg(feature = "persistence")]
             Self::Serialized(SerializedElement { ron, .. }) => {
-                *self = Self::new_persisted(from_ron_str::<T>(ron)?);
+                *self = Self::new_persisted(from_ron_str::<T>(ron)?, pass_nr);
 
                 match self {
                     Self::Value { value, .. } => value.downcast_mut(),
@@ -407,6 +472,11 @@ pub struct IdTypeMap {
     map: nohash_hasher::IntMap<RawKey, Element>,
 
     max_bytes_per_type: usize,
+
+    /// Incremented by [`Self::begin_pass`].
+    pass_nr: u64,
+
+    max_idle_passes: Option<u64>,
 }
 
 impl Default for IdTypeMap {
@@ -414,6 +484,8 @@ impl Default for IdTypeMap {
         Self {
             map: Default::default(),
             max_bytes_per_type: 256 * 1024,
+            pass_nr: 0,
+            max_idle_passes: None,
         }
     }
 }
@@ -427,7 +499,7 @@ impl IdTypeMap {
         value: T,
     ) -> RawKey {
         let key = RawKey::new::<T>(id);
-        self.map.insert(key, Element::new_temp(value));
+        self.map.insert(key, Element::new_temp(value, self.pass_nr));
         key
     }
 
@@ -435,7 +507,8 @@ impl IdTypeMap {
     #[inline]
     pub fn insert_persisted<T: SerializableAny>(&mut self, id: Id, value: T) {
         let key = RawKey::new::<T>(id);
-        self.map.insert(key, Element::new_persisted(value));
+        self.map
+            .insert(key, Element::new_persisted(value, self.pass_nr));
         // We don't yet return the key here, because currently all our `raw`
         // methods are only for temporary values.
     }
@@ -446,14 +519,18 @@ impl IdTypeMap {
     #[inline]
     pub fn get_temp<T: 'static + Clone>(&self, id: Id) -> Option<T> {
         let key = RawKey::new::<T>(id);
-        self.map.get(&key).and_then(|x| x.get_temp()).cloned()
+        let element = self.map.get(&key)?;
+        element.touch(self.pass_nr);
+        element.get_temp().cloned()
     }
 
     /// Gets a reference to a value for a given raw key.
     ///
     /// Serialized values are ignored.
     pub fn get_temp_raw(&self, raw: RawKey) -> Option<&(dyn Any + Send + Sync)> {
-        match self.map.get(&raw)? {
+        let element = self.map.get(&raw)?;
+        element.touch(self.pass_nr);
+        match element {
             Element::Value { value, .. } => Some(value.as_ref()),
             Element::Serialized(_) => None,
         }
@@ -463,7 +540,9 @@ impl IdTypeMap {
     ///
     /// Serialized values are ignored.
     pub fn get_temp_raw_mut(&mut self, raw: RawKey) -> Option<&mut (dyn Any + Send + Sync)> {
-        match self.map.get_mut(&raw)? {
+        let element = self.map.get_mut(&raw)?;
+        element.touch(self.pass_nr);
+        match element {
             Element::Value { value, .. } => Some(value.as_mut()),
             Element::Serialized(_) => None,
         }
@@ -478,10 +557,9 @@ impl IdTypeMap {
     #[inline]
     pub fn get_persisted<T: SerializableAny>(&mut self, id: Id) -> Option<T> {
         let key = RawKey::new::<T>(id);
-        self.map
-            .get_mut(&key)
-            .and_then(|x| x.get_mut_persisted())
-            .cloned()
+        let element = self.map.get_mut(&key)?;
+        element.touch(self.pass_nr);
+        element.get_mut_persisted(self.pass_nr).cloned()
     }
 
     #[inline]
@@ -523,12 +601,14 @@ impl IdTypeMap {
                 // this unwrap will never panic, because we insert correct type right now
                 #[expect(clippy::unwrap_used)]
                 vacant
-                    .insert(Element::new_temp(insert_with()))
+                    .insert(Element::new_temp(insert_with(), self.pass_nr))
                     .get_mut_temp()
                     .unwrap()
             }
             Entry::Occupied(occupied) => {
-                occupied.into_mut().get_temp_mut_or_insert_with(insert_with)
+                let element = occupied.into_mut();
+                element.touch(self.pass_nr);
+                element.get_temp_mut_or_insert_with(self.pass_nr, insert_with)
             }
         }
     }
@@ -545,13 +625,15 @@ impl IdTypeMap {
                 // this unwrap will never panic, because we insert correct type right now
                 #[expect(clippy::unwrap_used)]
                 vacant
-                    .insert(Element::new_persisted(insert_with()))
-                    .get_mut_persisted()
+                    .insert(Element::new_persisted(insert_with(), self.pass_nr))
+                    .get_mut_persisted(self.pass_nr)
                     .unwrap()
             }
-            Entry::Occupied(occupied) => occupied
-                .into_mut()
-                .get_persisted_mut_or_insert_with(insert_with),
+            Entry::Occupied(occupied) => {
+                let element = occupied.into_mut();
+                element.touch(self.pass_nr);
+                element.get_persisted_mut_or_insert_with(self.pass_nr, insert_with)
+            }
         }
     }
 
@@ -573,6 +655,19 @@ impl IdTypeMap {
         self.map.remove(&key);
     }
 
+    /// Move the state of this type from one id to another, replacing any state at `to`.
+    ///
+    /// Works for both temporary and persisted values, without deserializing them.
+    /// Returns `false` if there was no state at `from`.
+    pub fn rename<T: 'static>(&mut self, from: Id, to: Id) -> bool {
+        if let Some(element) = self.map.remove(&RawKey::new::<T>(from)) {
+            self.map.insert(RawKey::new::<T>(to), element);
+            true
+        } else {
+            false
+        }
+    }
+
     /// Remove and fetch the state of this type and id.
     #[inline]
     pub fn remove_temp<T: 'static + Default>(&mut self, id: Id) -> Option<T> {
@@ -674,6 +769,149 @@ impl IdTypeMap {
     pub fn set_max_bytes_per_type(&mut self, max_bytes_per_type: usize) {
         self.max_bytes_per_type = max_bytes_per_type;
     }
+
+    /// How many passes this map has seen. Incremented by [`Self::begin_pass`].
+    ///
+    /// Each value remembers the pass in which it was last read or written,
+    /// see [`IdTypeMapEntry::last_touched_pass`].
+    #[inline]
+    pub fn pass_nr(&self) -> u64 {
+        self.pass_nr
+    }
+
+    /// Start a new pass, and remove stale values if [`Self::max_idle_passes`] is set.
+    ///
+    /// Called by egui at the start of each pass (of any viewport).
+    pub fn begin_pass(&mut self) {
+        self.pass_nr += 1;
+        if let Some(max_idle_passes) = self.max_idle_passes {
+            self.remove_untouched_since(self.pass_nr.saturating_sub(max_idle_passes));
+        }
+    }
+
+    /// If set, values that haven't been read or written in this many passes
+    /// are removed at the start of each pass.
+    ///
+    /// This stops apps that generate lots of unique [`Id`]s from slowly leaking memory.
+    /// Note that this also removes persisted state of widgets that haven't been shown in a while,
+    /// such as whether a collapsing header in a closed window is open.
+    ///
+    /// Values loaded from disk count as last touched in pass `0`.
+    ///
+    /// Default: `None` (never remove values).
+    /// This value in itself will not be serialized.
+    pub fn max_idle_passes(&self) -> Option<u64> {
+        self.max_idle_passes
+    }
+
+    /// See [`Self::max_idle_passes`].
+    pub fn set_max_idle_passes(&mut self, max_idle_passes: Option<u64>) {
+        self.max_idle_passes = max_idle_passes;
+    }
+
+    /// Remove all values that were last read or written before the given pass.
+    ///
+    /// Returns the number of removed values.
+    pub fn remove_untouched_since(&mut self, pass_nr: u64) -> usize {
+        let len_before = self.map.len();
+        self.map.retain(|_, e| pass_nr <= e.last_touched());
+        len_before - self.map.len()
+    }
+
+    /// Information about all values in the map, in arbitrary order.
+    ///
+    /// Use this to find out what is taking up memory, e.g. with [`Self::stats_per_type`].
+    pub fn entries(&self) -> impl Iterator<Item = IdTypeMapEntry> + '_ {
+        self.map.iter().map(|(key, element)| IdTypeMapEntry {
+            key: *key,
+            type_id: element.type_id(),
+            type_name: element.type_name(),
+            is_temp: element.is_temp(),
+            is_serialized: matches!(element, Element::Serialized(_)),
+            size_in_bytes: element.size_in_bytes(),
+            last_touched_pass: element.last_touched(),
+        })
+    }
+
+    /// Statistics of the values in the map, grouped by type, with the most common type first.
+    pub fn stats_per_type(&self) -> Vec<IdTypeMapTypeStats> {
+        let mut stats: nohash_hasher::IntMap<TypeId, IdTypeMapTypeStats> = Default::default();
+
+        for entry in self.entries() {
+            let type_stats = stats
+                .entry(entry.type_id)
+                .or_insert_with(|| IdTypeMapTypeStats {
+                    type_id: entry.type_id,
+                    type_name: None,
+                    count: 0,
+                    num_serialized: 0,
+                    size_in_bytes: 0,
+                    oldest_touched_pass: entry.last_touched_pass,
+                });
+            type_stats.type_name = type_stats.type_name.or(entry.type_name);
+            type_stats.count += 1;
+            type_stats.num_serialized += entry.is_serialized as usize;
+            type_stats.size_in_bytes += entry.size_in_bytes;
+            type_stats.oldest_touched_pass =
+                type_stats.oldest_touched_pass.min(entry.last_touched_pass);
+        }
+
+        let mut stats: Vec<_> = stats.into_values().collect();
+        stats.sort_by(|a, b| b.count.cmp(&a.count).then(a.type_name.cmp(&b.type_name)));
+        stats
+    }
+}
+
+/// Information about a value in an [`IdTypeMap`]. See [`IdTypeMap::entries`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct IdTypeMapEntry {
+    /// The key of the value, which combines its [`Id`] and [`TypeId`].
+    pub key: RawKey,
+
+    pub type_id: TypeId,
+
+    /// [`std::any::type_name`] of the value.
+    ///
+    /// `None` for values loaded from disk that haven't been read yet.
+    pub type_name: Option<&'static str>,
+
+    /// Will this value be forgotten when the app is closed?
+    pub is_temp: bool,
+
+    /// Is this value loaded from disk, but not yet read?
+    pub is_serialized: bool,
+
+    /// The shallow size of the value, or the size of its serialized form.
+    ///
+    /// Heap allocations owned by the value are not included.
+    pub size_in_bytes: usize,
+
+    /// The [`IdTypeMap::pass_nr`] in which the value was last read or written.
+    pub last_touched_pass: u64,
+}
+
+/// Statistics about all values of one type in an [`IdTypeMap`].
+/// See [`IdTypeMap::stats_per_type`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct IdTypeMapTypeStats {
+    pub type_id: TypeId,
+
+    /// [`std::any::type_name`] of the type.
+    ///
+    /// `None` if all values of this type are loaded from disk and haven't been read yet.
+    pub type_name: Option<&'static str>,
+
+    /// Number of values of this type.
+    pub count: usize,
+
+    /// How many of [`Self::count`] are loaded from disk, but not yet read.
+    pub num_serialized: usize,
+
+    /// See [`IdTypeMapEntry::size_in_bytes`].
+    pub size_in_bytes: usize,
+
+    /// The [`IdTypeMap::pass_nr`] in which the least recently used value of this type was touched.
+    pub oldest_touched_pass: u64,
 }
 
 // ----------------------------------------------------------------------------
@@ -1098,3 +1336,34 @@ fn test_serialize_gc() {
         Some(B(2_000_000))
     );
 }
+
+#[test]
+fn test_stats_and_gc() {
+    let a = Id::new("a");
+    let b = Id::new("b");
+    let c = Id::new("c");
+
+    let mut map: IdTypeMap = Default::default();
+    map.set_max_idle_passes(Some(2));
+    map.insert_temp(a, 1_i32);
+    map.insert_temp(b, 2_i32);
+    map.insert_persisted(c, 3.0_f64);
+
+    let stats = map.stats_per_type();
+    assert_eq!(stats.len(), 2);
+    assert_eq!(stats[0].type_name, Some("i32"));
+    assert_eq!(stats[0].count, 2);
+    assert_eq!(stats[0].size_in_bytes, 8);
+    assert_eq!(stats[1].type_name, Some("f64"));
+
+    for _ in 0..3 {
+        map.begin_pass();
+        assert_eq!(map.get_temp::<i32>(a), Some(1));
+    }
+
+    assert_eq!(map.len(), 1, "only the value we kept reading is left");
+    assert_eq!(
+        map.entries().next().map(|e| e.last_touched_pass),
+        Some(map.pass_nr())
+    );
+}
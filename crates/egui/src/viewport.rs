@@ -310,6 +310,11 @@ pub struct ViewportBuilder {
     pub active: Option<bool>,
     pub visible: Option<bool>,
 
+    /// Create the window hidden, and show it once its first frame has been painted.
+    ///
+    /// See [`Self::with_show_when_ready`].
+    pub show_when_ready: Option<bool>,
+
     // macOS:
     pub fullsize_content_view: Option<bool>,
     pub movable_by_window_background: Option<bool>,
@@ -463,6 +468,21 @@ impl ViewportBuilder {
         self
     }
 
+    /// Create the window hidden, and only show it once its first frame has been painted.
+    ///
+    /// This avoids the window flashing white (or showing garbage) while waiting for
+    /// its first frame.
+    ///
+    /// If you also set [`Self::with_visible`] to `false`, the window stays hidden until you send
+    /// [`ViewportCommand::ShowWhenReady`], e.g. once your fonts and images have been loaded.
+    ///
+    /// `eframe` does this for the root viewport by default.
+    #[inline]
+    pub fn with_show_when_ready(mut self, show_when_ready: bool) -> Self {
+        self.show_when_ready = Some(show_when_ready);
+        self
+    }
+
     /// macOS: Makes the window content appear behind the titlebar.
     ///
     /// You often want to combine this with [`Self::with_titlebar_shown`]
@@ -729,6 +749,7 @@ impl ViewportBuilder {
             icon: new_icon,
             active: new_active,
             visible: new_visible,
+            show_when_ready: new_show_when_ready,
             drag_and_drop: new_drag_and_drop,
             fullsize_content_view: new_fullsize_content_view,
             movable_by_window_background: new_movable_by_window_background,
@@ -838,6 +859,11 @@ impl ViewportBuilder {
             commands.push(ViewportCommand::Visible(new_visible));
         }
 
+        if new_show_when_ready.is_some() {
+            // Only affects how the window is created, so there is nothing to update.
+            self.show_when_ready = new_show_when_ready;
+        }
+
         if let Some(new_mouse_passthrough) = new_mouse_passthrough
             && Some(new_mouse_passthrough) != self.mouse_passthrough
         {
@@ -959,6 +985,10 @@ impl ViewportBuilder {
 }
 
 /// For winit platform compatibility, see [`winit::WindowLevel` documentation](https://docs.rs/winit/latest/winit/window/enum.WindowLevel.html#platform-specific)
+///
+/// Wayland has no window levels for regular windows.
+/// Docks, bars, and lock-screen style overlays need the wlr-layer-shell protocol,
+/// which winit, and therefore `egui-winit` and `eframe`, does not support yet.
 #[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
 #[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
 pub enum WindowLevel {
@@ -966,6 +996,15 @@ pub enum WindowLevel {
     Normal,
     AlwaysOnBottom,
     AlwaysOnTop,
+
+    /// Like [`Self::AlwaysOnTop`], but only while one of the windows of the app has focus.
+    ///
+    /// Useful for tool palettes, which should stay above the main window,
+    /// but not above other apps.
+    ///
+    /// The integration needs to track focus across all viewports for this,
+    /// which `eframe` does.
+    AlwaysOnTopWhileAppFocused,
 }
 
 #[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
@@ -1096,6 +1135,13 @@ pub enum ViewportCommand {
     /// Set the visibility of the window.
     Visible(bool),
 
+    /// Show the window once the next frame has been painted.
+    ///
+    /// Unlike [`Self::Visible`], which shows the window right away, this never shows a window
+    /// without content. Use it together with [`ViewportBuilder::with_visible`]`(false)`
+    /// to reveal a window only once it is ready.
+    ShowWhenReady,
+
     /// Moves the window with the left mouse button until the button is released.
     ///
     /// There's no guarantee that this will work unless the left mouse button was pressed
@@ -1146,6 +1192,18 @@ pub enum ViewportCommand {
     /// command is ignored (logged as a warning).
     SetMonitor(usize),
 
+    /// Enter exclusive fullscreen on the monitor at the given index, changing its video mode.
+    ///
+    /// Use one of the [`crate::MonitorInfo::video_modes`] of [`crate::ViewportInfo::monitors`].
+    /// Leave exclusive fullscreen with [`Self::Fullscreen`]`(false)`.
+    ///
+    /// If the monitor or video mode can't be found, the command is ignored (logged as a warning).
+    /// Not supported on Wayland or the web.
+    ExclusiveFullscreen {
+        monitor: usize,
+        video_mode: crate::VideoMode,
+    },
+
     /// Show window decorations, i.e. the chrome around the content
     /// with the title bar, close buttons, resize handles, etc.
     Decorations(bool),
@@ -1161,6 +1219,16 @@ pub enum ViewportCommand {
     IMEAllowed(bool),
     IMEPurpose(IMEPurpose),
 
+    /// Show or hide the on-screen (soft) keyboard.
+    ///
+    /// egui shows the keyboard automatically when a [`crate::TextEdit`] gains focus,
+    /// and hides it when it loses focus.
+    /// Use this to override that until the next focus change,
+    /// e.g. to bring the keyboard back after the user dismissed it.
+    ///
+    /// Only has an effect on Android.
+    SoftKeyboardVisible(bool),
+
     /// Bring the window into focus (native only).
     ///
     /// This command puts the window on top of other applications and takes input focus away from them,
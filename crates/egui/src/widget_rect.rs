@@ -144,6 +144,27 @@ impl WidgetRects {
         self.by_layer.get(&layer_id).into_iter().flatten()
     }
 
+    /// Position of this widget among the focusable widgets with the same parent,
+    /// as `(position, count)` with the position starting at 1.
+    ///
+    /// Returns `None` if the widget is unknown, not focusable, or has no focusable siblings.
+    pub fn focusable_position(&self, id: Id) -> Option<(usize, usize)> {
+        let widget = self.get(id)?;
+        let mut position = None;
+        let mut count = 0;
+        for sibling in self.get_layer(widget.layer_id) {
+            if sibling.parent_id == widget.parent_id && sibling.sense.is_focusable() {
+                count += 1;
+                if sibling.id == id {
+                    position = Some(count);
+                }
+            }
+        }
+        position
+            .filter(|_| 1 < count)
+            .map(|position| (position, count))
+    }
+
     /// Clear the contents while retaining allocated memory.
     pub fn clear(&mut self) {
         let Self {
@@ -27,6 +27,7 @@ pub struct RichText {
     text: String,
     size: Option<f32>,
     extra_letter_spacing: f32,
+    extra_word_spacing: f32,
     line_height: Option<f32>,
     family: Option<FontFamily>,
     text_style: Option<TextStyle>,
@@ -49,6 +50,7 @@ impl Default for RichText {
             text: Default::default(),
             size: Default::default(),
             extra_letter_spacing: Default::default(),
+            extra_word_spacing: Default::default(),
             line_height: Default::default(),
             family: Default::default(),
             text_style: Default::default(),
@@ -162,6 +164,15 @@ impl RichText {
         self
     }
 
+    /// Extra spacing added to each space between words, in points.
+    ///
+    /// Default: 0.0.
+    #[inline]
+    pub fn extra_word_spacing(mut self, extra_word_spacing: f32) -> Self {
+        self.extra_word_spacing = extra_word_spacing;
+        self
+    }
+
     /// Explicit line height of the text in points.
     ///
     /// This is the distance between the bottom row of two subsequent lines of text.
@@ -403,6 +414,7 @@ impl RichText {
             text,
             size,
             extra_letter_spacing,
+            extra_word_spacing,
             line_height,
             family,
             text_style,
@@ -465,6 +477,7 @@ impl RichText {
             crate::text::TextFormat {
                 font_id,
                 extra_letter_spacing,
+                extra_word_spacing,
                 line_height,
                 color: text_color,
                 background: background_color,
@@ -474,6 +487,8 @@ impl RichText {
                 strikethrough,
                 valign,
                 expand_bg,
+                font_features: Default::default(),
+                ruby: None,
             },
         )
     }
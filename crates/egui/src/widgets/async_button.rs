@@ -0,0 +1,229 @@
+use std::sync::Arc;
+
+use epaint::mutex::Mutex;
+
+use crate::{
+    Atom, Atoms, Button, Context, IdSalt, IntoAtoms, Response, RichText, Spinner, TextStyle, Ui,
+    Vec2, id_salt::AsIdSalt,
+};
+
+/// The state of the operation started by an [`AsyncButton`].
+#[derive(Clone, Debug, Default, PartialEq)]
+pub enum AsyncState {
+    /// Nothing has been started yet.
+    #[default]
+    Idle,
+
+    /// The operation is in progress.
+    Running {
+        /// How far along it is, in `[0, 1]`, if known.
+        progress: Option<f32>,
+    },
+
+    /// The operation finished successfully.
+    Succeeded,
+
+    /// The operation failed with this error message.
+    Failed(String),
+}
+
+impl AsyncState {
+    /// Is the operation in progress?
+    #[inline]
+    pub fn is_running(&self) -> bool {
+        matches!(self, Self::Running { .. })
+    }
+
+    /// Did the operation succeed or fail?
+    #[inline]
+    pub fn is_finished(&self) -> bool {
+        matches!(self, Self::Succeeded | Self::Failed(_))
+    }
+}
+
+/// A handle for reporting the progress of an operation started by an [`AsyncButton`].
+///
+/// This is `Send`, so you can move it into a thread, or an `async` block
+/// running on whatever executor you use.
+/// Each update requests a repaint, so the button reflects it right away.
+#[derive(Clone)]
+pub struct AsyncTask {
+    state: Arc<Mutex<AsyncState>>,
+    ctx: Context,
+}
+
+impl AsyncTask {
+    /// Report how far along the operation is, in `[0, 1]`.
+    pub fn set_progress(&self, progress: f32) {
+        self.set(AsyncState::Running {
+            progress: Some(progress.clamp(0.0, 1.0)),
+        });
+    }
+
+    /// The operation finished successfully.
+    pub fn succeed(&self) {
+        self.set(AsyncState::Succeeded);
+    }
+
+    /// The operation failed.
+    pub fn fail(&self, error: impl Into<String>) {
+        self.set(AsyncState::Failed(error.into()));
+    }
+
+    /// Report the result of the operation.
+    pub fn finish<E: ToString>(&self, result: Result<(), E>) {
+        match result {
+            Ok(()) => self.succeed(),
+            Err(err) => self.fail(err.to_string()),
+        }
+    }
+
+    /// The current state.
+    pub fn state(&self) -> AsyncState {
+        self.state.lock().clone()
+    }
+
+    fn set(&self, state: AsyncState) {
+        *self.state.lock() = state;
+        self.ctx.request_repaint();
+    }
+}
+
+/// Stored in temporary memory, shared with the [`AsyncTask`].
+#[derive(Clone, Default)]
+struct AsyncButtonMemory {
+    state: Arc<Mutex<AsyncState>>,
+
+    /// Have we reported the finished state in [`AsyncButtonResponse::just_finished`]?
+    reported_finish: bool,
+}
+
+/// A button that starts an operation running in the background, and tracks it.
+///
+/// While the operation is running the button shows a spinner (and the progress, if reported)
+/// and can't be clicked again.
+/// When it is done, the button shows whether it succeeded or failed,
+/// and clicking it starts the operation anew.
+///
+/// ```
+/// # egui::__run_test_ui(|ui| {
+/// # fn save_to_disk() -> std::io::Result<()> { Ok(()) }
+/// let response = egui::AsyncButton::new("Save").show(ui, |task| {
+///     std::thread::spawn(move || {
+///         task.set_progress(0.5);
+///         task.finish(save_to_disk());
+///     });
+/// });
+/// if response.just_finished {
+///     // e.g. show a notification
+/// }
+/// # });
+/// ```
+#[must_use = "You should call .show()"]
+pub struct AsyncButton<'a> {
+    atoms: Atoms<'a>,
+    id_salt: Option<IdSalt>,
+}
+
+impl<'a> AsyncButton<'a> {
+    pub fn new(atoms: impl IntoAtoms<'a>) -> Self {
+        Self {
+            atoms: atoms.into_atoms(),
+            id_salt: None,
+        }
+    }
+
+    /// Explicitly set the source of the [`crate::Id`] of this button.
+    ///
+    /// By default it uses the position in the [`Ui`], so set this if the button moves around,
+    /// or the state of the operation could get lost.
+    #[inline]
+    pub fn id_salt(mut self, id_salt: impl AsIdSalt) -> Self {
+        self.id_salt = Some(IdSalt::new(id_salt));
+        self
+    }
+
+    /// Show the button.
+    ///
+    /// When it is clicked, `start` is called with a handle to report the outcome through.
+    pub fn show(self, ui: &mut Ui, start: impl FnOnce(AsyncTask)) -> AsyncButtonResponse {
+        let Self { atoms, id_salt } = self;
+
+        let id = match id_salt {
+            Some(id_salt) => ui.make_persistent_id(id_salt),
+            None => ui.next_auto_id().with("async_button"),
+        };
+        let mut memory: AsyncButtonMemory = ui.data_mut(|d| d.get_temp(id)).unwrap_or_default();
+        let state = memory.state.lock().clone();
+
+        let spinner_id = id.with("spinner");
+        let button = Button::new(atoms);
+        let button = match &state {
+            AsyncState::Idle => button,
+            AsyncState::Running { progress } => {
+                let size = Vec2::splat(ui.text_style_height(&TextStyle::Button));
+                let spinner = Atom::custom(spinner_id, size);
+                match progress {
+                    Some(progress) => {
+                        button.right_text((spinner, format!("{:.0}%", progress * 100.0)))
+                    }
+                    None => button.right_text(spinner),
+                }
+            }
+            AsyncState::Succeeded => button.right_text("✔"),
+            AsyncState::Failed(_) => {
+                button.right_text(RichText::new("⚠").color(ui.visuals().error_fg_color))
+            }
+        };
+
+        let atom_response = ui
+            .add_enabled_ui(!state.is_running(), |ui| button.atom_ui(ui))
+            .inner;
+        if let Some(rect) = atom_response.rect(spinner_id) {
+            Spinner::new().paint_at(ui, rect);
+        }
+        let mut response = atom_response.response;
+
+        if let AsyncState::Failed(error) = &state {
+            response = response.on_hover_text(error);
+        }
+
+        let just_finished = state.is_finished() && !memory.reported_finish;
+        memory.reported_finish |= just_finished;
+
+        let mut state = state;
+        if response.clicked() && !state.is_running() {
+            state = AsyncState::Running { progress: None };
+            memory = AsyncButtonMemory {
+                state: Arc::new(Mutex::new(state.clone())),
+                reported_finish: false,
+            };
+            start(AsyncTask {
+                state: Arc::clone(&memory.state),
+                ctx: ui.ctx().clone(),
+            });
+        }
+
+        ui.data_mut(|d| d.insert_temp(id, memory));
+
+        AsyncButtonResponse {
+            response,
+            state,
+            just_finished,
+        }
+    }
+}
+
+/// The result of showing an [`AsyncButton`].
+pub struct AsyncButtonResponse {
+    /// The response of the button itself.
+    pub response: Response,
+
+    /// The state of the operation, as of this pass.
+    pub state: AsyncState,
+
+    /// Did the operation succeed or fail since the last pass?
+    ///
+    /// This is `true` for only one pass per operation.
+    pub just_finished: bool,
+}
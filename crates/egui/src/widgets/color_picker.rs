@@ -1,5 +1,6 @@
 //! Color picker widgets.
 
+use crate::localization::BuiltinString;
 use crate::util::fixed_cache::FixedCache;
 use crate::{
     Context, DragValue, Id, Painter, Popup, PopupCloseBehavior, Response, Sense, Ui, Widget as _,
@@ -341,15 +342,24 @@ fn color_picker_hsvag_2d(ui: &mut Ui, hsvag: &mut HsvaGamma, alpha: Alpha) {
         Hsva::from(*hsvag).to_srgba_unmultiplied(),
         current_color_size,
     )
-    .on_hover_text("Selected color");
+    .on_hover_text(ui.ctx().localize(BuiltinString::ColorPickerSelectedColor));
 
     if alpha == Alpha::BlendOrAdditive {
         let a = &mut hsvag.a;
         let mut additive = is_additive_alpha(*a);
         ui.horizontal(|ui| {
-            ui.label("Blending:");
-            ui.radio_value(&mut additive, false, "Normal");
-            ui.radio_value(&mut additive, true, "Additive");
+            ui.label(ui.ctx().localize(BuiltinString::ColorPickerBlending));
+            ui.radio_value(
+                &mut additive,
+                false,
+                ui.ctx().localize(BuiltinString::ColorPickerNormalBlending),
+            );
+            ui.radio_value(
+                &mut additive,
+                true,
+                ui.ctx()
+                    .localize(BuiltinString::ColorPickerAdditiveBlending),
+            );
 
             if additive {
                 *a = -a.abs();
@@ -384,7 +394,7 @@ fn color_picker_hsvag_2d(ui: &mut Ui, hsvag: &mut HsvaGamma, alpha: Alpha) {
         }
         .into()
     })
-    .on_hover_text("Hue");
+    .on_hover_text(ui.ctx().localize(BuiltinString::ColorPickerHue));
 
     let additive = is_additive_alpha(hsvag.a);
 
@@ -397,9 +407,11 @@ fn color_picker_hsvag_2d(ui: &mut Ui, hsvag: &mut HsvaGamma, alpha: Alpha) {
             if is_additive_alpha(*a) {
                 *a = 0.5; // was additive, but isn't allowed to be
             }
-            color_slider_1d(ui, a, |a| HsvaGamma { a, ..opaque }.into()).on_hover_text("Alpha");
+            color_slider_1d(ui, a, |a| HsvaGamma { a, ..opaque }.into())
+                .on_hover_text(ui.ctx().localize(BuiltinString::ColorPickerAlpha));
         } else if !additive {
-            color_slider_1d(ui, a, |a| HsvaGamma { a, ..opaque }.into()).on_hover_text("Alpha");
+            color_slider_1d(ui, a, |a| HsvaGamma { a, ..opaque }.into())
+                .on_hover_text(ui.ctx().localize(BuiltinString::ColorPickerAlpha));
         }
     }
 }
@@ -425,7 +437,7 @@ fn srgba_edit_ui(ui: &mut Ui, [r, g, b, a]: &mut [u8; 4], alpha: Alpha) -> bool
 
         if ui
             .button("📋")
-            .on_hover_text("Click to copy color values")
+            .on_hover_text(ui.ctx().localize(BuiltinString::ColorPickerCopyValues))
             .clicked()
         {
             if alpha == Alpha::Opaque {
@@ -466,7 +478,7 @@ fn rgba_edit_ui(ui: &mut Ui, [r, g, b, a]: &mut [f32; 4], alpha: Alpha) -> bool
 
         if ui
             .button("📋")
-            .on_hover_text("Click to copy color values")
+            .on_hover_text(ui.ctx().localize(BuiltinString::ColorPickerCopyValues))
             .clicked()
         {
             if alpha == Alpha::Opaque {
@@ -520,7 +532,8 @@ pub fn color_edit_button_hsva(ui: &mut Ui, hsva: &mut Hsva, alpha: Alpha) -> Res
     let open = Popup::is_id_open(ui.ctx(), popup_id);
     let mut button_response = color_button(ui, hsva.to_srgba_unmultiplied(), open);
     if ui.style().explanation_tooltips {
-        button_response = button_response.on_hover_text("Click to edit color");
+        button_response =
+            button_response.on_hover_text(ui.ctx().localize(BuiltinString::ColorPickerEdit));
     }
 
     const COLOR_SLIDER_WIDTH: f32 = 275.0;
@@ -250,6 +250,9 @@ impl<'a> DragValue<'a> {
     /// A custom parser takes an `&str` to parse into a number and returns a `f64` if it was successfully parsed
     /// or `None` otherwise.
     ///
+    /// The default parser is [`crate::Style::number_parser`], which also evaluates
+    /// simple math expressions like `1920/2` when the edit is committed.
+    ///
     /// See also: [`DragValue::custom_formatter`]
     ///
     /// ```
@@ -542,7 +545,7 @@ impl Widget for DragValue<'_> {
             if let Some(value_text) = value_text {
                 // We were editing the value as text last frame, but lost focus.
                 // Make sure we applied the last text value:
-                let parsed_value = parse(custom_parser.as_ref(), &value_text);
+                let parsed_value = parse(ui, custom_parser.as_ref(), &value_text, true);
                 if let Some(mut parsed_value) = parsed_value {
                     // User edits always clamps:
                     parsed_value = clamp_value_to_range(parsed_value, range.clone());
@@ -574,15 +577,16 @@ impl Widget for DragValue<'_> {
                 select_all_text(ui, id, response.id, &value_text);
             }
 
+            let committed = response.lost_focus() && !ui.input(|i| i.key_pressed(Key::Escape));
             let update = if update_while_editing {
                 // Update when the edit content has changed.
-                response.changed()
+                response.changed() || committed
             } else {
                 // Update only when the edit has lost focus.
-                response.lost_focus() && !ui.input(|i| i.key_pressed(Key::Escape))
+                committed
             };
             if update {
-                let parsed_value = parse(custom_parser.as_ref(), &value_text);
+                let parsed_value = parse(ui, custom_parser.as_ref(), &value_text, committed);
                 if let Some(mut parsed_value) = parsed_value {
                     // User edits always clamps:
                     parsed_value = clamp_value_to_range(parsed_value, range.clone());
@@ -724,9 +728,19 @@ impl Widget for DragValue<'_> {
     }
 }
 
-fn parse(custom_parser: Option<&NumParser<'_>>, value_text: &str) -> Option<f64> {
+/// Parse the text of the [`TextEdit`].
+///
+/// While editing, only plain numbers are accepted so the value doesn't jump around while an
+/// expression is typed. On commit, [`crate::Style::number_parser`] evaluates the whole text.
+fn parse(
+    ui: &Ui,
+    custom_parser: Option<&NumParser<'_>>,
+    value_text: &str,
+    committed: bool,
+) -> Option<f64> {
     match custom_parser {
         Some(parser) => parser(value_text),
+        None if committed => ui.style().number_parser.parse(value_text),
         None => default_parser(value_text),
     }
 }
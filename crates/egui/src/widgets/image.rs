@@ -9,7 +9,7 @@ use epaint::{
 use crate::{
     Color32, Context, CornerRadius, Id, Mesh, Painter, Rect, Response, Sense, Shape, Spinner,
     TextStyle, TextureOptions, Ui, Vec2, Widget, WidgetInfo, WidgetType,
-    load::{Bytes, SizeHint, SizedTexture, TextureLoadResult, TexturePoll},
+    load::{Bytes, LoadPriority, SizeHint, SizedTexture, TextureLoadResult, TexturePoll},
     pos2,
 };
 
@@ -56,6 +56,7 @@ pub struct Image<'a> {
     size: ImageSize,
     pub(crate) show_loading_spinner: Option<bool>,
     pub(crate) alt_text: Option<String>,
+    load_priority: LoadPriority,
 }
 
 impl<'a> Image<'a> {
@@ -82,6 +83,7 @@ impl<'a> Image<'a> {
                 size,
                 show_loading_spinner: None,
                 alt_text: None,
+                load_priority: LoadPriority::Normal,
             }
         }
 
@@ -273,6 +275,16 @@ impl<'a> Image<'a> {
         self.alt_text = Some(label.into());
         self
     }
+
+    /// How urgently to load the image, compared to other images.
+    ///
+    /// Images are only loaded while they are visible.
+    /// See [`crate::load::LoadScheduler`] for details.
+    #[inline]
+    pub fn load_priority(mut self, load_priority: LoadPriority) -> Self {
+        self.load_priority = load_priority;
+        self
+    }
 }
 
 impl<'a, T: Into<ImageSource<'a>>> From<T> for Image<'a> {
@@ -384,6 +396,8 @@ impl<'a> Image<'a> {
             },
         );
 
+        self.request_load(ui, rect);
+
         paint_texture_load_result(
             ui,
             &texture,
@@ -393,6 +407,16 @@ impl<'a> Image<'a> {
             self.alt_text.as_deref(),
         );
     }
+
+    /// Tell the [`crate::load::LoadScheduler`] whether we need the image.
+    fn request_load(&self, ui: &Ui, rect: Rect) {
+        if let Some(uri) = self.source.uri() {
+            ui.ctx()
+                .loaders()
+                .scheduler
+                .request(uri, self.load_priority, ui.is_rect_visible(rect));
+        }
+    }
 }
 
 impl Widget for Image<'_> {
@@ -402,6 +426,7 @@ impl Widget for Image<'_> {
         let ui_size = self.calc_size(ui.available_size(), image_source_size);
 
         let (rect, response) = ui.allocate_exact_size(ui_size, self.sense);
+        self.request_load(ui, rect);
         response.widget_info(|| {
             let mut info = WidgetInfo::new(WidgetType::Image);
             info.label = self.alt_text.clone();
@@ -905,30 +930,132 @@ pub fn decode_animated_image_uri(uri: &str) -> Result<(&str, usize), String> {
     Ok((uri, index))
 }
 
+/// The playback state of an animated image (gif, webp or apng).
+///
+/// The state is shared by all [`Image`]s showing the same uri.
+/// By default an animation is playing, in a loop, from when the app started.
+///
+/// ```
+/// # egui::__run_test_ui(|ui| {
+/// let uri = "https://example.com/sticker.gif";
+/// ui.image(uri);
+///
+/// let mut playback = egui::AnimationPlayback::load(ui.ctx(), uri);
+/// if playback.is_playing() {
+///     if ui.button("⏸").clicked() {
+///         playback.pause(ui.ctx());
+///     }
+/// } else if ui.button("▶").clicked() {
+///     playback.play(ui.ctx());
+/// }
+/// if ui.button("⏮").clicked() {
+///     playback.seek(ui.ctx(), std::time::Duration::ZERO);
+/// }
+/// playback.store(ui.ctx(), uri);
+/// # });
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct AnimationPlayback {
+    /// The [`crate::InputState::time`] at which the animation was at its start.
+    start_time: f64,
+
+    /// The position the animation was paused at, in seconds.
+    paused_at: Option<f64>,
+}
+
+impl AnimationPlayback {
+    /// Load the playback state of the animated image at `uri`.
+    pub fn load(ctx: &Context, uri: &str) -> Self {
+        ctx.data(|data| data.get_temp(Id::new(uri)))
+            .unwrap_or_default()
+    }
+
+    /// Store the playback state of the animated image at `uri`.
+    pub fn store(self, ctx: &Context, uri: &str) {
+        ctx.data_mut(|data| data.insert_temp(Id::new(uri), self));
+        ctx.request_repaint();
+    }
+
+    /// Total length of one loop of the animation at `uri`.
+    ///
+    /// `None` if the image is not loaded yet, or is not animated.
+    pub fn duration(ctx: &Context, uri: &str) -> Option<Duration> {
+        let durations: FrameDurations = ctx.data(|data| data.get_temp(Id::new(uri)))?;
+        Some(durations.all().sum())
+    }
+
+    #[inline]
+    pub fn is_playing(&self) -> bool {
+        self.paused_at.is_none()
+    }
+
+    /// How far into the animation we are.
+    ///
+    /// This keeps growing past the end of the animation, which then loops.
+    pub fn position(&self, ctx: &Context) -> Duration {
+        let seconds = self
+            .paused_at
+            .unwrap_or_else(|| ctx.input(|i| i.time) - self.start_time);
+        Duration::from_secs_f64(seconds.max(0.0))
+    }
+
+    /// Resume playing from where the animation was paused.
+    pub fn play(&mut self, ctx: &Context) {
+        if let Some(paused_at) = self.paused_at.take() {
+            self.start_time = ctx.input(|i| i.time) - paused_at;
+        }
+    }
+
+    /// Freeze the animation at its current frame.
+    pub fn pause(&mut self, ctx: &Context) {
+        if self.is_playing() {
+            self.paused_at = Some(self.position(ctx).as_secs_f64());
+        }
+    }
+
+    /// Jump to `position` in the animation, keeping it playing or paused.
+    pub fn seek(&mut self, ctx: &Context, position: Duration) {
+        let position = position.as_secs_f64();
+        if self.is_playing() {
+            self.start_time = ctx.input(|i| i.time) - position;
+        } else {
+            self.paused_at = Some(position);
+        }
+    }
+}
+
 /// Calculates at which frame the animated image is
 fn animated_image_frame_index(ctx: &Context, uri: &str) -> usize {
-    let now = ctx.input(|input| Duration::from_secs_f64(input.time));
-
     let durations: Option<FrameDurations> = ctx.data(|data| data.get_temp(Id::new(uri)));
+    let Some(durations) = durations else {
+        return 0;
+    };
+
+    let playback = AnimationPlayback::load(ctx, uri);
+    let (index, until_next_frame) = frame_index_at(&durations, playback.position(ctx));
+    if playback.is_playing() {
+        ctx.request_repaint_after(until_next_frame);
+    }
+    index
+}
 
-    if let Some(durations) = durations {
-        let frames: Duration = durations.all().sum();
-        let pos_ms = now.as_millis() % frames.as_millis().max(1);
+/// Which frame is shown at `position`, and for how much longer.
+fn frame_index_at(durations: &FrameDurations, position: Duration) -> (usize, Duration) {
+    let frames: Duration = durations.all().sum();
+    let pos_ms = position.as_millis() % frames.as_millis().max(1);
 
-        let mut cumulative_ms = 0;
+    let mut cumulative_ms = 0;
 
-        for (index, duration) in durations.all().enumerate() {
-            cumulative_ms += duration.as_millis();
+    for (index, duration) in durations.all().enumerate() {
+        cumulative_ms += duration.as_millis();
 
-            if pos_ms < cumulative_ms {
-                let ms_until_next_frame = cumulative_ms - pos_ms;
-                ctx.request_repaint_after(Duration::from_millis(ms_until_next_frame as u64));
-                return index;
-            }
+        if pos_ms < cumulative_ms {
+            let ms_until_next_frame = cumulative_ms - pos_ms;
+            return (index, Duration::from_millis(ms_until_next_frame as u64));
         }
     }
 
-    0
+    (0, Duration::ZERO)
 }
 
 /// Checks if uri is a gif file
@@ -951,10 +1078,86 @@ pub fn has_webp_header(bytes: &[u8]) -> bool {
     bytes.len() >= 12 && &bytes[0..4] == b"RIFF" && &bytes[8..12] == b"WEBP"
 }
 
+/// Checks if uri is an animated png file.
+///
+/// Since most png files are not animated, only the `.apng` extension counts.
+/// Use [`ImageSource::Bytes`] to show animated png files with a `.png` extension.
+fn is_apng_uri(uri: &str) -> bool {
+    uri.ends_with(".apng") || uri.contains(".apng#")
+}
+
+/// Checks if bytes are an animated png
+pub fn has_apng_header(bytes: &[u8]) -> bool {
+    const PNG_SIGNATURE: &[u8] = b"\x89PNG\r\n\x1a\n";
+    if !bytes.starts_with(PNG_SIGNATURE) {
+        return false;
+    }
+
+    // An animated png has an `acTL` chunk before its first `IDAT` chunk:
+    let mut offset = PNG_SIGNATURE.len();
+    while let Some(chunk) = bytes.get(offset..offset + 8) {
+        let length = u32::from_be_bytes([chunk[0], chunk[1], chunk[2], chunk[3]]) as usize;
+        match &chunk[4..8] {
+            b"acTL" => return true,
+            b"IDAT" | b"IEND" => return false,
+            _ => {}
+        }
+        offset = offset.saturating_add(12).saturating_add(length); // length + type + data + crc
+    }
+    false
+}
+
 fn is_animated_image_uri(uri: &str) -> bool {
-    is_gif_uri(uri) || is_webp_uri(uri)
+    is_gif_uri(uri) || is_webp_uri(uri) || is_apng_uri(uri)
 }
 
 fn are_animated_image_bytes(bytes: &[u8]) -> bool {
-    has_gif_magic_header(bytes) || has_webp_header(bytes)
+    has_gif_magic_header(bytes) || has_webp_header(bytes) || has_apng_header(bytes)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn apng_header() {
+        fn chunk(kind: &[u8; 4], data: &[u8]) -> Vec<u8> {
+            let mut chunk = (data.len() as u32).to_be_bytes().to_vec();
+            chunk.extend_from_slice(kind);
+            chunk.extend_from_slice(data);
+            chunk.extend_from_slice(&[0; 4]); // crc
+            chunk
+        }
+
+        let png = |chunks: &[Vec<u8>]| {
+            let mut bytes = b"\x89PNG\r\n\x1a\n".to_vec();
+            for chunk in chunks {
+                bytes.extend_from_slice(chunk);
+            }
+            bytes
+        };
+
+        let ihdr = chunk(b"IHDR", &[0; 13]);
+        let actl = chunk(b"acTL", &[0; 8]);
+        let idat = chunk(b"IDAT", &[0; 3]);
+
+        assert!(has_apng_header(&png(&[
+            ihdr.clone(),
+            actl.clone(),
+            idat.clone()
+        ])));
+        assert!(!has_apng_header(&png(&[ihdr.clone(), idat.clone(), actl])));
+        assert!(!has_apng_header(&png(&[ihdr, idat])));
+        assert!(!has_apng_header(b"GIF89a"));
+    }
+
+    #[test]
+    fn frame_index_at_position() {
+        let durations =
+            FrameDurations::new(vec![Duration::from_millis(100), Duration::from_millis(50)]);
+        let at = |ms| frame_index_at(&durations, Duration::from_millis(ms));
+        assert_eq!(at(0), (0, Duration::from_millis(100)));
+        assert_eq!(at(120), (1, Duration::from_millis(30)));
+        assert_eq!(at(160), (0, Duration::from_millis(90)), "loops");
+    }
 }
@@ -1,8 +1,9 @@
 use std::sync::Arc;
 
 use crate::{
-    Align, Direction, FontSelection, Galley, Pos2, Response, Sense, Stroke, TextWrapMode, Ui,
-    Widget, WidgetInfo, WidgetText, WidgetType, epaint, pos2, text_selection::LabelSelectionState,
+    Align, Direction, FontSelection, Galley, Overflow, OverflowBox, Pos2, Response, Sense, Stroke,
+    TextWrapMode, Ui, Widget, WidgetInfo, WidgetText, WidgetType, epaint, pos2,
+    text_selection::LabelSelectionState, vec2,
 };
 
 /// Static text.
@@ -28,6 +29,7 @@ pub struct Label {
     sense: Option<Sense>,
     selectable: Option<bool>,
     halign: Option<Align>,
+    overflow: Option<Overflow>,
     show_tooltip_when_elided: bool,
 }
 
@@ -39,6 +41,7 @@ impl Label {
             sense: None,
             selectable: None,
             halign: None,
+            overflow: None,
             show_tooltip_when_elided: true,
         }
     }
@@ -117,6 +120,31 @@ impl Label {
         self
     }
 
+    /// What to do with text that is wider than the available space.
+    ///
+    /// The text is kept on one line. [`Overflow::Visible`] is the same as [`Self::extend`],
+    /// and [`Overflow::Ellipsis`] the same as [`Self::truncate`].
+    /// The other policies show the label in an [`OverflowBox`] as wide as the available space.
+    /// For [`Overflow::Scroll`] the text is used as the id salt of the scroll area.
+    ///
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// ui.add(egui::Label::new("A long name that fades out").overflow(egui::Overflow::Fade));
+    /// # });
+    /// ```
+    #[inline]
+    pub fn overflow(mut self, overflow: Overflow) -> Self {
+        match overflow {
+            Overflow::Visible => self.wrap_mode = Some(TextWrapMode::Extend),
+            Overflow::Ellipsis => self.wrap_mode = Some(TextWrapMode::Truncate),
+            Overflow::Clip | Overflow::Fade | Overflow::Scroll => {
+                self.wrap_mode = Some(TextWrapMode::Extend);
+                self.overflow = Some(overflow);
+            }
+        }
+        self
+    }
+
     /// Show the full text when hovered, if the text was elided.
     ///
     /// By default, this is true.
@@ -268,7 +296,16 @@ impl Label {
 }
 
 impl Widget for Label {
-    fn ui(self, ui: &mut Ui) -> Response {
+    fn ui(mut self, ui: &mut Ui) -> Response {
+        if let Some(overflow) = self.overflow.take() {
+            let text = self.text.text().to_owned();
+            return OverflowBox::new(vec2(ui.available_width(), f32::INFINITY))
+                .id_salt(text)
+                .overflow(overflow)
+                .show(ui, |ui| self.ui(ui))
+                .inner;
+        }
+
         // Interactive = the uses asked to sense interaction.
         // We DON'T want to have the color respond just because the text is selectable;
         // the cursor is enough to communicate that.
@@ -6,6 +6,7 @@
 
 use crate::{Response, Ui};
 
+mod async_button;
 mod button;
 mod checkbox;
 pub mod color_picker;
@@ -21,13 +22,15 @@ mod spinner;
 pub mod text_edit;
 
 pub use self::{
+    async_button::{AsyncButton, AsyncButtonResponse, AsyncState, AsyncTask},
     button::Button,
     checkbox::Checkbox,
     drag_value::DragValue,
     hyperlink::{Hyperlink, Link},
     image::{
-        FrameDurations, Image, ImageFit, ImageOptions, ImageSize, ImageSource,
-        decode_animated_image_uri, has_gif_magic_header, has_webp_header, paint_texture_at,
+        AnimationPlayback, FrameDurations, Image, ImageFit, ImageOptions, ImageSize, ImageSource,
+        decode_animated_image_uri, has_apng_header, has_gif_magic_header, has_webp_header,
+        paint_texture_at,
     },
     label::Label,
     progress_bar::ProgressBar,
@@ -15,6 +15,12 @@ use super::drag_value::clamp_value_to_range;
 type NumFormatter<'a> = Box<dyn 'a + Fn(f64, RangeInclusive<usize>) -> String>;
 type NumParser<'a> = Box<dyn 'a + Fn(&str) -> Option<f64>>;
 
+/// A monotonic function from slider values to a space where the slider is linear, and its inverse.
+struct SliderMapping<'a> {
+    to_linear: Box<dyn 'a + Fn(f64) -> f64>,
+    from_linear: Box<dyn 'a + Fn(f64) -> f64>,
+}
+
 // ----------------------------------------------------------------------------
 
 /// Combined into one function (rather than two) to make it easier
@@ -115,6 +121,9 @@ pub struct Slider<'a> {
     max_decimals: Option<usize>,
     custom_formatter: Option<NumFormatter<'a>>,
     custom_parser: Option<NumParser<'a>>,
+    custom_mapping: Option<SliderMapping<'a>>,
+    detents: Vec<f64>,
+    detent_magnetism: f32,
     trailing_fill: Option<bool>,
     handle_shape: Option<HandleShape>,
     update_while_editing: bool,
@@ -166,6 +175,9 @@ impl<'a> Slider<'a> {
             max_decimals: None,
             custom_formatter: None,
             custom_parser: None,
+            custom_mapping: None,
+            detents: Vec::new(),
+            detent_magnetism: 6.0,
             trailing_fill: None,
             handle_shape: None,
             update_while_editing: true,
@@ -249,6 +261,57 @@ impl<'a> Slider<'a> {
         self
     }
 
+    /// Use a custom mapping between the value and the slider position.
+    ///
+    /// `to_linear` maps a value to a space where the slider moves linearly,
+    /// and `from_linear` must be its inverse.
+    /// The mapping must be monotonic (increasing or decreasing) over the slider range,
+    /// and finite at both of its ends.
+    ///
+    /// This overrides [`Self::logarithmic`].
+    ///
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// # let mut exposure_time: f64 = 1.0 / 60.0;
+    /// // Exposure time in seconds, with each stop (doubling) taking up the same width:
+    /// ui.add(
+    ///     egui::Slider::new(&mut exposure_time, 1.0 / 4000.0..=30.0)
+    ///         .custom_mapping(|seconds| seconds.log2(), |stops| stops.exp2())
+    ///         .detents([1.0 / 1000.0, 1.0 / 60.0, 1.0]),
+    /// );
+    /// # });
+    /// ```
+    pub fn custom_mapping(
+        mut self,
+        to_linear: impl 'a + Fn(f64) -> f64,
+        from_linear: impl 'a + Fn(f64) -> f64,
+    ) -> Self {
+        self.custom_mapping = Some(SliderMapping {
+            to_linear: Box::new(to_linear),
+            from_linear: Box::new(from_linear),
+        });
+        self
+    }
+
+    /// Values that the slider snaps to when dragged close to them,
+    /// e.g. unity gain on a volume fader.
+    ///
+    /// A small tick is painted on the rail at each detent.
+    /// How close is "close" is set with [`Self::detent_magnetism`].
+    pub fn detents(mut self, detents: impl IntoIterator<Item = f64>) -> Self {
+        self.detents = detents.into_iter().collect();
+        self
+    }
+
+    /// How close (in points) the pointer must be to a detent for the slider to snap to it.
+    ///
+    /// Default: `6.0`.
+    #[inline]
+    pub fn detent_magnetism(mut self, detent_magnetism: f32) -> Self {
+        self.detent_magnetism = detent_magnetism.max(0.0);
+        self
+    }
+
     /// Controls when the values will be clamped to the range.
     ///
     /// ### With `.clamping(SliderClamping::Always)` (default)
@@ -626,14 +689,52 @@ impl<'a> Slider<'a> {
     /// For instance, `position` is the mouse position and `position_range` is the physical location of the slider on the screen.
     fn value_from_position(&self, position: f32, position_range: Rangef) -> f64 {
         let normalized = remap_clamp(position, position_range, 0.0..=1.0) as f64;
-        value_from_normalized(normalized, self.range(), &self.spec)
+        if let Some(mapping) = &self.custom_mapping {
+            let (min, max) = (*self.range.start(), *self.range.end());
+            if normalized <= 0.0 {
+                min
+            } else if normalized >= 1.0 {
+                max
+            } else {
+                let linear = lerp(
+                    (mapping.to_linear)(min)..=(mapping.to_linear)(max),
+                    normalized,
+                );
+                (mapping.from_linear)(linear)
+            }
+        } else {
+            value_from_normalized(normalized, self.range(), &self.spec)
+        }
     }
 
     fn position_from_value(&self, value: f64, position_range: Rangef) -> f32 {
-        let normalized = normalized_from_value(value, self.range(), &self.spec);
+        let normalized = if let Some(mapping) = &self.custom_mapping {
+            let linear_range =
+                (mapping.to_linear)(*self.range.start())..=(mapping.to_linear)(*self.range.end());
+            if linear_range.start() == linear_range.end() {
+                0.5 // empty range, show center of slider
+            } else {
+                remap_clamp((mapping.to_linear)(value), linear_range, 0.0..=1.0)
+            }
+        } else {
+            normalized_from_value(value, self.range(), &self.spec)
+        };
         lerp(position_range, normalized as f32)
     }
 
+    /// The detent closest to `position`, if it is within [`Self::detent_magnetism`].
+    fn detent_at(&self, position: f32, position_range: Rangef) -> Option<f64> {
+        self.detents
+            .iter()
+            .map(|&detent| {
+                let distance = (self.position_from_value(detent, position_range) - position).abs();
+                (detent, distance)
+            })
+            .filter(|&(_, distance)| distance <= self.detent_magnetism)
+            .min_by(|(_, a), (_, b)| a.total_cmp(b))
+            .map(|(detent, _)| detent)
+    }
+
     /// Update the value on each key press when text-editing the value.
     ///
     /// Default: `true`.
@@ -665,7 +766,9 @@ impl Slider<'_> {
 
         if let Some(pointer_position_2d) = response.interact_pointer_pos() {
             let position = self.pointer_position(pointer_position_2d);
-            let new_value = if self.smart_aim {
+            let new_value = if let Some(detent) = self.detent_at(position, position_range) {
+                detent
+            } else if self.smart_aim {
                 let aim_radius = ui.input(|i| i.aim_radius());
                 emath::smart_aim::best_in_range_f64(
                     self.value_from_position(position - aim_radius, position_range),
@@ -774,6 +877,19 @@ impl Slider<'_> {
             ui.painter()
                 .rect_filled(rail_rect, corner_radius, widget_visuals.inactive.bg_fill);
 
+            let detent_stroke = widget_visuals.noninteractive.fg_stroke;
+            let detent_half_length = rail_radius + 2.0;
+            for &detent in &self.detents {
+                let center = self
+                    .marker_center(self.position_from_value(detent, position_range), &rail_rect);
+                let half = match self.orientation {
+                    SliderOrientation::Horizontal => vec2(0.0, detent_half_length),
+                    SliderOrientation::Vertical => vec2(detent_half_length, 0.0),
+                };
+                ui.painter()
+                    .line_segment([center - half, center + half], detent_stroke);
+            }
+
             let position_1d = self.position_from_value(value, position_range);
             let center = self.marker_center(position_1d, &rail_rect);
 
@@ -5,9 +5,10 @@ use epaint::text::{Galley, LayoutJob, TextWrapMode, cursor::CCursor};
 
 use crate::{
     Align, Align2, AsIdSalt, AtomExt as _, AtomKind, AtomLayout, Atoms, Color32, Context,
-    CursorIcon, Event, EventFilter, FontSelection, Frame, Id, IdSalt, ImeEvent, IntoAtoms,
-    IntoSizedResult, Key, KeyboardShortcut, Margin, Modifiers, NumExt as _, Response, Sense,
-    SizedAtomKind, TextBuffer, TextStyle, Ui, Vec2, Widget, WidgetInfo, WidgetWithState, epaint,
+    CursorIcon, Event, EventFilter, FontSelection, Frame, Id, IdSalt, ImeEvent, InputPurpose,
+    IntoAtoms, IntoSizedResult, Key, KeyboardShortcut, Margin, Modifiers, NumExt as _, Response,
+    Sense, SizedAtomKind, TextBuffer, TextStyle, Ui, Vec2, Widget, WidgetInfo, WidgetWithState,
+    epaint,
     os::OperatingSystem,
     output::OutputEvent,
     response,
@@ -77,6 +78,7 @@ pub struct TextEdit<'t> {
     text_color: Option<Color32>,
     layouter: Option<LayouterFn<'t>>,
     password: bool,
+    input_purpose: Option<InputPurpose>,
     frame: Option<Frame>,
     margin: Margin,
     multiline: bool,
@@ -131,6 +133,7 @@ impl<'t> TextEdit<'t> {
             text_color: None,
             layouter: None,
             password: false,
+            input_purpose: None,
             frame: None,
             margin: Margin::symmetric(4, 2),
             multiline: true,
@@ -239,6 +242,18 @@ impl<'t> TextEdit<'t> {
         self
     }
 
+    /// What kind of text this is for, e.g. [`InputPurpose::Email`].
+    ///
+    /// This is passed on to the integration, so that it can e.g. show a fitting on-screen keyboard.
+    /// It does not restrict what can be typed.
+    ///
+    /// Default: [`InputPurpose::Password`] for [`Self::password`] fields, else [`InputPurpose::Normal`].
+    #[inline]
+    pub fn input_purpose(mut self, input_purpose: InputPurpose) -> Self {
+        self.input_purpose = Some(input_purpose);
+        self
+    }
+
     /// Pick a [`crate::FontId`] or [`TextStyle`].
     #[inline]
     pub fn font(mut self, font_selection: impl Into<FontSelection>) -> Self {
@@ -444,6 +459,7 @@ impl TextEdit<'_> {
             text_color,
             layouter,
             password,
+            input_purpose,
             frame,
             margin,
             multiline,
@@ -847,8 +863,9 @@ impl TextEdit<'_> {
             );
 
             if has_focus && let Some(cursor_range) = state.cursor.range(&galley) {
+                let galley_offset = galley_pos.to_vec2() - vec2(galley.rect.left(), 0.0);
                 let primary_cursor_rect = cursor_rect(&galley, &cursor_range.primary, row_height)
-                    .translate(galley_pos.to_vec2() - vec2(galley.rect.left(), 0.0));
+                    .translate(galley_offset);
 
                 if response.changed() || selection_changed {
                     // Scroll to keep primary cursor in view:
@@ -900,6 +917,23 @@ impl TextEdit<'_> {
                         }
                     }
                     if ui.memory(|mem| mem.owns_ime_events(id)) {
+                        // The row with the cursor, starting where the composition started if that is on the same row:
+                        let mut cursor_row_rect = primary_cursor_rect;
+                        if state.cursor_purpose.is_ime_composition() {
+                            let [start, _] = cursor_range.sorted_cursors();
+                            let start_rect =
+                                cursor_rect(&galley, &start, row_height).translate(galley_offset);
+                            if start_rect.min.y == primary_cursor_rect.min.y {
+                                cursor_row_rect.min.x = start_rect.min.x;
+                            }
+                        }
+                        // Keep it within the visible part of the TextEdit, also when scrolled:
+                        let visible_rect = ui.clip_rect().intersect(inner_rect);
+                        let cursor_row_rect = Rect::from_min_max(
+                            visible_rect.clamp(cursor_row_rect.min),
+                            visible_rect.clamp(cursor_row_rect.max),
+                        );
+
                         // Set IME output (in screen coords) when text is editable and visible
                         let to_global = ui
                             .ctx()
@@ -909,7 +943,13 @@ impl TextEdit<'_> {
                             o.ime = Some(crate::output::IMEOutput {
                                 rect: to_global * inner_rect,
                                 cursor_rect: to_global * primary_cursor_rect,
+                                cursor_row_rect: to_global * cursor_row_rect,
                                 should_interrupt_composition: false,
+                                purpose: input_purpose.unwrap_or(if password {
+                                    InputPurpose::Password
+                                } else {
+                                    InputPurpose::Normal
+                                }),
                             });
                         });
                     }
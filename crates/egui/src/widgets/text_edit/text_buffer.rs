@@ -3,7 +3,8 @@ use std::{borrow::Cow, ops::Range};
 use epaint::{
     Galley,
     text::{
-        ByteIndex, ByteRangeExt as _, CharIndex, CharRange, CharRangeExt as _, cursor::CCursor,
+        ByteIndex, ByteRangeExt as _, CharIndex, CharRange, CharRangeExt as _,
+        cursor::{CCursor, next_grapheme_boundary, previous_grapheme_boundary},
     },
 };
 
@@ -130,18 +131,22 @@ pub trait TextBuffer {
         }
     }
 
+    /// Delete the user-perceived character (extended grapheme cluster) before the cursor.
     fn delete_previous_char(&mut self, ccursor: CCursor) -> CCursor {
         if CharIndex::ZERO < ccursor.index {
             let max_ccursor = ccursor;
-            let min_ccursor = max_ccursor - 1;
+            let min_ccursor =
+                CCursor::new(previous_grapheme_boundary(self.as_str(), max_ccursor.index));
             self.delete_selected_ccursor_range([min_ccursor, max_ccursor])
         } else {
             ccursor
         }
     }
 
+    /// Delete the user-perceived character (extended grapheme cluster) after the cursor.
     fn delete_next_char(&mut self, ccursor: CCursor) -> CCursor {
-        self.delete_selected_ccursor_range([ccursor, ccursor + 1])
+        let max_ccursor = CCursor::new(next_grapheme_boundary(self.as_str(), ccursor.index));
+        self.delete_selected_ccursor_range([ccursor, max_ccursor])
     }
 
     fn delete_previous_word(&mut self, max_ccursor: CCursor) -> CCursor {
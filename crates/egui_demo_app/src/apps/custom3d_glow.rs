@@ -36,6 +36,7 @@ impl crate::DemoApp for Custom3d {
                 ui.label(
                     "It's not a very impressive demo, but it shows you can embed 3D inside of egui.",
                 );
+                ui.label("The two triangles are painted with a depth buffer, so they intersect.");
 
                 egui::Frame::canvas(ui.style()).show(ui, |ui| {
                     self.custom_painting(ui);
@@ -66,7 +67,8 @@ impl Custom3d {
 
         let cb = egui_glow::CallbackFn::new(move |_info, painter| {
             rotating_triangle.lock().paint(painter.gl(), angle);
-        });
+        })
+        .with_depth_buffer(24);
 
         let callback = egui::PaintCallback {
             rect,
@@ -111,9 +113,13 @@ impl RotatingTriangle {
                     out vec4 v_color;
                     uniform float u_angle;
                     void main() {
-                        v_color = colors[gl_VertexID];
-                        gl_Position = vec4(verts[gl_VertexID], 0.0, 1.0);
-                        gl_Position.x *= cos(u_angle);
+                        // The second triangle is turned a quarter around the vertical axis:
+                        int triangle = gl_VertexID / 3;
+                        float angle = u_angle + 1.5707963 * float(triangle);
+                        vec2 vert = verts[gl_VertexID % 3];
+                        v_color = colors[gl_VertexID % 3] * (1.0 - 0.4 * float(triangle));
+                        v_color.a = 1.0;
+                        gl_Position = vec4(vert.x * cos(angle), vert.y, 0.5 * vert.x * sin(angle), 1.0);
                     }
                 "#,
                 r#"
@@ -197,7 +203,9 @@ impl RotatingTriangle {
                 angle,
             );
             gl.bind_vertex_array(Some(self.vertex_array));
-            gl.draw_arrays(glow::TRIANGLES, 0, 3);
+            gl.enable(glow::DEPTH_TEST);
+            gl.depth_func(glow::LESS);
+            gl.draw_arrays(glow::TRIANGLES, 0, 6);
         }
     }
 }
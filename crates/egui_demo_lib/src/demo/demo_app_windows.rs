@@ -371,20 +371,14 @@ fn file_menu_button(ui: &mut Ui) {
         }
 
         if ui
-            .add(
-                egui::Button::new("Organize Windows")
-                    .shortcut_text(ui.ctx().format_shortcut(&organize_shortcut)),
-            )
+            .add(menu::MenuItem::new("&Organize Windows").shortcut(organize_shortcut))
             .clicked()
         {
             ui.memory_mut(|mem| mem.reset_areas());
         }
 
         if ui
-            .add(
-                egui::Button::new("Reset egui memory")
-                    .shortcut_text(ui.ctx().format_shortcut(&reset_shortcut)),
-            )
+            .add(menu::MenuItem::new("&Reset egui memory").shortcut(reset_shortcut))
             .on_hover_text("Forget scroll, positions, sizes etc")
             .clicked()
         {
@@ -54,7 +54,8 @@ impl crate::View for SceneDemo {
             .show(ui, |ui| {
                 let scene = Scene::new()
                     .max_inner_size([350.0, 1000.0])
-                    .zoom_range(0.1..=2.0);
+                    .zoom_range(0.1..=2.0)
+                    .pan_inertia(true);
 
                 let mut reset_view = false;
                 let mut inner_rect = Rect::NAN;
@@ -94,6 +94,9 @@ impl crate::View for InputEventHistory {
                         egui::Event::PointerMoved { .. }
                             | egui::Event::MouseMoved { .. }
                             | egui::Event::Touch { .. }
+                            | egui::Event::PointerPen { .. }
+                            | egui::Event::PointerForce { .. }
+                            | egui::Event::PointerSource(_)
                     )
                 {
                     continue;
@@ -6,6 +6,7 @@ pub struct TextLayoutDemo {
     max_rows: usize,
     overflow_character: Option<char>,
     extra_letter_spacing: f32,
+    extra_word_spacing: f32,
     line_height_pixels: u32,
     halign: egui::Align,
     justify: bool,
@@ -19,6 +20,7 @@ impl Default for TextLayoutDemo {
             break_anywhere: false,
             overflow_character: Some('…'),
             extra_letter_spacing: 0.0,
+            extra_word_spacing: 0.0,
             line_height_pixels: 0,
             halign: egui::Align::LEFT,
             justify: false,
@@ -51,6 +53,7 @@ impl crate::View for TextLayoutDemo {
             max_rows,
             overflow_character,
             extra_letter_spacing,
+            extra_word_spacing,
             line_height_pixels,
             halign,
             justify,
@@ -95,6 +98,10 @@ impl crate::View for TextLayoutDemo {
                 ui.add(egui::DragValue::new(extra_letter_spacing).speed(0.1));
                 ui.end_row();
 
+                ui.label("Extra word spacing:");
+                ui.add(egui::DragValue::new(extra_word_spacing).speed(0.1));
+                ui.end_row();
+
                 ui.label("Line height:");
                 ui.horizontal(|ui| {
                     if ui
@@ -152,6 +159,7 @@ impl crate::View for TextLayoutDemo {
                     text.to_owned(),
                     egui::TextFormat {
                         extra_letter_spacing: *extra_letter_spacing,
+                        extra_word_spacing: *extra_word_spacing,
                         line_height,
                         ..Default::default()
                     },
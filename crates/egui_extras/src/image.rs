@@ -12,6 +12,16 @@ use egui::SizeHint;
 /// On invalid image or unsupported image format.
 #[cfg(feature = "image")]
 pub fn load_image_bytes(image_bytes: &[u8]) -> Result<egui::ColorImage, egui::load::LoadError> {
+    profiling::function_scope!();
+    let image = decode_image_bytes(image_bytes)?;
+    Ok(dynamic_image_to_color_image(&image))
+}
+
+/// Decode a (non-svg) image, applying its orientation.
+#[cfg(feature = "image")]
+pub(crate) fn decode_image_bytes(
+    image_bytes: &[u8],
+) -> Result<image::DynamicImage, egui::load::LoadError> {
     fn load_image_with_orientation(image_bytes: &[u8]) -> image::ImageResult<image::DynamicImage> {
         use std::io::Cursor;
 
@@ -28,7 +38,7 @@ pub fn load_image_bytes(image_bytes: &[u8]) -> Result<egui::ColorImage, egui::lo
     }
 
     profiling::function_scope!();
-    let image = load_image_with_orientation(image_bytes).map_err(|err| match err {
+    load_image_with_orientation(image_bytes).map_err(|err| match err {
         image::ImageError::Unsupported(err) => match err.kind() {
             image::error::UnsupportedErrorKind::Format(format) => {
                 egui::load::LoadError::FormatNotSupported {
@@ -38,7 +48,12 @@ pub fn load_image_bytes(image_bytes: &[u8]) -> Result<egui::ColorImage, egui::lo
             _ => egui::load::LoadError::Loading(err.to_string()),
         },
         err => egui::load::LoadError::Loading(err.to_string()),
-    })?;
+    })
+}
+
+#[cfg(feature = "image")]
+pub(crate) fn dynamic_image_to_color_image(image: &image::DynamicImage) -> egui::ColorImage {
+    profiling::function_scope!();
     let size = [image.width() as _, image.height() as _];
     let image_buffer = image.to_rgba8();
     let pixels = image_buffer.as_flat_samples();
@@ -46,10 +61,7 @@ pub fn load_image_bytes(image_bytes: &[u8]) -> Result<egui::ColorImage, egui::lo
     // TODO(emilk): if this is a PNG, looks for DPI info to calculate the source size,
     // e.g. for screenshots taken on a high-DPI/retina display.
 
-    Ok(egui::ColorImage::from_rgba_unmultiplied(
-        size,
-        pixels.as_slice(),
-    ))
+    egui::ColorImage::from_rgba_unmultiplied(size, pixels.as_slice())
 }
 
 /// Load an SVG and rasterize it into an egui image.
@@ -17,6 +17,8 @@ pub mod syntax_highlighting;
 pub mod image;
 mod layout;
 pub mod loaders;
+mod masonry;
+mod responsive;
 mod sizing;
 mod strip;
 mod table;
@@ -25,6 +27,8 @@ mod table;
 pub use crate::datepicker::DatePickerButton;
 
 pub(crate) use crate::layout::StripLayout;
+pub use crate::masonry::Masonry;
+pub use crate::responsive::{Breakpoint, Responsive};
 pub use crate::sizing::Size;
 pub use crate::strip::*;
 pub use crate::table::*;
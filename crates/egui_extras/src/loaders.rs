@@ -10,6 +10,7 @@
 /// - `http` feature: `http(s)://` loader
 /// - `image` feature: Loader of png, jpeg etc using the [`image`] crate
 /// - `svg` feature: `.svg` loader
+/// - `gif`, `webp` and `apng` features: loaders of (animated) gif, webp and png images
 ///
 /// Calling this multiple times on the same [`egui::Context`] is safe.
 /// It will never install duplicate loaders.
@@ -54,6 +55,12 @@
 /// The content type specified by [`BytesPoll::Ready::mime`][`egui::load::BytesPoll::Ready::mime`] always takes precedence,
 /// and must include `svg` for it to be considered supported. For example, `image/svg+xml` would be loaded by the `svg` loader.
 ///
+/// The `gif`, `webp` and `apng` loaders are [`ImageLoader`][`egui::load::ImageLoader`]s of animated images.
+/// Animated png files are only recognized by their `.apng` extension, or when given as bytes.
+/// Use [`egui::AnimationPlayback`] to pause, resume, or seek an animation.
+/// To limit how much memory the frames of an animation may use,
+/// add a loader yourself, e.g. with `GifLoader::default().with_max_decoded_bytes(…)`.
+///
 /// See [`egui::load`] for more information about how loaders work.
 pub fn install_image_loaders(ctx: &egui::Context) {
     #[cfg(all(not(target_arch = "wasm32"), feature = "file"))]
@@ -90,6 +97,12 @@ pub fn install_image_loaders(ctx: &egui::Context) {
         log::trace!("installed WebPLoader");
     }
 
+    #[cfg(feature = "apng")]
+    if !ctx.is_loader_installed(self::apng_loader::ApngLoader::ID) {
+        ctx.add_image_loader(std::sync::Arc::new(self::apng_loader::ApngLoader::default()));
+        log::trace!("installed ApngLoader");
+    }
+
     #[cfg(feature = "svg")]
     if !ctx.is_loader_installed(self::svg_loader::SvgLoader::ID) {
         ctx.add_image_loader(std::sync::Arc::new(self::svg_loader::SvgLoader::default()));
@@ -113,6 +126,11 @@ pub mod file_loader;
 #[cfg(feature = "http")]
 pub mod http_loader;
 
+#[cfg(any(feature = "gif", feature = "webp", feature = "apng"))]
+mod animation;
+
+#[cfg(feature = "apng")]
+pub mod apng_loader;
 #[cfg(feature = "gif")]
 pub mod gif_loader;
 #[cfg(feature = "image")]
@@ -0,0 +1,62 @@
+use egui::{ColorImage, FrameDurations};
+use std::{mem::size_of, sync::Arc, time::Duration};
+
+/// Default for the `max_decoded_bytes` of the animated image loaders.
+pub(crate) const DEFAULT_MAX_DECODED_BYTES: usize = 256 * 1024 * 1024;
+
+/// Frames shown for this long or shorter are shown for [`CLAMPED_FRAME_DELAY`] instead.
+///
+/// Many gifs in the wild have a delay of zero, and browsers slow them down like this.
+const MIN_FRAME_DELAY: Duration = Duration::from_millis(10);
+
+const CLAMPED_FRAME_DELAY: Duration = Duration::from_millis(100);
+
+/// Decode all the frames of an animated image.
+///
+/// Stops decoding once the frames take up more than `max_decoded_bytes`,
+/// keeping the frames decoded so far (always at least one).
+pub(crate) fn decode_frames(
+    uri: &str,
+    frames: image::Frames<'_>,
+    max_decoded_bytes: usize,
+) -> Result<(Vec<Arc<ColorImage>>, FrameDurations), String> {
+    profiling::function_scope!();
+
+    let mut images = vec![];
+    let mut durations = vec![];
+    let mut decoded_bytes = 0;
+
+    for frame in frames {
+        let frame = frame.map_err(|err| format!("Failed to decode frame: {err}"))?;
+        let image = frame.buffer();
+
+        let frame_bytes =
+            image.width() as usize * image.height() as usize * size_of::<egui::Color32>();
+        if !images.is_empty() && max_decoded_bytes < decoded_bytes + frame_bytes {
+            log::warn!(
+                "Only showing the first {} frames of {uri:?}: the rest would use more than {max_decoded_bytes} bytes",
+                images.len()
+            );
+            break;
+        }
+        decoded_bytes += frame_bytes;
+
+        let pixels = image.as_flat_samples();
+        images.push(Arc::new(ColorImage::from_rgba_unmultiplied(
+            [image.width() as usize, image.height() as usize],
+            pixels.as_slice(),
+        )));
+
+        let mut delay: Duration = frame.delay().into();
+        if delay <= MIN_FRAME_DELAY {
+            delay = CLAMPED_FRAME_DELAY;
+        }
+        durations.push(delay);
+    }
+
+    if images.is_empty() {
+        return Err("Animation has no frames".to_owned());
+    }
+
+    Ok((images, FrameDurations::new(durations)))
+}
@@ -0,0 +1,145 @@
+use ahash::HashMap;
+use egui::{
+    ColorImage, FrameDurations, Id, decode_animated_image_uri, has_apng_header,
+    load::{BytesPoll, ImageLoadResult, ImageLoader, ImagePoll, LoadError, SizeHint},
+    mutex::Mutex,
+};
+use image::{AnimationDecoder as _, codecs::png::PngDecoder};
+use std::{io::Cursor, mem::size_of, sync::Arc, time::Duration};
+
+/// Array of Frames and the duration for how long each frame should be shown
+#[derive(Debug, Clone)]
+pub struct AnimatedImage {
+    frames: Vec<Arc<ColorImage>>,
+    frame_durations: FrameDurations,
+}
+
+impl AnimatedImage {
+    fn load_apng(uri: &str, data: &[u8], max_decoded_bytes: usize) -> Result<Self, String> {
+        let decoder = PngDecoder::new(Cursor::new(data))
+            .and_then(|decoder| decoder.apng())
+            .map_err(|err| format!("Failed to decode apng: {err}"))?;
+        let (frames, frame_durations) =
+            super::animation::decode_frames(uri, decoder.into_frames(), max_decoded_bytes)
+                .map_err(|err| format!("Failed to decode apng: {err}"))?;
+        Ok(Self {
+            frames,
+            frame_durations,
+        })
+    }
+
+    pub fn byte_len(&self) -> usize {
+        size_of::<Self>()
+            + self
+                .frames
+                .iter()
+                .map(|image| {
+                    image.pixels.len() * size_of::<egui::Color32>() + size_of::<Duration>()
+                })
+                .sum::<usize>()
+    }
+
+    /// Gets image at index
+    pub fn get_image(&self, index: usize) -> Arc<ColorImage> {
+        Arc::clone(&self.frames[index % self.frames.len()])
+    }
+}
+
+type Entry = Result<Arc<AnimatedImage>, String>;
+
+/// Loads animated png files.
+///
+/// Plain (non-animated) png files are left to the [`super::image_loader::ImageCrateLoader`].
+pub struct ApngLoader {
+    cache: Mutex<HashMap<String, Entry>>,
+    max_decoded_bytes: usize,
+}
+
+impl Default for ApngLoader {
+    fn default() -> Self {
+        Self {
+            cache: Default::default(),
+            max_decoded_bytes: super::animation::DEFAULT_MAX_DECODED_BYTES,
+        }
+    }
+}
+
+impl ApngLoader {
+    pub const ID: &'static str = egui::generate_loader_id!(ApngLoader);
+
+    /// Only decode as many frames of each animated png as fit in this many bytes.
+    ///
+    /// Default: 256 MiB.
+    #[inline]
+    pub fn with_max_decoded_bytes(mut self, max_decoded_bytes: usize) -> Self {
+        self.max_decoded_bytes = max_decoded_bytes;
+        self
+    }
+}
+
+impl ImageLoader for ApngLoader {
+    fn id(&self) -> &str {
+        Self::ID
+    }
+
+    fn load(&self, ctx: &egui::Context, frame_uri: &str, _: SizeHint) -> ImageLoadResult {
+        let (image_uri, frame_index) =
+            decode_animated_image_uri(frame_uri).map_err(|_err| LoadError::NotSupported)?;
+        let mut cache = self.cache.lock();
+        if let Some(entry) = cache.get(image_uri).cloned() {
+            match entry {
+                Ok(image) => Ok(ImagePoll::Ready {
+                    image: image.get_image(frame_index),
+                }),
+                Err(err) => Err(LoadError::Loading(err)),
+            }
+        } else {
+            match ctx.try_load_bytes(image_uri) {
+                Ok(BytesPoll::Ready { bytes, .. }) => {
+                    if !has_apng_header(&bytes) {
+                        return Err(LoadError::NotSupported);
+                    }
+                    log::trace!("started loading {image_uri:?}");
+                    let result =
+                        AnimatedImage::load_apng(image_uri, &bytes, self.max_decoded_bytes)
+                            .map(Arc::new);
+                    if let Ok(v) = &result {
+                        ctx.data_mut(|data| {
+                            *data.get_temp_mut_or_default(Id::new(image_uri)) =
+                                v.frame_durations.clone();
+                        });
+                    }
+                    log::trace!("finished loading {image_uri:?}");
+                    cache.insert(image_uri.into(), result.clone());
+                    match result {
+                        Ok(image) => Ok(ImagePoll::Ready {
+                            image: image.get_image(frame_index),
+                        }),
+                        Err(err) => Err(LoadError::Loading(err)),
+                    }
+                }
+                Ok(BytesPoll::Pending { size }) => Ok(ImagePoll::Pending { size }),
+                Err(err) => Err(err),
+            }
+        }
+    }
+
+    fn forget(&self, uri: &str) {
+        let _ = self.cache.lock().remove(uri);
+    }
+
+    fn forget_all(&self) {
+        self.cache.lock().clear();
+    }
+
+    fn byte_size(&self) -> usize {
+        self.cache
+            .lock()
+            .values()
+            .map(|v| match v {
+                Ok(v) => v.byte_len(),
+                Err(e) => e.len(),
+            })
+            .sum()
+    }
+}
@@ -15,26 +15,15 @@ pub struct AnimatedImage {
 }
 
 impl AnimatedImage {
-    fn load_gif(data: &[u8]) -> Result<Self, String> {
+    fn load_gif(uri: &str, data: &[u8], max_decoded_bytes: usize) -> Result<Self, String> {
         let decoder = image::codecs::gif::GifDecoder::new(Cursor::new(data))
             .map_err(|err| format!("Failed to decode gif: {err}"))?;
-        let mut images = vec![];
-        let mut durations = vec![];
-        for frame in decoder.into_frames() {
-            let frame = frame.map_err(|err| format!("Failed to decode gif: {err}"))?;
-            let img = frame.buffer();
-            let pixels = img.as_flat_samples();
-
-            let delay: Duration = frame.delay().into();
-            images.push(Arc::new(ColorImage::from_rgba_unmultiplied(
-                [img.width() as usize, img.height() as usize],
-                pixels.as_slice(),
-            )));
-            durations.push(delay);
-        }
+        let (frames, frame_durations) =
+            super::animation::decode_frames(uri, decoder.into_frames(), max_decoded_bytes)
+                .map_err(|err| format!("Failed to decode gif: {err}"))?;
         Ok(Self {
-            frames: images,
-            frame_durations: FrameDurations::new(durations),
+            frames,
+            frame_durations,
         })
     }
 }
@@ -58,13 +47,31 @@ impl AnimatedImage {
 }
 type Entry = Result<Arc<AnimatedImage>, String>;
 
-#[derive(Default)]
 pub struct GifLoader {
     cache: Mutex<HashMap<String, Entry>>,
+    max_decoded_bytes: usize,
+}
+
+impl Default for GifLoader {
+    fn default() -> Self {
+        Self {
+            cache: Default::default(),
+            max_decoded_bytes: super::animation::DEFAULT_MAX_DECODED_BYTES,
+        }
+    }
 }
 
 impl GifLoader {
     pub const ID: &'static str = egui::generate_loader_id!(GifLoader);
+
+    /// Only decode as many frames of each gif as fit in this many bytes.
+    ///
+    /// Default: 256 MiB.
+    #[inline]
+    pub fn with_max_decoded_bytes(mut self, max_decoded_bytes: usize) -> Self {
+        self.max_decoded_bytes = max_decoded_bytes;
+        self
+    }
 }
 
 impl ImageLoader for GifLoader {
@@ -90,7 +97,8 @@ impl ImageLoader for GifLoader {
                         return Err(LoadError::NotSupported);
                     }
                     log::trace!("started loading {image_uri:?}");
-                    let result = AnimatedImage::load_gif(&bytes).map(Arc::new);
+                    let result = AnimatedImage::load_gif(image_uri, &bytes, self.max_decoded_bytes)
+                        .map(Arc::new);
                     if let Ok(v) = &result {
                         ctx.data_mut(|data| {
                             *data.get_temp_mut_or_default(Id::new(image_uri)) =
@@ -1,9 +1,9 @@
 use ahash::HashMap;
 use egui::{
-    load::{Bytes, BytesLoadResult, BytesLoader, BytesPoll, LoadError},
+    load::{Bytes, BytesLoadResult, BytesLoader, BytesPoll, LoadError, LoadPermit},
     mutex::Mutex,
 };
-use std::{sync::Arc, task::Poll};
+use std::sync::Arc;
 
 #[derive(Clone)]
 struct File {
@@ -37,7 +37,16 @@ impl File {
     }
 }
 
-type Entry = Poll<Result<File, String>>;
+#[derive(Clone)]
+enum Entry {
+    /// Waiting for the [`egui::load::LoadScheduler`] to start the request.
+    Queued,
+
+    /// The request has been sent.
+    Loading,
+
+    Ready(Result<File, String>),
+}
 
 #[derive(Default)]
 pub struct EhttpLoader {
@@ -80,30 +89,38 @@ impl BytesLoader for EhttpLoader {
         let mut cache = self.cache.lock();
         if let Some(entry) = cache.get(uri).cloned() {
             match entry {
-                Poll::Ready(Ok(file)) => Ok(BytesPoll::Ready {
+                Entry::Ready(Ok(file)) => Ok(BytesPoll::Ready {
                     size: None,
                     bytes: Bytes::Shared(file.bytes),
                     mime: file.mime,
                 }),
-                Poll::Ready(Err(err)) => Err(LoadError::Loading(err)),
-                Poll::Pending => Ok(BytesPoll::Pending { size: None }),
+                Entry::Ready(Err(err)) => Err(LoadError::Loading(err)),
+                Entry::Queued | Entry::Loading => Ok(BytesPoll::Pending { size: None }),
             }
         } else {
-            log::trace!("started loading {uri:?}");
-
             let uri = uri.to_owned();
-            cache.insert(uri.clone(), Poll::Pending);
+            cache.insert(uri.clone(), Entry::Queued);
             drop(cache);
 
-            ehttp::fetch(
-                match &self.request_template {
-                    Some(templ) => templ(ehttp::Request::get(uri.clone())),
-                    None => ehttp::Request::get(uri.clone()),
-                },
-                {
-                    let ctx = ctx.clone();
-                    let cache = Arc::clone(&self.cache);
-                    move |response| {
+            let request = match &self.request_template {
+                Some(templ) => templ(ehttp::Request::get(uri.clone())),
+                None => ehttp::Request::get(uri.clone()),
+            };
+
+            let start = {
+                let ctx = ctx.clone();
+                let cache = Arc::clone(&self.cache);
+                let uri = uri.clone();
+                move |permit: LoadPermit| {
+                    if let Some(entry) = cache.lock().get_mut(&uri) {
+                        *entry = Entry::Loading;
+                    } else {
+                        return; // Forgotten while queued
+                    }
+
+                    log::trace!("started loading {uri:?}");
+                    ehttp::fetch(request, move |response| {
+                        drop(permit);
                         let result = match response {
                             Ok(response) => File::from_response(&uri, response),
                             Err(err) => {
@@ -118,7 +135,7 @@ impl BytesLoader for EhttpLoader {
                                 cache.entry(uri.clone())
                             {
                                 let entry = entry.get_mut();
-                                *entry = Poll::Ready(result);
+                                *entry = Entry::Ready(result);
                                 log::trace!("Finished loading {uri:?}");
                                 true
                             } else {
@@ -133,9 +150,20 @@ impl BytesLoader for EhttpLoader {
                         if repaint {
                             ctx.request_repaint();
                         }
+                    });
+                }
+            };
+            let cancel = {
+                let cache = Arc::clone(&self.cache);
+                let uri = uri.clone();
+                move || {
+                    let mut cache = cache.lock();
+                    if matches!(cache.get(&uri), Some(Entry::Queued)) {
+                        cache.remove(&uri);
                     }
-                },
-            );
+                }
+            };
+            ctx.loaders().scheduler.schedule(&uri, start, cancel);
 
             Ok(BytesPoll::Pending { size: None })
         }
@@ -154,16 +182,20 @@ impl BytesLoader for EhttpLoader {
             .lock()
             .values()
             .map(|entry| match entry {
-                Poll::Ready(Ok(file)) => {
+                Entry::Ready(Ok(file)) => {
                     file.bytes.len() + file.mime.as_ref().map_or(0, |m| m.len())
                 }
-                Poll::Ready(Err(err)) => err.len(),
-                _ => 0,
+                Entry::Ready(Err(err)) => err.len(),
+                Entry::Queued | Entry::Loading => 0,
             })
             .sum()
     }
 
     fn has_pending(&self) -> bool {
-        self.cache.lock().values().any(|entry| entry.is_pending())
+        // Queued requests are not pending: they may wait for their widget to become visible.
+        self.cache
+            .lock()
+            .values()
+            .any(|entry| matches!(entry, Entry::Loading))
     }
 }
@@ -5,12 +5,36 @@ use egui::{
     mutex::Mutex,
 };
 use image::ImageFormat;
-use std::{mem::size_of, path::Path, sync::Arc, task::Poll};
+use std::{mem::size_of, path::Path, sync::Arc};
 
 #[cfg(not(target_arch = "wasm32"))]
-use std::thread;
+use {egui::load::LoadPermit, std::thread};
 
-type Entry = Poll<Result<Arc<ColorImage>, String>>;
+#[derive(Clone)]
+enum Entry {
+    /// Waiting for the [`egui::load::LoadScheduler`] to start decoding.
+    #[cfg(not(target_arch = "wasm32"))]
+    Queued,
+
+    /// Decoding on a background thread.
+    #[cfg(not(target_arch = "wasm32"))]
+    Loading,
+
+    /// Still decoding, but we have a low resolution preview.
+    #[cfg(not(target_arch = "wasm32"))]
+    Preview(Arc<ColorImage>),
+
+    Ready(Result<Arc<ColorImage>, String>),
+}
+
+/// Images larger than this (in either dimension) get a low resolution preview
+/// while the full image is being converted and uploaded.
+#[cfg(not(target_arch = "wasm32"))]
+const PREVIEW_THRESHOLD: u32 = 1024;
+
+/// The maximum size of a preview, in either dimension.
+#[cfg(not(target_arch = "wasm32"))]
+const PREVIEW_SIZE: u32 = 128;
 
 #[derive(Default)]
 pub struct ImageCrateLoader {
@@ -87,52 +111,103 @@ impl ImageLoader for ImageCrateLoader {
             bytes: &Bytes,
         ) -> ImageLoadResult {
             let uri = uri.to_owned();
-            cache.lock().insert(uri.clone(), Poll::Pending);
-
-            // Do the image parsing on a bg thread
-            thread::Builder::new()
-                .name(format!("egui_extras::ImageLoader::load({uri:?})"))
-                .spawn({
-                    let ctx = ctx.clone();
-                    let cache = Arc::clone(cache);
-
-                    let uri = uri.clone();
-                    let bytes = bytes.clone();
-                    move || {
-                        log::trace!("ImageLoader - started loading {uri:?}");
-                        let result = crate::image::load_image_bytes(&bytes)
-                            .map(Arc::new)
-                            .map_err(|err| err.to_string());
-                        let repaint = {
-                            let mut cache = cache.lock();
-
-                            if let std::collections::hash_map::Entry::Occupied(mut entry) = cache.entry(uri.clone()) {
-                                let entry = entry.get_mut();
-                                *entry = Poll::Ready(result);
-                                log::trace!("ImageLoader - finished loading {uri:?}");
-                                true
-                            } else {
-                                log::trace!("ImageLoader - canceled loading {uri:?}\nNote: This can happen if `forget_image` is called while the image is still loading.");
-                                false
-                            }
-                        };
-                        // We may not lock Context while the cache lock is held, since this can
-                        // deadlock.
-                        // Example deadlock scenario:
-                        // - loader thread: lock cache
-                        // - main thread: lock ctx (e.g. in `Context::has_pending_images`)
-                        // - loader thread: try to lock ctx (in `request_repaint`)
-                        // - main thread: try to lock cache (from `Self::has_pending`)
-                        if repaint {
-                            ctx.request_repaint();
-                        }
+            cache.lock().insert(uri.clone(), Entry::Queued);
+
+            let start = {
+                let ctx = ctx.clone();
+                let cache = Arc::clone(cache);
+                let uri = uri.clone();
+                let bytes = bytes.clone();
+                move |permit: LoadPermit| {
+                    if let Some(entry) = cache.lock().get_mut(&uri) {
+                        *entry = Entry::Loading;
+                    } else {
+                        return; // Forgotten while queued
+                    }
+
+                    // Do the image parsing on a bg thread
+                    thread::Builder::new()
+                        .name(format!("egui_extras::ImageLoader::load({uri:?})"))
+                        .spawn(move || {
+                            log::trace!("ImageLoader - started loading {uri:?}");
+                            let result = decode_image(&ctx, &cache, &uri, &bytes);
+                            drop(permit);
+                            set_entry(&ctx, &cache, &uri, Entry::Ready(result));
+                        })
+                        .expect("failed to spawn thread");
+                }
+            };
+            let cancel = {
+                let cache = Arc::clone(cache);
+                let uri = uri.clone();
+                move || {
+                    let mut cache = cache.lock();
+                    if matches!(cache.get(&uri), Some(Entry::Queued)) {
+                        cache.remove(&uri);
                     }
-                })
-                .expect("failed to spawn thread");
+                }
+            };
+            ctx.loaders().scheduler.schedule(&uri, start, cancel);
 
             Ok(ImagePoll::Pending { size: None })
         }
 
+        /// Decode the image, publishing a preview first if it is large.
+        #[cfg(not(target_arch = "wasm32"))]
+        fn decode_image(
+            ctx: &egui::Context,
+            cache: &Arc<Mutex<HashMap<String, Entry>>>,
+            uri: &str,
+            bytes: &Bytes,
+        ) -> Result<Arc<ColorImage>, String> {
+            let image = crate::image::decode_image_bytes(bytes).map_err(|err| err.to_string())?;
+
+            if PREVIEW_THRESHOLD < image.width().max(image.height()) {
+                let source_size = egui::vec2(image.width() as f32, image.height() as f32);
+                let preview = image.thumbnail(PREVIEW_SIZE, PREVIEW_SIZE);
+                let preview = crate::image::dynamic_image_to_color_image(&preview)
+                    .with_source_size(source_size);
+                set_entry(ctx, cache, uri, Entry::Preview(Arc::new(preview)));
+            }
+
+            Ok(Arc::new(crate::image::dynamic_image_to_color_image(&image)))
+        }
+
+        /// Update the entry of `uri`, unless it has been forgotten.
+        #[cfg(not(target_arch = "wasm32"))]
+        fn set_entry(
+            ctx: &egui::Context,
+            cache: &Arc<Mutex<HashMap<String, Entry>>>,
+            uri: &str,
+            new_entry: Entry,
+        ) {
+            let repaint = {
+                let mut cache = cache.lock();
+                if let Some(entry) = cache.get_mut(uri) {
+                    if matches!(new_entry, Entry::Ready(_)) {
+                        log::trace!("ImageLoader - finished loading {uri:?}");
+                    }
+                    *entry = new_entry;
+                    true
+                } else {
+                    log::trace!(
+                        "ImageLoader - canceled loading {uri:?}\nNote: This can happen if `forget_image` is called while the image is still loading."
+                    );
+                    false
+                }
+            };
+            // We may not lock Context while the cache lock is held, since this can
+            // deadlock.
+            // Example deadlock scenario:
+            // - loader thread: lock cache
+            // - main thread: lock ctx (e.g. in `Context::has_pending_images`)
+            // - loader thread: try to lock ctx (in `request_repaint`)
+            // - main thread: try to lock cache (from `Self::has_pending`)
+            if repaint {
+                ctx.request_repaint();
+            }
+        }
+
         #[cfg(target_arch = "wasm32")]
         fn load_image(
             _ctx: &egui::Context,
@@ -146,7 +221,7 @@ impl ImageLoader for ImageCrateLoader {
                 .map(Arc::new)
                 .map_err(|err| err.to_string());
             log::trace!("finished loading {uri:?}");
-            cache_lock.insert(uri.into(), std::task::Poll::Ready(result.clone()));
+            cache_lock.insert(uri.into(), Entry::Ready(result.clone()));
             match result {
                 Ok(image) => Ok(ImagePoll::Ready { image }),
                 Err(err) => Err(LoadError::Loading(err)),
@@ -156,9 +231,12 @@ impl ImageLoader for ImageCrateLoader {
         let entry = self.cache.lock().get(uri).cloned();
         if let Some(entry) = entry {
             match entry {
-                Poll::Ready(Ok(image)) => Ok(ImagePoll::Ready { image }),
-                Poll::Ready(Err(err)) => Err(LoadError::Loading(err)),
-                Poll::Pending => Ok(ImagePoll::Pending { size: None }),
+                Entry::Ready(Ok(image)) => Ok(ImagePoll::Ready { image }),
+                Entry::Ready(Err(err)) => Err(LoadError::Loading(err)),
+                #[cfg(not(target_arch = "wasm32"))]
+                Entry::Preview(image) => Ok(ImagePoll::Preview { image }),
+                #[cfg(not(target_arch = "wasm32"))]
+                Entry::Queued | Entry::Loading => Ok(ImagePoll::Pending { size: None }),
             }
         } else {
             match ctx.try_load_bytes(uri) {
@@ -191,16 +269,26 @@ impl ImageLoader for ImageCrateLoader {
         self.cache
             .lock()
             .values()
-            .map(|result| match result {
-                Poll::Ready(Ok(image)) => image.pixels.len() * size_of::<egui::Color32>(),
-                Poll::Ready(Err(err)) => err.len(),
-                Poll::Pending => 0,
+            .map(|entry| match entry {
+                Entry::Ready(Ok(image)) => image.pixels.len() * size_of::<egui::Color32>(),
+                Entry::Ready(Err(err)) => err.len(),
+                #[cfg(not(target_arch = "wasm32"))]
+                Entry::Preview(image) => image.pixels.len() * size_of::<egui::Color32>(),
+                #[cfg(not(target_arch = "wasm32"))]
+                Entry::Queued | Entry::Loading => 0,
             })
             .sum()
     }
 
     fn has_pending(&self) -> bool {
-        self.cache.lock().values().any(|result| result.is_pending())
+        // Queued loads are not pending: they may wait for their widget to become visible.
+        self.cache.lock().values().any(|entry| match entry {
+            Entry::Ready(_) => false,
+            #[cfg(not(target_arch = "wasm32"))]
+            Entry::Queued => false,
+            #[cfg(not(target_arch = "wasm32"))]
+            Entry::Loading | Entry::Preview(_) => true,
+        })
     }
 }
 
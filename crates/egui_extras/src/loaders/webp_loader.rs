@@ -14,7 +14,7 @@ enum WebP {
 }
 
 impl WebP {
-    fn load(data: &[u8]) -> Result<Self, String> {
+    fn load(uri: &str, data: &[u8], max_decoded_bytes: usize) -> Result<Self, String> {
         let mut decoder = WebPDecoder::new(Cursor::new(data))
             .map_err(|error| format!("WebP decode failure ({error})"))?;
 
@@ -25,26 +25,12 @@ impl WebP {
                     format!("Failure to set default background color for animated WebP ({error})")
                 })?;
 
-            let mut images = vec![];
-            let mut durations = vec![];
-
-            for frame in decoder.into_frames() {
-                let frame =
-                    frame.map_err(|error| format!("WebP frame decode failure ({error})"))?;
-                let image = frame.buffer();
-                let pixels = image.as_flat_samples();
-
-                images.push(Arc::new(ColorImage::from_rgba_unmultiplied(
-                    [image.width() as usize, image.height() as usize],
-                    pixels.as_slice(),
-                )));
-
-                let delay: Duration = frame.delay().into();
-                durations.push(delay);
-            }
+            let (frames, frame_durations) =
+                super::animation::decode_frames(uri, decoder.into_frames(), max_decoded_bytes)
+                    .map_err(|error| format!("WebP frame decode failure ({error})"))?;
             Ok(Self::Animated(AnimatedImage {
-                frames: images,
-                frame_durations: FrameDurations::new(durations),
+                frames,
+                frame_durations,
             }))
         } else {
             // color_type() of WebPDecoder only returns Rgb8/Rgba8 variants of ColorType
@@ -114,13 +100,31 @@ impl AnimatedImage {
 
 type Entry = Result<WebP, String>;
 
-#[derive(Default)]
 pub struct WebPLoader {
     cache: Mutex<HashMap<String, Entry>>,
+    max_decoded_bytes: usize,
+}
+
+impl Default for WebPLoader {
+    fn default() -> Self {
+        Self {
+            cache: Default::default(),
+            max_decoded_bytes: super::animation::DEFAULT_MAX_DECODED_BYTES,
+        }
+    }
 }
 
 impl WebPLoader {
     pub const ID: &'static str = egui::generate_loader_id!(WebPLoader);
+
+    /// Only decode as many frames of each animated webp as fit in this many bytes.
+    ///
+    /// Default: 256 MiB.
+    #[inline]
+    pub fn with_max_decoded_bytes(mut self, max_decoded_bytes: usize) -> Self {
+        self.max_decoded_bytes = max_decoded_bytes;
+        self
+    }
 }
 
 impl ImageLoader for WebPLoader {
@@ -149,7 +153,7 @@ impl ImageLoader for WebPLoader {
 
                     log::trace!("started loading {image_uri:?}");
 
-                    let result = WebP::load(&bytes);
+                    let result = WebP::load(image_uri, &bytes, self.max_decoded_bytes);
 
                     if let Ok(WebP::Animated(animated_image)) = &result {
                         ctx.data_mut(|data| {
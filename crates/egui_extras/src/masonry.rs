@@ -0,0 +1,389 @@
+use egui::{
+    AsIdSalt, IdSalt, NumExt as _, Rangef, Rect, Response, Sense, Ui, UiBuilder, Vec2, pos2, vec2,
+};
+
+/// Packs items of varying height into columns, like bricks in a wall.
+///
+/// Each item is placed in the currently shortest column, which keeps the column heights balanced.
+/// The items are placed in order, so the same items always end up in the same place
+/// for a given width.
+///
+/// Only the items that are visible (inside the clip rect) are shown,
+/// so this is suitable for large collections inside a [`egui::ScrollArea`].
+/// Where each item goes is remembered too, and only worked out again when an item changes height.
+/// The height of each item is remembered from the last time it was shown.
+/// Items that have never been shown are assumed to be as high as the average of the known items,
+/// or [`Self::estimated_item_height`] if no item has been shown yet.
+///
+/// ### Example
+/// ```
+/// # egui::__run_test_ui(|ui| {
+/// egui::ScrollArea::vertical().show(ui, |ui| {
+///     egui_extras::Masonry::new()
+///         .min_column_width(150.0)
+///         .show(ui, 1000, |ui, index| {
+///             ui.heading(format!("Card {index}"));
+///             for _ in 0..index % 5 {
+///                 ui.label("Some content");
+///             }
+///         });
+/// });
+/// # });
+/// ```
+#[must_use = "You should call .show()"]
+pub struct Masonry {
+    id_salt: IdSalt,
+    num_columns: Option<usize>,
+    min_column_width: f32,
+    spacing: Option<Vec2>,
+    estimated_item_height: f32,
+}
+
+impl Default for Masonry {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Masonry {
+    pub fn new() -> Self {
+        Self {
+            id_salt: IdSalt::new("__masonry"),
+            num_columns: None,
+            min_column_width: 200.0,
+            spacing: None,
+            estimated_item_height: 100.0,
+        }
+    }
+
+    /// Give this masonry a unique id within the parent [`Ui`].
+    ///
+    /// This is required if you have multiple masonries in the same [`Ui`].
+    #[inline]
+    pub fn id_salt(mut self, id_salt: impl AsIdSalt) -> Self {
+        self.id_salt = IdSalt::new(id_salt);
+        self
+    }
+
+    /// Use exactly this many columns.
+    ///
+    /// By default the number of columns is decided by [`Self::min_column_width`].
+    #[inline]
+    pub fn num_columns(mut self, num_columns: usize) -> Self {
+        self.num_columns = Some(num_columns.at_least(1));
+        self
+    }
+
+    /// Use as many columns as fit, each at least this wide.
+    ///
+    /// Ignored if [`Self::num_columns`] is set.
+    ///
+    /// Default: `200.0`.
+    #[inline]
+    pub fn min_column_width(mut self, min_column_width: f32) -> Self {
+        self.min_column_width = min_column_width;
+        self
+    }
+
+    /// Space between columns (`x`) and between items in a column (`y`).
+    ///
+    /// Default: [`egui::style::Spacing::item_spacing`].
+    #[inline]
+    pub fn spacing(mut self, spacing: impl Into<Vec2>) -> Self {
+        self.spacing = Some(spacing.into());
+        self
+    }
+
+    /// How high to assume items are before they have been shown for the first time.
+    ///
+    /// Once any item has been shown, the average height of the shown items is used instead.
+    ///
+    /// Default: `100.0`.
+    #[inline]
+    pub fn estimated_item_height(mut self, estimated_item_height: f32) -> Self {
+        self.estimated_item_height = estimated_item_height;
+        self
+    }
+
+    /// Show `num_items` items, calling `add_item` for each visible one.
+    ///
+    /// `add_item` is given a [`Ui`] as wide as a column, and the index of the item to show.
+    pub fn show(
+        self,
+        ui: &mut Ui,
+        num_items: usize,
+        mut add_item: impl FnMut(&mut Ui, usize),
+    ) -> Response {
+        let Self {
+            id_salt,
+            num_columns,
+            min_column_width,
+            spacing,
+            estimated_item_height,
+        } = self;
+
+        let spacing = spacing.unwrap_or_else(|| ui.spacing().item_spacing);
+        let available_width = ui.available_width();
+        let num_columns = num_columns.unwrap_or_else(|| {
+            ((available_width + spacing.x) / (min_column_width + spacing.x)).floor() as usize
+        });
+        let num_columns = num_columns.at_least(1);
+        let column_width = ((available_width - spacing.x * (num_columns - 1) as f32)
+            / num_columns as f32)
+            .at_least(0.0);
+
+        let top_left = ui.available_rect_before_wrap().min;
+        let state_id = ui.id().with(id_salt);
+        ui.ctx().check_for_id_clash(
+            state_id,
+            Rect::from_min_size(top_left, Vec2::ZERO),
+            "Masonry",
+        );
+
+        let mut state = MasonryState::load(ui, state_id);
+        state.set_num_items(num_items);
+        let params = LayoutParams {
+            num_columns,
+            column_width,
+            spacing,
+            estimated_item_height: state.average_height().unwrap_or(estimated_item_height),
+        };
+        if state
+            .layout
+            .as_ref()
+            .is_some_and(|layout| layout.params != params)
+        {
+            state.layout = None;
+        }
+        let layout = state
+            .layout
+            .get_or_insert_with(|| Layout::new(params, &state.item_heights));
+        let total_height = layout.total_height;
+
+        // Only visit the items that are visible, using the cached layout:
+        let clip_rect = ui.clip_rect();
+        let visible = layout.visible_items(Rangef::new(
+            clip_rect.min.y - top_left.y,
+            clip_rect.max.y - top_left.y,
+        ));
+
+        let mut heights_changed = false;
+        for (index, column, top) in visible {
+            let min = pos2(
+                top_left.x + column as f32 * (column_width + spacing.x),
+                top_left.y + top,
+            );
+            let max_rect = Rect::from_min_size(min, vec2(column_width, f32::INFINITY));
+            let mut item_ui = ui.new_child(
+                UiBuilder::new()
+                    .id(state_id.with(index))
+                    .max_rect(max_rect)
+                    .layout(egui::Layout::top_down_justified(egui::Align::Min)),
+            );
+            add_item(&mut item_ui, index);
+            heights_changed |= state.set_height(index, item_ui.min_rect().height());
+        }
+
+        if heights_changed {
+            // The items below the ones that changed need to move:
+            state.layout = None;
+            ui.ctx().request_discard("Masonry item heights changed");
+        }
+
+        state.store(ui, state_id);
+
+        ui.allocate_rect(
+            Rect::from_min_size(top_left, vec2(available_width, total_height)),
+            Sense::hover(),
+        )
+    }
+}
+
+/// The leftmost of the shortest columns.
+fn shortest_column(column_heights: &[f32]) -> usize {
+    let mut shortest = 0;
+    for (column, &height) in column_heights.iter().enumerate() {
+        if height < column_heights[shortest] {
+            shortest = column;
+        }
+    }
+    shortest
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+struct LayoutParams {
+    num_columns: usize,
+    column_width: f32,
+    spacing: Vec2,
+    estimated_item_height: f32,
+}
+
+/// Where all the items go, so we only need to visit the visible ones each frame.
+#[derive(Clone, Debug)]
+struct Layout {
+    params: LayoutParams,
+
+    /// The vertical span of each item, relative to the top of the masonry.
+    item_spans: Vec<Rangef>,
+
+    /// The indices of the items in each column, from top to bottom.
+    columns: Vec<Vec<usize>>,
+
+    /// The height of the tallest column.
+    total_height: f32,
+}
+
+impl Layout {
+    /// Place each item in the shortest column, using `estimated_item_height` for unknown (NaN) heights.
+    fn new(params: LayoutParams, item_heights: &[f32]) -> Self {
+        let mut column_heights = vec![0.0_f32; params.num_columns];
+        let mut columns = vec![vec![]; params.num_columns];
+        let mut item_spans = Vec::with_capacity(item_heights.len());
+
+        for (index, &height) in item_heights.iter().enumerate() {
+            let height = if height.is_finite() {
+                height
+            } else {
+                params.estimated_item_height
+            };
+            let column = shortest_column(&column_heights);
+            let top = column_heights[column];
+            item_spans.push(Rangef::new(top, top + height));
+            columns[column].push(index);
+            column_heights[column] += height + params.spacing.y;
+        }
+
+        // Remove the spacing after the last item of the tallest column:
+        let total_height = column_heights
+            .iter()
+            .map(|height| height - params.spacing.y)
+            .fold(0.0, f32::max);
+
+        Self {
+            params,
+            item_spans,
+            columns,
+            total_height,
+        }
+    }
+
+    /// The `(index, column, top)` of the items that overlap `y_range`, in index order.
+    fn visible_items(&self, y_range: Rangef) -> Vec<(usize, usize, f32)> {
+        let mut visible = vec![];
+        for (column, items) in self.columns.iter().enumerate() {
+            // The items of a column are sorted by position, so we can skip to the first visible one:
+            let first = items.partition_point(|&index| self.item_spans[index].max < y_range.min);
+            for &index in &items[first..] {
+                let span = self.item_spans[index];
+                if y_range.max < span.min {
+                    break;
+                }
+                visible.push((index, column, span.min));
+            }
+        }
+        visible.sort_unstable_by_key(|&(index, _, _)| index);
+        visible
+    }
+}
+
+#[derive(Clone, Default)]
+struct MasonryState {
+    /// The height of each item the last time it was shown, or NaN if never shown.
+    item_heights: Vec<f32>,
+
+    /// The sum and number of the known heights in [`Self::item_heights`].
+    known_height_sum: f32,
+    num_known_heights: usize,
+
+    /// Cleared whenever an item changes height.
+    layout: Option<Layout>,
+}
+
+impl MasonryState {
+    fn load(ui: &Ui, state_id: egui::Id) -> Self {
+        ui.data_mut(|d| d.get_temp::<Self>(state_id))
+            .unwrap_or_default()
+    }
+
+    fn store(self, ui: &Ui, state_id: egui::Id) {
+        ui.data_mut(|d| d.insert_temp(state_id, self));
+    }
+
+    fn set_num_items(&mut self, num_items: usize) {
+        if self.item_heights.len() != num_items {
+            self.item_heights.resize(num_items, f32::NAN);
+            let known = self.item_heights.iter().filter(|height| height.is_finite());
+            self.known_height_sum = known.clone().sum();
+            self.num_known_heights = known.count();
+            self.layout = None;
+        }
+    }
+
+    /// Returns `true` if the height changed.
+    fn set_height(&mut self, index: usize, height: f32) -> bool {
+        let old_height = self.item_heights[index];
+        if old_height == height {
+            return false;
+        }
+        if old_height.is_finite() {
+            self.known_height_sum -= old_height;
+        } else {
+            self.num_known_heights += 1;
+        }
+        self.known_height_sum += height;
+        self.item_heights[index] = height;
+        true
+    }
+
+    fn average_height(&self) -> Option<f32> {
+        (0 < self.num_known_heights).then(|| self.known_height_sum / self.num_known_heights as f32)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn layout_of(num_columns: usize, item_heights: &[f32]) -> Layout {
+        let params = LayoutParams {
+            num_columns,
+            column_width: 100.0,
+            spacing: vec2(10.0, 10.0),
+            estimated_item_height: 50.0,
+        };
+        Layout::new(params, item_heights)
+    }
+
+    #[test]
+    fn test_items_go_in_the_shortest_column() {
+        let layout = layout_of(3, &[100.0, 20.0, 50.0, 30.0, 10.0, f32::NAN]);
+
+        // The first row fills the columns left to right,
+        // then each item goes below the one that ends the highest:
+        assert_eq!(layout.columns, vec![vec![0], vec![1, 3, 5], vec![2, 4]]);
+        assert_eq!(layout.item_spans[3], Rangef::new(30.0, 60.0));
+        assert_eq!(layout.item_spans[4], Rangef::new(60.0, 70.0));
+        assert_eq!(layout.item_spans[5], Rangef::new(70.0, 120.0)); // estimated height
+        assert_eq!(layout.total_height, 120.0);
+
+        // Ties go to the leftmost column:
+        assert_eq!(
+            layout_of(2, &[10.0; 4]).columns,
+            vec![vec![0, 2], vec![1, 3]]
+        );
+    }
+
+    #[test]
+    fn test_only_visible_items() {
+        let layout = layout_of(2, &[100.0; 1000]);
+        assert_eq!(layout.total_height, 500.0 * 110.0 - 10.0);
+
+        let visible = layout.visible_items(Rangef::new(1000.0, 1200.0));
+        let indices: Vec<usize> = visible.iter().map(|&(index, _, _)| index).collect();
+        assert_eq!(indices, vec![18, 19, 20, 21]);
+        assert_eq!(visible[0], (18, 0, 990.0));
+        assert_eq!(visible[3], (21, 1, 1100.0));
+
+        assert!(layout.visible_items(Rangef::new(-200.0, -1.0)).is_empty());
+    }
+}
@@ -0,0 +1,178 @@
+use egui::{AsIdSalt, Id, IdSalt, Ui};
+
+/// A coarse classification of how much width is available.
+///
+/// Use this to change the layout depending on the size of the window,
+/// e.g. collapsing a side panel into a hamburger menu when the window is narrow.
+///
+/// See [`Responsive`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub enum Breakpoint {
+    /// Phone-sized: there is only room for one column of content.
+    Compact,
+
+    /// Tablet-sized: there is room for some side content, but not much.
+    Medium,
+
+    /// Desktop-sized: there is plenty of room.
+    #[default]
+    Expanded,
+}
+
+/// Decides on a [`Breakpoint`] based on the available width, and remembers it.
+///
+/// The breakpoint only changes once the available width is more than [`Self::hysteresis`]
+/// past a threshold. This prevents flickering between two layouts when the available width
+/// depends on the layout, e.g. when a scroll bar appears in one of the layouts.
+///
+/// ### Example
+/// ```
+/// # egui::__run_test_ui(|ui| {
+/// use egui_extras::{Breakpoint, Responsive};
+///
+/// Responsive::new("main_layout").show(ui, |ui, breakpoint| {
+///     if breakpoint == Breakpoint::Compact {
+///         ui.menu_button("☰", |ui| {
+///             ui.label("Side panel content");
+///         });
+///     } else {
+///         ui.label("Side panel content");
+///     }
+/// });
+/// # });
+/// ```
+#[must_use = "You should call .show() or .breakpoint()"]
+pub struct Responsive {
+    id_salt: IdSalt,
+    medium_width: f32,
+    expanded_width: f32,
+    hysteresis: f32,
+}
+
+impl Responsive {
+    pub fn new(id_salt: impl AsIdSalt) -> Self {
+        Self {
+            id_salt: IdSalt::new(id_salt),
+            medium_width: 600.0,
+            expanded_width: 840.0,
+            hysteresis: 8.0,
+        }
+    }
+
+    /// Widths at or above this are at least [`Breakpoint::Medium`].
+    ///
+    /// Default: `600.0`.
+    #[inline]
+    pub fn medium_width(mut self, medium_width: f32) -> Self {
+        self.medium_width = medium_width;
+        self
+    }
+
+    /// Widths at or above this are [`Breakpoint::Expanded`].
+    ///
+    /// Default: `840.0`.
+    #[inline]
+    pub fn expanded_width(mut self, expanded_width: f32) -> Self {
+        self.expanded_width = expanded_width;
+        self
+    }
+
+    /// How far past a threshold the width must go before the breakpoint changes.
+    ///
+    /// Default: `8.0`.
+    #[inline]
+    pub fn hysteresis(mut self, hysteresis: f32) -> Self {
+        self.hysteresis = hysteresis;
+        self
+    }
+
+    /// The breakpoint for the available width of this [`Ui`].
+    ///
+    /// This also remembers the breakpoint, so it can be read with [`Self::cached`].
+    pub fn breakpoint(&self, ui: &Ui) -> Breakpoint {
+        let id = self.id(ui);
+        let previous = ui.data_mut(|d| d.get_temp::<Breakpoint>(id));
+        let breakpoint = self.classify(ui.available_width(), previous);
+        ui.data_mut(|d| d.insert_temp(id, breakpoint));
+        breakpoint
+    }
+
+    /// Call `add_contents` with the breakpoint for the available width.
+    pub fn show<R>(self, ui: &mut Ui, add_contents: impl FnOnce(&mut Ui, Breakpoint) -> R) -> R {
+        let breakpoint = self.breakpoint(ui);
+        add_contents(ui, breakpoint)
+    }
+
+    /// The breakpoint that was last used by a [`Responsive`] with this id salt in this [`Ui`],
+    /// if any.
+    pub fn cached(ui: &Ui, id_salt: impl AsIdSalt) -> Option<Breakpoint> {
+        let id = Self::new(id_salt).id(ui);
+        ui.data(|d| d.get_temp::<Breakpoint>(id))
+    }
+
+    fn id(&self, ui: &Ui) -> Id {
+        ui.id().with(self.id_salt)
+    }
+
+    fn classify(&self, width: f32, previous: Option<Breakpoint>) -> Breakpoint {
+        let Self {
+            id_salt: _,
+            medium_width,
+            expanded_width,
+            hysteresis,
+        } = *self;
+
+        let classify_with_margin = |margin: f32| {
+            if width < medium_width + margin {
+                Breakpoint::Compact
+            } else if width < expanded_width + margin {
+                Breakpoint::Medium
+            } else {
+                Breakpoint::Expanded
+            }
+        };
+
+        let Some(previous) = previous else {
+            return classify_with_margin(0.0);
+        };
+
+        // Only change breakpoint if we are clearly past a threshold:
+        let lenient = classify_with_margin(-hysteresis);
+        let strict = classify_with_margin(hysteresis);
+        if lenient < previous {
+            lenient
+        } else if previous < strict {
+            strict
+        } else {
+            previous
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_hysteresis() {
+        let responsive = Responsive::new("test");
+        assert_eq!(responsive.classify(500.0, None), Breakpoint::Compact);
+        assert_eq!(responsive.classify(600.0, None), Breakpoint::Medium);
+        assert_eq!(responsive.classify(1000.0, None), Breakpoint::Expanded);
+
+        // Slightly past the threshold: keep the previous breakpoint.
+        let previous = Some(Breakpoint::Compact);
+        assert_eq!(responsive.classify(604.0, previous), Breakpoint::Compact);
+        assert_eq!(responsive.classify(610.0, previous), Breakpoint::Medium);
+        let previous = Some(Breakpoint::Medium);
+        assert_eq!(responsive.classify(596.0, previous), Breakpoint::Medium);
+        assert_eq!(responsive.classify(590.0, previous), Breakpoint::Compact);
+
+        // Big jumps can skip a breakpoint:
+        assert_eq!(
+            responsive.classify(1000.0, Some(Breakpoint::Compact)),
+            Breakpoint::Expanded
+        );
+    }
+}
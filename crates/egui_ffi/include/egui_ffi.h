@@ -0,0 +1,159 @@
+/*
+ * C API for embedding egui.
+ *
+ * See the documentation of the `egui_ffi` crate for details.
+ * Strings are nul-terminated UTF-8.
+ * Pointers returned by the `egui_output_*` functions are valid until the next call to `egui_run`.
+ */
+
+#ifndef EGUI_FFI_H
+#define EGUI_FFI_H
+
+#include <stdbool.h>
+#include <stddef.h>
+#include <stdint.h>
+
+#ifdef __cplusplus
+extern "C" {
+#endif
+
+typedef struct EguiContext EguiContext;
+typedef struct EguiUi EguiUi;
+
+typedef void (*EguiUiCallback)(EguiUi* ui, void* user_data);
+
+/* ------------------------------------------------------------------------ */
+/* Context */
+
+EguiContext* egui_context_new(void);
+void egui_context_free(EguiContext* ctx);
+
+/* Run one frame, calling `callback` with the root ui. */
+void egui_run(EguiContext* ctx, EguiUiCallback callback, void* user_data);
+
+/* ------------------------------------------------------------------------ */
+/* Input */
+
+typedef enum EguiPointerButton {
+    EGUI_POINTER_BUTTON_PRIMARY = 0,
+    EGUI_POINTER_BUTTON_SECONDARY = 1,
+    EGUI_POINTER_BUTTON_MIDDLE = 2,
+    EGUI_POINTER_BUTTON_EXTRA1 = 3,
+    EGUI_POINTER_BUTTON_EXTRA2 = 4,
+} EguiPointerButton;
+
+#define EGUI_MODIFIER_ALT (1u << 0)
+#define EGUI_MODIFIER_CTRL (1u << 1)
+#define EGUI_MODIFIER_SHIFT (1u << 2)
+#define EGUI_MODIFIER_MAC_CMD (1u << 3)
+#define EGUI_MODIFIER_COMMAND (1u << 4)
+
+void egui_input_screen_rect(EguiContext* ctx, float width, float height, float pixels_per_point);
+void egui_input_time(EguiContext* ctx, double seconds);
+void egui_input_focused(EguiContext* ctx, bool focused);
+void egui_input_modifiers(EguiContext* ctx, uint32_t modifiers);
+void egui_input_pointer_moved(EguiContext* ctx, float x, float y);
+void egui_input_pointer_button(EguiContext* ctx, EguiPointerButton button, bool pressed);
+void egui_input_pointer_gone(EguiContext* ctx);
+void egui_input_scroll(EguiContext* ctx, float dx, float dy);
+/* `key` is the name of an `egui::Key`, e.g. "Enter", "A" or "ArrowLeft". */
+void egui_input_key(EguiContext* ctx, const char* key, bool pressed, bool repeat);
+void egui_input_text(EguiContext* ctx, const char* text);
+void egui_input_paste(EguiContext* ctx, const char* text);
+
+/* ------------------------------------------------------------------------ */
+/* Widgets */
+
+void egui_ui_label(EguiUi* ui, const char* text);
+void egui_ui_heading(EguiUi* ui, const char* text);
+void egui_ui_separator(EguiUi* ui);
+bool egui_ui_button(EguiUi* ui, const char* text);
+bool egui_ui_checkbox(EguiUi* ui, const char* text, bool* checked);
+bool egui_ui_slider_f32(EguiUi* ui, const char* text, float* value, float min, float max);
+bool egui_ui_text_edit_singleline(EguiUi* ui, char* buffer, size_t capacity);
+void egui_ui_horizontal(EguiUi* ui, EguiUiCallback callback, void* user_data);
+bool egui_ui_collapsing(EguiUi* ui, const char* heading, EguiUiCallback callback, void* user_data);
+void egui_ui_window(EguiUi* ui, const char* title, EguiUiCallback callback, void* user_data);
+
+/* ------------------------------------------------------------------------ */
+/* Output */
+
+typedef struct EguiPos2 {
+    float x;
+    float y;
+} EguiPos2;
+
+typedef struct EguiRect {
+    EguiPos2 min;
+    EguiPos2 max;
+} EguiRect;
+
+typedef struct EguiVertex {
+    EguiPos2 pos;     /* points */
+    EguiPos2 uv;      /* normalized texture coordinates */
+    uint8_t color[4]; /* sRGBA, premultiplied alpha */
+} EguiVertex;
+
+typedef struct EguiTextureId {
+    uint64_t id;
+    bool is_user; /* false: managed by egui (0 is the font atlas). true: your own texture. */
+} EguiTextureId;
+
+typedef enum EguiTextureFilter {
+    EGUI_TEXTURE_FILTER_NEAREST = 0,
+    EGUI_TEXTURE_FILTER_LINEAR = 1,
+} EguiTextureFilter;
+
+typedef enum EguiTextureWrapMode {
+    EGUI_TEXTURE_WRAP_MODE_CLAMP_TO_EDGE = 0,
+    EGUI_TEXTURE_WRAP_MODE_REPEAT = 1,
+    EGUI_TEXTURE_WRAP_MODE_MIRRORED_REPEAT = 2,
+} EguiTextureWrapMode;
+
+typedef struct EguiMesh {
+    EguiRect clip_rect;
+    EguiTextureId texture_id;
+    const EguiVertex* vertices;
+    size_t vertex_count;
+    const uint32_t* indices; /* three per triangle */
+    size_t index_count;
+} EguiMesh;
+
+typedef struct EguiTextureDelta {
+    EguiTextureId id;
+    bool is_partial; /* if true, update the region at x, y of an existing texture */
+    size_t x;
+    size_t y;
+    size_t width;
+    size_t height;
+    const uint8_t* pixels; /* width * height * 4 bytes of sRGBA, premultiplied alpha */
+    EguiTextureFilter magnification;
+    EguiTextureFilter minification;
+    EguiTextureWrapMode wrap_mode;
+} EguiTextureDelta;
+
+size_t egui_output_mesh_count(EguiContext* ctx);
+bool egui_output_mesh(EguiContext* ctx, size_t index, EguiMesh* out);
+
+size_t egui_output_texture_set_count(EguiContext* ctx);
+bool egui_output_texture_set(EguiContext* ctx, size_t index, EguiTextureDelta* out);
+
+size_t egui_output_texture_free_count(EguiContext* ctx);
+EguiTextureId egui_output_texture_free(EguiContext* ctx, size_t index);
+
+/* May return null. */
+const char* egui_output_copied_text(EguiContext* ctx);
+/* May return null. */
+const char* egui_output_open_url(EguiContext* ctx);
+/* The name of an `egui::CursorIcon`, e.g. "Default", "PointingHand" or "Text". */
+const char* egui_output_cursor_icon(EguiContext* ctx);
+/* Seconds until egui wants to run again. */
+double egui_output_repaint_delay(EguiContext* ctx);
+bool egui_output_wants_keyboard_input(EguiContext* ctx);
+bool egui_output_wants_pointer_input(EguiContext* ctx);
+
+#ifdef __cplusplus
+}
+#endif
+
+#endif /* EGUI_FFI_H */
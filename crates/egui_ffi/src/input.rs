@@ -0,0 +1,266 @@
+//! Feeding input to egui.
+
+use std::ffi::c_char;
+
+use crate::{EguiContext, context, str_from_c};
+
+/// A mouse button.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum EguiPointerButton {
+    Primary = 0,
+    Secondary = 1,
+    Middle = 2,
+    Extra1 = 3,
+    Extra2 = 4,
+}
+
+impl From<EguiPointerButton> for egui::PointerButton {
+    fn from(button: EguiPointerButton) -> Self {
+        match button {
+            EguiPointerButton::Primary => Self::Primary,
+            EguiPointerButton::Secondary => Self::Secondary,
+            EguiPointerButton::Middle => Self::Middle,
+            EguiPointerButton::Extra1 => Self::Extra1,
+            EguiPointerButton::Extra2 => Self::Extra2,
+        }
+    }
+}
+
+/// Bit for the Alt/Option key in the modifiers given to [`egui_input_modifiers`].
+pub const EGUI_MODIFIER_ALT: u32 = 1 << 0;
+
+/// Bit for the Ctrl key in the modifiers given to [`egui_input_modifiers`].
+pub const EGUI_MODIFIER_CTRL: u32 = 1 << 1;
+
+/// Bit for the Shift key in the modifiers given to [`egui_input_modifiers`].
+pub const EGUI_MODIFIER_SHIFT: u32 = 1 << 2;
+
+/// Bit for the Cmd key on Mac in the modifiers given to [`egui_input_modifiers`].
+pub const EGUI_MODIFIER_MAC_CMD: u32 = 1 << 3;
+
+/// Bit for the "command" key (Ctrl on Windows/Linux, Cmd on Mac)
+/// in the modifiers given to [`egui_input_modifiers`].
+pub const EGUI_MODIFIER_COMMAND: u32 = 1 << 4;
+
+/// Set the size of the screen (or window) egui covers, in points,
+/// and how many physical pixels there are per point.
+///
+/// # Safety
+/// `ctx` must be null or a valid context.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn egui_input_screen_rect(
+    ctx: *mut EguiContext,
+    width: f32,
+    height: f32,
+    pixels_per_point: f32,
+) {
+    // SAFETY: forwarded from the caller.
+    let Some(ctx) = (unsafe { context(ctx) }) else {
+        return;
+    };
+    ctx.input.screen_rect = Some(egui::Rect::from_min_size(
+        egui::Pos2::ZERO,
+        egui::vec2(width, height),
+    ));
+    ctx.input
+        .viewports
+        .entry(ctx.input.viewport_id)
+        .or_default()
+        .native_pixels_per_point = Some(pixels_per_point);
+}
+
+/// Set the current time, in seconds since some fixed point (e.g. the start of the application).
+///
+/// Needed for animations and double-clicks.
+///
+/// # Safety
+/// `ctx` must be null or a valid context.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn egui_input_time(ctx: *mut EguiContext, seconds: f64) {
+    // SAFETY: forwarded from the caller.
+    if let Some(ctx) = unsafe { context(ctx) } {
+        ctx.input.time = Some(seconds);
+    }
+}
+
+/// Does the window have keyboard focus?
+///
+/// # Safety
+/// `ctx` must be null or a valid context.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn egui_input_focused(ctx: *mut EguiContext, focused: bool) {
+    // SAFETY: forwarded from the caller.
+    if let Some(ctx) = unsafe { context(ctx) } {
+        ctx.input.focused = focused;
+    }
+}
+
+/// Set which modifier keys are held down, as a combination of the `EGUI_MODIFIER_*` bits.
+///
+/// # Safety
+/// `ctx` must be null or a valid context.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn egui_input_modifiers(ctx: *mut EguiContext, modifiers: u32) {
+    // SAFETY: forwarded from the caller.
+    if let Some(ctx) = unsafe { context(ctx) } {
+        ctx.modifiers = egui::Modifiers {
+            alt: modifiers & EGUI_MODIFIER_ALT != 0,
+            ctrl: modifiers & EGUI_MODIFIER_CTRL != 0,
+            shift: modifiers & EGUI_MODIFIER_SHIFT != 0,
+            mac_cmd: modifiers & EGUI_MODIFIER_MAC_CMD != 0,
+            command: modifiers & EGUI_MODIFIER_COMMAND != 0,
+        };
+        ctx.input.modifiers = ctx.modifiers;
+    }
+}
+
+/// The mouse moved to this position, in points.
+///
+/// # Safety
+/// `ctx` must be null or a valid context.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn egui_input_pointer_moved(ctx: *mut EguiContext, x: f32, y: f32) {
+    // SAFETY: forwarded from the caller.
+    if let Some(ctx) = unsafe { context(ctx) } {
+        ctx.pointer_pos = egui::pos2(x, y);
+        ctx.input
+            .events
+            .push(egui::Event::PointerMoved(ctx.pointer_pos));
+    }
+}
+
+/// A mouse button was pressed or released at the last position given to
+/// [`egui_input_pointer_moved`].
+///
+/// # Safety
+/// `ctx` must be null or a valid context.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn egui_input_pointer_button(
+    ctx: *mut EguiContext,
+    button: EguiPointerButton,
+    pressed: bool,
+) {
+    // SAFETY: forwarded from the caller.
+    if let Some(ctx) = unsafe { context(ctx) } {
+        ctx.input.events.push(egui::Event::PointerButton {
+            pos: ctx.pointer_pos,
+            button: button.into(),
+            pressed,
+            modifiers: ctx.modifiers,
+        });
+    }
+}
+
+/// The mouse left the window.
+///
+/// # Safety
+/// `ctx` must be null or a valid context.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn egui_input_pointer_gone(ctx: *mut EguiContext) {
+    // SAFETY: forwarded from the caller.
+    if let Some(ctx) = unsafe { context(ctx) } {
+        ctx.input.events.push(egui::Event::PointerGone);
+    }
+}
+
+/// The mouse wheel or touchpad scrolled, in points.
+///
+/// Positive `dy` means the content should move down (i.e. scroll up).
+///
+/// # Safety
+/// `ctx` must be null or a valid context.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn egui_input_scroll(ctx: *mut EguiContext, dx: f32, dy: f32) {
+    // SAFETY: forwarded from the caller.
+    if let Some(ctx) = unsafe { context(ctx) } {
+        ctx.input.events.push(egui::Event::MouseWheel {
+            unit: egui::MouseWheelUnit::Point,
+            delta: egui::vec2(dx, dy),
+            phase: egui::TouchPhase::Move,
+            momentum: false,
+            modifiers: ctx.modifiers,
+        });
+    }
+}
+
+/// A key was pressed or released.
+///
+/// `key` is the name of an [`egui::Key`], e.g. `"Enter"`, `"A"` or `"ArrowLeft"`.
+/// Unknown names are ignored.
+///
+/// # Safety
+/// `ctx` must be null or a valid context.
+/// `key` must be null or a nul-terminated string.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn egui_input_key(
+    ctx: *mut EguiContext,
+    key: *const c_char,
+    pressed: bool,
+    repeat: bool,
+) {
+    // SAFETY: forwarded from the caller.
+    let Some(ctx) = (unsafe { context(ctx) }) else {
+        return;
+    };
+    // SAFETY: forwarded from the caller.
+    let Some(key) = unsafe { str_from_c(key) }.and_then(egui::Key::from_name) else {
+        return;
+    };
+
+    let modifiers = ctx.modifiers;
+    let events = &mut ctx.input.events;
+    if pressed && modifiers.command {
+        match key {
+            egui::Key::C => events.push(egui::Event::Copy),
+            egui::Key::X => events.push(egui::Event::Cut),
+            _ => {}
+        }
+    }
+    events.push(egui::Event::Key {
+        key,
+        physical_key: None,
+        location: egui::KeyLocation::Standard,
+        pressed,
+        repeat,
+        modifiers,
+    });
+}
+
+/// Text was typed, as nul-terminated UTF-8.
+///
+/// Don't send control characters like newlines or tabs; send them as keys instead.
+///
+/// # Safety
+/// `ctx` must be null or a valid context.
+/// `text` must be null or a nul-terminated string.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn egui_input_text(ctx: *mut EguiContext, text: *const c_char) {
+    // SAFETY: forwarded from the caller.
+    let Some(ctx) = (unsafe { context(ctx) }) else {
+        return;
+    };
+    // SAFETY: forwarded from the caller.
+    if let Some(text) = unsafe { str_from_c(text) }
+        && !text.is_empty()
+    {
+        ctx.input.events.push(egui::Event::Text(text.to_owned()));
+    }
+}
+
+/// Text was pasted from the clipboard, as nul-terminated UTF-8.
+///
+/// # Safety
+/// `ctx` must be null or a valid context.
+/// `text` must be null or a nul-terminated string.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn egui_input_paste(ctx: *mut EguiContext, text: *const c_char) {
+    // SAFETY: forwarded from the caller.
+    let Some(ctx) = (unsafe { context(ctx) }) else {
+        return;
+    };
+    // SAFETY: forwarded from the caller.
+    if let Some(text) = unsafe { str_from_c(text) } {
+        ctx.input.events.push(egui::Event::Paste(text.to_owned()));
+    }
+}
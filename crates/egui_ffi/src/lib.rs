@@ -0,0 +1,204 @@
+//! A C API for embedding [`egui`] in C and C++ applications.
+//!
+//! The C declarations are in `include/egui_ffi.h`.
+//!
+//! All functions take an [`EguiContext`] created with [`egui_context_new`].
+//! A context is not thread-safe: only use it from one thread at a time.
+//! Passing a null pointer where a context is expected is a no-op.
+//!
+//! A frame goes like this:
+//!
+//! 1. Feed input with the `egui_input_*` functions.
+//! 2. Call [`egui_run`] with a callback that adds widgets with the `egui_ui_*` functions.
+//! 3. Read back the output with the `egui_output_*` functions.
+//!    The output stays valid until the next call to [`egui_run`].
+//!
+//! Panics are not unwound across the FFI boundary: they abort the process.
+//!
+//! ## Feature flags
+#![cfg_attr(feature = "document-features", doc = document_features::document_features!())]
+//!
+
+#![expect(unsafe_code)]
+
+mod input;
+mod output;
+mod widgets;
+
+use std::ffi::{CStr, c_char, c_void};
+
+pub use input::*;
+pub use output::*;
+pub use widgets::*;
+
+/// An egui context, together with the input for the next frame
+/// and the output of the last one.
+///
+/// Opaque to C.
+pub struct EguiContext {
+    ctx: egui::Context,
+    input: egui::RawInput,
+    modifiers: egui::Modifiers,
+    pointer_pos: egui::Pos2,
+    output: output::Output,
+}
+
+/// A [`egui::Ui`] to add widgets to.
+///
+/// Opaque to C. Only valid during the callback it was given to.
+#[repr(transparent)]
+pub struct EguiUi(egui::Ui);
+
+/// Called by [`egui_run`] and the container widgets to add the contents of a [`EguiUi`].
+pub type EguiUiCallback = Option<unsafe extern "C" fn(ui: *mut EguiUi, user_data: *mut c_void)>;
+
+/// Create a new egui context. Free it with [`egui_context_free`].
+#[unsafe(no_mangle)]
+pub extern "C" fn egui_context_new() -> *mut EguiContext {
+    Box::into_raw(Box::new(EguiContext {
+        ctx: egui::Context::default(),
+        input: egui::RawInput::default(),
+        modifiers: egui::Modifiers::default(),
+        pointer_pos: egui::Pos2::ZERO,
+        output: output::Output::default(),
+    }))
+}
+
+/// Free a context created with [`egui_context_new`].
+///
+/// # Safety
+/// `ctx` must be null or come from [`egui_context_new`], and must not be used afterwards.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn egui_context_free(ctx: *mut EguiContext) {
+    if !ctx.is_null() {
+        // SAFETY: the caller guarantees that this came from `egui_context_new`.
+        drop(unsafe { Box::from_raw(ctx) });
+    }
+}
+
+/// Run one frame of egui.
+///
+/// Consumes all input given since the last call,
+/// calls `callback` with the root [`EguiUi`] of the screen,
+/// and stores the output for the `egui_output_*` functions.
+///
+/// # Safety
+/// `ctx` must be null or a valid context.
+/// `callback` is called with `user_data`, and must uphold the safety requirements
+/// of the `egui_ui_*` functions it calls.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn egui_run(
+    ctx: *mut EguiContext,
+    callback: EguiUiCallback,
+    user_data: *mut c_void,
+) {
+    // SAFETY: the caller guarantees that `ctx` is null or valid.
+    let Some(ctx) = (unsafe { ctx.as_mut() }) else {
+        return;
+    };
+
+    let input = ctx.input.take();
+    let full_output = ctx.ctx.run_ui(input, |ui| {
+        // SAFETY: forwarded from the caller.
+        unsafe { call_ui_callback(ui, callback, user_data) };
+    });
+    ctx.output = output::Output::new(&ctx.ctx, full_output);
+}
+
+/// Call a C callback with a [`egui::Ui`].
+///
+/// # Safety
+/// See [`egui_run`].
+unsafe fn call_ui_callback(ui: &mut egui::Ui, callback: EguiUiCallback, user_data: *mut c_void) {
+    if let Some(callback) = callback {
+        let ui: *mut egui::Ui = ui;
+        // SAFETY: `EguiUi` is a transparent wrapper around `egui::Ui`,
+        // and the caller guarantees that the callback is sound.
+        unsafe { callback(ui.cast::<EguiUi>(), user_data) };
+    }
+}
+
+/// # Safety
+/// `ctx` must be null or a valid context.
+unsafe fn context<'a>(ctx: *mut EguiContext) -> Option<&'a mut EguiContext> {
+    // SAFETY: the caller guarantees that `ctx` is null or valid.
+    unsafe { ctx.as_mut() }
+}
+
+/// # Safety
+/// `ui` must be null or a valid [`EguiUi`].
+unsafe fn ui<'a>(ui: *mut EguiUi) -> Option<&'a mut egui::Ui> {
+    // SAFETY: the caller guarantees that `ui` is null or valid.
+    unsafe { ui.as_mut() }.map(|ui| &mut ui.0)
+}
+
+/// Read a nul-terminated UTF-8 string. Returns `None` for null or invalid UTF-8.
+///
+/// # Safety
+/// `s` must be null or point to a nul-terminated string.
+unsafe fn str_from_c<'a>(s: *const c_char) -> Option<&'a str> {
+    if s.is_null() {
+        None
+    } else {
+        // SAFETY: the caller guarantees that `s` is nul-terminated.
+        unsafe { CStr::from_ptr(s) }.to_str().ok()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Default)]
+    struct TestState {
+        clicked: bool,
+        button_pos: egui::Pos2,
+    }
+
+    unsafe extern "C" fn test_ui(ui: *mut EguiUi, user_data: *mut c_void) {
+        // SAFETY: `user_data` is always a `TestState`.
+        unsafe {
+            let state = &mut *user_data.cast::<TestState>();
+            egui_ui_label(ui, c"Hello from C".as_ptr());
+            state.button_pos = (*ui).0.cursor().min + egui::vec2(4.0, 4.0);
+            state.clicked |= egui_ui_button(ui, c"Click me".as_ptr());
+        }
+    }
+
+    #[test]
+    fn run_frame_and_read_output() {
+        // SAFETY: we only pass valid pointers.
+        unsafe {
+            let ctx = egui_context_new();
+            let mut state = TestState::default();
+            let user_data = (&raw mut state).cast::<c_void>();
+
+            egui_input_screen_rect(ctx, 400.0, 300.0, 1.0);
+            egui_run(ctx, Some(test_ui), user_data);
+
+            assert!(0 < egui_output_mesh_count(ctx));
+            assert!(0 < egui_output_texture_set_count(ctx), "font atlas");
+
+            let mut mesh = std::mem::zeroed::<EguiMesh>();
+            assert!(egui_output_mesh(ctx, 0, &raw mut mesh));
+            assert!(0 < mesh.index_count);
+            assert!(!egui_output_mesh(ctx, usize::MAX, &raw mut mesh));
+
+            let mut delta = std::mem::zeroed::<EguiTextureDelta>();
+            assert!(egui_output_texture_set(ctx, 0, &raw mut delta));
+            assert_eq!(delta.id.id, 0);
+            assert!(!delta.id.is_user);
+            assert!(!delta.pixels.is_null());
+
+            // Click the button:
+            egui_input_pointer_moved(ctx, state.button_pos.x, state.button_pos.y);
+            egui_input_pointer_button(ctx, EguiPointerButton::Primary, true);
+            egui_run(ctx, Some(test_ui), user_data);
+            egui_input_pointer_button(ctx, EguiPointerButton::Primary, false);
+            egui_run(ctx, Some(test_ui), user_data);
+            assert!(state.clicked);
+
+            egui_context_free(ctx);
+        }
+    }
+}
@@ -0,0 +1,439 @@
+//! Reading back what egui wants painted.
+
+use std::ffi::{CString, c_char};
+
+use crate::{EguiContext, context};
+
+/// A position, in points (or in UV coordinates for [`EguiVertex::uv`]).
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct EguiPos2 {
+    pub x: f32,
+    pub y: f32,
+}
+
+impl From<egui::Pos2> for EguiPos2 {
+    fn from(pos: egui::Pos2) -> Self {
+        Self { x: pos.x, y: pos.y }
+    }
+}
+
+/// A rectangle, in points.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct EguiRect {
+    pub min: EguiPos2,
+    pub max: EguiPos2,
+}
+
+impl From<egui::Rect> for EguiRect {
+    fn from(rect: egui::Rect) -> Self {
+        Self {
+            min: rect.min.into(),
+            max: rect.max.into(),
+        }
+    }
+}
+
+/// A vertex of a triangle mesh.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct EguiVertex {
+    /// Logical pixel coordinates (points).
+    pub pos: EguiPos2,
+
+    /// Normalized texture coordinates.
+    pub uv: EguiPos2,
+
+    /// sRGBA with premultiplied alpha.
+    pub color: [u8; 4],
+}
+
+/// Which texture to use.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct EguiTextureId {
+    pub id: u64,
+
+    /// `false`: a texture managed by egui, described by [`EguiTextureDelta`]s.
+    /// `id` 0 is the font atlas.
+    ///
+    /// `true`: one of your own textures.
+    pub is_user: bool,
+}
+
+impl From<egui::TextureId> for EguiTextureId {
+    fn from(id: egui::TextureId) -> Self {
+        match id {
+            egui::TextureId::Managed(id) => Self { id, is_user: false },
+            egui::TextureId::User(id) => Self { id, is_user: true },
+        }
+    }
+}
+
+/// How to sample a texture.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum EguiTextureFilter {
+    Nearest = 0,
+    #[default]
+    Linear = 1,
+}
+
+impl From<egui::TextureFilter> for EguiTextureFilter {
+    fn from(filter: egui::TextureFilter) -> Self {
+        match filter {
+            egui::TextureFilter::Nearest => Self::Nearest,
+            egui::TextureFilter::Linear => Self::Linear,
+        }
+    }
+}
+
+/// How to sample outside of the texture.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum EguiTextureWrapMode {
+    #[default]
+    ClampToEdge = 0,
+    Repeat = 1,
+    MirroredRepeat = 2,
+}
+
+impl From<egui::TextureWrapMode> for EguiTextureWrapMode {
+    fn from(wrap_mode: egui::TextureWrapMode) -> Self {
+        match wrap_mode {
+            egui::TextureWrapMode::ClampToEdge => Self::ClampToEdge,
+            egui::TextureWrapMode::Repeat => Self::Repeat,
+            egui::TextureWrapMode::MirroredRepeat => Self::MirroredRepeat,
+        }
+    }
+}
+
+/// A triangle mesh to paint, with a single texture and clip rectangle.
+///
+/// The pointers are valid until the next call to [`crate::egui_run`].
+#[repr(C)]
+#[derive(Clone, Copy, Debug)]
+pub struct EguiMesh {
+    /// Only paint inside this rectangle, in points.
+    pub clip_rect: EguiRect,
+    pub texture_id: EguiTextureId,
+    pub vertices: *const EguiVertex,
+    pub vertex_count: usize,
+
+    /// Triangles, as indices into `vertices`. Three per triangle.
+    pub indices: *const u32,
+    pub index_count: usize,
+}
+
+/// Create or update a texture.
+///
+/// The pointer is valid until the next call to [`crate::egui_run`].
+#[repr(C)]
+#[derive(Clone, Copy, Debug)]
+pub struct EguiTextureDelta {
+    pub id: EguiTextureId,
+
+    /// If `false`, create (or replace) the whole texture.
+    ///
+    /// If `true`, update the region at `x, y` of an existing texture.
+    pub is_partial: bool,
+    pub x: usize,
+    pub y: usize,
+    pub width: usize,
+    pub height: usize,
+
+    /// `width * height * 4` bytes of sRGBA with premultiplied alpha, row by row.
+    pub pixels: *const u8,
+    pub magnification: EguiTextureFilter,
+    pub minification: EguiTextureFilter,
+    pub wrap_mode: EguiTextureWrapMode,
+}
+
+struct Mesh {
+    clip_rect: egui::Rect,
+    texture_id: egui::TextureId,
+    vertices: Vec<EguiVertex>,
+    indices: Vec<u32>,
+}
+
+struct TextureDelta {
+    id: egui::TextureId,
+    pos: Option<[usize; 2]>,
+    size: [usize; 2],
+    pixels: Vec<u8>,
+    options: egui::TextureOptions,
+}
+
+/// The output of the last frame, in a form that C can read.
+#[derive(Default)]
+pub(crate) struct Output {
+    meshes: Vec<Mesh>,
+    textures_set: Vec<TextureDelta>,
+    textures_free: Vec<egui::TextureId>,
+    copied_text: Option<CString>,
+    open_url: Option<CString>,
+    cursor_icon: Option<CString>,
+    repaint_delay: Option<std::time::Duration>,
+    wants_keyboard_input: bool,
+    wants_pointer_input: bool,
+}
+
+impl Output {
+    pub fn new(ctx: &egui::Context, full_output: egui::FullOutput) -> Self {
+        let egui::FullOutput {
+            platform_output,
+            textures_delta,
+            shapes,
+            pixels_per_point,
+            viewport_output,
+        } = full_output;
+
+        let meshes = ctx
+            .tessellate(shapes, pixels_per_point)
+            .into_iter()
+            .filter_map(|clipped| match clipped.primitive {
+                egui::epaint::Primitive::Mesh(mesh) => Some(Mesh {
+                    clip_rect: clipped.clip_rect,
+                    texture_id: mesh.texture_id,
+                    vertices: mesh
+                        .vertices
+                        .iter()
+                        .map(|v| EguiVertex {
+                            pos: v.pos.into(),
+                            uv: v.uv.into(),
+                            color: v.color.to_array(),
+                        })
+                        .collect(),
+                    indices: mesh.indices,
+                }),
+                egui::epaint::Primitive::Callback(_) => None, // Not supported over FFI
+            })
+            .collect();
+
+        let textures_set = textures_delta
+            .set
+            .into_iter()
+            .map(|(id, delta)| {
+                let egui::ImageData::Color(image) = &delta.image;
+                TextureDelta {
+                    id,
+                    pos: delta.pos,
+                    size: image.size,
+                    pixels: image.pixels.iter().flat_map(|c| c.to_array()).collect(),
+                    options: delta.options,
+                }
+            })
+            .collect();
+
+        let mut copied_text = None;
+        let mut open_url = None;
+        for command in platform_output.commands {
+            match command {
+                egui::OutputCommand::CopyText(text) => copied_text = CString::new(text).ok(),
+                egui::OutputCommand::OpenUrl(url) => open_url = CString::new(url.url).ok(),
+                egui::OutputCommand::CopyImage(_) | egui::OutputCommand::CopyFiles(_) => {}
+            }
+        }
+
+        Self {
+            meshes,
+            textures_set,
+            textures_free: textures_delta.free,
+            copied_text,
+            open_url,
+            cursor_icon: CString::new(format!("{:?}", platform_output.cursor_icon)).ok(),
+            repaint_delay: viewport_output
+                .get(&egui::ViewportId::ROOT)
+                .map(|output| output.repaint_delay),
+            wants_keyboard_input: ctx.egui_wants_keyboard_input(),
+            wants_pointer_input: ctx.egui_wants_pointer_input(),
+        }
+    }
+}
+
+/// How many meshes to paint, in order, back to front.
+///
+/// # Safety
+/// `ctx` must be null or a valid context.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn egui_output_mesh_count(ctx: *mut EguiContext) -> usize {
+    // SAFETY: forwarded from the caller.
+    unsafe { context(ctx) }.map_or(0, |ctx| ctx.output.meshes.len())
+}
+
+/// Get mesh number `index`. Returns `false` if there is no such mesh.
+///
+/// # Safety
+/// `ctx` must be null or a valid context. `out` must be null or valid for writes.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn egui_output_mesh(
+    ctx: *mut EguiContext,
+    index: usize,
+    out: *mut EguiMesh,
+) -> bool {
+    // SAFETY: forwarded from the caller.
+    let Some(ctx) = (unsafe { context(ctx) }) else {
+        return false;
+    };
+    let (Some(mesh), false) = (ctx.output.meshes.get(index), out.is_null()) else {
+        return false;
+    };
+
+    let mesh = EguiMesh {
+        clip_rect: mesh.clip_rect.into(),
+        texture_id: mesh.texture_id.into(),
+        vertices: mesh.vertices.as_ptr(),
+        vertex_count: mesh.vertices.len(),
+        indices: mesh.indices.as_ptr(),
+        index_count: mesh.indices.len(),
+    };
+    // SAFETY: the caller guarantees that `out` is valid for writes.
+    unsafe { out.write(mesh) };
+    true
+}
+
+/// How many textures to create or update before painting the meshes.
+///
+/// # Safety
+/// `ctx` must be null or a valid context.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn egui_output_texture_set_count(ctx: *mut EguiContext) -> usize {
+    // SAFETY: forwarded from the caller.
+    unsafe { context(ctx) }.map_or(0, |ctx| ctx.output.textures_set.len())
+}
+
+/// Get texture update number `index`. Returns `false` if there is no such update.
+///
+/// # Safety
+/// `ctx` must be null or a valid context. `out` must be null or valid for writes.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn egui_output_texture_set(
+    ctx: *mut EguiContext,
+    index: usize,
+    out: *mut EguiTextureDelta,
+) -> bool {
+    // SAFETY: forwarded from the caller.
+    let Some(ctx) = (unsafe { context(ctx) }) else {
+        return false;
+    };
+    let (Some(delta), false) = (ctx.output.textures_set.get(index), out.is_null()) else {
+        return false;
+    };
+
+    let [x, y] = delta.pos.unwrap_or([0, 0]);
+    let delta = EguiTextureDelta {
+        id: delta.id.into(),
+        is_partial: delta.pos.is_some(),
+        x,
+        y,
+        width: delta.size[0],
+        height: delta.size[1],
+        pixels: delta.pixels.as_ptr(),
+        magnification: delta.options.magnification.into(),
+        minification: delta.options.minification.into(),
+        wrap_mode: delta.options.wrap_mode.into(),
+    };
+    // SAFETY: the caller guarantees that `out` is valid for writes.
+    unsafe { out.write(delta) };
+    true
+}
+
+/// How many textures to free after painting the meshes.
+///
+/// # Safety
+/// `ctx` must be null or a valid context.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn egui_output_texture_free_count(ctx: *mut EguiContext) -> usize {
+    // SAFETY: forwarded from the caller.
+    unsafe { context(ctx) }.map_or(0, |ctx| ctx.output.textures_free.len())
+}
+
+/// The texture to free number `index`.
+///
+/// # Safety
+/// `ctx` must be null or a valid context.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn egui_output_texture_free(
+    ctx: *mut EguiContext,
+    index: usize,
+) -> EguiTextureId {
+    // SAFETY: forwarded from the caller.
+    unsafe { context(ctx) }
+        .and_then(|ctx| ctx.output.textures_free.get(index).copied())
+        .map_or_else(EguiTextureId::default, Into::into)
+}
+
+/// Text that should be copied to the clipboard, or null.
+///
+/// # Safety
+/// `ctx` must be null or a valid context.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn egui_output_copied_text(ctx: *mut EguiContext) -> *const c_char {
+    // SAFETY: forwarded from the caller.
+    unsafe { context(ctx) }
+        .and_then(|ctx| ctx.output.copied_text.as_deref())
+        .map_or(std::ptr::null(), |text| text.as_ptr())
+}
+
+/// A URL that was clicked and should be opened, or null.
+///
+/// # Safety
+/// `ctx` must be null or a valid context.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn egui_output_open_url(ctx: *mut EguiContext) -> *const c_char {
+    // SAFETY: forwarded from the caller.
+    unsafe { context(ctx) }
+        .and_then(|ctx| ctx.output.open_url.as_deref())
+        .map_or(std::ptr::null(), |url| url.as_ptr())
+}
+
+/// The name of the [`egui::CursorIcon`] to show, e.g. `"Default"`, `"PointingHand"` or `"Text"`.
+///
+/// # Safety
+/// `ctx` must be null or a valid context.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn egui_output_cursor_icon(ctx: *mut EguiContext) -> *const c_char {
+    // SAFETY: forwarded from the caller.
+    unsafe { context(ctx) }
+        .and_then(|ctx| ctx.output.cursor_icon.as_deref())
+        .map_or(c"Default".as_ptr(), |icon| icon.as_ptr())
+}
+
+/// In how many seconds egui wants to run again, e.g. for an animation.
+///
+/// `0` means as soon as possible. A very large number means only on new input.
+///
+/// # Safety
+/// `ctx` must be null or a valid context.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn egui_output_repaint_delay(ctx: *mut EguiContext) -> f64 {
+    // SAFETY: forwarded from the caller.
+    unsafe { context(ctx) }
+        .and_then(|ctx| ctx.output.repaint_delay)
+        .map_or(f64::MAX, |delay| delay.as_secs_f64())
+}
+
+/// Is egui using the keyboard, e.g. for a text field?
+///
+/// If so, don't use the keyboard input for your own things.
+///
+/// # Safety
+/// `ctx` must be null or a valid context.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn egui_output_wants_keyboard_input(ctx: *mut EguiContext) -> bool {
+    // SAFETY: forwarded from the caller.
+    unsafe { context(ctx) }.is_some_and(|ctx| ctx.output.wants_keyboard_input)
+}
+
+/// Is the mouse over egui, or is egui dragging something?
+///
+/// If so, don't use the mouse input for your own things.
+///
+/// # Safety
+/// `ctx` must be null or a valid context.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn egui_output_wants_pointer_input(ctx: *mut EguiContext) -> bool {
+    // SAFETY: forwarded from the caller.
+    unsafe { context(ctx) }.is_some_and(|ctx| ctx.output.wants_pointer_input)
+}
@@ -0,0 +1,210 @@
+//! A small set of widgets and containers.
+//!
+//! Strings are nul-terminated UTF-8. Widgets with a null or invalid label are skipped.
+
+use std::ffi::{c_char, c_void};
+
+use crate::{EguiUi, EguiUiCallback, call_ui_callback, str_from_c, ui};
+
+/// Show some text.
+///
+/// # Safety
+/// `ui` must be null or a valid [`EguiUi`]. `text` must be null or a nul-terminated string.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn egui_ui_label(ui: *mut EguiUi, text: *const c_char) {
+    // SAFETY: forwarded from the caller.
+    if let (Some(ui), Some(text)) = unsafe { (self::ui(ui), str_from_c(text)) } {
+        ui.label(text);
+    }
+}
+
+/// Show some large text.
+///
+/// # Safety
+/// `ui` must be null or a valid [`EguiUi`]. `text` must be null or a nul-terminated string.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn egui_ui_heading(ui: *mut EguiUi, text: *const c_char) {
+    // SAFETY: forwarded from the caller.
+    if let (Some(ui), Some(text)) = unsafe { (self::ui(ui), str_from_c(text)) } {
+        ui.heading(text);
+    }
+}
+
+/// A horizontal or vertical line, depending on the layout.
+///
+/// # Safety
+/// `ui` must be null or a valid [`EguiUi`].
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn egui_ui_separator(ui: *mut EguiUi) {
+    // SAFETY: forwarded from the caller.
+    if let Some(ui) = unsafe { self::ui(ui) } {
+        ui.separator();
+    }
+}
+
+/// Show a button. Returns `true` if it was clicked.
+///
+/// # Safety
+/// `ui` must be null or a valid [`EguiUi`]. `text` must be null or a nul-terminated string.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn egui_ui_button(ui: *mut EguiUi, text: *const c_char) -> bool {
+    // SAFETY: forwarded from the caller.
+    if let (Some(ui), Some(text)) = unsafe { (self::ui(ui), str_from_c(text)) } {
+        ui.button(text).clicked()
+    } else {
+        false
+    }
+}
+
+/// Show a checkbox for `checked`. Returns `true` if it was toggled.
+///
+/// # Safety
+/// `ui` must be null or a valid [`EguiUi`]. `text` must be null or a nul-terminated string.
+/// `checked` must be null or valid for reads and writes.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn egui_ui_checkbox(
+    ui: *mut EguiUi,
+    text: *const c_char,
+    checked: *mut bool,
+) -> bool {
+    // SAFETY: forwarded from the caller.
+    let (Some(ui), Some(text), Some(checked)) =
+        (unsafe { (self::ui(ui), str_from_c(text), checked.as_mut()) })
+    else {
+        return false;
+    };
+    ui.checkbox(checked, text).changed()
+}
+
+/// Show a slider for `value` in the range `min..=max`. Returns `true` if it was changed.
+///
+/// # Safety
+/// `ui` must be null or a valid [`EguiUi`]. `text` must be null or a nul-terminated string.
+/// `value` must be null or valid for reads and writes.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn egui_ui_slider_f32(
+    ui: *mut EguiUi,
+    text: *const c_char,
+    value: *mut f32,
+    min: f32,
+    max: f32,
+) -> bool {
+    // SAFETY: forwarded from the caller.
+    let (Some(ui), Some(text), Some(value)) =
+        (unsafe { (self::ui(ui), str_from_c(text), value.as_mut()) })
+    else {
+        return false;
+    };
+    ui.add(egui::Slider::new(value, min..=max).text(text))
+        .changed()
+}
+
+/// Show a single-line text field editing the nul-terminated UTF-8 string in `buffer`.
+///
+/// The edited text is written back to `buffer`, truncated to fit in `capacity` bytes
+/// (including the nul terminator).
+/// Returns `true` if the text was changed.
+///
+/// # Safety
+/// `ui` must be null or a valid [`EguiUi`].
+/// `buffer` must be null or valid for reads and writes of `capacity` bytes,
+/// and contain a nul-terminated string.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn egui_ui_text_edit_singleline(
+    ui: *mut EguiUi,
+    buffer: *mut c_char,
+    capacity: usize,
+) -> bool {
+    // SAFETY: forwarded from the caller.
+    let (Some(ui), false) = (unsafe { self::ui(ui) }, buffer.is_null() || capacity == 0) else {
+        return false;
+    };
+
+    // SAFETY: the caller guarantees that `buffer` is nul-terminated.
+    let mut text = unsafe { str_from_c(buffer) }.unwrap_or_default().to_owned();
+    let changed = ui.text_edit_singleline(&mut text).changed();
+
+    if changed {
+        let mut len = text.len().min(capacity - 1);
+        while !text.is_char_boundary(len) {
+            len -= 1;
+        }
+        // SAFETY: the caller guarantees that `buffer` has room for `capacity` bytes,
+        // and `len < capacity`.
+        unsafe {
+            std::ptr::copy_nonoverlapping(text.as_ptr(), buffer.cast::<u8>(), len);
+            buffer.add(len).write(0);
+        }
+    }
+
+    changed
+}
+
+/// Lay out the widgets added by `callback` horizontally.
+///
+/// # Safety
+/// `ui` must be null or a valid [`EguiUi`]. See also [`crate::egui_run`].
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn egui_ui_horizontal(
+    ui: *mut EguiUi,
+    callback: EguiUiCallback,
+    user_data: *mut c_void,
+) {
+    // SAFETY: forwarded from the caller.
+    if let Some(ui) = unsafe { self::ui(ui) } {
+        ui.horizontal(|ui| {
+            // SAFETY: forwarded from the caller.
+            unsafe { call_ui_callback(ui, callback, user_data) };
+        });
+    }
+}
+
+/// A header that can be clicked to show or hide the widgets added by `callback`.
+///
+/// Returns `true` if open.
+///
+/// # Safety
+/// `ui` must be null or a valid [`EguiUi`]. `heading` must be null or a nul-terminated string.
+/// See also [`crate::egui_run`].
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn egui_ui_collapsing(
+    ui: *mut EguiUi,
+    heading: *const c_char,
+    callback: EguiUiCallback,
+    user_data: *mut c_void,
+) -> bool {
+    // SAFETY: forwarded from the caller.
+    let (Some(ui), Some(heading)) = (unsafe { (self::ui(ui), str_from_c(heading)) }) else {
+        return false;
+    };
+    ui.collapsing(heading, |ui| {
+        // SAFETY: forwarded from the caller.
+        unsafe { call_ui_callback(ui, callback, user_data) };
+    })
+    .body_returned
+    .is_some()
+}
+
+/// A floating window with the widgets added by `callback`.
+///
+/// The title must be unique among all windows.
+///
+/// # Safety
+/// `ui` must be null or a valid [`EguiUi`]. `title` must be null or a nul-terminated string.
+/// See also [`crate::egui_run`].
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn egui_ui_window(
+    ui: *mut EguiUi,
+    title: *const c_char,
+    callback: EguiUiCallback,
+    user_data: *mut c_void,
+) {
+    // SAFETY: forwarded from the caller.
+    let (Some(ui), Some(title)) = (unsafe { (self::ui(ui), str_from_c(title)) }) else {
+        return;
+    };
+    egui::Window::new(title).show(ui.ctx(), |ui| {
+        // SAFETY: forwarded from the caller.
+        unsafe { call_ui_callback(ui, callback, user_data) };
+    });
+}
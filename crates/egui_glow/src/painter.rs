@@ -84,6 +84,9 @@ pub struct Painter {
 
     max_texture_side: usize,
 
+    /// The highest [`egui::TextureOptions::max_anisotropy`] supported, or `None` if unsupported.
+    max_anisotropy: Option<f32>,
+
     program: glow::Program,
     u_screen_size: glow::UniformLocation,
     u_sampler: glow::UniformLocation,
@@ -101,6 +104,12 @@ pub struct Painter {
     /// Stores outdated OpenGL textures that are yet to be deleted
     textures_to_destroy: Vec<glow::Texture>,
 
+    /// Offscreen target for callbacks that asked for a depth and/or stencil buffer.
+    callback_target: Option<CallbackTarget>,
+
+    /// The framebuffer bound while the current callback runs, if not the screen.
+    current_fbo: Option<glow::Framebuffer>,
+
     /// Used to make sure we are destroyed correctly.
     destroyed: bool,
 }
@@ -111,20 +120,67 @@ pub struct Painter {
 /// The callback is passed, the [`egui::PaintCallbackInfo`] and the [`Painter`] which can be used to
 /// access the OpenGL context.
 ///
+/// By default the callback paints straight into the framebuffer egui is painting to,
+/// which usually has no depth buffer. Use [`Self::with_depth_buffer`] and/or
+/// [`Self::with_stencil_buffer`] to instead render into an offscreen framebuffer of the
+/// viewport size with the requested attachments. It is cleared before the callback is called
+/// (color to transparent, depth to `1.0`, stencil to `0`), and composited into the egui output
+/// afterwards, respecting the clip rectangle.
+///
 /// # Example
 ///
 /// See the [`custom3d_glow`](https://github.com/emilk/egui/blob/main/crates/egui_demo_app/src/apps/custom3d_wgpu.rs) demo source for a detailed usage example.
 pub struct CallbackFn {
     f: Box<dyn Fn(PaintCallbackInfo, &Painter) + Sync + Send>,
+    depth_bits: u8,
+    stencil_bits: u8,
 }
 
 impl CallbackFn {
     pub fn new<F: Fn(PaintCallbackInfo, &Painter) + Sync + Send + 'static>(callback: F) -> Self {
         let f = Box::new(callback);
-        Self { f }
+        Self {
+            f,
+            depth_bits: 0,
+            stencil_bits: 0,
+        }
+    }
+
+    /// Render the callback with a depth buffer of (at least) this many bits.
+    ///
+    /// Supported values are `16`, `24` and `32`; other non-zero values are rounded up.
+    /// `0` (the default) means no depth buffer.
+    #[inline]
+    pub fn with_depth_buffer(mut self, bits: u8) -> Self {
+        self.depth_bits = bits;
+        self
+    }
+
+    /// Render the callback with an 8-bit stencil buffer if `bits` is non-zero.
+    ///
+    /// `0` (the default) means no stencil buffer.
+    #[inline]
+    pub fn with_stencil_buffer(mut self, bits: u8) -> Self {
+        self.stencil_bits = bits;
+        self
+    }
+
+    fn wants_offscreen_target(&self) -> bool {
+        self.depth_bits > 0 || self.stencil_bits > 0
     }
 }
 
+/// An offscreen framebuffer with a color texture and depth/stencil renderbuffer,
+/// used for [`CallbackFn`]s that need those attachments.
+struct CallbackTarget {
+    fbo: glow::Framebuffer,
+    texture_id: egui::TextureId,
+    depth_stencil: glow::Renderbuffer,
+    size_px: [i32; 2],
+    depth_bits: u8,
+    stencil_bits: u8,
+}
+
 impl Painter {
     /// Create painter.
     ///
@@ -181,6 +237,15 @@ impl Painter {
             });
         log::debug!("SRGB framebuffer Support: {supports_srgb_framebuffer}");
 
+        let max_anisotropy = supported_extensions
+            .iter()
+            .any(|extension| {
+                // GL_EXT_texture_filter_anisotropic, EXT_texture_filter_anisotropic (WebGL), …
+                extension.ends_with("texture_filter_anisotropic")
+            })
+            .then(|| unsafe { gl.get_parameter_f32(glow::MAX_TEXTURE_MAX_ANISOTROPY_EXT) });
+        log::debug!("Max anisotropy: {max_anisotropy:?}");
+
         unsafe {
             let vert = compile_shader(
                 &gl,
@@ -255,6 +320,7 @@ impl Painter {
             Ok(Self {
                 gl,
                 max_texture_side,
+                max_anisotropy,
                 program,
                 u_screen_size,
                 u_sampler,
@@ -267,6 +333,8 @@ impl Painter {
                 textures: Default::default(),
                 next_native_tex_id: 1 << 32,
                 textures_to_destroy: Vec::new(),
+                callback_target: None,
+                current_fbo: None,
                 destroyed: false,
             })
         }
@@ -290,11 +358,11 @@ impl Painter {
     /// So if in a [`egui::Shape::Callback`] you need to use an offscreen FBO, you should
     /// then restore to this afterwards with
     /// `gl.bind_framebuffer(glow::FRAMEBUFFER, painter.intermediate_fbo());`
-    #[expect(clippy::unused_self)]
+    ///
+    /// For a [`CallbackFn`] with a depth or stencil buffer this is the offscreen
+    /// framebuffer holding those attachments.
     pub fn intermediate_fbo(&self) -> Option<glow::Framebuffer> {
-        // We don't currently ever render to an offscreen buffer,
-        // but we may want to start to in order to do anti-aliasing on web, for instance.
-        None
+        self.current_fbo
     }
 
     unsafe fn prepare_painting(
@@ -437,7 +505,11 @@ impl Painter {
                         }
 
                         if let Some(callback) = callback.callback.downcast_ref::<CallbackFn>() {
-                            (callback.f)(info, self);
+                            if callback.wants_offscreen_target() {
+                                self.paint_offscreen_callback(callback, info, *clip_rect);
+                            } else {
+                                (callback.f)(info, self);
+                            }
                         } else {
                             log::warn!(
                                 "Warning: Unsupported render callback. Expected egui_glow::CallbackFn"
@@ -463,6 +535,234 @@ impl Painter {
         }
     }
 
+    /// Run a [`CallbackFn`] in an offscreen framebuffer with the depth/stencil attachments
+    /// it asked for, then composite the result at the callback viewport.
+    ///
+    /// Falls back to painting directly if the framebuffer could not be created.
+    fn paint_offscreen_callback(
+        &mut self,
+        callback: &CallbackFn,
+        info: PaintCallbackInfo,
+        clip_rect: Rect,
+    ) {
+        let viewport_px = info.viewport_in_pixels();
+        let size_px = [viewport_px.width_px, viewport_px.height_px];
+        if size_px[0] <= 0 || size_px[1] <= 0 {
+            return;
+        }
+
+        // The integration may be painting into a framebuffer of its own, so go back to that afterwards:
+        let previous_fbo = unsafe { self.gl.get_parameter_framebuffer(glow::FRAMEBUFFER_BINDING) };
+
+        let Some((fbo, texture_id)) =
+            self.prepare_callback_target(size_px, callback.depth_bits, callback.stencil_bits)
+        else {
+            (callback.f)(info, self);
+            return;
+        };
+
+        let PaintCallbackInfo {
+            pixels_per_point,
+            screen_size_px,
+            ..
+        } = info;
+
+        // Where the viewport ends up on screen, snapped to the pixels we actually render:
+        let top_px = screen_size_px[1] as i32 - viewport_px.from_bottom_px - size_px[1];
+        let composite_rect = Rect::from_min_size(
+            egui::pos2(viewport_px.left_px as f32, top_px as f32) / pixels_per_point,
+            egui::vec2(size_px[0] as f32, size_px[1] as f32) / pixels_per_point,
+        );
+
+        let offscreen_info = PaintCallbackInfo {
+            viewport: Rect::from_min_size(egui::Pos2::ZERO, composite_rect.size()),
+            clip_rect: clip_rect.translate(-composite_rect.min.to_vec2()),
+            pixels_per_point,
+            screen_size_px: [size_px[0] as u32, size_px[1] as u32],
+        };
+
+        unsafe {
+            self.gl.bind_framebuffer(glow::FRAMEBUFFER, Some(fbo));
+            self.gl.viewport(0, 0, size_px[0], size_px[1]);
+            self.gl.disable(glow::SCISSOR_TEST);
+            self.gl.color_mask(true, true, true, true);
+            self.gl.depth_mask(true);
+            self.gl.stencil_mask(!0);
+            self.gl.clear_color(0.0, 0.0, 0.0, 0.0);
+            self.gl.clear_depth_f32(1.0);
+            self.gl.clear_stencil(0);
+            self.gl
+                .clear(glow::COLOR_BUFFER_BIT | glow::DEPTH_BUFFER_BIT | glow::STENCIL_BUFFER_BIT);
+        }
+
+        self.current_fbo = Some(fbo);
+        (callback.f)(offscreen_info, self);
+        self.current_fbo = None;
+
+        check_for_gl_error!(&self.gl, "offscreen callback");
+
+        unsafe {
+            self.gl.bind_framebuffer(glow::FRAMEBUFFER, previous_fbo);
+            self.prepare_painting(screen_size_px, pixels_per_point);
+        }
+        set_clip_rect(&self.gl, screen_size_px, pixels_per_point, clip_rect);
+
+        // The framebuffer is stored bottom-up, so flip it vertically:
+        let mut mesh = Mesh::with_texture(texture_id);
+        mesh.add_rect_with_uv(
+            composite_rect,
+            Rect::from_min_max(egui::pos2(0.0, 1.0), egui::pos2(1.0, 0.0)),
+            egui::Color32::WHITE,
+        );
+        self.paint_mesh(&mesh);
+    }
+
+    /// Get (or lazily (re)create) the offscreen callback target with the given size and attachments.
+    fn prepare_callback_target(
+        &mut self,
+        size_px: [i32; 2],
+        depth_bits: u8,
+        stencil_bits: u8,
+    ) -> Option<(glow::Framebuffer, egui::TextureId)> {
+        let up_to_date = self.callback_target.as_ref().is_some_and(|target| {
+            target.size_px == size_px
+                && target.depth_bits == depth_bits
+                && target.stencil_bits == stencil_bits
+        });
+        if !up_to_date {
+            if let Some(old) = self.callback_target.take() {
+                unsafe { self.destroy_callback_target(&old) };
+            }
+            self.callback_target =
+                unsafe { self.create_callback_target(size_px, depth_bits, stencil_bits) };
+        }
+        self.callback_target
+            .as_ref()
+            .map(|target| (target.fbo, target.texture_id))
+    }
+
+    unsafe fn create_callback_target(
+        &mut self,
+        size_px: [i32; 2],
+        depth_bits: u8,
+        stencil_bits: u8,
+    ) -> Option<CallbackTarget> {
+        profiling::function_scope!();
+        let [width, height] = size_px;
+        let gl = &self.gl;
+        let previous_fbo = unsafe { gl.get_parameter_framebuffer(glow::FRAMEBUFFER_BINDING) };
+
+        let depth_stencil_format = match (depth_bits, stencil_bits > 0) {
+            (_, true) if self.is_webgl_1 => glow::DEPTH_STENCIL,
+            (0, true) => glow::STENCIL_INDEX8,
+            (1..=24, true) => glow::DEPTH24_STENCIL8,
+            (_, true) => glow::DEPTH32F_STENCIL8,
+            (_, false) if self.is_webgl_1 => glow::DEPTH_COMPONENT16,
+            (0..=16, false) => glow::DEPTH_COMPONENT16,
+            (17..=24, false) => glow::DEPTH_COMPONENT24,
+            (_, false) => glow::DEPTH_COMPONENT32F,
+        };
+        let attachment = match (depth_bits, stencil_bits > 0) {
+            (0, _) => glow::STENCIL_ATTACHMENT,
+            (_, true) => glow::DEPTH_STENCIL_ATTACHMENT,
+            (_, false) => glow::DEPTH_ATTACHMENT,
+        };
+
+        unsafe {
+            let texture = gl.create_texture().ok()?;
+            gl.bind_texture(glow::TEXTURE_2D, Some(texture));
+            gl.tex_parameter_i32(
+                glow::TEXTURE_2D,
+                glow::TEXTURE_MIN_FILTER,
+                glow::NEAREST as i32,
+            );
+            gl.tex_parameter_i32(
+                glow::TEXTURE_2D,
+                glow::TEXTURE_MAG_FILTER,
+                glow::NEAREST as i32,
+            );
+            gl.tex_parameter_i32(
+                glow::TEXTURE_2D,
+                glow::TEXTURE_WRAP_S,
+                glow::CLAMP_TO_EDGE as i32,
+            );
+            gl.tex_parameter_i32(
+                glow::TEXTURE_2D,
+                glow::TEXTURE_WRAP_T,
+                glow::CLAMP_TO_EDGE as i32,
+            );
+            let internal_format = if self.is_webgl_1 {
+                glow::RGBA
+            } else {
+                glow::RGBA8
+            };
+            gl.tex_image_2d(
+                glow::TEXTURE_2D,
+                0,
+                internal_format as _,
+                width,
+                height,
+                0,
+                glow::RGBA,
+                glow::UNSIGNED_BYTE,
+                glow::PixelUnpackData::Slice(None),
+            );
+
+            let depth_stencil = gl.create_renderbuffer().ok()?;
+            gl.bind_renderbuffer(glow::RENDERBUFFER, Some(depth_stencil));
+            gl.renderbuffer_storage(glow::RENDERBUFFER, depth_stencil_format, width, height);
+            gl.bind_renderbuffer(glow::RENDERBUFFER, None);
+
+            let fbo = gl.create_framebuffer().ok()?;
+            gl.bind_framebuffer(glow::FRAMEBUFFER, Some(fbo));
+            gl.framebuffer_texture_2d(
+                glow::FRAMEBUFFER,
+                glow::COLOR_ATTACHMENT0,
+                glow::TEXTURE_2D,
+                Some(texture),
+                0,
+            );
+            gl.framebuffer_renderbuffer(
+                glow::FRAMEBUFFER,
+                attachment,
+                glow::RENDERBUFFER,
+                Some(depth_stencil),
+            );
+            let status = gl.check_framebuffer_status(glow::FRAMEBUFFER);
+            gl.bind_framebuffer(glow::FRAMEBUFFER, previous_fbo);
+
+            if status != glow::FRAMEBUFFER_COMPLETE {
+                log::warn!(
+                    "Failed to create a {depth_bits}-bit depth, {stencil_bits}-bit stencil framebuffer for a paint callback (status 0x{status:x}). Painting without it."
+                );
+                gl.delete_framebuffer(fbo);
+                gl.delete_renderbuffer(depth_stencil);
+                gl.delete_texture(texture);
+                return None;
+            }
+
+            check_for_gl_error!(gl, "create_callback_target");
+
+            let texture_id = self.register_native_texture(texture);
+            Some(CallbackTarget {
+                fbo,
+                texture_id,
+                depth_stencil,
+                size_px,
+                depth_bits,
+                stencil_bits,
+            })
+        }
+    }
+
+    unsafe fn destroy_callback_target(&mut self, target: &CallbackTarget) {
+        unsafe {
+            self.gl.delete_framebuffer(target.fbo);
+            self.gl.delete_renderbuffer(target.depth_stencil);
+        }
+        self.free_texture(target.texture_id);
+    }
+
     #[inline(never)] // Easier profiling
     fn paint_mesh(&mut self, mesh: &Mesh) {
         debug_assert!(mesh.is_valid(), "Mesh is not valid");
@@ -575,6 +875,13 @@ impl Painter {
                 glow::TEXTURE_WRAP_T,
                 options.wrap_mode.glow_code() as i32,
             );
+            if let Some(max_anisotropy) = self.max_anisotropy {
+                self.gl.tex_parameter_f32(
+                    glow::TEXTURE_2D,
+                    glow::TEXTURE_MAX_ANISOTROPY_EXT,
+                    f32::from(options.max_anisotropy).clamp(1.0, max_anisotropy),
+                );
+            }
             check_for_gl_error!(&self.gl, "tex_parameter");
 
             let (internal_format, src_format) = if self.is_webgl_1 {
@@ -707,6 +1014,10 @@ impl Painter {
             for t in &self.textures_to_destroy {
                 self.gl.delete_texture(*t);
             }
+            if let Some(target) = &self.callback_target {
+                self.gl.delete_framebuffer(target.fbo);
+                self.gl.delete_renderbuffer(target.depth_stencil);
+            }
         }
     }
 
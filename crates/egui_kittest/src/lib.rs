@@ -69,6 +69,8 @@ impl Display for ExceededMaxStepsError {
 /// Some egui style options are changed from the defaults:
 /// - The cursor blinking is disabled
 /// - The scroll animation is disabled
+///
+/// [`egui::Options::widget_events`] is enabled, see [`Harness::widget_events`].
 pub struct Harness<'a, State = ()> {
     pub ctx: egui::Context,
     input: egui::RawInput,
@@ -82,6 +84,7 @@ pub struct Harness<'a, State = ()> {
     step_dt: f32,
     wait_for_pending_images: bool,
     queued_events: EventQueue,
+    widget_events: Vec<egui::WidgetEvent>,
 
     #[cfg(feature = "snapshot")]
     default_snapshot_options: SnapshotOptions,
@@ -126,6 +129,7 @@ impl<'a, State> Harness<'a, State> {
         ctx.set_theme(theme);
         ctx.set_os(os);
         ctx.enable_accesskit();
+        ctx.options_mut(|o| o.widget_events = true);
         ctx.all_styles_mut(|style| {
             // Disable cursor blinking so it doesn't interfere with snapshots
             style.visuals.text_cursor.blink = false;
@@ -148,6 +152,7 @@ impl<'a, State> Harness<'a, State> {
         });
 
         renderer.handle_delta(&output.textures_delta);
+        let widget_events = std::mem::take(&mut output.platform_output.widget_events);
 
         let mut harness = Self {
             app,
@@ -168,6 +173,7 @@ impl<'a, State> Harness<'a, State> {
             step_dt,
             wait_for_pending_images,
             queued_events: Default::default(),
+            widget_events,
 
             #[cfg(feature = "snapshot")]
             default_snapshot_options,
@@ -278,6 +284,8 @@ impl<'a, State> Harness<'a, State> {
                 .expect("AccessKit was disabled"),
         );
         self.renderer.handle_delta(&output.textures_delta);
+        self.widget_events
+            .append(&mut output.platform_output.widget_events);
         self.output = output;
 
         #[cfg(any(feature = "wgpu", feature = "snapshot"))]
@@ -449,6 +457,18 @@ impl<'a, State> Harness<'a, State> {
         &self.output
     }
 
+    /// The [`egui::WidgetEvent`]s of all frames run so far, oldest first.
+    ///
+    /// Use [`Self::take_widget_events`] to only look at the events after some interaction.
+    pub fn widget_events(&self) -> &[egui::WidgetEvent] {
+        &self.widget_events
+    }
+
+    /// Take the [`egui::WidgetEvent`]s collected so far, see [`Self::widget_events`].
+    pub fn take_widget_events(&mut self) -> Vec<egui::WidgetEvent> {
+        std::mem::take(&mut self.widget_events)
+    }
+
     /// Access the [`kittest::State`].
     pub fn kittest_state(&self) -> &kittest::State {
         &self.kittest
@@ -497,6 +517,7 @@ impl<'a, State> Harness<'a, State> {
             modifiers: Modifiers::default(),
             repeat: false,
             physical_key: None,
+            location: egui::KeyLocation::Standard,
         });
     }
 
@@ -508,6 +529,7 @@ impl<'a, State> Harness<'a, State> {
                 modifiers,
                 repeat: false,
                 physical_key: None,
+                location: egui::KeyLocation::Standard,
             },
             modifiers,
         );
@@ -520,6 +542,7 @@ impl<'a, State> Harness<'a, State> {
             modifiers: Modifiers::default(),
             repeat: false,
             physical_key: None,
+            location: egui::KeyLocation::Standard,
         });
     }
 
@@ -531,6 +554,7 @@ impl<'a, State> Harness<'a, State> {
                 modifiers,
                 repeat: false,
                 physical_key: None,
+                location: egui::KeyLocation::Standard,
             },
             modifiers,
         );
@@ -572,6 +596,7 @@ impl<'a, State> Harness<'a, State> {
                     modifiers,
                     repeat: false,
                     physical_key: None,
+                    location: egui::KeyLocation::Standard,
                 });
             }
         }
@@ -675,6 +700,24 @@ impl<'a, State> Harness<'a, State> {
         self.renderer.render(&self.ctx, &output)
     }
 
+    /// Render the last output to a PNG image, without a mouse cursor.
+    ///
+    /// This lets you run an egui app without a window and serve frames of it,
+    /// e.g. for dashboards embedded in notebooks, chat bots, or remote previews:
+    /// drive the app with [`Self::event`], [`Self::run`] etc, and then render it.
+    ///
+    /// # Errors
+    /// Returns an error if the rendering or encoding fails.
+    #[cfg(feature = "png")]
+    pub fn render_png(&mut self) -> Result<Vec<u8>, String> {
+        let image = self.renderer.render(&self.ctx, &self.output)?;
+        let mut png = Vec::new();
+        image
+            .write_to(&mut std::io::Cursor::new(&mut png), image::ImageFormat::Png)
+            .map_err(|err| format!("Failed to encode PNG: {err}"))?;
+        Ok(png)
+    }
+
     /// Fulfill any [`egui::ViewportCommand::Screenshot`] requests made by the app during the
     /// last frame.
     ///
@@ -104,6 +104,31 @@ impl Node<'_> {
         ));
     }
 
+    /// Request the custom action with this description via accesskit.
+    ///
+    /// See [`egui::Response::accesskit_custom_action`].
+    ///
+    /// # Panics
+    /// If the node has no custom action with this description.
+    #[track_caller]
+    pub fn custom_action(&self, description: &str) {
+        let action_id = self
+            .accesskit_node
+            .data()
+            .custom_actions()
+            .iter()
+            .find(|action| &*action.description == description)
+            .unwrap_or_else(|| panic!("No custom action {description:?} on {self:?}"))
+            .id;
+        let (target_node, target_tree) = self.accesskit_node.locate();
+        self.event(egui::Event::AccessKitActionRequest(ActionRequest {
+            action: accesskit::Action::CustomAction,
+            target_node,
+            target_tree,
+            data: Some(accesskit::ActionData::CustomAction(action_id)),
+        }));
+    }
+
     pub fn rect(&self) -> egui::Rect {
         let rect = self
             .accesskit_node
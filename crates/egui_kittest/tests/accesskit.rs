@@ -4,6 +4,10 @@ use egui::{
     CentralPanel, Context, RawInput, Ui, Window,
     accesskit::{NodeId, Role, TreeUpdate},
 };
+use egui_kittest::{
+    Harness,
+    kittest::{NodeT as _, Queryable as _},
+};
 
 /// Baseline test that asserts there are no spurious nodes in the
 /// accesskit output when the ui is empty.
@@ -138,6 +142,28 @@ fn window_children() {
     assert_button_exists(&output, "Hide", window_id);
 }
 
+#[test]
+fn custom_action_is_exposed_and_routed() {
+    let mut harness = Harness::new_ui_state(
+        |ui, value: &mut i32| {
+            let _ = ui
+                .add(egui::DragValue::new(value))
+                .on_accesskit_action_request("Increment by 10", || *value += 10);
+        },
+        0,
+    );
+
+    let node = harness.get_by_role(Role::SpinButton);
+    let custom_actions = node.accesskit_node().data().custom_actions();
+    assert_eq!(custom_actions.len(), 1);
+    assert_eq!(&*custom_actions[0].description, "Increment by 10");
+
+    node.custom_action("Increment by 10");
+    harness.run();
+
+    assert_eq!(*harness.state(), 10);
+}
+
 fn accesskit_output_single_egui_frame(run_ui: impl FnMut(&mut Ui)) -> TreeUpdate {
     let ctx = Context::default();
     // Disable animations, so we do not need to wait for animations to end to see the result.
@@ -0,0 +1,76 @@
+use egui::ScrollArea;
+use egui_extras::Masonry;
+use egui_kittest::Harness;
+
+#[derive(Default)]
+struct State {
+    scroll_offset: f32,
+    shown: Vec<usize>,
+}
+
+#[test]
+fn masonry_only_shows_visible_items() {
+    const NUM_ITEMS: usize = 10_000;
+
+    let mut harness = Harness::builder()
+        .with_size(egui::vec2(400.0, 300.0))
+        .build_ui_state(
+            |ui, state: &mut State| {
+                state.shown.clear();
+                ScrollArea::vertical()
+                    .vertical_scroll_offset(state.scroll_offset)
+                    .show(ui, |ui| {
+                        Masonry::new()
+                            .num_columns(2)
+                            .show(ui, NUM_ITEMS, |ui, index| {
+                                ui.label(format!("Item {index}"));
+                                if index % 3 == 0 {
+                                    ui.label("Taller");
+                                }
+                                state.shown.push(index);
+                            });
+                    });
+            },
+            State::default(),
+        );
+    harness.run();
+
+    let shown = &harness.state().shown;
+    assert!(!shown.is_empty());
+    assert!(shown.len() < 100, "Showed {} items", shown.len());
+    assert_eq!(shown[0], 0);
+
+    // Far down, only the items around there are shown:
+    harness.state_mut().scroll_offset = 50_000.0;
+    harness.run();
+    let shown = &harness.state().shown;
+    assert!(!shown.is_empty());
+    assert!(shown.len() < 100, "Showed {} items", shown.len());
+    assert!(1000 < shown[0], "Showed item {}", shown[0]);
+    assert!(
+        shown.windows(2).all(|pair| pair[0] < pair[1]),
+        "Items not shown in order: {shown:?}"
+    );
+}
+
+#[test]
+fn masonry_puts_items_in_the_shortest_column() {
+    let mut harness = Harness::new_ui_state(
+        |ui, rects: &mut Vec<egui::Rect>| {
+            rects.clear();
+            Masonry::new().num_columns(2).show(ui, 3, |ui, index| {
+                let height = if index == 0 { 100.0 } else { 20.0 };
+                rects.push(ui.allocate_space(egui::vec2(10.0, height)).1);
+            });
+        },
+        Vec::new(),
+    );
+    harness.run();
+
+    // The tall first item fills the left column, so both of the others go in the right one:
+    let rects = harness.state();
+    assert_eq!(rects.len(), 3);
+    assert_eq!(rects[1].left(), rects[2].left());
+    assert!(rects[0].left() < rects[1].left());
+    assert!(rects[1].bottom() < rects[2].top());
+}
@@ -1,5 +1,5 @@
-use egui::containers::menu::{MenuBar, MenuConfig, SubMenuButton};
-use egui::{PopupCloseBehavior, Ui, include_image};
+use egui::containers::menu::{MenuBar, MenuButton, MenuConfig, MenuItem, SubMenuButton};
+use egui::{Event, Key, Modifiers, PopupCloseBehavior, Ui, include_image};
 use egui_kittest::Harness;
 use kittest::Queryable as _;
 
@@ -160,6 +160,43 @@ fn clicking_submenu_button_should_never_close_menu() {
     assert!(harness.query_by_label("Button in Submenu B").is_none());
 }
 
+#[test]
+fn menu_mnemonics_and_type_ahead() {
+    let mut harness = Harness::new_ui_state(
+        |ui, saved: &mut bool| {
+            MenuBar::new().ui(ui, |ui| {
+                MenuButton::with_mnemonic("&File").ui(ui, |ui| {
+                    if ui.add(MenuItem::new("&Save")).clicked() {
+                        *saved = true;
+                    }
+                    _ = ui.add(MenuItem::new("Export"));
+                    _ = ui.add(MenuItem::new("Exit"));
+                });
+            });
+        },
+        false,
+    );
+
+    // Alt + mnemonic opens the menu
+    harness.key_press_modifiers(Modifiers::ALT, Key::F);
+    harness.run();
+    assert!(harness.query_by_label("Export").is_some());
+
+    // Typing selects the first matching entry
+    harness.event(Event::Text("ex".to_owned()));
+    harness.run();
+    assert!(harness.get_by_label("Export").is_focused());
+    harness.event(Event::Text("i".to_owned()));
+    harness.run();
+    assert!(harness.get_by_label("Exit").is_focused());
+
+    // The mnemonic activates the entry and closes the menu
+    harness.event(Event::Text("s".to_owned()));
+    harness.run();
+    assert!(harness.state());
+    assert!(harness.query_by_label("Export").is_none());
+}
+
 #[cfg(feature = "snapshot")]
 #[test]
 fn menu_snapshots() {
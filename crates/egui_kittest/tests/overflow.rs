@@ -0,0 +1,120 @@
+use egui::{Color32, Frame, Label, Overflow, OverflowBox, Rect, Shape, vec2};
+use egui_kittest::Harness;
+
+const LONG_TEXT: &str = "This is a very long text that will never fit in the small box";
+
+#[derive(Default)]
+struct State {
+    box_rect: Option<Rect>,
+    text_rect: Option<Rect>,
+    elided: bool,
+}
+
+fn show_box(overflow: Overflow) -> Harness<'static, State> {
+    let mut harness = Harness::new_ui_state(
+        move |ui, state: &mut State| {
+            let response = OverflowBox::new(vec2(100.0, 40.0))
+                .overflow(overflow)
+                .show(ui, |ui| {
+                    let (_, galley, response) = Label::new(LONG_TEXT).layout_in_ui(ui);
+                    state.elided = galley.elided;
+                    response.rect
+                });
+            state.box_rect = Some(response.response.rect);
+            state.text_rect = Some(response.inner);
+        },
+        State::default(),
+    );
+    harness.run();
+    harness
+}
+
+#[test]
+fn overflow_clip_keeps_the_box_size() {
+    let harness = show_box(Overflow::Clip);
+    let state = harness.state();
+
+    let box_rect = state.box_rect.unwrap();
+    assert!(box_rect.width() <= 100.0, "box grew to {box_rect:?}");
+
+    // The text keeps wrapping at the box width, so it grows downwards, past the box:
+    let text_rect = state.text_rect.unwrap();
+    assert!(text_rect.height() > 40.0, "text is only {text_rect:?}");
+    assert_eq!(box_rect.height(), 40.0);
+    assert!(!state.elided);
+}
+
+#[test]
+fn overflow_ellipsis_truncates_text() {
+    let harness = show_box(Overflow::Ellipsis);
+    let state = harness.state();
+
+    assert!(state.elided);
+    let text_rect = state.text_rect.unwrap();
+    assert!(text_rect.width() <= 100.0, "text is {text_rect:?}");
+    assert!(text_rect.height() < 40.0, "text is {text_rect:?}");
+}
+
+#[test]
+fn overflow_visible_grows_the_parent() {
+    let harness = show_box(Overflow::Visible);
+    let state = harness.state();
+
+    assert!(!state.elided);
+    assert!(state.box_rect.unwrap().height() > 40.0);
+}
+
+#[test]
+fn overflow_fade_uses_the_background_of_the_frame() {
+    const FILL: Color32 = Color32::from_rgb(200, 30, 30);
+
+    let mut harness = Harness::new_ui(|ui| {
+        Frame::new().fill(FILL).show(ui, |ui| {
+            ui.add(Label::new(LONG_TEXT).overflow(Overflow::Fade));
+        });
+    });
+    harness.set_size(vec2(200.0, 100.0));
+    harness.run();
+
+    let fade_colors: Vec<Color32> = harness
+        .output()
+        .shapes
+        .iter()
+        .filter_map(|clipped| match &clipped.shape {
+            Shape::Mesh(mesh) => Some(mesh.vertices.iter().map(|vertex| vertex.color)),
+            _ => None,
+        })
+        .flatten()
+        .filter(|color| *color != Color32::TRANSPARENT)
+        .collect();
+
+    assert!(!fade_colors.is_empty(), "Nothing faded");
+    assert!(
+        fade_colors.iter().all(|color| *color == FILL),
+        "Faded into {fade_colors:?} instead of the frame fill"
+    );
+}
+
+#[test]
+fn label_overflow_clip_stays_within_available_width() {
+    let mut harness = Harness::new_ui_state(
+        |ui, state: &mut State| {
+            ui.set_max_width(120.0);
+            let response = ui.add(Label::new(LONG_TEXT).overflow(Overflow::Clip));
+            state.text_rect = Some(response.rect);
+            state.box_rect = Some(ui.min_rect());
+        },
+        State::default(),
+    );
+    harness.run();
+    let state = harness.state();
+
+    // The label is laid out on one line, but the parent only grows to the available width:
+    let text_rect = state.text_rect.unwrap();
+    assert!(text_rect.width() > 120.0, "label is only {text_rect:?}");
+    let parent_rect = state.box_rect.unwrap();
+    assert!(
+        parent_rect.width() <= 120.0,
+        "parent grew to {parent_rect:?}"
+    );
+}
@@ -0,0 +1,30 @@
+#![cfg(feature = "png")]
+
+use egui_kittest::Harness;
+use egui_kittest::kittest::Queryable as _;
+
+#[test]
+fn render_png_after_input() {
+    let mut clicks = 0;
+    let mut harness = Harness::builder()
+        .with_size(egui::vec2(120.0, 60.0))
+        .build_ui_state(
+            |ui, clicks: &mut i32| {
+                if ui.button(format!("Clicked {clicks} times")).clicked() {
+                    *clicks += 1;
+                }
+            },
+            clicks,
+        );
+
+    harness.get_by_label("Clicked 0 times").click();
+    harness.run();
+    clicks = *harness.state();
+    assert_eq!(clicks, 1);
+
+    let png = harness.render_png().expect("rendering failed");
+    assert!(png.starts_with(b"\x89PNG\r\n\x1a\n"));
+
+    let image = image::load_from_memory(&png).expect("invalid PNG");
+    assert_eq!((image.width(), image.height()), (120, 60));
+}
@@ -1,4 +1,4 @@
-use egui::{Align, Layout, Popup};
+use egui::{Align, Layout, Popup, Tooltip};
 use egui_kittest::Harness;
 use kittest::Queryable as _;
 
@@ -96,3 +96,30 @@ fn test_interactive_tooltip() {
 
     assert!(harness.state().link_clicked);
 }
+
+#[test]
+fn tooltip_hide_delay() {
+    let mut harness = Harness::new_ui(|ui| {
+        let response = ui.label("Hover me");
+        Tooltip::for_enabled(&response)
+            .hide_delay(0.6)
+            .show(|ui| ui.label("Tooltip text"));
+        ui.label("Somewhere else");
+    });
+
+    harness.get_by_label("Hover me").hover();
+    harness.run();
+    assert!(harness.query_by_label("Tooltip text").is_some());
+
+    harness.get_by_label("Somewhere else").hover();
+    harness.step();
+    assert!(
+        harness.query_by_label("Tooltip text").is_some(),
+        "tooltip should stay open for a while after the pointer leaves"
+    );
+
+    for _ in 0..4 {
+        harness.step();
+    }
+    assert!(harness.query_by_label("Tooltip text").is_none());
+}
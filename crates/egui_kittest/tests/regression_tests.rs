@@ -5,7 +5,10 @@ use egui::{
 };
 #[cfg(all(feature = "wgpu", feature = "snapshot"))]
 use egui_kittest::SnapshotResults;
-use egui_kittest::{Harness, kittest::Queryable as _};
+use egui_kittest::{
+    Harness,
+    kittest::{NodeT as _, Queryable as _},
+};
 
 #[test]
 pub fn focus_should_skip_over_disabled_buttons() {
@@ -59,6 +62,32 @@ pub fn focus_should_skip_over_disabled_drag_values() {
     assert!(drag_value_3.is_focused());
 }
 
+#[test]
+fn drag_value_evaluates_expression_on_commit() {
+    let mut harness = Harness::new_ui_state(
+        |ui, value: &mut f64| {
+            ui.add(egui::DragValue::new(value));
+        },
+        1.0,
+    );
+
+    harness.key_press(egui::Key::Tab);
+    harness.run();
+
+    // The whole text is selected on focus, so typing replaces it:
+    harness.get_by(|node| node.is_focused()).type_text("1920/2");
+    harness.run();
+    assert_eq!(
+        *harness.state(),
+        1.0,
+        "expressions should not be applied while typing"
+    );
+
+    harness.key_press(egui::Key::Enter);
+    harness.run();
+    assert_eq!(*harness.state(), 960.0);
+}
+
 #[test]
 fn image_failed() {
     let mut harness = Harness::new_ui(|ui| {
@@ -161,6 +190,232 @@ pub fn slider_should_move_with_fixed_decimals() {
     assert_eq!(actual_slider.value(), Some("1.00".to_owned()));
 }
 
+#[test]
+fn slider_snaps_to_detents_with_custom_mapping() {
+    let mut harness = Harness::new_ui_state(
+        |ui, value: &mut f64| {
+            ui.spacing_mut().slider_width = 200.0;
+            ui.add(
+                egui::Slider::new(value, 0.0..=100.0)
+                    .custom_mapping(f64::sqrt, |position| position * position)
+                    .detents([25.0])
+                    .show_value(false),
+            );
+        },
+        0.0,
+    );
+    harness.run();
+
+    // With the square-root mapping, 25 is in the middle of the slider:
+    let center = harness.get_by_role(Role::Slider).rect().center();
+
+    harness.drag_at(center + Vec2::new(3.0, 0.0));
+    harness.drop_at(center + Vec2::new(3.0, 0.0));
+    harness.run();
+    assert_eq!(*harness.state(), 25.0, "Should snap to the nearby detent");
+
+    harness.drag_at(center + Vec2::new(40.0, 0.0));
+    harness.drop_at(center + Vec2::new(40.0, 0.0));
+    harness.run();
+    let value = *harness.state();
+    assert!(
+        (40.0..70.0).contains(&value),
+        "Far from the detent the mapping should apply, got {value}"
+    );
+}
+
+#[test]
+fn async_button_tracks_task_state() {
+    #[derive(Default)]
+    struct State {
+        task: Option<egui::AsyncTask>,
+        finished: Vec<egui::AsyncState>,
+    }
+
+    let mut harness = Harness::new_ui_state(
+        |ui, state: &mut State| {
+            let response = egui::AsyncButton::new("Upload").show(ui, |task| {
+                state.task = Some(task);
+            });
+            if response.just_finished {
+                state.finished.push(response.state);
+            }
+        },
+        State::default(),
+    );
+
+    // The spinner keeps repainting while the task runs, so step manually.
+    harness.get_by_label_contains("Upload").click();
+    harness.run_steps(4);
+    assert!(
+        harness
+            .get_by_role(Role::Button)
+            .accesskit_node()
+            .is_disabled()
+    );
+
+    // A second click while running must not start another task:
+    let task = harness
+        .state_mut()
+        .task
+        .take()
+        .expect("task should have started");
+    harness.get_by_role(Role::Button).click();
+    harness.run_steps(4);
+    assert!(harness.state().task.is_none());
+
+    task.set_progress(0.5);
+    harness.run_steps(4);
+    assert!(harness.query_by_label_contains("50%").is_some());
+
+    task.fail("connection lost");
+    harness.run();
+    harness.run();
+    assert!(
+        !harness
+            .get_by_role(Role::Button)
+            .accesskit_node()
+            .is_disabled()
+    );
+    assert_eq!(
+        harness.state().finished,
+        vec![egui::AsyncState::Failed("connection lost".to_owned())],
+        "just_finished should be reported exactly once"
+    );
+}
+
+#[test]
+fn async_button_shows_error_and_restarts() {
+    #[derive(Default)]
+    struct State {
+        tasks: Vec<egui::AsyncTask>,
+        last_state: egui::AsyncState,
+        finished: usize,
+    }
+
+    let mut harness = Harness::new_ui_state(
+        |ui, state: &mut State| {
+            let response = egui::AsyncButton::new("Sync").show(ui, |task| {
+                state.tasks.push(task);
+            });
+            state.finished += usize::from(response.just_finished);
+            state.last_state = response.state;
+        },
+        State::default(),
+    );
+
+    harness.get_by_label_contains("Sync").click();
+    harness.run_steps(4);
+    let first = harness.state().tasks[0].clone();
+    first.fail("server unreachable");
+    harness.run();
+
+    // The error is shown when hovering the button:
+    assert!(harness.query_by_label("server unreachable").is_none());
+    harness.get_by_role(Role::Button).hover();
+    harness.run();
+    assert!(harness.query_by_label("server unreachable").is_some());
+
+    // Clicking it again starts over:
+    harness.get_by_role(Role::Button).click();
+    harness.run_steps(4);
+    assert_eq!(harness.state().tasks.len(), 2);
+    assert_eq!(
+        harness.state().last_state,
+        egui::AsyncState::Running { progress: None }
+    );
+
+    // The old task no longer affects the button:
+    first.succeed();
+    harness.run_steps(4);
+    assert!(harness.state().last_state.is_running());
+    assert_eq!(harness.state().finished, 1);
+
+    harness.state().tasks[1].succeed();
+    harness.run();
+    assert_eq!(harness.state().last_state, egui::AsyncState::Succeeded);
+    assert_eq!(harness.state().finished, 2);
+}
+
+#[test]
+fn focus_summary_describes_newly_focused_widget() {
+    let mut harness = Harness::new_ui(|ui| {
+        ui.label("Not focusable");
+        _ = ui.button("One");
+        _ = ui.button("Two");
+        _ = ui.button("Three");
+    });
+
+    harness.key_press(egui::Key::Tab);
+    harness.run();
+
+    // Only set on the pass the focus changed:
+    let mut summaries = Vec::new();
+    harness.input_mut().events.push(egui::Event::Key {
+        key: egui::Key::Tab,
+        physical_key: None,
+        location: egui::KeyLocation::Standard,
+        pressed: true,
+        repeat: false,
+        modifiers: Modifiers::NONE,
+    });
+    for _ in 0..3 {
+        harness.step();
+        if let Some(summary) = &harness.output().platform_output.focus_summary {
+            summaries.push(summary.text());
+        }
+    }
+    assert_eq!(summaries, vec!["Two: button, 2 of 3".to_owned()]);
+}
+
+#[test]
+fn focused_rect_follows_keyboard_focus() {
+    let mut harness = Harness::new_ui(|ui| {
+        _ = ui.button("One");
+        _ = ui.button("Two");
+    });
+    harness.run();
+    assert_eq!(harness.output().platform_output.focused_rect, None);
+
+    harness.key_press(egui::Key::Tab);
+    harness.run();
+    let first = harness.output().platform_output.focused_rect;
+    assert_eq!(first, Some(harness.get_by_label("One").rect()));
+
+    // Set on every pass, not just when the focus changes:
+    harness.step();
+    assert_eq!(harness.output().platform_output.focused_rect, first);
+
+    harness.key_press(egui::Key::Tab);
+    harness.run();
+    assert_eq!(
+        harness.output().platform_output.focused_rect,
+        Some(harness.get_by_label("Two").rect())
+    );
+}
+
+#[test]
+fn text_edit_reports_input_purpose_to_ime() {
+    let mut harness = Harness::new_ui_state(
+        |ui, (email, password): &mut (String, String)| {
+            ui.add(egui::TextEdit::singleline(email).input_purpose(egui::InputPurpose::Email));
+            ui.add(egui::TextEdit::singleline(password).password(true));
+        },
+        Default::default(),
+    );
+
+    let ime_purpose =
+        |harness: &Harness<'_, _>| harness.output().platform_output.ime.map(|ime| ime.purpose);
+
+    harness.get_by_role(Role::TextInput).focus();
+    harness.run();
+    assert_eq!(ime_purpose(&harness), Some(egui::InputPurpose::Email));
+
+    harness.get_by_role(Role::PasswordInput).focus();
+    harness.run();
+    assert_eq!(ime_purpose(&harness), Some(egui::InputPurpose::Password));
+}
+
 #[test]
 pub fn override_text_color_affects_interactive_widgets() {
     use egui::{Color32, RichText};
@@ -513,6 +768,116 @@ fn window_resize_wraps_to_content_min_width() {
     );
 }
 
+#[test]
+fn dragging_window_to_edge_tiles_it() {
+    let window_title = "snap_test";
+    let mut harness = Harness::builder()
+        .with_size(Vec2::new(800.0, 600.0))
+        .build_ui(move |ui| {
+            Window::new(window_title)
+                .default_pos([200.0, 200.0])
+                .default_size([300.0, 200.0])
+                .snap_zones(true)
+                .show(ui.ctx(), |ui| {
+                    // Fill the available space so `Resize` doesn't auto-shrink the window.
+                    ui.allocate_space(ui.available_size());
+                });
+        });
+
+    harness.run();
+    let window_rect = harness
+        .get_by_role_and_label(Role::Window, window_title)
+        .rect();
+    let title_pos = Pos2::new(window_rect.center().x, window_rect.top() + 10.0);
+
+    harness.drag_at(title_pos);
+    harness.run();
+    for pos in [Pos2::new(150.0, 250.0), Pos2::new(4.0, 300.0)] {
+        harness.hover_at(pos);
+        harness.run();
+    }
+    harness.drop_at(Pos2::new(4.0, 300.0));
+    harness.run();
+
+    let tiled_rect = harness
+        .get_by_role_and_label(Role::Window, window_title)
+        .rect();
+    let screen = Rect::from_min_size(Pos2::ZERO, Vec2::new(800.0, 600.0));
+    let left_half = egui::WindowTile::LeftHalf.rect_in(screen);
+    assert!(
+        (tiled_rect.min - left_half.min).length() < 1.0
+            && (tiled_rect.size() - left_half.size()).length() < 1.0,
+        "window {tiled_rect:?} should be tiled to the left half {left_half:?}"
+    );
+
+    // Dragging it out again restores the previous size:
+    let title_pos = Pos2::new(tiled_rect.center().x, tiled_rect.top() + 10.0);
+    harness.drag_at(title_pos);
+    harness.run();
+    for pos in [Pos2::new(220.0, 200.0), Pos2::new(400.0, 250.0)] {
+        harness.hover_at(pos);
+        harness.run();
+    }
+    harness.drop_at(Pos2::new(400.0, 250.0));
+    harness.run();
+
+    let restored_rect = harness
+        .get_by_role_and_label(Role::Window, window_title)
+        .rect();
+    assert!(
+        (restored_rect.size() - window_rect.size()).length() < 1.0,
+        "window {restored_rect:?} should be back to its original size {window_rect:?}"
+    );
+}
+
+#[test]
+fn reduce_motion_opens_collapsing_instantly() {
+    #[derive(Default)]
+    struct State {
+        open: bool,
+        openness: f32,
+    }
+
+    for reduce_motion in [false, true] {
+        let mut harness = Harness::builder().with_step_dt(1.0 / 60.0).build_ui_state(
+            move |ui, state: &mut State| {
+                // The harness turns animations off by default:
+                ui.ctx().global_style_mut(|style| {
+                    style.animation_time = 0.2;
+                    style.reduce_motion = reduce_motion;
+                });
+
+                let id = ui.make_persistent_id("details");
+                let mut collapsing =
+                    egui::collapsing_header::CollapsingState::load_with_default_open(
+                        ui.ctx(),
+                        id,
+                        false,
+                    );
+                collapsing.set_open(state.open);
+                state.openness = collapsing.openness(ui.ctx());
+                collapsing.show_body_unindented(ui, |ui| ui.label("Body"));
+            },
+            State::default(),
+        );
+        harness.run();
+
+        harness.state_mut().open = true;
+        harness.step();
+        harness.step();
+
+        let openness = harness.state().openness;
+        if reduce_motion {
+            assert_eq!(openness, 1.0, "should open instantly with reduce_motion");
+        } else {
+            assert!(
+                openness < 1.0,
+                "should animate open without reduce_motion, got {openness}"
+            );
+        }
+    }
+}
+
 /// Ensure that the size passed to window is actually treated as outer size (including
 /// margins and borders).
 #[test]
@@ -713,3 +1078,634 @@ fn collapsing_panel_must_not_grow_enclosing_window() {
         );
     }
 }
+
+#[test]
+fn focus_follows_mouse_between_panels() {
+    let mut harness = Harness::builder()
+        .with_size(Vec2::new(400.0, 300.0))
+        .build_ui(|ui| {
+            ui.ctx().options_mut(|o| o.focus_follows_mouse = true);
+            egui::Panel::left("left_panel")
+                .exact_size(200.0)
+                .show(ui, |ui| {
+                    ui.add(Button::new("Left"));
+                });
+            egui::CentralPanel::default().show(ui, |ui| {
+                ui.add(Button::new("Right"));
+            });
+        });
+
+    harness.hover_at(Pos2::new(50.0, 150.0));
+    harness.run();
+    harness.get_by_label("Left").focus();
+    harness.run();
+    assert!(harness.get_by_label("Left").is_focused());
+
+    // Moving into the other panel takes focus away:
+    harness.hover_at(Pos2::new(300.0, 150.0));
+    harness.run();
+    assert!(!harness.get_by_label("Left").is_focused());
+    assert!(!harness.get_by_label("Right").is_focused());
+
+    // …and coming back gives it back:
+    harness.hover_at(Pos2::new(50.0, 150.0));
+    harness.run();
+    assert!(harness.get_by_label("Left").is_focused());
+}
+
+#[test]
+fn clicking_scroll_bar_mark_scrolls_to_it() {
+    struct State {
+        offset: f32,
+        inner_rect: Rect,
+        content_height: f32,
+    }
+
+    let mark = 0.75;
+    let mut harness = Harness::builder()
+        .with_size(Vec2::new(200.0, 200.0))
+        .build_ui_state(
+            |ui, state: &mut State| {
+                let output = egui::ScrollArea::vertical()
+                    .auto_shrink(false)
+                    .vertical_scroll_bar_marks([egui::ScrollBarMark::new(mark, egui::Color32::RED)])
+                    .show(ui, |ui| {
+                        for i in 0..100 {
+                            ui.label(format!("Line {i}"));
+                        }
+                    });
+                state.offset = output.state.offset.y;
+                state.inner_rect = output.inner_rect;
+                state.content_height = output.content_size.y;
+            },
+            State {
+                offset: 0.0,
+                inner_rect: Rect::NOTHING,
+                content_height: 0.0,
+            },
+        );
+    harness.run();
+
+    let inner_rect = harness.state().inner_rect;
+    // Slightly off the mark, which should still hit it:
+    let click_pos = Pos2::new(
+        inner_rect.right() - 3.0,
+        egui::lerp(inner_rect.y_range(), mark) + 3.0,
+    );
+    harness.hover_at(click_pos);
+    harness.run();
+    harness.drag_at(click_pos);
+    harness.run();
+    harness.drop_at(click_pos);
+    harness.run();
+
+    let state = harness.state();
+    let expected = mark * state.content_height - 0.5 * state.inner_rect.height();
+    assert!(
+        (state.offset - expected).abs() < 1.0,
+        "Expected the mark in the middle of the view, at offset {expected}, got {}",
+        state.offset
+    );
+}
+
+#[test]
+fn swapped_wheel_axes_scroll_horizontally() {
+    let mut harness = Harness::builder()
+        .with_size(Vec2::new(200.0, 200.0))
+        .build_ui_state(
+            |ui, offset: &mut Vec2| {
+                let output = egui::ScrollArea::both()
+                    .wheel_axis_policy(egui::WheelAxisPolicy::Swapped)
+                    .animated(false)
+                    .show(ui, |ui| {
+                        ui.allocate_space(Vec2::splat(1000.0));
+                    });
+                *offset = output.state.offset;
+            },
+            Vec2::ZERO,
+        );
+
+    harness.hover_at(Pos2::new(100.0, 100.0));
+    harness.run();
+    harness.event(egui::Event::MouseWheel {
+        unit: egui::MouseWheelUnit::Point,
+        delta: Vec2::new(0.0, -50.0),
+        phase: egui::TouchPhase::Move,
+        momentum: false,
+        modifiers: Modifiers::NONE,
+    });
+    harness.run();
+
+    let offset = *harness.state();
+    assert!(
+        offset.x > 0.0,
+        "Expected horizontal scrolling, got {offset:?}"
+    );
+    assert_eq!(offset.y, 0.0, "Expected no vertical scrolling");
+}
+
+#[test]
+fn sticky_section_header_pins_to_top() {
+    let mut harness = Harness::builder()
+        .with_size(Vec2::new(200.0, 200.0))
+        .build_ui_state(
+            |ui, offset: &mut f32| {
+                egui::ScrollArea::vertical()
+                    .vertical_scroll_offset(*offset)
+                    .animated(false)
+                    .show(ui, |ui| {
+                        for group in ["First", "Second"] {
+                            egui::StickySection::new(group).show(
+                                ui,
+                                |ui| {
+                                    ui.label(format!("{group} header"));
+                                },
+                                |ui| {
+                                    for i in 0..20 {
+                                        ui.label(format!("{group} #{i}"));
+                                    }
+                                },
+                            );
+                        }
+                    });
+            },
+            0.0,
+        );
+    harness.run();
+
+    let viewport_top = harness.get_by_label("First header").rect().top();
+    let second_header_top = harness.get_by_label("Second header").rect().top();
+
+    // Scrolled into the first section, its header stays at the top:
+    *harness.state_mut() = 100.0;
+    harness.run();
+    let header_top = harness.get_by_label("First header").rect().top();
+    assert!(
+        (header_top - viewport_top).abs() < 1.0,
+        "Expected the header at {viewport_top}, got {header_top}"
+    );
+
+    // The second header pushes the first one out:
+    *harness.state_mut() = second_header_top - viewport_top - 5.0;
+    harness.run();
+    let header_top = harness.get_by_label("First header").rect().top();
+    assert!(
+        header_top < viewport_top - 1.0,
+        "Expected the header to be pushed up, got {header_top}"
+    );
+}
+
+#[test]
+fn scroll_anchoring_keeps_content_in_place() {
+    struct State {
+        offset: Option<f32>,
+        spacer_height: f32,
+    }
+
+    let mut harness = Harness::builder()
+        .with_size(Vec2::new(200.0, 200.0))
+        .build_ui_state(
+            |ui, state: &mut State| {
+                let mut scroll_area = egui::ScrollArea::vertical()
+                    .scroll_anchoring(true)
+                    .animated(false);
+                if let Some(offset) = state.offset.take() {
+                    scroll_area = scroll_area.vertical_scroll_offset(offset);
+                }
+                scroll_area.show(ui, |ui| {
+                    // Like an image that hasn't loaded yet:
+                    ui.allocate_space(Vec2::new(100.0, state.spacer_height));
+                    for i in 0..50 {
+                        ui.label(format!("Row {i}"));
+                    }
+                });
+            },
+            State {
+                offset: Some(100.0),
+                spacer_height: 20.0,
+            },
+        );
+    harness.run();
+    let row_top = harness.get_by_label("Row 10").rect().top();
+
+    harness.state_mut().spacer_height = 70.0;
+    harness.run();
+    let new_row_top = harness.get_by_label("Row 10").rect().top();
+    assert!(
+        (new_row_top - row_top).abs() < 1.0,
+        "Expected the rows to stay at {row_top}, got {new_row_top}"
+    );
+}
+
+#[test]
+fn pulling_down_past_the_top_requests_refresh() {
+    #[derive(Default)]
+    struct State {
+        pull_progress: f32,
+        refreshes: usize,
+    }
+
+    let mut harness = Harness::builder()
+        .with_size(Vec2::new(200.0, 200.0))
+        .build_ui_state(
+            |ui, state: &mut State| {
+                let output = egui::ScrollArea::vertical()
+                    .auto_shrink(false)
+                    .scroll_source(egui::scroll_area::ScrollSource::ALL)
+                    .pull_to_refresh(40.0)
+                    .show(ui, |ui| {
+                        ui.label("Row 0");
+                        ui.label("Row 1");
+                    });
+                state.pull_progress = output.pull_progress;
+                if output.refresh_requested {
+                    state.refreshes += 1;
+                }
+            },
+            State::default(),
+        );
+    harness.run();
+    let row_top = harness.get_by_label("Row 0").rect().top();
+
+    harness.drag_at(Pos2::new(100.0, 20.0));
+    harness.step();
+    for y in [40.0, 80.0, 120.0, 160.0, 190.0] {
+        harness.hover_at(Pos2::new(100.0, y));
+        harness.step();
+    }
+    harness.step();
+    assert_eq!(harness.state().pull_progress, 1.0);
+    assert!(harness.get_by_label("Row 0").rect().top() > row_top + 40.0);
+    assert_eq!(harness.state().refreshes, 0);
+
+    harness.drop_at(Pos2::new(100.0, 190.0));
+    for _ in 0..60 {
+        harness.step();
+    }
+    assert_eq!(harness.state().refreshes, 1);
+    assert_eq!(harness.state().pull_progress, 0.0);
+    assert_eq!(harness.get_by_label("Row 0").rect().top(), row_top);
+}
+
+#[test]
+fn animated_scroll_to_cursor_signals_completion() {
+    use egui::style::{ScrollAnimation, ScrollEasing};
+
+    #[derive(Default)]
+    struct State {
+        scroll_to_bottom: bool,
+        scrolling_frames: usize,
+        times_reached: usize,
+        offset: f32,
+    }
+
+    let mut harness = Harness::builder()
+        .with_size(Vec2::new(200.0, 200.0))
+        .with_step_dt(1.0 / 60.0)
+        .build_ui_state(
+            |ui, state: &mut State| {
+                let output = egui::ScrollArea::vertical().show(ui, |ui| {
+                    for i in 0..50 {
+                        ui.label(format!("Row {i}"));
+                    }
+                    if std::mem::take(&mut state.scroll_to_bottom) {
+                        let animation =
+                            ScrollAnimation::duration(0.25).with_easing(ScrollEasing::Linear);
+                        ui.scroll_to_cursor_animation(Some(egui::Align::BOTTOM), animation);
+                    }
+                });
+                if output.state.is_scrolling_to_target() {
+                    state.scrolling_frames += 1;
+                }
+                if output.state.scroll_target_reached() {
+                    state.times_reached += 1;
+                }
+                state.offset = output.state.offset.y;
+            },
+            State::default(),
+        );
+    harness.run();
+
+    harness.state_mut().scroll_to_bottom = true;
+    for _ in 0..60 {
+        harness.step();
+    }
+
+    let state = harness.state();
+    assert_eq!(state.times_reached, 1);
+    assert!(
+        state.scrolling_frames > 5,
+        "Expected the scroll to be animated over several frames, got {}",
+        state.scrolling_frames
+    );
+    assert!(state.offset > 0.0);
+}
+
+#[test]
+fn nested_scroll_area_propagates_scrolling_at_its_end() {
+    use egui::ScrollPropagation;
+
+    struct State {
+        propagation: ScrollPropagation,
+        scroll_inner_with_delta: bool,
+        inner_offset: Option<f32>,
+        outer_offset: f32,
+    }
+
+    let run = |propagation: ScrollPropagation, with_wheel: bool| {
+        let mut harness = Harness::builder()
+            .with_size(Vec2::new(200.0, 200.0))
+            .build_ui_state(
+                |ui, state: &mut State| {
+                    let output = egui::ScrollArea::vertical()
+                        .id_salt("outer")
+                        .animated(false)
+                        .show(ui, |ui| {
+                            let mut inner = egui::ScrollArea::vertical()
+                                .id_salt("inner")
+                                .max_height(100.0)
+                                .scroll_propagation(state.propagation)
+                                .animated(false);
+                            if let Some(offset) = state.inner_offset.take() {
+                                inner = inner.vertical_scroll_offset(offset);
+                            }
+                            inner.show(ui, |ui| {
+                                for i in 0..20 {
+                                    ui.label(format!("Inner {i}"));
+                                }
+                                if std::mem::take(&mut state.scroll_inner_with_delta) {
+                                    ui.scroll_with_delta(Vec2::new(0.0, -50.0));
+                                }
+                            });
+                            for i in 0..50 {
+                                ui.label(format!("Outer {i}"));
+                            }
+                        });
+                    state.outer_offset = output.state.offset.y;
+                },
+                State {
+                    propagation,
+                    scroll_inner_with_delta: false,
+                    // Start at the bottom:
+                    inner_offset: Some(10_000.0),
+                    outer_offset: 0.0,
+                },
+            );
+
+        harness.hover_at(Pos2::new(50.0, 50.0));
+        harness.run();
+        if with_wheel {
+            harness.event(egui::Event::MouseWheel {
+                unit: egui::MouseWheelUnit::Point,
+                delta: Vec2::new(0.0, -50.0),
+                phase: egui::TouchPhase::Move,
+                momentum: false,
+                modifiers: Modifiers::NONE,
+            });
+        } else {
+            harness.state_mut().scroll_inner_with_delta = true;
+        }
+        harness.run();
+        harness.state().outer_offset
+    };
+
+    for with_wheel in [true, false] {
+        assert!(
+            run(ScrollPropagation::AtEdge, with_wheel) > 0.0,
+            "Expected the outer area to scroll (with_wheel: {with_wheel})"
+        );
+        assert_eq!(
+            run(ScrollPropagation::Never, with_wheel),
+            0.0,
+            "Expected the outer area to stay put (with_wheel: {with_wheel})"
+        );
+        assert_eq!(
+            run(ScrollPropagation::AfterThreshold(100.0), with_wheel),
+            0.0,
+            "Expected the outer area to stay put below the threshold (with_wheel: {with_wheel})"
+        );
+        let outer_offset = run(ScrollPropagation::AfterThreshold(30.0), with_wheel);
+        assert!(
+            (outer_offset - 20.0).abs() < 1.0,
+            "Expected only the scrolling past the threshold to carry over, got {outer_offset} (with_wheel: {with_wheel})"
+        );
+    }
+}
+
+#[test]
+fn clicking_minimap_scrolls_there() {
+    #[derive(Default)]
+    struct State {
+        offset: f32,
+        max_offset: f32,
+        inner_rect: Option<Rect>,
+    }
+
+    let mut harness = Harness::builder()
+        .with_size(Vec2::new(200.0, 200.0))
+        .build_ui_state(
+            |ui, state: &mut State| {
+                let output = egui::ScrollArea::vertical()
+                    .auto_shrink(false)
+                    .minimap(40.0)
+                    .animated(false)
+                    .show(ui, |ui| {
+                        for i in 0..100 {
+                            ui.label(format!("Row {i}"));
+                        }
+                    });
+                state.offset = output.state.offset.y;
+                state.max_offset = output.content_size.y - output.inner_rect.height();
+                state.inner_rect = Some(output.inner_rect);
+            },
+            State::default(),
+        );
+    harness.run();
+
+    let inner_rect = harness.state().inner_rect.expect("ScrollArea was shown");
+    let minimap_bottom = Pos2::new(inner_rect.right() + 20.0, inner_rect.bottom() - 1.0);
+    harness.hover_at(minimap_bottom);
+    harness.run();
+    harness.drag_at(minimap_bottom);
+    harness.run();
+    harness.drop_at(minimap_bottom);
+    harness.run();
+
+    let state = harness.state();
+    assert!(state.max_offset > 0.0);
+    assert!(
+        (state.offset - state.max_offset).abs() < 1.0,
+        "Expected to scroll to the end at {}, got {}",
+        state.max_offset,
+        state.offset
+    );
+}
+
+#[test]
+fn hidden_overlay_scroll_bar_lets_clicks_through() {
+    #[derive(Default)]
+    struct State {
+        clicks: usize,
+        inner_rect: Option<Rect>,
+        button_rect: Option<Rect>,
+    }
+
+    let mut harness = Harness::builder()
+        .with_size(Vec2::new(200.0, 200.0))
+        .build_ui_state(
+            |ui, state: &mut State| {
+                ui.spacing_mut().scroll = egui::style::ScrollStyle::overlay();
+                let output = egui::ScrollArea::vertical()
+                    .auto_shrink(false)
+                    .show(ui, |ui| {
+                        let response =
+                            ui.add_sized([ui.available_width(), 40.0], Button::new("Wide"));
+                        if response.clicked() {
+                            state.clicks += 1;
+                        }
+                        state.button_rect = Some(response.rect);
+                        for i in 0..50 {
+                            ui.label(format!("Row {i}"));
+                        }
+                    });
+                state.inner_rect = Some(output.inner_rect);
+            },
+            State::default(),
+        );
+    harness.run();
+
+    let inner_rect = harness.state().inner_rect.expect("ScrollArea was shown");
+    let button_rect = harness.state().button_rect.expect("Button was shown");
+    // Where the vertical scroll bar would be:
+    let pos = Pos2::new(inner_rect.right() - 3.0, button_rect.center().y);
+    harness.hover_at(pos);
+    harness.run();
+    harness.drag_at(pos);
+    harness.run();
+    harness.drop_at(pos);
+    harness.run();
+
+    assert_eq!(harness.state().clicks, 1);
+}
+
+#[test]
+fn pen_input_is_tracked_by_pointer_state() {
+    let mut harness = Harness::builder()
+        .with_size(Vec2::new(200.0, 200.0))
+        .build_ui_state(
+            |ui, pen: &mut Option<egui::PenState>| {
+                ui.label("Draw here");
+                *pen = ui.input(|i| i.pointer.pen());
+            },
+            None,
+        );
+
+    let pos = Pos2::new(50.0, 60.0);
+    harness.input_mut().events.push(egui::Event::PointerPen {
+        pos,
+        pressure: 0.75,
+        tilt: Some(0.5),
+        azimuth: None,
+        barrel_button: false,
+        eraser: true,
+    });
+    harness.step();
+
+    let pen = harness.state().expect("pen state should be set");
+    assert_eq!(pen.pos, pos);
+    assert_eq!(pen.pressure, 0.75);
+    assert_eq!(pen.tilt, Some(0.5));
+    assert!(pen.eraser);
+
+    harness.input_mut().events.push(egui::Event::PointerGone);
+    harness.step();
+    assert!(harness.state().is_none());
+}
+
+#[test]
+fn touchpad_force_is_tracked_by_pointer_state() {
+    let mut harness = Harness::new_ui_state(
+        |ui, force: &mut Option<(f32, u8)>| {
+            ui.label("Press hard");
+            *force = ui.input(|i| i.pointer.force().zip(i.pointer.force_stage()));
+        },
+        None,
+    );
+
+    harness.input_mut().events.push(egui::Event::PointerForce {
+        force: 0.8,
+        stage: 2,
+    });
+    harness.step();
+    assert_eq!(*harness.state(), Some((0.8, 2)));
+
+    harness.input_mut().events.push(egui::Event::PointerGone);
+    harness.step();
+    assert_eq!(*harness.state(), None);
+}
+
+#[test]
+fn momentum_scroll_is_not_trackpad_scrolling() {
+    let mut harness = Harness::new_ui_state(
+        |ui, state: &mut (bool, bool)| {
+            ui.label("Fling me");
+            *state = ui.input(|i| (i.is_trackpad_scrolling(), i.is_scroll_momentum()));
+        },
+        (false, false),
+    );
+
+    let wheel = |phase, momentum| egui::Event::MouseWheel {
+        unit: egui::MouseWheelUnit::Point,
+        delta: Vec2::new(0.0, -10.0),
+        phase,
+        momentum,
+        modifiers: Modifiers::NONE,
+    };
+
+    harness
+        .input_mut()
+        .events
+        .push(wheel(egui::TouchPhase::Start, false));
+    harness.step();
+    assert_eq!(*harness.state(), (true, false));
+
+    harness.input_mut().events.extend([
+        wheel(egui::TouchPhase::End, false),
+        wheel(egui::TouchPhase::Start, true),
+    ]);
+    harness.step();
+    assert_eq!(*harness.state(), (false, true));
+
+    harness
+        .input_mut()
+        .events
+        .push(wheel(egui::TouchPhase::End, true));
+    harness.step();
+    assert_eq!(*harness.state(), (false, false));
+}
+
+#[test]
+fn pointer_source_is_tracked_by_pointer_state() {
+    let mut harness = Harness::new_ui_state(
+        |ui, source: &mut egui::PointerSource| {
+            ui.label("Touch me");
+            *source = ui.input(|i| i.pointer.source());
+        },
+        egui::PointerSource::Pen,
+    );
+
+    harness.step();
+    assert_eq!(*harness.state(), egui::PointerSource::Mouse);
+
+    harness
+        .input_mut()
+        .events
+        .push(egui::Event::PointerSource(egui::PointerSource::Touch));
+    harness.step();
+    assert_eq!(*harness.state(), egui::PointerSource::Touch);
+
+    // The source outlives the pointer:
+    harness.input_mut().events.push(egui::Event::PointerGone);
+    harness.step();
+    assert_eq!(*harness.state(), egui::PointerSource::Touch);
+}
@@ -262,3 +262,47 @@ fn test_ime_composition_visuals() {
     harness.run();
     harness.snapshot("test_ime_composition_visuals_cursor");
 }
+
+#[test]
+fn test_ime_area_follows_cursor_row() {
+    let mut harness = Harness::new_ui_state(
+        |ui, state| {
+            egui::TextEdit::multiline(state)
+                .desired_width(120.0)
+                .desired_rows(5)
+                .show(ui);
+        },
+        "First row\nSecond row".to_owned(),
+    );
+
+    let text_edit = harness.get_by_role(egui::accesskit::Role::MultilineTextInput);
+    text_edit.focus();
+    harness.run();
+
+    harness.key_press(egui::Key::End);
+    harness.event(egui::Event::Ime(egui::ImeEvent::Preedit {
+        text: "abc".to_owned(),
+        active_range_chars: Some(3..3),
+    }));
+    harness.run();
+
+    let ime = harness
+        .output()
+        .platform_output
+        .ime
+        .expect("The TextEdit should have IME output");
+    assert!(
+        ime.cursor_row_rect.min.y > ime.rect.min.y + 10.0,
+        "The IME area should be on the second row: {ime:?}"
+    );
+    assert!(
+        ime.cursor_row_rect.width() > 10.0,
+        "The IME area should span the composition: {ime:?}"
+    );
+    assert!(
+        ime.cursor_rect
+            .x_range()
+            .contains(ime.cursor_row_rect.max.x),
+        "The IME area should end at the cursor: {ime:?}"
+    );
+}
@@ -0,0 +1,45 @@
+use egui::{WidgetEventKind, Window};
+use egui_kittest::Harness;
+use kittest::Queryable as _;
+
+#[test]
+fn widget_events() {
+    let mut harness = Harness::new_ui_state(
+        |ui, show: &mut bool| {
+            ui.checkbox(show, "Show window");
+            Window::new("My window")
+                .open(show)
+                .default_pos([100.0, 100.0])
+                .show(ui.ctx(), |ui| {
+                    ui.label("Hello");
+                });
+        },
+        true,
+    );
+
+    let events = harness.take_widget_events();
+    assert!(events.iter().any(|event| {
+        event.kind == WidgetEventKind::WindowOpened
+            && event.info.label.as_deref() == Some("My window")
+    }));
+
+    harness.get_by_label("Show window").click();
+    harness.run();
+
+    let kinds: Vec<_> = harness
+        .widget_events()
+        .iter()
+        .filter(|event| event.info.label.as_deref() == Some("Show window"))
+        .map(|event| event.kind)
+        .collect();
+    assert_eq!(
+        kinds,
+        [WidgetEventKind::Clicked, WidgetEventKind::ValueChanged]
+    );
+    assert!(
+        harness
+            .widget_events()
+            .iter()
+            .any(|event| event.kind == WidgetEventKind::WindowClosed)
+    );
+}
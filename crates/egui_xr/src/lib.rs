@@ -0,0 +1,416 @@
+//! Helpers for showing [`egui`] panels in VR and AR.
+//!
+//! A [`Panel`] is a flat rectangle somewhere in the 3D world, with its own [`egui::Context`].
+//! Each frame you:
+//!
+//! 1. Give it the ray and trigger state of the controller with [`Panel::on_controller`]
+//!    (and the thumbstick with [`Panel::on_scroll`]).
+//! 2. Run your UI with [`Panel::run_ui`].
+//! 3. Render the output to a texture, e.g. with [`PanelRenderer`] (requires the `wgpu` feature).
+//! 4. Draw the texture as a quad at [`Panel::pose`] in your scene.
+//!
+//! The panel is rendered once and shown to both eyes, so there is no per-eye work for egui.
+//!
+//! This crate is agnostic to the XR runtime: convert the poses you get from `OpenXR`
+//! (or whatever you are using) to [`Ray`] and [`PanelPose`].
+//! All positions are in meters, in the same world space.
+//!
+//! ## Feature flags
+#![cfg_attr(feature = "document-features", doc = document_features::document_features!())]
+//!
+
+pub use egui;
+#[cfg(feature = "wgpu")]
+pub use egui_wgpu;
+
+#[cfg(feature = "wgpu")]
+mod renderer;
+
+#[cfg(feature = "wgpu")]
+pub use renderer::PanelRenderer;
+
+use egui::{Pos2, Rect, Vec2};
+
+/// A point or direction in 3D space, in meters.
+pub type Vec3 = [f32; 3];
+
+/// A ray shot from a controller (or the user's gaze).
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Ray {
+    /// Where the ray starts.
+    pub origin: Vec3,
+
+    /// Which way the ray points. Does not need to be normalized.
+    pub direction: Vec3,
+}
+
+/// Where a [`Panel`] is in the world.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct PanelPose {
+    /// The center of the panel.
+    pub center: Vec3,
+
+    /// Unit vector pointing to the right edge of the panel.
+    pub right: Vec3,
+
+    /// Unit vector pointing to the top edge of the panel.
+    ///
+    /// Must be orthogonal to [`Self::right`].
+    pub up: Vec3,
+}
+
+impl Default for PanelPose {
+    /// One meter in front of the origin, facing the origin (`OpenXR` convention: -Z is forward).
+    fn default() -> Self {
+        Self {
+            center: [0.0, 0.0, -1.0],
+            right: [1.0, 0.0, 0.0],
+            up: [0.0, 1.0, 0.0],
+        }
+    }
+}
+
+impl PanelPose {
+    /// The direction the front of the panel is facing.
+    pub fn normal(&self) -> Vec3 {
+        cross(self.right, self.up)
+    }
+}
+
+/// Where a [`Ray`] hit a [`Panel`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct PanelHit {
+    /// Where on the panel, in egui points.
+    pub pos: Pos2,
+
+    /// How far along the ray the hit is, in units of [`Ray::direction`].
+    ///
+    /// Use this to find out which panel is closest if the ray hits several.
+    pub distance: f32,
+}
+
+/// A flat egui panel somewhere in the 3D world.
+///
+/// See the [crate-level docs](crate) for how to use it.
+pub struct Panel {
+    ctx: egui::Context,
+    pose: PanelPose,
+    size_in_meters: Vec2,
+    size_in_points: Vec2,
+    pixels_per_point: f32,
+    start_time: web_time::Instant,
+    input: egui::RawInput,
+
+    /// Where the controller is pointing, if it is pointing at the panel (or is dragging).
+    pointer_pos: Option<Pos2>,
+    trigger_down: bool,
+
+    /// The trigger was pressed on the panel, and is still held.
+    is_dragging: bool,
+}
+
+impl Panel {
+    /// Create a panel with the given physical size, in meters,
+    /// and logical size, in egui points.
+    ///
+    /// The aspect ratios of the two should match.
+    pub fn new(ctx: egui::Context, size_in_meters: Vec2, size_in_points: Vec2) -> Self {
+        Self {
+            ctx,
+            pose: PanelPose::default(),
+            size_in_meters,
+            size_in_points,
+            pixels_per_point: 2.0,
+            start_time: web_time::Instant::now(),
+            input: egui::RawInput::default(),
+            pointer_pos: None,
+            trigger_down: false,
+            is_dragging: false,
+        }
+    }
+
+    /// Where the panel is in the world.
+    #[inline]
+    pub fn with_pose(mut self, pose: PanelPose) -> Self {
+        self.pose = pose;
+        self
+    }
+
+    /// How many texture pixels to use per egui point. Default: `2.0`.
+    ///
+    /// Panels are usually viewed at an angle and from varying distances,
+    /// so it pays off to render them at a higher resolution than a monitor would need.
+    #[inline]
+    pub fn with_pixels_per_point(mut self, pixels_per_point: f32) -> Self {
+        self.pixels_per_point = pixels_per_point;
+        self
+    }
+
+    /// The [`egui::Context`] of this panel.
+    pub fn ctx(&self) -> &egui::Context {
+        &self.ctx
+    }
+
+    /// Where the panel is in the world.
+    pub fn pose(&self) -> &PanelPose {
+        &self.pose
+    }
+
+    /// Move the panel.
+    pub fn set_pose(&mut self, pose: PanelPose) {
+        self.pose = pose;
+    }
+
+    /// The physical size of the panel, in meters.
+    pub fn size_in_meters(&self) -> Vec2 {
+        self.size_in_meters
+    }
+
+    /// The logical size of the panel, in egui points.
+    pub fn size_in_points(&self) -> Vec2 {
+        self.size_in_points
+    }
+
+    /// The size of the texture the panel should be rendered to.
+    pub fn size_in_pixels(&self) -> [u32; 2] {
+        let size = self.size_in_points * self.pixels_per_point;
+        [size.x.round() as u32, size.y.round() as u32]
+    }
+
+    /// How many texture pixels per egui point.
+    pub fn pixels_per_point(&self) -> f32 {
+        self.pixels_per_point
+    }
+
+    /// Where the controller is pointing on the panel, in egui points, if anywhere.
+    pub fn pointer_pos(&self) -> Option<Pos2> {
+        self.pointer_pos
+    }
+
+    /// Where does the ray hit the front of the panel, if at all?
+    pub fn hit(&self, ray: &Ray) -> Option<PanelHit> {
+        let hit = self.hit_plane(ray)?;
+        Rect::from_min_size(Pos2::ZERO, self.size_in_points)
+            .contains(hit.pos)
+            .then_some(hit)
+    }
+
+    /// Where does the ray hit the infinite plane of the panel, if at all?
+    fn hit_plane(&self, ray: &Ray) -> Option<PanelHit> {
+        let normal = self.pose.normal();
+        let denom = dot(ray.direction, normal);
+        if -f32::EPSILON <= denom {
+            return None; // Parallel to the panel, or hitting it from behind
+        }
+
+        let distance = dot(sub(self.pose.center, ray.origin), normal) / denom;
+        if distance < 0.0 {
+            return None; // The panel is behind the controller
+        }
+
+        let on_plane = sub(
+            add(ray.origin, scale(ray.direction, distance)),
+            self.pose.center,
+        );
+        let x = dot(on_plane, self.pose.right) / self.size_in_meters.x + 0.5;
+        let y = 0.5 - dot(on_plane, self.pose.up) / self.size_in_meters.y;
+
+        Some(PanelHit {
+            pos: Pos2::new(x * self.size_in_points.x, y * self.size_in_points.y),
+            distance,
+        })
+    }
+
+    /// Call this every frame with the controller ray and whether the trigger (or whatever
+    /// button you use for clicking) is held down.
+    ///
+    /// Pass `None` for the ray if the controller isn't tracked,
+    /// or if it is pointing at something closer than this panel.
+    ///
+    /// While the trigger is held after pressing it on the panel, the pointer keeps following
+    /// the ray across the plane of the panel, even outside of it, so that drags work as expected.
+    ///
+    /// Returns where the ray hit the panel, if it did.
+    pub fn on_controller(&mut self, ray: Option<&Ray>, trigger_down: bool) -> Option<PanelHit> {
+        let hit = ray.and_then(|ray| self.hit(ray));
+
+        let new_pos = if self.is_dragging {
+            // Keep following the ray while dragging, even outside the panel:
+            ray.and_then(|ray| self.hit_plane(ray)).map(|hit| hit.pos)
+        } else {
+            hit.map(|hit| hit.pos)
+        };
+
+        match new_pos {
+            Some(pos) => {
+                if self.pointer_pos != Some(pos) {
+                    self.input.events.push(egui::Event::PointerMoved(pos));
+                }
+                let pressed = trigger_down && !self.trigger_down;
+                let released = !trigger_down && self.is_dragging;
+                if pressed || released {
+                    self.input.events.push(egui::Event::PointerButton {
+                        pos,
+                        button: egui::PointerButton::Primary,
+                        pressed,
+                        modifiers: Default::default(),
+                    });
+                    self.is_dragging = pressed;
+                }
+                self.pointer_pos = Some(pos);
+            }
+            None => {
+                if let Some(pos) = self.pointer_pos.take() {
+                    if self.is_dragging {
+                        self.is_dragging = false;
+                        self.input.events.push(egui::Event::PointerButton {
+                            pos,
+                            button: egui::PointerButton::Primary,
+                            pressed: false,
+                            modifiers: Default::default(),
+                        });
+                    }
+                    self.input.events.push(egui::Event::PointerGone);
+                }
+            }
+        }
+
+        self.trigger_down = trigger_down;
+        hit
+    }
+
+    /// Scroll the panel, e.g. with the thumbstick.
+    ///
+    /// The delta is in points; positive `y` scrolls the content down (like a mouse wheel moving up).
+    /// Only has an effect while the controller is pointing at the panel.
+    pub fn on_scroll(&mut self, delta: Vec2) {
+        if self.pointer_pos.is_some() && delta != Vec2::ZERO {
+            self.input.events.push(egui::Event::MouseWheel {
+                unit: egui::MouseWheelUnit::Point,
+                delta,
+                phase: egui::TouchPhase::Move,
+                momentum: false,
+                modifiers: Default::default(),
+            });
+        }
+    }
+
+    /// Add any other events, e.g. text typed on a virtual keyboard.
+    pub fn push_event(&mut self, event: egui::Event) {
+        self.input.events.push(event);
+    }
+
+    /// Run your UI code for this frame.
+    ///
+    /// Render the returned output with e.g. [`PanelRenderer::render`].
+    pub fn run_ui(&mut self, run_ui: impl FnMut(&mut egui::Ui)) -> egui::FullOutput {
+        profiling::function_scope!();
+
+        let mut input = self.input.take();
+        input.time = Some(self.start_time.elapsed().as_secs_f64());
+        input.screen_rect = Some(Rect::from_min_size(Pos2::ZERO, self.size_in_points));
+        input.focused = true;
+        input
+            .viewports
+            .entry(input.viewport_id)
+            .or_default()
+            .native_pixels_per_point = Some(self.pixels_per_point);
+
+        self.ctx.run_ui(input, run_ui)
+    }
+}
+
+fn add(a: Vec3, b: Vec3) -> Vec3 {
+    [a[0] + b[0], a[1] + b[1], a[2] + b[2]]
+}
+
+fn sub(a: Vec3, b: Vec3) -> Vec3 {
+    [a[0] - b[0], a[1] - b[1], a[2] - b[2]]
+}
+
+fn scale(a: Vec3, s: f32) -> Vec3 {
+    [a[0] * s, a[1] * s, a[2] * s]
+}
+
+fn dot(a: Vec3, b: Vec3) -> f32 {
+    a[0] * b[0] + a[1] * b[1] + a[2] * b[2]
+}
+
+fn cross(a: Vec3, b: Vec3) -> Vec3 {
+    [
+        a[1] * b[2] - a[2] * b[1],
+        a[2] * b[0] - a[0] * b[2],
+        a[0] * b[1] - a[1] * b[0],
+    ]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn panel() -> Panel {
+        // 2x1 meters, one meter in front of the origin:
+        Panel::new(
+            egui::Context::default(),
+            egui::vec2(2.0, 1.0),
+            egui::vec2(200.0, 100.0),
+        )
+    }
+
+    fn ray_towards(x: f32, y: f32) -> Ray {
+        Ray {
+            origin: [0.0, 0.0, 0.0],
+            direction: [x, y, -1.0],
+        }
+    }
+
+    #[test]
+    fn ray_hits_panel() {
+        let panel = panel();
+
+        let hit = panel.hit(&ray_towards(0.0, 0.0)).unwrap();
+        assert_eq!(hit.pos, Pos2::new(100.0, 50.0));
+        assert_eq!(hit.distance, 1.0);
+
+        let top_left = panel.hit(&ray_towards(-0.5, 0.25)).unwrap();
+        assert_eq!(top_left.pos, Pos2::new(50.0, 25.0));
+
+        assert_eq!(panel.hit(&ray_towards(2.0, 0.0)), None, "outside the panel");
+        assert_eq!(panel.hit(&ray_towards(0.0, 0.0).reversed()), None, "behind");
+    }
+
+    #[test]
+    fn trigger_clicks_and_drags() {
+        let mut panel = panel();
+
+        panel.on_controller(Some(&ray_towards(0.0, 0.0)), true);
+        panel.on_controller(Some(&ray_towards(5.0, 0.0)), true);
+        assert!(
+            panel.pointer_pos().unwrap().x > 200.0,
+            "dragging should follow the ray outside the panel"
+        );
+        panel.on_controller(None, false);
+
+        let events = panel.input.events;
+        assert!(matches!(events[0], egui::Event::PointerMoved(_)));
+        assert!(matches!(
+            events[1],
+            egui::Event::PointerButton { pressed: true, .. }
+        ));
+        assert!(matches!(events[2], egui::Event::PointerMoved(_)));
+        assert!(matches!(
+            events[3],
+            egui::Event::PointerButton { pressed: false, .. }
+        ));
+        assert_eq!(events[4], egui::Event::PointerGone);
+    }
+
+    impl Ray {
+        fn reversed(self) -> Self {
+            Self {
+                origin: self.origin,
+                direction: scale(self.direction, -1.0),
+            }
+        }
+    }
+}
@@ -0,0 +1,143 @@
+use egui_wgpu::{Renderer, RendererOptions, ScreenDescriptor, wgpu};
+
+use crate::Panel;
+
+/// Renders a [`Panel`] to a [`wgpu::Texture`].
+///
+/// Sample the texture on a quad in your scene to show the panel.
+/// You need one renderer per panel.
+pub struct PanelRenderer {
+    renderer: Renderer,
+    format: wgpu::TextureFormat,
+    texture: Option<(wgpu::Texture, wgpu::TextureView)>,
+}
+
+impl PanelRenderer {
+    /// `format` is the format of the texture the panel is rendered to.
+    pub fn new(device: &wgpu::Device, format: wgpu::TextureFormat) -> Self {
+        Self {
+            renderer: Renderer::new(device, format, RendererOptions::default()),
+            format,
+            texture: None,
+        }
+    }
+
+    /// The egui renderer, e.g. for registering your own textures.
+    pub fn renderer_mut(&mut self) -> &mut Renderer {
+        &mut self.renderer
+    }
+
+    /// The texture the panel was last rendered to, if any.
+    pub fn texture(&self) -> Option<&wgpu::Texture> {
+        self.texture.as_ref().map(|(texture, _)| texture)
+    }
+
+    /// Render the output of [`Panel::run_ui`] to the texture, and return it.
+    ///
+    /// The texture is (re)created whenever the size of the panel changes,
+    /// so don't hold on to it across frames.
+    pub fn render(
+        &mut self,
+        device: &wgpu::Device,
+        queue: &wgpu::Queue,
+        panel: &Panel,
+        output: egui::FullOutput,
+    ) -> &wgpu::Texture {
+        profiling::function_scope!();
+
+        let egui::FullOutput {
+            textures_delta,
+            shapes,
+            pixels_per_point,
+            ..
+        } = output;
+
+        let screen_descriptor = ScreenDescriptor {
+            size_in_pixels: panel.size_in_pixels(),
+            pixels_per_point,
+        };
+
+        for (id, image_delta) in &textures_delta.set {
+            self.renderer
+                .update_texture(device, queue, *id, image_delta);
+        }
+
+        let paint_jobs = panel.ctx().tessellate(shapes, pixels_per_point);
+
+        let mut encoder = device.create_command_encoder(&wgpu::CommandEncoderDescriptor {
+            label: Some("egui_xr panel encoder"),
+        });
+
+        let user_buffers = self.renderer.update_buffers(
+            device,
+            queue,
+            &mut encoder,
+            &paint_jobs,
+            &screen_descriptor,
+        );
+
+        self.ensure_texture(device, screen_descriptor.size_in_pixels);
+        let Some((_, view)) = &self.texture else {
+            unreachable!("created above");
+        };
+
+        {
+            let mut render_pass = encoder
+                .begin_render_pass(&wgpu::RenderPassDescriptor {
+                    label: Some("egui_xr panel render pass"),
+                    color_attachments: &[Some(wgpu::RenderPassColorAttachment {
+                        view,
+                        resolve_target: None,
+                        ops: wgpu::Operations {
+                            load: wgpu::LoadOp::Clear(wgpu::Color::TRANSPARENT),
+                            store: wgpu::StoreOp::Store,
+                        },
+                        depth_slice: None,
+                    })],
+                    ..Default::default()
+                })
+                .forget_lifetime();
+
+            self.renderer
+                .render(&mut render_pass, &paint_jobs, &screen_descriptor);
+        }
+
+        queue.submit(user_buffers.into_iter().chain(Some(encoder.finish())));
+
+        for id in &textures_delta.free {
+            self.renderer.free_texture(id);
+        }
+
+        let Some((texture, _)) = &self.texture else {
+            unreachable!("created above");
+        };
+        texture
+    }
+
+    fn ensure_texture(&mut self, device: &wgpu::Device, [width, height]: [u32; 2]) {
+        let needs_new_texture = self.texture.as_ref().is_none_or(|(texture, _)| {
+            texture.width() != width.max(1) || texture.height() != height.max(1)
+        });
+
+        if needs_new_texture {
+            let texture = device.create_texture(&wgpu::TextureDescriptor {
+                label: Some("egui_xr panel"),
+                size: wgpu::Extent3d {
+                    width: width.max(1),
+                    height: height.max(1),
+                    depth_or_array_layers: 1,
+                },
+                mip_level_count: 1,
+                sample_count: 1,
+                dimension: wgpu::TextureDimension::D2,
+                format: self.format,
+                usage: wgpu::TextureUsages::RENDER_ATTACHMENT
+                    | wgpu::TextureUsages::TEXTURE_BINDING
+                    | wgpu::TextureUsages::COPY_SRC,
+                view_formats: &[],
+            });
+            let view = texture.create_view(&wgpu::TextureViewDescriptor::default());
+            self.texture = Some((texture, view));
+        }
+    }
+}
@@ -0,0 +1,199 @@
+/// Evaluate a simple math expression, like `"1920/2"` or `"2*pi"`.
+///
+/// Supports:
+/// * numbers like `42`, `1.5`, `.5` and `1e-3`
+/// * `+`, `-`, `*`, `/`, `%` (remainder) and `^` (power, right-associative)
+/// * parentheses, and unary `+` and `-`
+/// * the constants `pi`, `tau` and `e`
+/// * the functions `sqrt`, `abs`, `floor`, `ceil`, `round`, `sin`, `cos`, `tan`, `ln`, `log10` and `exp`
+///
+/// Whitespace is ignored anywhere (so it can be used as a thousands separator),
+/// and the special minus character (U+2212) is treated as a normal minus.
+///
+/// Returns `None` if the expression can't be parsed or the result is not finite.
+///
+/// ```
+/// # use emath::evaluate_expression;
+/// assert_eq!(evaluate_expression("1920/2"), Some(960.0));
+/// assert_eq!(evaluate_expression("-(1 + 2) * 3"), Some(-9.0));
+/// assert_eq!(evaluate_expression("2^3^2"), Some(512.0));
+/// assert_eq!(evaluate_expression("1 000"), Some(1000.0));
+/// assert_eq!(evaluate_expression("1/0"), None);
+/// assert_eq!(evaluate_expression("2*"), None);
+/// ```
+pub fn evaluate_expression(text: &str) -> Option<f64> {
+    let chars: Vec<char> = text
+        .chars()
+        .filter(|c| !c.is_whitespace())
+        .map(|c| if c == '−' { '-' } else { c })
+        .collect();
+    let mut parser = Parser { chars, pos: 0 };
+    let value = parser.sum()?;
+    (parser.pos == parser.chars.len() && value.is_finite()).then_some(value)
+}
+
+struct Parser {
+    chars: Vec<char>,
+    pos: usize,
+}
+
+impl Parser {
+    fn peek(&self) -> Option<char> {
+        self.chars.get(self.pos).copied()
+    }
+
+    fn eat(&mut self, c: char) -> bool {
+        let found = self.peek() == Some(c);
+        if found {
+            self.pos += 1;
+        }
+        found
+    }
+
+    /// `sum = product (('+' | '-') product)*`
+    fn sum(&mut self) -> Option<f64> {
+        let mut value = self.product()?;
+        loop {
+            if self.eat('+') {
+                value += self.product()?;
+            } else if self.eat('-') {
+                value -= self.product()?;
+            } else {
+                return Some(value);
+            }
+        }
+    }
+
+    /// `product = unary (('*' | '/' | '%') unary)*`
+    fn product(&mut self) -> Option<f64> {
+        let mut value = self.unary()?;
+        loop {
+            if self.eat('*') {
+                value *= self.unary()?;
+            } else if self.eat('/') {
+                value /= self.unary()?;
+            } else if self.eat('%') {
+                value %= self.unary()?;
+            } else {
+                return Some(value);
+            }
+        }
+    }
+
+    /// `unary = ('+' | '-') unary | power`
+    fn unary(&mut self) -> Option<f64> {
+        if self.eat('-') {
+            Some(-self.unary()?)
+        } else if self.eat('+') {
+            self.unary()
+        } else {
+            self.power()
+        }
+    }
+
+    /// `power = atom ('^' unary)?`
+    ///
+    /// So `-2^2 = -4` and `2^-1 = 0.5`, like in most calculators.
+    fn power(&mut self) -> Option<f64> {
+        let base = self.atom()?;
+        if self.eat('^') {
+            Some(base.powf(self.unary()?))
+        } else {
+            Some(base)
+        }
+    }
+
+    /// `atom = number | '(' sum ')' | constant | function '(' sum ')'`
+    fn atom(&mut self) -> Option<f64> {
+        let c = self.peek()?;
+        if c.is_ascii_digit() || c == '.' {
+            self.number()
+        } else if self.eat('(') {
+            let value = self.sum()?;
+            self.eat(')').then_some(value)
+        } else if c.is_alphabetic() {
+            let start = self.pos;
+            while self.peek().is_some_and(|c| c.is_alphanumeric()) {
+                self.pos += 1;
+            }
+            let name: String = self.chars[start..self.pos].iter().collect();
+            match name.to_lowercase().as_str() {
+                "pi" => Some(std::f64::consts::PI),
+                "tau" => Some(std::f64::consts::TAU),
+                "e" => Some(std::f64::consts::E),
+                function => {
+                    let function: fn(f64) -> f64 = match function {
+                        "sqrt" => f64::sqrt,
+                        "abs" => f64::abs,
+                        "floor" => f64::floor,
+                        "ceil" => f64::ceil,
+                        "round" => f64::round,
+                        "sin" => f64::sin,
+                        "cos" => f64::cos,
+                        "tan" => f64::tan,
+                        "ln" => f64::ln,
+                        "log10" => f64::log10,
+                        "exp" => f64::exp,
+                        _ => return None,
+                    };
+                    if !self.eat('(') {
+                        return None;
+                    }
+                    let argument = self.sum()?;
+                    self.eat(')').then(|| function(argument))
+                }
+            }
+        } else {
+            None
+        }
+    }
+
+    fn number(&mut self) -> Option<f64> {
+        let start = self.pos;
+        while self.peek().is_some_and(|c| c.is_ascii_digit() || c == '.') {
+            self.pos += 1;
+        }
+
+        // Exponent, e.g. `1e-3`. An `e` not followed by a number is left alone.
+        if matches!(self.peek(), Some('e' | 'E')) {
+            let mut end = self.pos + 1;
+            if matches!(self.chars.get(end), Some('+' | '-')) {
+                end += 1;
+            }
+            if self.chars.get(end).is_some_and(|c| c.is_ascii_digit()) {
+                self.pos = end;
+                while self.peek().is_some_and(|c| c.is_ascii_digit()) {
+                    self.pos += 1;
+                }
+            }
+        }
+
+        let text: String = self.chars[start..self.pos].iter().collect();
+        text.parse().ok()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::evaluate_expression;
+
+    #[test]
+    fn test_evaluate_expression() {
+        assert_eq!(evaluate_expression("42"), Some(42.0));
+        assert_eq!(evaluate_expression(" -1.5e2 "), Some(-150.0));
+        assert_eq!(evaluate_expression("−3"), Some(-3.0));
+        assert_eq!(evaluate_expression("1 + 2 * 3"), Some(7.0));
+        assert_eq!(evaluate_expression("(1 + 2) * 3"), Some(9.0));
+        assert_eq!(evaluate_expression("10 - 4 - 3"), Some(3.0));
+        assert_eq!(evaluate_expression("7 % 4"), Some(3.0));
+        assert_eq!(evaluate_expression("-2^2"), Some(-4.0));
+        assert_eq!(evaluate_expression("2^-1"), Some(0.5));
+        assert_eq!(evaluate_expression("2*pi"), Some(std::f64::consts::TAU));
+        assert_eq!(evaluate_expression("sqrt(16) + abs(-1)"), Some(5.0));
+        assert_eq!(evaluate_expression("2e"), None);
+        assert_eq!(evaluate_expression("(1 + 2"), None);
+        assert_eq!(evaluate_expression("foo(1)"), None);
+        assert_eq!(evaluate_expression(""), None);
+        assert_eq!(evaluate_expression("sqrt(-1)"), None);
+    }
+}
@@ -27,6 +27,7 @@ use std::ops::{Add, Div, Mul, RangeInclusive, Sub};
 
 pub mod align;
 pub mod easing;
+mod expression;
 mod gui_rounding;
 mod history;
 mod numeric;
@@ -44,6 +45,7 @@ mod vec2b;
 
 pub use self::{
     align::{Align, Align2},
+    expression::evaluate_expression,
     gui_rounding::{GUI_ROUNDING, GuiRounding},
     history::History,
     numeric::*,
@@ -50,6 +50,46 @@ impl Margin {
         }
     }
 
+    /// Replace the left margin.
+    #[inline]
+    pub const fn with_left(mut self, left: i8) -> Self {
+        self.left = left;
+        self
+    }
+
+    /// Replace the right margin.
+    #[inline]
+    pub const fn with_right(mut self, right: i8) -> Self {
+        self.right = right;
+        self
+    }
+
+    /// Replace the top margin.
+    #[inline]
+    pub const fn with_top(mut self, top: i8) -> Self {
+        self.top = top;
+        self
+    }
+
+    /// Replace the bottom margin.
+    #[inline]
+    pub const fn with_bottom(mut self, bottom: i8) -> Self {
+        self.bottom = bottom;
+        self
+    }
+
+    /// Replace the left and right margins.
+    #[inline]
+    pub const fn with_x(self, x: i8) -> Self {
+        self.with_left(x).with_right(x)
+    }
+
+    /// Replace the top and bottom margins.
+    #[inline]
+    pub const fn with_y(self, y: i8) -> Self {
+        self.with_top(y).with_bottom(y)
+    }
+
     /// Left margin, as `f32`
     #[inline]
     pub const fn leftf(self) -> f32 {
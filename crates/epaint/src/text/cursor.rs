@@ -1,5 +1,7 @@
 //! Different types of text cursors, i.e. ways to point into a [`super::Galley`].
 
+use unicode_segmentation::GraphemeCursor;
+
 use super::index::CharIndex;
 
 /// Character cursor.
@@ -109,3 +111,86 @@ pub struct LayoutCursor {
     /// When moving up/down it may again be within the next row.
     pub column: CharIndex,
 }
+
+// ----------------------------------------------------------------------------
+
+/// The closest extended grapheme cluster boundary before `index` in `text`, or zero.
+///
+/// A grapheme cluster is what a user thinks of as a single character,
+/// even if it is made up of several chars, like `e\u{301}` (`é` with a combining accent)
+/// or `👨‍👩‍👧` (emojis joined with zero-width joiners).
+/// Text cursors should only move between grapheme cluster boundaries.
+pub fn previous_grapheme_boundary(text: &str, index: CharIndex) -> CharIndex {
+    let byte_index = byte_index_from_char_index(text, index);
+    let mut cursor = GraphemeCursor::new(byte_index, text.len(), true);
+    match cursor.prev_boundary(text, 0) {
+        Ok(Some(boundary)) => char_index_from_byte_index(text, boundary),
+        _ => CharIndex::ZERO,
+    }
+}
+
+/// The closest extended grapheme cluster boundary after `index` in `text`,
+/// or the end of the text.
+///
+/// See [`previous_grapheme_boundary`].
+pub fn next_grapheme_boundary(text: &str, index: CharIndex) -> CharIndex {
+    let byte_index = byte_index_from_char_index(text, index);
+    let mut cursor = GraphemeCursor::new(byte_index, text.len(), true);
+    match cursor.next_boundary(text, 0) {
+        Ok(Some(boundary)) => char_index_from_byte_index(text, boundary),
+        _ => CharIndex(text.chars().count()),
+    }
+}
+
+/// Is `index` between two extended grapheme clusters in `text` (or at the start or end)?
+///
+/// See [`previous_grapheme_boundary`].
+pub fn is_grapheme_boundary(text: &str, index: CharIndex) -> bool {
+    let byte_index = byte_index_from_char_index(text, index);
+    let mut cursor = GraphemeCursor::new(byte_index, text.len(), true);
+    cursor.is_boundary(text, 0).unwrap_or(true)
+}
+
+fn byte_index_from_char_index(text: &str, index: CharIndex) -> usize {
+    text.char_indices()
+        .nth(index.0)
+        .map_or(text.len(), |(byte_index, _)| byte_index)
+}
+
+fn char_index_from_byte_index(text: &str, byte_index: usize) -> CharIndex {
+    CharIndex(text[..byte_index].chars().count())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_grapheme_boundaries() {
+        let text = "ae\u{301}👨\u{200D}👩\u{200D}👧b";
+        let boundaries: Vec<usize> = (0..=text.chars().count())
+            .filter(|&i| is_grapheme_boundary(text, CharIndex(i)))
+            .collect();
+        assert_eq!(boundaries, [0, 1, 3, 8, 9]);
+
+        let mut index = CharIndex::ZERO;
+        let mut forward = vec![];
+        while index.0 < 9 {
+            index = next_grapheme_boundary(text, index);
+            forward.push(index.0);
+        }
+        assert_eq!(forward, [1, 3, 8, 9]);
+
+        let mut backward = vec![];
+        while CharIndex::ZERO < index {
+            index = previous_grapheme_boundary(text, index);
+            backward.push(index.0);
+        }
+        assert_eq!(backward, [8, 3, 1, 0]);
+
+        assert_eq!(previous_grapheme_boundary(text, CharIndex(5)).0, 3);
+        assert_eq!(next_grapheme_boundary(text, CharIndex(5)).0, 8);
+        assert_eq!(next_grapheme_boundary(text, CharIndex(9)).0, 9);
+        assert_eq!(previous_grapheme_boundary("", CharIndex::ZERO).0, 0);
+    }
+}
@@ -10,7 +10,7 @@ use vello_cpu::{color, kurbo};
 use crate::{
     TextOptions, TextureAtlas,
     text::{
-        FontTweak, VariationCoords,
+        CjkLineBreak, FontTweak, VariationCoords,
         fonts::{Blob, CachedFamily, FontFaceKey},
     },
 };
@@ -880,7 +880,31 @@ pub(super) fn is_cjk(c: char) -> bool {
 }
 
 #[inline]
-pub(super) fn is_cjk_break_allowed(c: char) -> bool {
+pub(super) fn is_hangul(c: char) -> bool {
+    ('\u{AC00}' <= c && c <= '\u{D7AF}') // Hangul Syllables
+        || ('\u{1100}' <= c && c <= '\u{11FF}') // Hangul Jamo
+        || ('\u{3130}' <= c && c <= '\u{318F}') // Hangul Compatibility Jamo
+}
+
+/// CJK symbols and punctuation, and full-width forms.
+#[inline]
+pub(super) fn is_cjk_punctuation(c: char) -> bool {
+    ('\u{3000}' <= c && c <= '\u{303F}') || ('\u{FF00}' <= c && c <= '\u{FFEF}')
+}
+
+/// May a row start with this character?
+#[inline]
+pub(super) fn is_cjk_break_allowed(c: char, rules: CjkLineBreak) -> bool {
     // See: https://en.wikipedia.org/wiki/Line_breaking_rules_in_East_Asian_languages#Characters_not_permitted_on_the_start_of_a_line.
-    !")]｝〕〉》」』】〙〗〟'\"｠»ヽヾーァィゥェォッャュョヮヵヶぁぃぅぇぉっゃゅょゎゕゖㇰㇱㇲㇳㇴㇵㇶㇷㇸㇹㇺㇻㇼㇽㇾㇿ々〻‐゠–〜?!‼⁇⁈⁉・、:;,。.".contains(c)
+    let never = ")]｝〕〉》」』】〙〗〟'\"｠»）］，．：；？！‐゠–〜?!‼⁇⁈⁉・、:;,。.";
+    let strict = "ヽヾーァィゥェォッャュョヮヵヶぁぃぅぇぉっゃゅょゎゕゖㇰㇱㇲㇳㇴㇵㇶㇷㇸㇹㇺㇻㇼㇽㇾㇿ々〻";
+    let is_forbidden = never.contains(c) || (rules == CjkLineBreak::Strict && strict.contains(c));
+    !is_forbidden
+}
+
+/// May a row end with this character?
+#[inline]
+pub(super) fn is_cjk_break_allowed_after(c: char) -> bool {
+    // See: https://en.wikipedia.org/wiki/Line_breaking_rules_in_East_Asian_languages#Characters_not_permitted_at_the_end_of_a_line.
+    !"([｛〔〈《「『【〘〖〝｟«（［".contains(c)
 }
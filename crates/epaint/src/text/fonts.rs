@@ -1206,7 +1206,7 @@ impl GalleyCache {
                 text: job.text[start..end].to_owned(),
                 wrap: crate::text::TextWrapping {
                     max_rows: max_rows_remaining,
-                    ..job.wrap
+                    ..job.wrap.clone()
                 },
                 sections: Vec::new(),
                 break_on_newline: job.break_on_newline,
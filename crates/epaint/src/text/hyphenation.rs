@@ -0,0 +1,258 @@
+//! Finding where words may be hyphenated, using Knuth-Liang patterns.
+
+/// Finds where words of a language may be broken with a hyphen.
+///
+/// This uses the Knuth-Liang algorithm, with patterns in the format of TeX hyphenation files
+/// (e.g. `hyph-en-us.pat.txt` from the `hyph-utf8` project).
+/// egui does not ship any patterns; load them for the languages you need.
+///
+/// Use it by setting [`super::TextWrapping::hyphenator`] and [`super::TextWrapping::hyphenate`].
+///
+/// ## Example:
+/// ```
+/// use epaint::text::Hyphenator;
+///
+/// let hyphenator = Hyphenator::new("en", "hy3ph he2n hena4 hen5at 1na n2at 1tio 2io o2n");
+/// assert_eq!(hyphenator.hyphenate("hyphenation"), ["hy", "phen", "ation"]);
+/// ```
+#[derive(Clone)]
+pub struct Hyphenator {
+    language: String,
+
+    /// Letters of a pattern (with `.` marking the start or end of a word)
+    /// to the values between them (one more than the number of letters).
+    patterns: ahash::HashMap<String, Vec<u8>>,
+
+    /// Number of letters in the longest pattern.
+    max_pattern_len: usize,
+
+    /// Lowercase words to the char offsets where they may be broken.
+    exceptions: ahash::HashMap<String, Vec<usize>>,
+
+    left_min: usize,
+    right_min: usize,
+
+    /// Hash of all of the above, for [`std::hash::Hash`] and [`PartialEq`].
+    hash: u64,
+}
+
+impl Hyphenator {
+    /// Create a hyphenator for a language, given as a BCP 47 tag like `"en-US"`.
+    ///
+    /// `patterns` are whitespace-separated Knuth-Liang patterns, e.g. `".ach4 a1b 4b1c"`.
+    /// Lines starting with `%` are comments.
+    pub fn new(language: impl Into<String>, patterns: &str) -> Self {
+        let mut parsed = ahash::HashMap::default();
+        let mut max_pattern_len = 0;
+        for pattern in words_without_comments(patterns) {
+            let mut letters = String::new();
+            let mut values = vec![0];
+            for c in pattern.chars() {
+                if let Some(digit) = c.to_digit(10) {
+                    *values.last_mut().unwrap_or(&mut 0) = digit as u8;
+                } else {
+                    letters.extend(c.to_lowercase());
+                    values.push(0);
+                }
+            }
+            max_pattern_len = max_pattern_len.max(values.len() - 1);
+            parsed.insert(letters, values);
+        }
+
+        let mut hyphenator = Self {
+            language: language.into(),
+            patterns: parsed,
+            max_pattern_len,
+            exceptions: Default::default(),
+            left_min: 2,
+            right_min: 3,
+            hash: 0,
+        };
+        hyphenator.update_hash();
+        hyphenator
+    }
+
+    /// Words that the patterns get wrong, with their hyphenation points as `-`,
+    /// e.g. `"ta-ble pro-ject"`.
+    pub fn with_exceptions(mut self, exceptions: &str) -> Self {
+        for word in words_without_comments(exceptions) {
+            let mut letters = String::new();
+            let mut points = vec![];
+            for c in word.chars() {
+                if c == '-' {
+                    points.push(letters.chars().count());
+                } else {
+                    letters.extend(c.to_lowercase());
+                }
+            }
+            self.exceptions.insert(letters, points);
+        }
+        self.update_hash();
+        self
+    }
+
+    /// The minimum number of letters to keep before and after a hyphen.
+    ///
+    /// Default: `(2, 3)`, as in TeX for English.
+    pub fn with_min_lengths(mut self, left_min: usize, right_min: usize) -> Self {
+        self.left_min = left_min.max(1);
+        self.right_min = right_min.max(1);
+        self.update_hash();
+        self
+    }
+
+    /// The language this hyphenator is for, as given to [`Self::new`].
+    pub fn language(&self) -> &str {
+        &self.language
+    }
+
+    /// The byte offsets into `word` where it may be broken with a hyphen.
+    ///
+    /// `word` should be a single word, without spaces or punctuation.
+    pub fn hyphenation_points(&self, word: &str) -> Vec<usize> {
+        let char_offsets: Vec<usize> = word.char_indices().map(|(i, _)| i).collect();
+        self.char_hyphenation_points(word)
+            .into_iter()
+            .map(|point| char_offsets[point])
+            .collect()
+    }
+
+    /// Split `word` into the parts between its hyphenation points.
+    pub fn hyphenate<'a>(&self, word: &'a str) -> Vec<&'a str> {
+        let mut parts = vec![];
+        let mut start = 0;
+        for point in self.hyphenation_points(word) {
+            parts.push(&word[start..point]);
+            start = point;
+        }
+        parts.push(&word[start..]);
+        parts
+    }
+
+    /// The char offsets into `word` where it may be broken with a hyphen.
+    pub(crate) fn char_hyphenation_points(&self, word: &str) -> Vec<usize> {
+        // One lowercase char per char, so that the offsets match `word`:
+        let lowercase: Vec<char> = word
+            .chars()
+            .map(|c| c.to_lowercase().next().unwrap_or(c))
+            .collect();
+        let num_chars = lowercase.len();
+        if num_chars < self.left_min + self.right_min {
+            return vec![];
+        }
+
+        let is_allowed =
+            |point: usize| self.left_min <= point && point + self.right_min <= num_chars;
+
+        let key: String = lowercase.iter().collect();
+        if let Some(points) = self.exceptions.get(&key) {
+            return points.iter().copied().filter(|&p| is_allowed(p)).collect();
+        }
+
+        // `values[i]` is between `dotted[i - 1]` and `dotted[i]`:
+        let dotted: Vec<char> = std::iter::once('.')
+            .chain(lowercase)
+            .chain(std::iter::once('.'))
+            .collect();
+        let mut values = vec![0_u8; dotted.len() + 1];
+        let mut substring = String::new();
+        for start in 0..dotted.len() {
+            substring.clear();
+            for &c in dotted[start..].iter().take(self.max_pattern_len) {
+                substring.push(c);
+                if let Some(pattern) = self.patterns.get(&substring) {
+                    for (i, &value) in pattern.iter().enumerate() {
+                        values[start + i] = values[start + i].max(value);
+                    }
+                }
+            }
+        }
+
+        // A break before char `point` of the word is between `dotted[point]` and `dotted[point + 1]`:
+        (1..num_chars)
+            .filter(|&point| values[point + 1] % 2 == 1 && is_allowed(point))
+            .collect()
+    }
+
+    fn update_hash(&mut self) {
+        let Self {
+            language,
+            patterns,
+            max_pattern_len: _, // Derived from `patterns`
+            exceptions,
+            left_min,
+            right_min,
+            hash,
+        } = self;
+        let mut patterns: Vec<_> = patterns.iter().collect();
+        patterns.sort_unstable();
+        let mut exceptions: Vec<_> = exceptions.iter().collect();
+        exceptions.sort_unstable();
+        *hash = crate::util::hash((&*language, patterns, exceptions, *left_min, *right_min));
+    }
+}
+
+impl std::fmt::Debug for Hyphenator {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Hyphenator")
+            .field("language", &self.language)
+            .field("patterns", &self.patterns.len())
+            .field("exceptions", &self.exceptions.len())
+            .finish_non_exhaustive()
+    }
+}
+
+impl PartialEq for Hyphenator {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.hash == other.hash
+    }
+}
+
+impl std::hash::Hash for Hyphenator {
+    #[inline]
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        self.hash.hash(state);
+    }
+}
+
+fn words_without_comments(text: &str) -> impl Iterator<Item = &str> {
+    text.lines()
+        .map(|line| line.split_once('%').map_or(line, |(before, _)| before))
+        .flat_map(str::split_whitespace)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// The example patterns from Liang's thesis.
+    const PATTERNS: &str = "
+        % Enough for `hyphenation`:
+        hy3ph he2n hena4 hen5at 1na n2at 1tio 2io o2n
+    ";
+
+    #[test]
+    fn test_patterns() {
+        let hyphenator = Hyphenator::new("en", PATTERNS);
+        assert_eq!(hyphenator.hyphenate("hyphenation"), ["hy", "phen", "ation"]);
+        assert_eq!(hyphenator.hyphenate("Hyphenation"), ["Hy", "phen", "ation"]);
+        assert_eq!(hyphenator.hyphenation_points("hyphenation"), [2, 6]);
+        assert_eq!(hyphenator.hyphenate("on"), ["on"], "Too short");
+
+        let hyphenator = hyphenator.with_min_lengths(3, 3);
+        assert_eq!(hyphenator.hyphenate("hyphenation"), ["hyphen", "ation"]);
+    }
+
+    #[test]
+    fn test_exceptions() {
+        let hyphenator = Hyphenator::new("en", PATTERNS).with_exceptions("hyphen-ation tab-le");
+        assert_eq!(hyphenator.hyphenate("hyphenation"), ["hyphen", "ation"]);
+        assert_eq!(
+            hyphenator.hyphenate("table"),
+            ["table"],
+            "Breaks closer to the end than `right_min` are ignored"
+        );
+        assert_ne!(hyphenator, Hyphenator::new("en", PATTERNS));
+    }
+}
@@ -3,6 +3,7 @@
 pub mod cursor;
 mod font;
 mod fonts;
+mod hyphenation;
 mod index;
 mod text_layout;
 mod text_layout_types;
@@ -13,6 +14,7 @@ pub use {
         FontVariationAxis, Fonts, FontsImpl, FontsView, HintingTarget, InsertFontFamily,
         SmoothHinting,
     },
+    hyphenation::Hyphenator,
     index::{ByteIndex, ByteRange, ByteRangeExt, CharIndex, CharRange, CharRangeExt},
     text_layout::*,
     text_layout_types::*,
@@ -4,25 +4,32 @@ use std::sync::Arc;
 use std::{iter, ops::Range};
 
 use emath::{Align, GuiRounding as _, NumExt as _, Pos2, Rect, Vec2, pos2, vec2};
+use smallvec::SmallVec;
 
 use crate::{
     Color32, Mesh, Stroke, Vertex,
     stroke::PathStroke,
     text::{
         ByteIndex, ByteRange,
-        font::{StyledMetrics, UvRect, is_cjk, is_cjk_break_allowed},
+        font::{
+            StyledMetrics, UvRect, is_cjk, is_cjk_break_allowed, is_cjk_break_allowed_after,
+            is_cjk_punctuation, is_hangul,
+        },
         fonts::FontFaceKey,
     },
 };
 
 use super::{
-    ByteRangeExt as _, FontsImpl, Galley, Glyph, LayoutJob, LayoutSection, PlacedRow, Row,
-    RowVisuals, VariationCoords,
+    ByteRangeExt as _, CjkLineBreak, FontFeatures, FontId, FontsImpl, Galley, Glyph, LayoutJob,
+    LayoutSection, PlacedRow, Row, RowVisuals, TextFormat, TextWrapping, VariationCoords,
     font::{Font, FontFace, ShapedGlyph},
 };
 
 // ----------------------------------------------------------------------------
 
+/// Invisible, unless a row is broken at it. See [`super::TextWrapping::hyphenate`].
+const SOFT_HYPHEN: char = '\u{AD}';
+
 /// Returns `true` if the character is a Unicode combining mark (categories Mn, Mc, Me).
 ///
 /// These characters modify the preceding base character and should not be
@@ -38,6 +45,12 @@ fn is_combining_mark(c: char) -> bool {
     )
 }
 
+/// Characters that [`TextFormat::extra_word_spacing`] applies to.
+#[inline]
+fn is_word_separator(c: char) -> bool {
+    matches!(c, ' ' | '\u{A0}' | '\u{1361}')
+}
+
 /// Represents GUI scale and convenience methods for rounding to pixels.
 #[derive(Clone, Copy)]
 struct PointScale {
@@ -143,7 +156,28 @@ pub fn layout(fonts: &mut FontsImpl, pixels_per_point: f32, job: Arc<LayoutJob>)
     let intrinsic_size = calculate_intrinsic_size(point_scale, &job, &paragraphs);
 
     let mut elided = false;
-    let mut rows = rows_from_paragraphs(paragraphs, &job, pixels_per_point, &mut elided);
+    let mut hyphenated_rows = vec![];
+    let mut rows = rows_from_paragraphs(
+        paragraphs,
+        &job,
+        pixels_per_point,
+        &mut elided,
+        &mut hyphenated_rows,
+    );
+    let num_rows = rows.len();
+    for row_idx in hyphenated_rows {
+        if elided && row_idx + 1 == num_rows {
+            continue; // This row gets an overflow character instead.
+        }
+        if let Some(placed_row) = rows.get_mut(row_idx) {
+            add_hyphen(
+                fonts,
+                pixels_per_point,
+                &job,
+                Arc::make_mut(&mut placed_row.row),
+            );
+        }
+    }
     if elided && let Some(last_placed) = rows.last_mut() {
         let last_row = Arc::make_mut(&mut last_placed.row);
         replace_last_glyph_with_overflow_character(fonts, pixels_per_point, &job, last_row);
@@ -170,6 +204,21 @@ pub fn layout(fonts: &mut FontsImpl, pixels_per_point: f32, job: Arc<LayoutJob>)
         }
     }
 
+    if job
+        .sections
+        .iter()
+        .any(|section| section.format.ruby.is_some())
+    {
+        for placed_row in &mut rows {
+            add_ruby(
+                fonts,
+                pixels_per_point,
+                &job,
+                Arc::make_mut(&mut placed_row.row),
+            );
+        }
+    }
+
     // Calculate the Y positions and tessellate the text:
     galley_from_rows(point_scale, job, rows, elided, intrinsic_size)
 }
@@ -180,6 +229,7 @@ struct ShapingContext {
     font_size: f32,
     line_height: f32,
     extra_letter_spacing: f32,
+    extra_word_spacing: f32,
     section_index: u32,
     font_metrics: StyledMetrics,
     is_first_glyph_in_section: bool,
@@ -227,6 +277,10 @@ struct TextRun {
 /// ligatures), zero-width "continuation" glyphs are emitted for the extra
 /// characters so that `glyphs.len() == char_count` — an invariant that all
 /// cursor and selection code relies on.
+///
+/// Right-to-left runs (e.g. Arabic and Hebrew) come out of the shaper in visual order.
+/// We position them in visual order, but then put the glyphs back into logical order,
+/// so that glyph `i` still corresponds to character `i`.
 fn layout_shaped_run(
     font: &mut Font<'_>,
     run: &TextRun,
@@ -242,11 +296,24 @@ fn layout_shaped_run(
     // so they are not comparable across runs.
     ctx.prev_cluster = None;
 
+    // Clusters are byte offsets, so they decrease in a right-to-left run:
+    let is_rtl = match glyph_buffer.glyph_infos() {
+        [first, .., last] => last.cluster < first.cluster,
+        _ => false,
+    };
+
     // Track how many glyphs we emit per cluster so we can add zero-width
     // continuation glyphs when a cluster has more chars than glyphs.
     let mut cluster_start_byte: usize = 0;
     let mut cluster_glyph_count: usize = 0;
 
+    // In a right-to-left run, a cluster ends where the previous (visual) one started.
+    let mut cluster_end_byte = run_text.len();
+
+    // Where in `paragraph.glyphs` each cluster starts, for reordering right-to-left runs.
+    let run_start_glyph = paragraph.glyphs.len();
+    let mut cluster_start_glyphs = vec![];
+
     for (info, pos) in iter::zip(glyph_buffer.glyph_infos(), glyph_buffer.glyph_positions()) {
         let glyph_id = skrifa::GlyphId::new(info.glyph_id);
         let cluster = info.cluster;
@@ -279,29 +346,51 @@ fn layout_shaped_run(
             advance_width_px = thin_space_width * space_width_px;
         }
 
+        // Word spacing is part of the advance width, so the cursor and selection cover it:
+        if is_word_separator(chr) {
+            advance_width_px += ctx.extra_word_spacing * ctx.pixels_per_point;
+        }
+
         // Apply extra_letter_spacing only at cluster boundaries,
         // never between glyphs within the same cluster (e.g. base + mark).
         let is_new_cluster = ctx.prev_cluster.is_none_or(|pc| pc != cluster);
         if is_new_cluster {
             if ctx.prev_cluster.is_some() {
+                let cluster_bytes = if is_rtl {
+                    cluster_start_byte..cluster_end_byte
+                } else {
+                    cluster_start_byte..cluster as usize
+                };
                 emit_continuation_glyphs(
                     ctx,
                     paragraph,
                     run_text,
-                    cluster_start_byte..cluster as usize,
+                    cluster_bytes,
                     cluster_glyph_count,
                     face_metrics,
                 );
+                cluster_end_byte = cluster_start_byte;
             }
             if !ctx.is_first_glyph_in_section {
                 paragraph.cursor_x_px += ctx.extra_letter_spacing * ctx.pixels_per_point;
             }
+            cluster_start_glyphs.push(paragraph.glyphs.len());
             cluster_start_byte = cluster as usize;
             cluster_glyph_count = 0;
             ctx.is_first_glyph_in_section = false;
         }
         ctx.prev_cluster = Some(cluster);
 
+        if chr == SOFT_HYPHEN {
+            // Only shown if we break the row here, and then as a separate `Row::hyphen`.
+            let physical_x = paragraph.cursor_x_px.round() as i32;
+            paragraph
+                .glyphs
+                .push(ctx.glyph(chr, physical_x, 0.0, face_metrics, UvRect::default()));
+            cluster_glyph_count += 1;
+            continue;
+        }
+
         let glyph = if glyph_id == skrifa::GlyphId::NOTDEF {
             // The shaper couldn't map this character. Drop combining marks
             // (Unicode category M) and duplicate NOTDEF glyphs within the same
@@ -382,15 +471,33 @@ fn layout_shaped_run(
 
     // Emit continuation glyphs for the last cluster in the run.
     if ctx.prev_cluster.is_some() {
+        let cluster_end_byte = if is_rtl {
+            cluster_end_byte
+        } else {
+            run_text.len()
+        };
         emit_continuation_glyphs(
             ctx,
             paragraph,
             run_text,
-            cluster_start_byte..run_text.len(),
+            cluster_start_byte..cluster_end_byte,
             cluster_glyph_count,
             face_metrics,
         );
     }
+
+    if is_rtl {
+        // Reverse the order of the clusters, but keep the order of the glyphs within each cluster:
+        let run_glyphs = paragraph.glyphs.split_off(run_start_glyph);
+        let mut cluster_end = run_glyphs.len();
+        for &cluster_start in cluster_start_glyphs.iter().rev() {
+            let cluster_start = cluster_start - run_start_glyph;
+            if let Some(cluster_glyphs) = run_glyphs.get(cluster_start..cluster_end) {
+                paragraph.glyphs.extend_from_slice(cluster_glyphs);
+            }
+            cluster_end = cluster_start;
+        }
+    }
 }
 
 /// Emit zero-width continuation glyphs when a cluster has more characters than
@@ -448,6 +555,7 @@ fn layout_section(
         .line_height
         .unwrap_or(font_metrics.row_height);
     let extra_letter_spacing = section.format.extra_letter_spacing;
+    let extra_word_spacing = section.format.extra_word_spacing;
 
     let mut paragraph = out_paragraphs.last_mut().unwrap();
     if paragraph.glyphs.is_empty() {
@@ -461,6 +569,7 @@ fn layout_section(
         font_size,
         line_height,
         extra_letter_spacing,
+        extra_word_spacing,
         section_index,
         font_metrics,
         is_first_glyph_in_section: paragraph.glyphs.is_empty(),
@@ -502,7 +611,14 @@ fn layout_section(
                 flags |= harfrust::BufferFlags::END_OF_TEXT;
             }
 
-            let glyph_buffer = shape_text(font_face, run_text, &format.coords, shape_buffer, flags);
+            let glyph_buffer = shape_text(
+                font_face,
+                run_text,
+                &format.coords,
+                &format.font_features,
+                shape_buffer,
+                flags,
+            );
 
             layout_shaped_run(
                 font,
@@ -588,6 +704,7 @@ fn rows_from_paragraphs(
     job: &LayoutJob,
     pixels_per_point: f32,
     elided: &mut bool,
+    hyphenated_rows: &mut Vec<usize>,
 ) -> Vec<PlacedRow> {
     let num_paragraphs = paragraphs.len();
 
@@ -607,6 +724,8 @@ fn rows_from_paragraphs(
                 row: Arc::new(Row {
                     section_index_at_start: paragraph.section_index_at_start,
                     glyphs: vec![],
+                    hyphen: None,
+                    ruby: vec![],
                     visuals: Default::default(),
                     size: vec2(0.0, paragraph.empty_paragraph_height),
                 }),
@@ -624,13 +743,15 @@ fn rows_from_paragraphs(
                     row: Arc::new(Row {
                         section_index_at_start: paragraph.section_index_at_start,
                         glyphs: paragraph.glyphs,
+                        hyphen: None,
+                        ruby: vec![],
                         visuals: Default::default(),
                         size: vec2(paragraph_width, 0.0),
                     }),
                     ends_with_newline: !is_last_paragraph,
                 });
             } else {
-                line_break(&paragraph, job, &mut rows, elided);
+                line_break(&paragraph, job, &mut rows, elided, hyphenated_rows);
                 let placed_row = rows.last_mut().unwrap();
                 placed_row.ends_with_newline = !is_last_paragraph;
             }
@@ -640,17 +761,23 @@ fn rows_from_paragraphs(
     rows
 }
 
+/// Breaks a paragraph into rows.
+///
+/// The indices of rows that end in the middle of a word are pushed to `hyphenated_rows`.
 fn line_break(
     paragraph: &Paragraph,
     job: &LayoutJob,
     out_rows: &mut Vec<PlacedRow>,
     elided: &mut bool,
+    hyphenated_rows: &mut Vec<usize>,
 ) {
     let wrap_width = job.effective_wrap_width();
 
     // Keeps track of good places to insert row break if we exceed `wrap_width`.
     let mut row_break_candidates = RowBreakCandidates::default();
 
+    let hyphenation_points = hyphenation_points(&paragraph.glyphs, &job.wrap);
+
     let mut first_row_indentation = paragraph.glyphs[0].pos.x;
     let mut row_start_x = 0.0;
     let mut row_start_idx = 0;
@@ -676,6 +803,8 @@ fn line_break(
                     row: Arc::new(Row {
                         section_index_at_start: paragraph.section_index_at_start,
                         glyphs: vec![],
+                        hyphen: None,
+                        ruby: vec![],
                         visuals: Default::default(),
                         size: Vec2::ZERO,
                     }),
@@ -697,11 +826,17 @@ fn line_break(
                 let section_index_at_start = glyphs[0].section_index;
                 let paragraph_max_x = glyphs.last().unwrap().max_x();
 
+                if row_break_candidates.hyphen == Some(last_kept_index) {
+                    hyphenated_rows.push(out_rows.len());
+                }
+
                 out_rows.push(PlacedRow {
                     pos: pos2(0.0, f32::NAN),
                     row: Arc::new(Row {
                         section_index_at_start,
                         glyphs,
+                        hyphen: None,
+                        ruby: vec![],
                         visuals: Default::default(),
                         size: vec2(paragraph_max_x, 0.0),
                     }),
@@ -717,7 +852,17 @@ fn line_break(
             }
         }
 
-        row_break_candidates.add(i, &paragraph.glyphs[i..]);
+        let is_inside_ruby_base = paragraph.glyphs.get(i + 1).is_some_and(|next| {
+            let section_index = paragraph.glyphs[i].section_index;
+            next.section_index == section_index
+                && job.sections[section_index as usize].format.ruby.is_some()
+        });
+        if !is_inside_ruby_base {
+            row_break_candidates.add(i, &paragraph.glyphs[i..], &job.wrap);
+            if hyphenation_points.binary_search(&i).is_ok() {
+                row_break_candidates.hyphen = Some(i);
+            }
+        }
     }
 
     if row_start_idx < paragraph.glyphs.len() {
@@ -745,6 +890,8 @@ fn line_break(
                 row: Arc::new(Row {
                     section_index_at_start,
                     glyphs,
+                    hyphen: None,
+                    ruby: vec![],
                     visuals: Default::default(),
                     size: vec2(paragraph_max_x - paragraph_min_x, 0.0),
                 }),
@@ -754,6 +901,41 @@ fn line_break(
     }
 }
 
+/// The sorted indices of the glyphs after which [`TextWrapping::hyphenator`] allows breaking a word.
+fn hyphenation_points(glyphs: &[Glyph], wrap: &TextWrapping) -> Vec<usize> {
+    let Some(hyphenator) = wrap.hyphenator.as_deref().filter(|_| wrap.hyphenate) else {
+        return vec![];
+    };
+
+    let mut points = vec![];
+    let mut word = String::new();
+    let mut start = 0;
+    while start < glyphs.len() {
+        let len = glyphs[start..]
+            .iter()
+            .take_while(|glyph| glyph.chr.is_alphabetic() || glyph.chr == SOFT_HYPHEN)
+            .count();
+        if len == 0 {
+            start += 1;
+            continue;
+        }
+
+        word.clear();
+        word.extend(glyphs[start..start + len].iter().map(|glyph| glyph.chr));
+        // Words with soft hyphens are only broken at those:
+        if !word.contains(SOFT_HYPHEN) {
+            points.extend(
+                hyphenator
+                    .char_hyphenation_points(&word)
+                    .into_iter()
+                    .map(|point| start + point - 1),
+            );
+        }
+        start += len;
+    }
+    points
+}
+
 /// Trims the last glyphs in the row and replaces it with an overflow character (e.g. `…`).
 ///
 /// Called before we have any Y coordinates.
@@ -850,6 +1032,124 @@ fn replace_last_glyph_with_overflow_character(
     }
 }
 
+/// Adds a `-` after the last glyph of a row that was broken in the middle of a word.
+///
+/// Called before we have any Y coordinates.
+fn add_hyphen(fonts: &mut FontsImpl, pixels_per_point: f32, job: &LayoutJob, row: &mut Row) {
+    const HYPHEN: char = '-';
+
+    let Some(last_glyph) = row.glyphs.last() else {
+        return;
+    };
+    let section_index = last_glyph.section_index;
+    let section = &job.sections[section_index as usize];
+    let hyphen_x = last_glyph.max_x() + section.format.extra_letter_spacing;
+
+    let mut font = fonts.font(&section.format.font_id.family);
+    let font_size = section.format.font_id.size;
+
+    let font_id = font.resolve_face(HYPHEN);
+    let font_face_metrics = font
+        .fonts_by_id
+        .get(&font_id)
+        .map(|f| f.styled_metrics(pixels_per_point, font_size, &section.format.coords))
+        .unwrap_or_default();
+    let (_, glyph_info) = font.glyph_info(HYPHEN, &font_face_metrics);
+    let advance_width_px = glyph_info.advance_width_unscaled.0 * font_face_metrics.px_scale_factor;
+
+    let (glyph_alloc, physical_x) = font
+        .fonts_by_id
+        .get_mut(&font_id)
+        .map(|f| {
+            f.allocate_glyph(
+                font.atlas,
+                &font_face_metrics,
+                &ShapedGlyph {
+                    glyph_id: glyph_info.id.unwrap_or(skrifa::GlyphId::NOTDEF),
+                    h_pos: hyphen_x * pixels_per_point,
+                    is_cjk: false,
+                },
+            )
+        })
+        .unwrap_or_default();
+
+    let font_metrics = font.styled_metrics(pixels_per_point, font_size, &section.format.coords);
+    let line_height = section
+        .format
+        .line_height
+        .unwrap_or(font_metrics.row_height);
+
+    let hyphen = Glyph {
+        chr: HYPHEN,
+        pos: pos2(physical_x as f32 / pixels_per_point, f32::NAN),
+        advance_width: advance_width_px / pixels_per_point,
+        line_height,
+        font_face_height: font_face_metrics.row_height,
+        font_face_ascent: font_face_metrics.ascent,
+        font_height: font_metrics.row_height,
+        font_ascent: font_metrics.ascent,
+        uv_rect: glyph_alloc.uv_rect,
+        section_index,
+        first_vertex: 0, // filled in later
+    };
+    row.size.x = hyphen.max_x();
+    row.hyphen = Some(hyphen);
+}
+
+/// How large ruby annotations are, relative to their base text.
+const RUBY_SCALE: f32 = 0.5;
+
+/// Lay out the ruby annotations (see [`super::TextFormat::ruby`]) of the base texts in this row.
+///
+/// The annotations are centered horizontally over their base text.
+/// They are positioned vertically in [`galley_from_rows`].
+fn add_ruby(fonts: &mut FontsImpl, pixels_per_point: f32, job: &LayoutJob, row: &mut Row) {
+    let mut base_start = 0;
+    while let Some(first) = row.glyphs.get(base_start) {
+        let section_index = first.section_index;
+        let base_len = row.glyphs[base_start..]
+            .iter()
+            .take_while(|glyph| glyph.section_index == section_index)
+            .count();
+        let base = &row.glyphs[base_start..base_start + base_len];
+        base_start += base_len;
+
+        let format = &job.sections[section_index as usize].format;
+        let Some(ruby) = &format.ruby else {
+            continue;
+        };
+        let base_center_x = 0.5 * (base[0].pos.x + base[base_len - 1].max_x());
+
+        let ruby_format = TextFormat {
+            font_id: FontId::new(
+                RUBY_SCALE * format.font_id.size,
+                format.font_id.family.clone(),
+            ),
+            extra_letter_spacing: RUBY_SCALE * format.extra_letter_spacing,
+            extra_word_spacing: RUBY_SCALE * format.extra_word_spacing,
+            line_height: None,
+            background: Color32::TRANSPARENT,
+            underline: Stroke::NONE,
+            strikethrough: Stroke::NONE,
+            ruby: None,
+            ..format.clone()
+        };
+        let mut ruby_job = LayoutJob::single_section(ruby.to_string(), ruby_format);
+        ruby_job.round_output_to_gui = false;
+        let ruby_galley = layout(fonts, pixels_per_point, Arc::new(ruby_job));
+
+        for ruby_row in &ruby_galley.rows {
+            let offset_x = (base_center_x - 0.5 * ruby_row.size.x).at_least(0.0);
+            row.ruby.extend(ruby_row.glyphs.iter().map(|glyph| Glyph {
+                pos: pos2(glyph.pos.x + offset_x, f32::NAN),
+                section_index, // For the color etc.
+                ..*glyph
+            }));
+            row.size.x = row.size.x.max(offset_x + ruby_row.size.x);
+        }
+    }
+}
+
 /// Horizontally aligned the text on a row.
 ///
 /// Ignores the Y coordinate.
@@ -890,11 +1190,18 @@ fn halign_and_justify_row(
 
         (num_leading_spaces, row.glyphs.len() - num_trailing_spaces)
     };
-    let num_glyphs_in_range = glyph_range.1 - glyph_range.0;
+    // The hyphen is treated as one extra glyph at the end of the row:
+    let num_glyphs_in_range = glyph_range.1 - glyph_range.0 + row.hyphen.is_some() as usize;
     assert!(num_glyphs_in_range > 0, "Should have at least one glyph");
 
     let original_min_x = row.glyphs[glyph_range.0].logical_rect().min.x;
-    let original_max_x = row.glyphs[glyph_range.1 - 1].logical_rect().max.x;
+    let original_max_x = row
+        .hyphen
+        .as_ref()
+        .unwrap_or(&row.glyphs[glyph_range.1 - 1])
+        .logical_rect()
+        .max
+        .x;
     let original_width = original_max_x - original_min_x;
 
     let target_width = if justify && num_glyphs_in_range > 1 {
@@ -937,7 +1244,7 @@ fn halign_and_justify_row(
     placed_row.pos.x = point_scale.round_to_pixel(target_min_x);
     let mut translate_x = -original_min_x - extra_x_per_glyph * glyph_range.0 as f32;
 
-    for glyph in &mut row.glyphs {
+    for glyph in row.glyphs.iter_mut().chain(row.hyphen.as_mut()) {
         glyph.pos.x += translate_x;
         glyph.pos.x = point_scale.round_to_pixel(glyph.pos.x);
         translate_x += extra_x_per_glyph;
@@ -971,11 +1278,19 @@ fn galley_from_rows(
         }
         max_row_height = point_scale.round_to_pixel(max_row_height);
 
+        // Ruby annotations go in a band above the rest of the row:
+        let ruby_height = row
+            .ruby
+            .iter()
+            .map(|glyph| glyph.line_height)
+            .fold(0.0, f32::max);
+        let ruby_height = point_scale.round_to_pixel(ruby_height);
+
         // Now position each glyph vertically:
-        for glyph in &mut row.glyphs {
+        for glyph in row.glyphs.iter_mut().chain(row.hyphen.as_mut()) {
             let format = &job.sections[glyph.section_index as usize].format;
 
-            glyph.pos.y = glyph.font_face_ascent
+            glyph.pos.y = ruby_height + glyph.font_face_ascent
 
                 // Apply valign to the different in height of the entire row, and the height of this `Font`:
                 + format.valign.to_factor() * (max_row_height - glyph.line_height)
@@ -986,11 +1301,17 @@ fn galley_from_rows(
 
             glyph.pos.y = point_scale.round_to_pixel(glyph.pos.y);
         }
+        for glyph in &mut row.ruby {
+            glyph.pos.y = glyph.font_face_ascent
+                + (ruby_height - glyph.line_height)
+                + 0.5 * (glyph.font_height - glyph.font_face_height);
+            glyph.pos.y = point_scale.round_to_pixel(glyph.pos.y);
+        }
 
         placed_row.pos.y = cursor_y;
-        row.size.y = max_row_height;
+        row.size.y = ruby_height + max_row_height;
 
-        cursor_y += max_row_height;
+        cursor_y += row.size.y;
         cursor_y = point_scale.round_to_pixel(cursor_y); // TODO(emilk): it would be better to do the calculations in pixels instead.
     }
 
@@ -1012,7 +1333,12 @@ fn galley_from_rows(
         num_indices += row.visuals.mesh.indices.len();
 
         row.section_index_at_start = u32::MAX; // No longer in use.
-        for glyph in &mut row.glyphs {
+        for glyph in row
+            .glyphs
+            .iter_mut()
+            .chain(row.hyphen.as_mut())
+            .chain(&mut row.ruby)
+        {
             glyph.section_index = u32::MAX; // No longer in use.
         }
     }
@@ -1153,7 +1479,12 @@ fn add_row_backgrounds(point_scale: PointScale, job: &LayoutJob, row: &Row, mesh
 }
 
 fn tessellate_glyphs(point_scale: PointScale, job: &LayoutJob, row: &mut Row, mesh: &mut Mesh) {
-    for glyph in &mut row.glyphs {
+    for glyph in row
+        .glyphs
+        .iter_mut()
+        .chain(row.hyphen.as_mut())
+        .chain(&mut row.ruby)
+    {
         glyph.first_vertex = mesh.vertices.len() as u32;
         let uv_rect = glyph.uv_rect;
         if !uv_rect.is_nothing() {
@@ -1270,6 +1601,10 @@ struct RowBreakCandidates {
     /// good idea.
     dash: Option<usize>,
 
+    /// Breaking at a soft hyphen, if [`super::TextWrapping::hyphenate`] is set.
+    /// Preferred over breaking at an earlier space, so that rows are filled.
+    hyphen: Option<usize>,
+
     /// This is nicer for things like URLs, e.g. www.
     /// example.com.
     punctuation: Option<usize>,
@@ -1280,25 +1615,42 @@ struct RowBreakCandidates {
 }
 
 impl RowBreakCandidates {
-    fn add(&mut self, index: usize, glyphs: &[Glyph]) {
+    /// Consider breaking after `glyphs[0]`, which is at `index`.
+    fn add(&mut self, index: usize, glyphs: &[Glyph], wrap: &TextWrapping) {
         let chr = glyphs[0].chr;
+        let next_chr = glyphs.get(1).map(|glyph| glyph.chr);
+        let is_cjk_like = |c: char| {
+            is_cjk(c)
+                || is_cjk_punctuation(c)
+                || (wrap.cjk_line_break == CjkLineBreak::BreakAll && is_hangul(c))
+        };
+        let cjk_break_allowed = || {
+            wrap.cjk_line_break != CjkLineBreak::KeepAll
+                && is_cjk_break_allowed_after(chr)
+                && next_chr.is_none_or(|next| is_cjk_break_allowed(next, wrap.cjk_line_break))
+        };
+
         const NON_BREAKING_SPACE: char = '\u{A0}';
         if chr.is_whitespace() && chr != NON_BREAKING_SPACE {
             self.space = Some(index);
-        } else if is_cjk(chr) && (glyphs.len() == 1 || is_cjk_break_allowed(glyphs[1].chr)) {
-            self.cjk = Some(index);
+        } else if wrap.hyphenate && chr == SOFT_HYPHEN {
+            self.hyphen = Some(index);
+        } else if is_cjk_like(chr) {
+            if cjk_break_allowed() {
+                self.cjk = Some(index);
+            }
         } else if chr == '-' {
             self.dash = Some(index);
         } else if chr.is_ascii_punctuation() {
             self.punctuation = Some(index);
-        } else if glyphs.len() > 1 && is_cjk(glyphs[1].chr) {
+        } else if next_chr.is_some_and(is_cjk_like) && cjk_break_allowed() {
             self.pre_cjk = Some(index);
         }
         self.any = Some(index);
     }
 
     fn word_boundary(&self) -> Option<usize> {
-        [self.space, self.cjk, self.pre_cjk]
+        [self.space, self.cjk, self.pre_cjk, self.hyphen]
             .into_iter()
             .max()
             .flatten()
@@ -1329,6 +1681,7 @@ impl RowBreakCandidates {
             cjk,
             pre_cjk,
             dash,
+            hyphen,
             punctuation,
             any,
         } = self;
@@ -1344,6 +1697,9 @@ impl RowBreakCandidates {
         if dash.is_some_and(|s| s < index) {
             *dash = None;
         }
+        if hyphen.is_some_and(|s| s < index) {
+            *hyphen = None;
+        }
         if punctuation.is_some_and(|s| s < index) {
             *punctuation = None;
         }
@@ -1402,6 +1758,7 @@ fn shape_text(
     font_face: &FontFace,
     text: &str,
     coords: &VariationCoords,
+    font_features: &FontFeatures,
     mut buffer: harfrust::UnicodeBuffer,
     flags: harfrust::BufferFlags,
 ) -> harfrust::GlyphBuffer {
@@ -1431,7 +1788,13 @@ fn shape_text(
     buffer.push_str(text);
     buffer.guess_segment_properties();
 
-    shaper.shape(buffer, &[])
+    let features: SmallVec<[harfrust::Feature; 4]> = font_features
+        .shaper_features()
+        .into_iter()
+        .map(|(tag, value)| harfrust::Feature::new(tag, value, ..))
+        .collect();
+
+    shaper.shape(buffer, &features)
 }
 
 // ----------------------------------------------------------------------------
@@ -1518,6 +1881,93 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_kinsoku() {
+        let pixels_per_point = 1.0;
+        let mut fonts = FontsImpl::new(TextOptions::default(), FontDefinitions::default());
+        let text = "これは「テスト」です。ちょっと長い文章ですね。";
+
+        for rules in [CjkLineBreak::Strict, CjkLineBreak::Normal] {
+            // Narrower than this, there are not always allowed break points within a row:
+            for max_width in (60..200).step_by(5) {
+                let mut layout_job = LayoutJob::single_section(text.into(), TextFormat::default());
+                layout_job.wrap.max_width = max_width as f32;
+                layout_job.wrap.cjk_line_break = rules;
+                let galley = layout(&mut fonts, pixels_per_point, layout_job.into());
+                for row in &galley.rows {
+                    let row_text = row.text();
+                    let first = row_text.chars().next().unwrap();
+                    let last = row_text.chars().last().unwrap();
+                    assert!(
+                        !"」。、".contains(first),
+                        "Row may not start with {first:?}: {row_text:?} ({rules:?})"
+                    );
+                    assert!(
+                        last != '「',
+                        "Row may not end with {last:?}: {row_text:?} ({rules:?})"
+                    );
+                    if rules == CjkLineBreak::Strict {
+                        assert!(
+                            !"ょっ".contains(first),
+                            "Row may not start with small kana: {row_text:?}"
+                        );
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_cjk_keep_all() {
+        let pixels_per_point = 1.0;
+        let mut fonts = FontsImpl::new(TextOptions::default(), FontDefinitions::default());
+        let word = LayoutJob::single_section("안녕하세요".into(), TextFormat::default());
+        let word_width = layout(&mut fonts, pixels_per_point, word.into()).size().x;
+
+        let mut layout_job =
+            LayoutJob::single_section("안녕하세요 세계".into(), TextFormat::default());
+        layout_job.wrap.max_width = 1.2 * word_width;
+        layout_job.wrap.cjk_line_break = CjkLineBreak::for_language("ko-KR");
+        let galley = layout(&mut fonts, pixels_per_point, layout_job.into());
+        assert_eq!(
+            galley.rows.iter().map(|row| row.text()).collect::<Vec<_>>(),
+            vec!["안녕하세요 ", "세계"]
+        );
+    }
+
+    #[test]
+    fn test_korean_breaks_at_spaces() {
+        let pixels_per_point = 1.0;
+        let mut fonts = FontsImpl::new(TextOptions::default(), FontDefinitions::default());
+        let word = LayoutJob::single_section("안녕하세요".into(), TextFormat::default());
+        let word_width = layout(&mut fonts, pixels_per_point, word.into()).size().x;
+
+        let wrap = |fonts: &mut FontsImpl, rules: CjkLineBreak| {
+            let mut layout_job =
+                LayoutJob::single_section("안녕하세요 세계입니다".into(), TextFormat::default());
+            layout_job.wrap.max_width = 1.5 * word_width;
+            layout_job.wrap.cjk_line_break = rules;
+            let galley = layout(fonts, pixels_per_point, layout_job.into());
+            galley.rows.iter().map(|row| row.text()).collect::<Vec<_>>()
+        };
+
+        // Hangul is kept together by default:
+        assert_eq!(
+            wrap(&mut fonts, CjkLineBreak::default()),
+            vec!["안녕하세요 ", "세계입니다"]
+        );
+        assert_eq!(
+            wrap(&mut fonts, CjkLineBreak::Normal),
+            vec!["안녕하세요 ", "세계입니다"]
+        );
+
+        // …unless asked for:
+        let rows = wrap(&mut fonts, CjkLineBreak::BreakAll);
+        assert_eq!(rows.len(), 2);
+        assert_ne!(rows[0], "안녕하세요 ");
+        assert!(rows[0].starts_with("안녕하세요 세"), "{rows:?}");
+    }
+
     #[test]
     fn test_pre_cjk() {
         let pixels_per_point = 1.0;
@@ -1534,6 +1984,204 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_soft_hyphen() {
+        let pixels_per_point = 1.0;
+        let mut fonts = FontsImpl::new(TextOptions::default(), FontDefinitions::default());
+        let text = "An extra\u{AD}ordinary word";
+
+        let unwrapped = LayoutJob::single_section(text.into(), TextFormat::default());
+        let galley = layout(&mut fonts, pixels_per_point, unwrapped.into());
+        let without_soft_hyphen =
+            LayoutJob::single_section("An extraordinary word".into(), TextFormat::default());
+        let reference = layout(&mut fonts, pixels_per_point, without_soft_hyphen.into());
+        assert_eq!(
+            galley.size(),
+            reference.size(),
+            "Soft hyphens should be invisible"
+        );
+        assert!(galley.rows[0].hyphen.is_none());
+
+        for hyphenate in [false, true] {
+            let mut job = LayoutJob::single_section(text.into(), TextFormat::default());
+            job.wrap.max_width = 60.0;
+            job.wrap.hyphenate = hyphenate;
+            let galley = layout(&mut fonts, pixels_per_point, job.into());
+            let row_texts: Vec<String> = galley.rows.iter().map(|row| row.text()).collect();
+
+            if hyphenate {
+                assert_eq!(row_texts, vec!["An extra\u{AD}", "ordinary ", "word"]);
+                let first_row = &galley.rows[0];
+                let hyphen = first_row
+                    .hyphen
+                    .expect("Expected a hyphen on the first row");
+                assert_eq!(hyphen.chr, '-');
+                assert!(first_row.glyphs.last().unwrap().max_x() <= hyphen.pos.x);
+                assert!(galley.rows[1].hyphen.is_none());
+            } else {
+                // The word is too long to fit, so it is broken anywhere, without a hyphen:
+                assert_eq!(row_texts, vec!["An ", "extra\u{AD}ordi", "nary ", "word"]);
+                assert!(galley.rows.iter().all(|row| row.hyphen.is_none()));
+            }
+        }
+    }
+
+    #[test]
+    fn test_hyphenator() {
+        let pixels_per_point = 1.0;
+        let mut fonts = FontsImpl::new(TextOptions::default(), FontDefinitions::default());
+        let hyphenator = Arc::new(Hyphenator::new(
+            "en",
+            "hy3ph he2n hena4 hen5at 1na n2at 1tio 2io o2n",
+        ));
+
+        let narrow = LayoutJob::single_section("Hyphenat".into(), TextFormat::default());
+        let max_width = layout(&mut fonts, pixels_per_point, narrow.into()).size().x;
+
+        for hyphenate in [false, true] {
+            let mut job = LayoutJob::single_section("Hyphenation".into(), TextFormat::default());
+            job.wrap.max_width = max_width;
+            job.wrap.hyphenate = hyphenate;
+            job.wrap.hyphenator = Some(Arc::clone(&hyphenator));
+            let galley = layout(&mut fonts, pixels_per_point, job.into());
+            let row_texts: Vec<String> = galley.rows.iter().map(|row| row.text()).collect();
+
+            if hyphenate {
+                assert_eq!(row_texts, vec!["Hyphen", "ation"]);
+                assert_eq!(galley.rows[0].hyphen.map(|hyphen| hyphen.chr), Some('-'));
+            } else {
+                assert!(galley.rows.iter().all(|row| row.hyphen.is_none()));
+            }
+        }
+    }
+
+    #[test]
+    fn test_right_to_left_logical_order() {
+        let pixels_per_point = 1.0;
+        let mut fonts = FontsImpl::new(TextOptions::default(), FontDefinitions::default());
+        let text = "abc שלום def";
+        let job = LayoutJob::single_section(text.into(), TextFormat::default());
+        let galley = layout(&mut fonts, pixels_per_point, job.into());
+
+        let row = &galley.rows[0];
+        let chars: String = row.glyphs.iter().map(|glyph| glyph.chr).collect();
+        assert_eq!(chars, text, "Glyphs should be in logical order");
+
+        // The Hebrew word is laid out right-to-left, between the two latin words:
+        let xs: Vec<f32> = row.glyphs.iter().map(|glyph| glyph.pos.x).collect();
+        let hebrew = &xs[4..8];
+        assert!(
+            hebrew.windows(2).all(|w| w[1] < w[0]),
+            "Expected decreasing x, got {hebrew:?}"
+        );
+        assert!(xs[2] < hebrew[3], "The hebrew word should be after 'abc'");
+        assert!(hebrew[0] < xs[9], "The hebrew word should be before 'def'");
+    }
+
+    #[test]
+    fn test_font_features() {
+        let pixels_per_point = 1.0;
+        let mut fonts = FontsImpl::new(TextOptions::default(), FontDefinitions::default());
+        let mut is_i_visible = |font_features: FontFeatures| {
+            let format = TextFormat {
+                font_features,
+                ..Default::default()
+            };
+            let job = LayoutJob::single_section("fi".to_owned(), format);
+            let galley = layout(&mut fonts, pixels_per_point, job.into());
+            !galley.rows[0].glyphs[1].uv_rect.is_nothing()
+        };
+
+        assert!(
+            !is_i_visible(FontFeatures::default()),
+            "The default font has an fi ligature"
+        );
+        assert!(is_i_visible(FontFeatures {
+            ligatures: false,
+            ..Default::default()
+        }));
+
+        let features = FontFeatures::default()
+            .with_stylistic_set(1, true)
+            .with_stylistic_set(20, true)
+            .with_stylistic_set(21, true);
+        assert!(features.has_stylistic_set(20));
+        assert!(!features.has_stylistic_set(21));
+        let tags: Vec<_> = features
+            .shaper_features()
+            .iter()
+            .map(|(tag, _)| tag.to_string())
+            .collect();
+        assert_eq!(tags, ["ss01", "ss20"]);
+    }
+
+    #[test]
+    fn test_extra_word_spacing() {
+        let pixels_per_point = 1.0;
+        let mut fonts = FontsImpl::new(TextOptions::default(), FontDefinitions::default());
+        let mut layout_with = |extra_word_spacing: f32| {
+            let format = TextFormat {
+                extra_word_spacing,
+                ..Default::default()
+            };
+            let job = LayoutJob::single_section("a b c".to_owned(), format);
+            layout(&mut fonts, pixels_per_point, job.into())
+        };
+
+        let plain = layout_with(0.0);
+        let spaced = layout_with(10.0);
+        assert_eq!(spaced.size().x, plain.size().x + 20.0);
+
+        let (plain, spaced) = (&plain.rows[0].glyphs, &spaced.rows[0].glyphs);
+        assert_eq!(spaced[0].pos.x, plain[0].pos.x, "'a' does not move");
+        assert_eq!(
+            spaced[1].advance_width,
+            plain[1].advance_width + 10.0,
+            "The space is wider, so the cursor and selection cover it"
+        );
+        assert_eq!(spaced[2].pos.x, plain[2].pos.x + 10.0);
+        assert_eq!(spaced[4].pos.x, plain[4].pos.x + 20.0);
+    }
+
+    #[test]
+    fn test_ruby() {
+        let pixels_per_point = 1.0;
+        let mut fonts = FontsImpl::new(TextOptions::default(), FontDefinitions::default());
+
+        let mut plain_job = LayoutJob::default();
+        plain_job.append("漢字です", 0.0, TextFormat::default());
+        let plain = layout(&mut fonts, pixels_per_point, plain_job.into());
+
+        let mut job = LayoutJob::default();
+        let ruby_format = TextFormat {
+            ruby: Some("かんじ".into()),
+            ..Default::default()
+        };
+        job.append("漢字", 0.0, ruby_format);
+        job.append("です", 0.0, TextFormat::default());
+        let galley = layout(&mut fonts, pixels_per_point, job.clone().into());
+
+        assert_eq!(galley.rows.len(), 1);
+        let row = &galley.rows[0];
+        assert_eq!(row.text(), "漢字です", "Ruby text is not part of the text");
+        assert_eq!(row.ruby.len(), 3);
+        assert!(
+            plain.size().y < galley.size().y,
+            "Ruby should make the row taller"
+        );
+
+        let base_top = row.glyphs[0].logical_rect().top();
+        for ruby_glyph in &row.ruby {
+            // Allow for pixel rounding:
+            assert!(ruby_glyph.logical_rect().bottom() <= base_top + 1.0);
+        }
+
+        // The base text is never broken:
+        job.wrap.max_width = 1.0;
+        let galley = layout(&mut fonts, pixels_per_point, job.into());
+        assert_eq!(galley.rows[0].text(), "漢字");
+    }
+
     #[test]
     fn test_truncate_width() {
         let pixels_per_point = 1.0;
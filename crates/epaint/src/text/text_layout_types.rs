@@ -2,8 +2,9 @@ use std::sync::Arc;
 use std::{ops::Range, str::FromStr as _};
 
 use super::{
-    cursor::{CCursor, LayoutCursor},
+    cursor::{CCursor, LayoutCursor, next_grapheme_boundary, previous_grapheme_boundary},
     font::UvRect,
+    hyphenation::Hyphenator,
     index::{ByteIndex, ByteRange, ByteRangeExt as _, CharIndex},
 };
 use crate::{Color32, FontId, Mesh, Stroke, text::FontsView};
@@ -465,6 +466,118 @@ impl std::hash::Hash for VariationCoords {
     }
 }
 
+/// Which optional OpenType features to use when shaping text.
+///
+/// Features the font does not support are ignored.
+///
+/// ## Example:
+/// ```
+/// use epaint::text::FontFeatures;
+///
+/// // For a table of numbers:
+/// let features = FontFeatures {
+///     tabular_numbers: true,
+///     ..Default::default()
+/// }
+/// .with_stylistic_set(1, true);
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct FontFeatures {
+    /// Use ligatures, like `fi` or `->` (the `liga`, `clig`, and `calt` features).
+    ///
+    /// Turn this off in code editors to show each character separately.
+    ///
+    /// Default: `true`.
+    pub ligatures: bool,
+
+    /// Make all digits equally wide, so that numbers line up in columns (the `tnum` feature).
+    ///
+    /// Default: `false`.
+    pub tabular_numbers: bool,
+
+    /// Show lowercase letters as small capitals (the `smcp` feature).
+    ///
+    /// Default: `false`.
+    pub small_caps: bool,
+
+    /// Which of the stylistic sets `ss01` to `ss20` to use.
+    ///
+    /// Bit `n - 1` enables `ssNN`. See [`Self::with_stylistic_set`].
+    ///
+    /// Default: `0`.
+    pub stylistic_sets: u32,
+}
+
+impl Default for FontFeatures {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            ligatures: true,
+            tabular_numbers: false,
+            small_caps: false,
+            stylistic_sets: 0,
+        }
+    }
+}
+
+impl FontFeatures {
+    /// The highest stylistic set number, `ss20`.
+    pub const MAX_STYLISTIC_SET: u8 = 20;
+
+    /// Enable or disable stylistic set `ssNN`, where `NN` is in `1..=20`.
+    ///
+    /// Other numbers are ignored.
+    #[inline]
+    pub fn with_stylistic_set(mut self, set: u8, enabled: bool) -> Self {
+        if (1..=Self::MAX_STYLISTIC_SET).contains(&set) {
+            let bit = 1 << (set - 1);
+            if enabled {
+                self.stylistic_sets |= bit;
+            } else {
+                self.stylistic_sets &= !bit;
+            }
+        }
+        self
+    }
+
+    /// Is stylistic set `ssNN` enabled?
+    #[inline]
+    pub fn has_stylistic_set(&self, set: u8) -> bool {
+        (1..=Self::MAX_STYLISTIC_SET).contains(&set) && self.stylistic_sets & (1 << (set - 1)) != 0
+    }
+
+    /// The features that differ from what the shaper does by default, as `(tag, value)` pairs.
+    pub(crate) fn shaper_features(&self) -> SmallVec<[(font_types::Tag, u32); 4]> {
+        let Self {
+            ligatures,
+            tabular_numbers,
+            small_caps,
+            stylistic_sets: _,
+        } = *self;
+
+        let mut features = SmallVec::new();
+        if !ligatures {
+            for tag in [b"liga", b"clig", b"calt"] {
+                features.push((font_types::Tag::new(tag), 0));
+            }
+        }
+        if tabular_numbers {
+            features.push((font_types::Tag::new(b"tnum"), 1));
+        }
+        if small_caps {
+            features.push((font_types::Tag::new(b"smcp"), 1));
+        }
+        for set in 1..=Self::MAX_STYLISTIC_SET {
+            if self.has_stylistic_set(set) {
+                let tag = [b's', b's', b'0' + set / 10, b'0' + set % 10];
+                features.push((font_types::Tag::new(&tag), 1));
+            }
+        }
+        features
+    }
+}
+
 /// Formatting option for a section of text.
 #[derive(Clone, Debug, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
@@ -476,6 +589,13 @@ pub struct TextFormat {
     /// Default: 0.0.
     pub extra_letter_spacing: f32,
 
+    /// Extra spacing added to each space between words, in points.
+    ///
+    /// This comes on top of [`Self::extra_letter_spacing`].
+    ///
+    /// Default: 0.0.
+    pub extra_word_spacing: f32,
+
     /// Explicit line height of the text in points.
     ///
     /// This is the distance between the bottom row of two subsequent lines of text.
@@ -513,6 +633,18 @@ pub struct TextFormat {
     /// around a common center-line, which is nice when mixining emojis
     /// and normal text in e.g. a button.
     pub valign: Align,
+
+    /// Optional OpenType features, like ligatures and tabular numbers.
+    pub font_features: FontFeatures,
+
+    /// Ruby text (e.g. Japanese furigana) to show above this section.
+    ///
+    /// The whole section is the base text of the annotation, and is never broken across rows.
+    /// The annotation is shown in a smaller font, centered above the base text,
+    /// and makes the row taller.
+    ///
+    /// Default: `None`.
+    pub ruby: Option<Arc<str>>,
 }
 
 impl Default for TextFormat {
@@ -521,6 +653,7 @@ impl Default for TextFormat {
         Self {
             font_id: FontId::default(),
             extra_letter_spacing: 0.0,
+            extra_word_spacing: 0.0,
             line_height: None,
             color: Color32::GRAY,
             background: Color32::TRANSPARENT,
@@ -530,6 +663,8 @@ impl Default for TextFormat {
             underline: Stroke::NONE,
             strikethrough: Stroke::NONE,
             valign: Align::BOTTOM,
+            font_features: FontFeatures::default(),
+            ruby: None,
         }
     }
 }
@@ -540,6 +675,7 @@ impl std::hash::Hash for TextFormat {
         let Self {
             font_id,
             extra_letter_spacing,
+            extra_word_spacing,
             line_height,
             color,
             background,
@@ -549,9 +685,12 @@ impl std::hash::Hash for TextFormat {
             underline,
             strikethrough,
             valign,
+            font_features,
+            ruby,
         } = self;
         font_id.hash(state);
         emath::OrderedFloat(*extra_letter_spacing).hash(state);
+        emath::OrderedFloat(*extra_word_spacing).hash(state);
         if let Some(line_height) = *line_height {
             emath::OrderedFloat(line_height).hash(state);
         }
@@ -563,6 +702,8 @@ impl std::hash::Hash for TextFormat {
         underline.hash(state);
         strikethrough.hash(state);
         valign.hash(state);
+        font_features.hash(state);
+        ruby.hash(state);
     }
 }
 
@@ -597,6 +738,52 @@ pub enum TextWrapMode {
     Truncate,
 }
 
+/// How to break rows in Chinese, Japanese, and Korean text.
+///
+/// The rules for which characters may not start or end a row
+/// (called _kinsoku shori_ in Japanese) differ between languages.
+/// Use [`Self::for_language`] to pick the rules for a language.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub enum CjkLineBreak {
+    /// Allow breaks between ideographs and kana, but never before closing punctuation,
+    /// small kana or the prolonged sound mark `ー`, nor after opening punctuation.
+    ///
+    /// This is the strict rule set used for Japanese.
+    #[default]
+    Strict,
+
+    /// Like [`Self::Strict`], but small kana and `ー` may start a row.
+    ///
+    /// This is common for Chinese, and for Japanese in narrow columns.
+    Normal,
+
+    /// Only break at spaces, keeping CJK words together, like for Korean.
+    KeepAll,
+
+    /// Like [`Self::Normal`], but also allow breaks between Hangul syllables.
+    ///
+    /// With the other rules Korean is only broken at spaces, which is usually what you want.
+    /// This can be useful in narrow columns.
+    BreakAll,
+}
+
+impl CjkLineBreak {
+    /// The usual rules for a language, given as a BCP 47 tag like `"ja"`, `"zh-Hant"` or `"ko-KR"`.
+    ///
+    /// Unknown languages get [`Self::Strict`].
+    pub fn for_language(language: &str) -> Self {
+        let primary = language.split(['-', '_']).next().unwrap_or_default();
+        if primary.eq_ignore_ascii_case("zh") {
+            Self::Normal
+        } else if primary.eq_ignore_ascii_case("ko") {
+            Self::KeepAll
+        } else {
+            Self::Strict
+        }
+    }
+}
+
 /// Controls the text wrapping and elision of a [`LayoutJob`].
 #[derive(Clone, Debug, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
@@ -642,6 +829,29 @@ pub struct TextWrapping {
     ///
     /// If not set, no character will be used (but the text will still be elided).
     pub overflow_character: Option<char>,
+
+    /// If `true`: allow breaking words at soft hyphens (`U+00AD`).
+    ///
+    /// When a row is broken at a soft hyphen, a `-` is shown at the end of that row.
+    /// Soft hyphens are always invisible elsewhere.
+    ///
+    /// Works well together with [`LayoutJob::justify`] for book-like paragraphs.
+    ///
+    /// Default: `false`.
+    pub hyphenate: bool,
+
+    /// If set, and [`Self::hyphenate`] is `true`, also break words where this suggests.
+    ///
+    /// Words that contain soft hyphens are only broken at those.
+    ///
+    /// Default: `None`.
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub hyphenator: Option<Arc<Hyphenator>>,
+
+    /// How to break rows in Chinese, Japanese, and Korean text.
+    ///
+    /// Default: [`CjkLineBreak::Strict`].
+    pub cjk_line_break: CjkLineBreak,
 }
 
 impl std::hash::Hash for TextWrapping {
@@ -652,11 +862,17 @@ impl std::hash::Hash for TextWrapping {
             max_rows,
             break_anywhere,
             overflow_character,
+            hyphenate,
+            hyphenator,
+            cjk_line_break,
         } = self;
         emath::OrderedFloat(*max_width).hash(state);
         max_rows.hash(state);
         break_anywhere.hash(state);
         overflow_character.hash(state);
+        hyphenate.hash(state);
+        hyphenator.hash(state);
+        cjk_line_break.hash(state);
     }
 }
 
@@ -667,6 +883,9 @@ impl Default for TextWrapping {
             max_rows: usize::MAX,
             break_anywhere: false,
             overflow_character: Some('…'),
+            hyphenate: false,
+            hyphenator: None,
+            cjk_line_break: CjkLineBreak::default(),
         }
     }
 }
@@ -831,8 +1050,20 @@ pub struct Row {
     /// One for each `char`.
     pub glyphs: Vec<Glyph>,
 
+    /// A `-` shown after the last glyph, if a word was broken at the end of this row.
+    ///
+    /// This is not part of [`Self::glyphs`], since it does not correspond to any `char` in the text.
+    /// See [`TextWrapping::hyphenate`].
+    pub hyphen: Option<Glyph>,
+
+    /// The glyphs of the ruby annotations of this row, shown above [`Self::glyphs`].
+    ///
+    /// Like [`Self::hyphen`], these do not correspond to any `char` in the text.
+    /// See [`TextFormat::ruby`].
+    pub ruby: Vec<Glyph>,
+
     /// Logical size based on font heights etc.
-    /// Includes leading and trailing whitespace.
+    /// Includes leading and trailing whitespace, the [`Self::hyphen`], and the [`Self::ruby`] annotations.
     pub size: Vec2,
 
     /// The mesh, ready to be rendered.
@@ -1313,21 +1544,26 @@ impl Galley {
 
 /// ## Cursor positions
 impl Galley {
-    #[expect(clippy::unused_self)]
+    /// Move one user-perceived character (extended grapheme cluster) to the left.
+    ///
+    /// See [`super::cursor::previous_grapheme_boundary`].
     pub fn cursor_left_one_character(&self, cursor: &CCursor) -> CCursor {
         if cursor.index == CharIndex::ZERO {
             Default::default()
         } else {
             CCursor {
-                index: cursor.index - 1,
+                index: previous_grapheme_boundary(self.text(), cursor.index),
                 prefer_next_row: true, // default to this when navigating. It is more often useful to put cursor at the beginning of a row than at the end.
             }
         }
     }
 
+    /// Move one user-perceived character (extended grapheme cluster) to the right.
+    ///
+    /// See [`super::cursor::next_grapheme_boundary`].
     pub fn cursor_right_one_character(&self, cursor: &CCursor) -> CCursor {
         CCursor {
-            index: (cursor.index + 1).min(self.end().index),
+            index: next_grapheme_boundary(self.text(), cursor.index).min(self.end().index),
             prefer_next_row: true, // default to this when navigating. It is more often useful to put cursor at the beginning of a row than at the end.
         }
     }
@@ -165,10 +165,21 @@ pub struct TextureOptions {
     /// Mipmaps ensures textures look smooth even when the texture is very small and pixels are much
     /// larger than individual texels.
     ///
+    /// The mipmaps are generated by the backend when the texture is uploaded.
+    pub mipmap_mode: Option<TextureFilter>,
+
+    /// The maximum level of anisotropic filtering, e.g. `16`.
+    ///
+    /// Anisotropic filtering keeps textures sharp when they are viewed at a steep angle,
+    /// or are scaled down more in one direction than the other.
+    /// `1` (or `0`) turns it off.
+    ///
     /// # Notes
     ///
-    /// - This may not be available on all backends (currently only `egui_glow`).
-    pub mipmap_mode: Option<TextureFilter>,
+    /// - Clamped to what the backend supports.
+    /// - `egui-wgpu` only uses it if all filters (including [`Self::mipmap_mode`]) are [`TextureFilter::Linear`].
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub max_anisotropy: u8,
 }
 
 impl TextureOptions {
@@ -178,6 +189,7 @@ impl TextureOptions {
         minification: TextureFilter::Linear,
         wrap_mode: TextureWrapMode::ClampToEdge,
         mipmap_mode: None,
+        max_anisotropy: 1,
     };
 
     /// Nearest magnification and minification.
@@ -186,6 +198,7 @@ impl TextureOptions {
         minification: TextureFilter::Nearest,
         wrap_mode: TextureWrapMode::ClampToEdge,
         mipmap_mode: None,
+        max_anisotropy: 1,
     };
 
     /// Linear magnification and minification, but with the texture repeated.
@@ -194,6 +207,7 @@ impl TextureOptions {
         minification: TextureFilter::Linear,
         wrap_mode: TextureWrapMode::Repeat,
         mipmap_mode: None,
+        max_anisotropy: 1,
     };
 
     /// Linear magnification and minification, but with the texture mirrored and repeated.
@@ -202,6 +216,7 @@ impl TextureOptions {
         minification: TextureFilter::Linear,
         wrap_mode: TextureWrapMode::MirroredRepeat,
         mipmap_mode: None,
+        max_anisotropy: 1,
     };
 
     /// Nearest magnification and minification, but with the texture repeated.
@@ -210,6 +225,7 @@ impl TextureOptions {
         minification: TextureFilter::Nearest,
         wrap_mode: TextureWrapMode::Repeat,
         mipmap_mode: None,
+        max_anisotropy: 1,
     };
 
     /// Nearest magnification and minification, but with the texture mirrored and repeated.
@@ -218,14 +234,29 @@ impl TextureOptions {
         minification: TextureFilter::Nearest,
         wrap_mode: TextureWrapMode::MirroredRepeat,
         mipmap_mode: None,
+        max_anisotropy: 1,
     };
 
+    /// Linear magnification and minification, and linear filtering between mipmaps.
+    ///
+    /// Use this for images that are shown much smaller than their size,
+    /// e.g. thumbnails or zoomed out canvases, to avoid aliasing and shimmering.
+    pub const TRILINEAR: Self = Self::LINEAR.with_mipmap_mode(Some(TextureFilter::Linear));
+
     pub const fn with_mipmap_mode(self, mipmap_mode: Option<TextureFilter>) -> Self {
         Self {
             mipmap_mode,
             ..self
         }
     }
+
+    /// See [`Self::max_anisotropy`].
+    pub const fn with_max_anisotropy(self, max_anisotropy: u8) -> Self {
+        Self {
+            max_anisotropy,
+            ..self
+        }
+    }
 }
 
 impl Default for TextureOptions {
@@ -96,6 +96,29 @@ fn text_edit_rtl() {
     }
 }
 
+#[test]
+fn text_edit_moves_and_deletes_grapheme_clusters() {
+    let family = "\u{1F468}\u{200D}\u{1F469}\u{200D}\u{1F467}";
+    let mut harness = Harness::new_ui_state(
+        |ui, text: &mut String| {
+            ui.add(egui::TextEdit::singleline(text));
+        },
+        format!("a{family}e\u{301}"),
+    );
+
+    harness.get_by_role(Role::TextInput).focus();
+    harness.step();
+    harness.key_press(egui::Key::End);
+    harness.key_press(egui::Key::Backspace);
+    harness.run();
+    assert_eq!(harness.state(), &format!("a{family}"));
+
+    harness.key_press(egui::Key::ArrowLeft);
+    harness.key_press(egui::Key::Delete);
+    harness.run();
+    assert_eq!(harness.state(), "a");
+}
+
 #[test]
 fn text_edit_halign() {
     let mut harness = Harness::builder().with_size((212.0, 212.0)).build_ui(|ui| {
@@ -473,3 +496,49 @@ fn animated_scroll_beats_sticky_bottom() {
         "animated explicit scroll should leave the sticky bottom"
     );
 }
+
+#[test]
+fn focused_text_edit_stays_visible_when_safe_area_changes() {
+    let id = egui::Id::new("text_edit");
+    let mut text = String::new();
+    let mut harness = Harness::builder().with_size((200.0, 300.0)).build_ui_state(
+        |ui, text_edit_rect: &mut egui::Rect| {
+            ui.style_mut().scroll_animation = ScrollAnimation::none();
+            let max_height = ui.ctx().content_rect().bottom() - ui.cursor().top();
+            ScrollArea::vertical()
+                .max_height(max_height)
+                .show(ui, |ui| {
+                    for i in 0..10 {
+                        ui.label(format!("Label {i}"));
+                    }
+                    *text_edit_rect = ui.add(egui::TextEdit::singleline(&mut text).id(id)).rect;
+                    for i in 10..20 {
+                        ui.label(format!("Label {i}"));
+                    }
+                });
+        },
+        egui::Rect::NOTHING,
+    );
+
+    harness.ctx.memory_mut(|mem| mem.request_focus(id));
+    harness.run();
+    let before = *harness.state();
+
+    // The on-screen keyboard covers the bottom of the screen:
+    harness.input_mut().safe_area_insets = Some(egui::SafeAreaInsets(egui::epaint::MarginF32 {
+        bottom: 200.0,
+        ..Default::default()
+    }));
+    harness.run();
+    let after = *harness.state();
+
+    let content_rect = harness.ctx.content_rect();
+    assert!(
+        before.max.y > content_rect.max.y,
+        "the text edit should start out covered by the keyboard"
+    );
+    assert!(
+        content_rect.contains_rect(after),
+        "the text edit should have been scrolled into view: {after:?} is not in {content_rect:?}"
+    );
+}